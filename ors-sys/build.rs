@@ -34,6 +34,10 @@ const ORT_ENV_GPU: &str = "ORT_USE_CUDA";
 /// Subdirectory (of the 'target' directory) into which to extract the prebuilt library.
 const ORT_PREBUILT_EXTRACT_DIR: &str = "onnxruntime";
 
+/// Name of the compile-time environment variable which exposes the library directory to `ors-sys`.
+/// `ors` uses it as one of the locations searched when loading onnxruntime at runtime.
+const ORS_SYS_LIB_DIR: &str = "ORS_SYS_LIB_DIR";
+
 fn main() {
     let libort_install_dir = prepare_libort_dir();
 
@@ -47,6 +51,8 @@ fn main() {
     println!("cargo:rerun-if-env-changed={}", ORT_ENV_GPU);
     println!("cargo:rerun-if-env-changed={}", ORT_ENV_SYSTEM_LIB_LOCATION);
 
+    println!("cargo:rustc-env={}={}", ORS_SYS_LIB_DIR, lib_dir.display());
+
     generate_bindings(&include_dir);
 }
 
@@ -156,7 +162,7 @@ fn extract_archive(filename: &Path, output: &Path) {
 }
 
 fn extract_tgz(filename: &Path, output: &Path) {
-    let file = fs::File::open(&filename).unwrap();
+    let file = fs::File::open(filename).unwrap();
     let buf = io::BufReader::new(file);
     let tar = flate2::read::GzDecoder::new(buf);
     let mut archive = tar::Archive::new(tar);
@@ -164,14 +170,14 @@ fn extract_tgz(filename: &Path, output: &Path) {
 }
 
 fn extract_zip(filename: &Path, outpath: &Path) {
-    let file = fs::File::open(&filename).unwrap();
+    let file = fs::File::open(filename).unwrap();
     let buf = io::BufReader::new(file);
     let mut archive = zip::ZipArchive::new(buf).unwrap();
     for i in 0..archive.len() {
         let mut file = archive.by_index(i).unwrap();
         #[allow(deprecated)]
         let outpath = outpath.join(file.sanitized_name());
-        if !(&*file.name()).ends_with('/') {
+        if !file.name().ends_with('/') {
             println!(
                 "File {} extracted to \"{}\" ({} bytes)",
                 i,
@@ -180,7 +186,7 @@ fn extract_zip(filename: &Path, outpath: &Path) {
             );
             if let Some(p) = outpath.parent() {
                 if !p.exists() {
-                    fs::create_dir_all(&p).unwrap();
+                    fs::create_dir_all(p).unwrap();
                 }
             }
             let mut outfile = fs::File::create(&outpath).unwrap();
@@ -190,7 +196,7 @@ fn extract_zip(filename: &Path, outpath: &Path) {
 }
 
 trait OnnxPrebuiltArchive {
-    fn as_onnx_str(&self) -> Cow<str>;
+    fn as_onnx_str(&self) -> Cow<'_, str>;
 }
 
 #[derive(Debug)]
@@ -216,7 +222,7 @@ impl FromStr for Architecture {
 }
 
 impl OnnxPrebuiltArchive for Architecture {
-    fn as_onnx_str(&self) -> Cow<str> {
+    fn as_onnx_str(&self) -> Cow<'_, str> {
         match self {
             Architecture::X86 => Cow::from("x86"),
             Architecture::X86_64 => Cow::from("x64"),
@@ -258,7 +264,7 @@ impl FromStr for Os {
 }
 
 impl OnnxPrebuiltArchive for Os {
-    fn as_onnx_str(&self) -> Cow<str> {
+    fn as_onnx_str(&self) -> Cow<'_, str> {
         match self {
             Os::Windows => Cow::from("win"),
             Os::Linux => Cow::from("linux"),
@@ -285,7 +291,7 @@ impl FromStr for Accelerator {
 }
 
 impl OnnxPrebuiltArchive for Accelerator {
-    fn as_onnx_str(&self) -> Cow<str> {
+    fn as_onnx_str(&self) -> Cow<'_, str> {
         match self {
             Accelerator::None => Cow::from(""),
             Accelerator::Gpu => Cow::from("gpu"),
@@ -303,7 +309,7 @@ struct Triplet {
 // Filenaming of onnxruntime's release is sometimes casual, it's hard to parse the archive name automatically
 // So check the released files when upgrading the onnxruntime version
 impl OnnxPrebuiltArchive for Triplet {
-    fn as_onnx_str(&self) -> Cow<str> {
+    fn as_onnx_str(&self) -> Cow<'_, str> {
         match (&self.os, &self.arch, &self.accelerator) {
             // onnxruntime-win-x86-1.12.0.zip
             // onnxruntime-win-x64-1.12.0.zip
@@ -402,7 +408,21 @@ fn prepare_libort_dir_prebuilt() -> PathBuf {
     extract_dir.join(prebuilt_archive.file_stem().unwrap())
 }
 
+/// Prepare the onnxruntime directory and, when it's linked at compile time, tell cargo to link it
 fn prepare_libort_dir() -> PathBuf {
+    let libort_install_dir = prepare_libort_dir_with_strategy();
+
+    // `CARGO_FEATURE_*` is set for build scripts of the crate whose feature is enabled
+    if env::var_os("CARGO_FEATURE_DYNAMIC_LOADING").is_none() {
+        let lib_dir = libort_install_dir.join("lib");
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!("cargo:rustc-link-lib=dylib=onnxruntime");
+    }
+
+    libort_install_dir
+}
+
+fn prepare_libort_dir_with_strategy() -> PathBuf {
     let strategy = env::var(ORT_ENV_STRATEGY);
     println!(
         "strategy: {:?}",
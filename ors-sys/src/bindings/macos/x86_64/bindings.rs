@@ -0,0 +1,22652 @@
+/* automatically generated by rust-bindgen 0.60.1 */
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+pub const __API_TO_BE_DEPRECATED: u32 = 100000;
+pub const __MAC_10_0: u32 = 1000;
+pub const __MAC_10_1: u32 = 1010;
+pub const __MAC_10_2: u32 = 1020;
+pub const __MAC_10_3: u32 = 1030;
+pub const __MAC_10_4: u32 = 1040;
+pub const __MAC_10_5: u32 = 1050;
+pub const __MAC_10_6: u32 = 1060;
+pub const __MAC_10_7: u32 = 1070;
+pub const __MAC_10_8: u32 = 1080;
+pub const __MAC_10_9: u32 = 1090;
+pub const __MAC_10_10: u32 = 101000;
+pub const __MAC_10_10_2: u32 = 101002;
+pub const __MAC_10_10_3: u32 = 101003;
+pub const __MAC_10_11: u32 = 101100;
+pub const __MAC_10_11_2: u32 = 101102;
+pub const __MAC_10_11_3: u32 = 101103;
+pub const __MAC_10_11_4: u32 = 101104;
+pub const __MAC_10_12: u32 = 101200;
+pub const __MAC_10_12_1: u32 = 101201;
+pub const __MAC_10_12_2: u32 = 101202;
+pub const __MAC_10_12_4: u32 = 101204;
+pub const __MAC_10_13: u32 = 101300;
+pub const __MAC_10_13_1: u32 = 101301;
+pub const __MAC_10_13_2: u32 = 101302;
+pub const __MAC_10_13_4: u32 = 101304;
+pub const __MAC_10_14: u32 = 101400;
+pub const __MAC_10_14_1: u32 = 101401;
+pub const __MAC_10_14_4: u32 = 101404;
+pub const __MAC_10_14_6: u32 = 101406;
+pub const __MAC_10_15: u32 = 101500;
+pub const __MAC_10_15_1: u32 = 101501;
+pub const __MAC_10_15_4: u32 = 101504;
+pub const __MAC_10_16: u32 = 101600;
+pub const __MAC_11_0: u32 = 110000;
+pub const __MAC_11_1: u32 = 110100;
+pub const __MAC_11_3: u32 = 110300;
+pub const __MAC_11_4: u32 = 110400;
+pub const __MAC_11_5: u32 = 110500;
+pub const __MAC_11_6: u32 = 110600;
+pub const __MAC_12_0: u32 = 120000;
+pub const __MAC_12_1: u32 = 120100;
+pub const __IPHONE_2_0: u32 = 20000;
+pub const __IPHONE_2_1: u32 = 20100;
+pub const __IPHONE_2_2: u32 = 20200;
+pub const __IPHONE_3_0: u32 = 30000;
+pub const __IPHONE_3_1: u32 = 30100;
+pub const __IPHONE_3_2: u32 = 30200;
+pub const __IPHONE_4_0: u32 = 40000;
+pub const __IPHONE_4_1: u32 = 40100;
+pub const __IPHONE_4_2: u32 = 40200;
+pub const __IPHONE_4_3: u32 = 40300;
+pub const __IPHONE_5_0: u32 = 50000;
+pub const __IPHONE_5_1: u32 = 50100;
+pub const __IPHONE_6_0: u32 = 60000;
+pub const __IPHONE_6_1: u32 = 60100;
+pub const __IPHONE_7_0: u32 = 70000;
+pub const __IPHONE_7_1: u32 = 70100;
+pub const __IPHONE_8_0: u32 = 80000;
+pub const __IPHONE_8_1: u32 = 80100;
+pub const __IPHONE_8_2: u32 = 80200;
+pub const __IPHONE_8_3: u32 = 80300;
+pub const __IPHONE_8_4: u32 = 80400;
+pub const __IPHONE_9_0: u32 = 90000;
+pub const __IPHONE_9_1: u32 = 90100;
+pub const __IPHONE_9_2: u32 = 90200;
+pub const __IPHONE_9_3: u32 = 90300;
+pub const __IPHONE_10_0: u32 = 100000;
+pub const __IPHONE_10_1: u32 = 100100;
+pub const __IPHONE_10_2: u32 = 100200;
+pub const __IPHONE_10_3: u32 = 100300;
+pub const __IPHONE_11_0: u32 = 110000;
+pub const __IPHONE_11_1: u32 = 110100;
+pub const __IPHONE_11_2: u32 = 110200;
+pub const __IPHONE_11_3: u32 = 110300;
+pub const __IPHONE_11_4: u32 = 110400;
+pub const __IPHONE_12_0: u32 = 120000;
+pub const __IPHONE_12_1: u32 = 120100;
+pub const __IPHONE_12_2: u32 = 120200;
+pub const __IPHONE_12_3: u32 = 120300;
+pub const __IPHONE_12_4: u32 = 120400;
+pub const __IPHONE_13_0: u32 = 130000;
+pub const __IPHONE_13_1: u32 = 130100;
+pub const __IPHONE_13_2: u32 = 130200;
+pub const __IPHONE_13_3: u32 = 130300;
+pub const __IPHONE_13_4: u32 = 130400;
+pub const __IPHONE_13_5: u32 = 130500;
+pub const __IPHONE_13_6: u32 = 130600;
+pub const __IPHONE_13_7: u32 = 130700;
+pub const __IPHONE_14_0: u32 = 140000;
+pub const __IPHONE_14_1: u32 = 140100;
+pub const __IPHONE_14_2: u32 = 140200;
+pub const __IPHONE_14_3: u32 = 140300;
+pub const __IPHONE_14_5: u32 = 140500;
+pub const __IPHONE_14_6: u32 = 140600;
+pub const __IPHONE_14_7: u32 = 140700;
+pub const __IPHONE_14_8: u32 = 140800;
+pub const __IPHONE_15_0: u32 = 150000;
+pub const __IPHONE_15_1: u32 = 150100;
+pub const __IPHONE_15_2: u32 = 150200;
+pub const __TVOS_9_0: u32 = 90000;
+pub const __TVOS_9_1: u32 = 90100;
+pub const __TVOS_9_2: u32 = 90200;
+pub const __TVOS_10_0: u32 = 100000;
+pub const __TVOS_10_0_1: u32 = 100001;
+pub const __TVOS_10_1: u32 = 100100;
+pub const __TVOS_10_2: u32 = 100200;
+pub const __TVOS_11_0: u32 = 110000;
+pub const __TVOS_11_1: u32 = 110100;
+pub const __TVOS_11_2: u32 = 110200;
+pub const __TVOS_11_3: u32 = 110300;
+pub const __TVOS_11_4: u32 = 110400;
+pub const __TVOS_12_0: u32 = 120000;
+pub const __TVOS_12_1: u32 = 120100;
+pub const __TVOS_12_2: u32 = 120200;
+pub const __TVOS_12_3: u32 = 120300;
+pub const __TVOS_12_4: u32 = 120400;
+pub const __TVOS_13_0: u32 = 130000;
+pub const __TVOS_13_2: u32 = 130200;
+pub const __TVOS_13_3: u32 = 130300;
+pub const __TVOS_13_4: u32 = 130400;
+pub const __TVOS_14_0: u32 = 140000;
+pub const __TVOS_14_1: u32 = 140100;
+pub const __TVOS_14_2: u32 = 140200;
+pub const __TVOS_14_3: u32 = 140300;
+pub const __TVOS_14_5: u32 = 140500;
+pub const __TVOS_14_6: u32 = 140600;
+pub const __TVOS_14_7: u32 = 140700;
+pub const __TVOS_15_0: u32 = 150000;
+pub const __TVOS_15_1: u32 = 150100;
+pub const __TVOS_15_2: u32 = 150200;
+pub const __WATCHOS_1_0: u32 = 10000;
+pub const __WATCHOS_2_0: u32 = 20000;
+pub const __WATCHOS_2_1: u32 = 20100;
+pub const __WATCHOS_2_2: u32 = 20200;
+pub const __WATCHOS_3_0: u32 = 30000;
+pub const __WATCHOS_3_1: u32 = 30100;
+pub const __WATCHOS_3_1_1: u32 = 30101;
+pub const __WATCHOS_3_2: u32 = 30200;
+pub const __WATCHOS_4_0: u32 = 40000;
+pub const __WATCHOS_4_1: u32 = 40100;
+pub const __WATCHOS_4_2: u32 = 40200;
+pub const __WATCHOS_4_3: u32 = 40300;
+pub const __WATCHOS_5_0: u32 = 50000;
+pub const __WATCHOS_5_1: u32 = 50100;
+pub const __WATCHOS_5_2: u32 = 50200;
+pub const __WATCHOS_5_3: u32 = 50300;
+pub const __WATCHOS_6_0: u32 = 60000;
+pub const __WATCHOS_6_1: u32 = 60100;
+pub const __WATCHOS_6_2: u32 = 60200;
+pub const __WATCHOS_7_0: u32 = 70000;
+pub const __WATCHOS_7_1: u32 = 70100;
+pub const __WATCHOS_7_2: u32 = 70200;
+pub const __WATCHOS_7_3: u32 = 70300;
+pub const __WATCHOS_7_4: u32 = 70400;
+pub const __WATCHOS_7_5: u32 = 70500;
+pub const __WATCHOS_7_6: u32 = 70600;
+pub const __WATCHOS_8_0: u32 = 80000;
+pub const __WATCHOS_8_1: u32 = 80100;
+pub const __WATCHOS_8_3: u32 = 80300;
+pub const MAC_OS_X_VERSION_10_0: u32 = 1000;
+pub const MAC_OS_X_VERSION_10_1: u32 = 1010;
+pub const MAC_OS_X_VERSION_10_2: u32 = 1020;
+pub const MAC_OS_X_VERSION_10_3: u32 = 1030;
+pub const MAC_OS_X_VERSION_10_4: u32 = 1040;
+pub const MAC_OS_X_VERSION_10_5: u32 = 1050;
+pub const MAC_OS_X_VERSION_10_6: u32 = 1060;
+pub const MAC_OS_X_VERSION_10_7: u32 = 1070;
+pub const MAC_OS_X_VERSION_10_8: u32 = 1080;
+pub const MAC_OS_X_VERSION_10_9: u32 = 1090;
+pub const MAC_OS_X_VERSION_10_10: u32 = 101000;
+pub const MAC_OS_X_VERSION_10_10_2: u32 = 101002;
+pub const MAC_OS_X_VERSION_10_10_3: u32 = 101003;
+pub const MAC_OS_X_VERSION_10_11: u32 = 101100;
+pub const MAC_OS_X_VERSION_10_11_2: u32 = 101102;
+pub const MAC_OS_X_VERSION_10_11_3: u32 = 101103;
+pub const MAC_OS_X_VERSION_10_11_4: u32 = 101104;
+pub const MAC_OS_X_VERSION_10_12: u32 = 101200;
+pub const MAC_OS_X_VERSION_10_12_1: u32 = 101201;
+pub const MAC_OS_X_VERSION_10_12_2: u32 = 101202;
+pub const MAC_OS_X_VERSION_10_12_4: u32 = 101204;
+pub const MAC_OS_X_VERSION_10_13: u32 = 101300;
+pub const MAC_OS_X_VERSION_10_13_1: u32 = 101301;
+pub const MAC_OS_X_VERSION_10_13_2: u32 = 101302;
+pub const MAC_OS_X_VERSION_10_13_4: u32 = 101304;
+pub const MAC_OS_X_VERSION_10_14: u32 = 101400;
+pub const MAC_OS_X_VERSION_10_14_1: u32 = 101401;
+pub const MAC_OS_X_VERSION_10_14_4: u32 = 101404;
+pub const MAC_OS_X_VERSION_10_14_6: u32 = 101406;
+pub const MAC_OS_X_VERSION_10_15: u32 = 101500;
+pub const MAC_OS_X_VERSION_10_15_1: u32 = 101501;
+pub const MAC_OS_X_VERSION_10_16: u32 = 101600;
+pub const MAC_OS_VERSION_11_0: u32 = 110000;
+pub const MAC_OS_VERSION_12_0: u32 = 120000;
+pub const __DRIVERKIT_19_0: u32 = 190000;
+pub const __DRIVERKIT_20_0: u32 = 200000;
+pub const __DRIVERKIT_21_0: u32 = 210000;
+pub const __MAC_OS_X_VERSION_MAX_ALLOWED: u32 = 120100;
+pub const __ENABLE_LEGACY_MAC_AVAILABILITY: u32 = 1;
+pub const __DARWIN_ONLY_64_BIT_INO_T: u32 = 0;
+pub const __DARWIN_ONLY_UNIX_CONFORMANCE: u32 = 1;
+pub const __DARWIN_ONLY_VERS_1050: u32 = 0;
+pub const __DARWIN_UNIX03: u32 = 1;
+pub const __DARWIN_64_BIT_INO_T: u32 = 1;
+pub const __DARWIN_VERS_1050: u32 = 1;
+pub const __DARWIN_NON_CANCELABLE: u32 = 0;
+pub const __DARWIN_SUF_64_BIT_INO_T: &[u8; 9usize] = b"$INODE64\0";
+pub const __DARWIN_SUF_1050: &[u8; 6usize] = b"$1050\0";
+pub const __DARWIN_SUF_EXTSN: &[u8; 14usize] = b"$DARWIN_EXTSN\0";
+pub const __DARWIN_C_ANSI: u32 = 4096;
+pub const __DARWIN_C_FULL: u32 = 900000;
+pub const __DARWIN_C_LEVEL: u32 = 900000;
+pub const __STDC_WANT_LIB_EXT1__: u32 = 1;
+pub const __DARWIN_NO_LONG_LONG: u32 = 0;
+pub const _DARWIN_FEATURE_64_BIT_INODE: u32 = 1;
+pub const _DARWIN_FEATURE_ONLY_UNIX_CONFORMANCE: u32 = 1;
+pub const _DARWIN_FEATURE_UNIX_CONFORMANCE: u32 = 3;
+pub const __PTHREAD_SIZE__: u32 = 8176;
+pub const __PTHREAD_ATTR_SIZE__: u32 = 56;
+pub const __PTHREAD_MUTEXATTR_SIZE__: u32 = 8;
+pub const __PTHREAD_MUTEX_SIZE__: u32 = 56;
+pub const __PTHREAD_CONDATTR_SIZE__: u32 = 8;
+pub const __PTHREAD_COND_SIZE__: u32 = 40;
+pub const __PTHREAD_ONCE_SIZE__: u32 = 8;
+pub const __PTHREAD_RWLOCK_SIZE__: u32 = 192;
+pub const __PTHREAD_RWLOCKATTR_SIZE__: u32 = 16;
+pub const __DARWIN_WCHAR_MIN: i32 = -2147483648;
+pub const _FORTIFY_SOURCE: u32 = 2;
+pub const __DARWIN_NSIG: u32 = 32;
+pub const NSIG: u32 = 32;
+pub const _I386_SIGNAL_H_: u32 = 1;
+pub const SIGHUP: u32 = 1;
+pub const SIGINT: u32 = 2;
+pub const SIGQUIT: u32 = 3;
+pub const SIGILL: u32 = 4;
+pub const SIGTRAP: u32 = 5;
+pub const SIGABRT: u32 = 6;
+pub const SIGIOT: u32 = 6;
+pub const SIGEMT: u32 = 7;
+pub const SIGFPE: u32 = 8;
+pub const SIGKILL: u32 = 9;
+pub const SIGBUS: u32 = 10;
+pub const SIGSEGV: u32 = 11;
+pub const SIGSYS: u32 = 12;
+pub const SIGPIPE: u32 = 13;
+pub const SIGALRM: u32 = 14;
+pub const SIGTERM: u32 = 15;
+pub const SIGURG: u32 = 16;
+pub const SIGSTOP: u32 = 17;
+pub const SIGTSTP: u32 = 18;
+pub const SIGCONT: u32 = 19;
+pub const SIGCHLD: u32 = 20;
+pub const SIGTTIN: u32 = 21;
+pub const SIGTTOU: u32 = 22;
+pub const SIGIO: u32 = 23;
+pub const SIGXCPU: u32 = 24;
+pub const SIGXFSZ: u32 = 25;
+pub const SIGVTALRM: u32 = 26;
+pub const SIGPROF: u32 = 27;
+pub const SIGWINCH: u32 = 28;
+pub const SIGINFO: u32 = 29;
+pub const SIGUSR1: u32 = 30;
+pub const SIGUSR2: u32 = 31;
+pub const FP_PREC_24B: u32 = 0;
+pub const FP_PREC_53B: u32 = 2;
+pub const FP_PREC_64B: u32 = 3;
+pub const FP_RND_NEAR: u32 = 0;
+pub const FP_RND_DOWN: u32 = 1;
+pub const FP_RND_UP: u32 = 2;
+pub const FP_CHOP: u32 = 3;
+pub const FP_STATE_BYTES: u32 = 512;
+pub const _X86_INSTRUCTION_STATE_MAX_INSN_BYTES: u32 = 2380;
+pub const _X86_INSTRUCTION_STATE_CACHELINE_SIZE: u32 = 64;
+pub const __LASTBRANCH_MAX: u32 = 32;
+pub const SIGEV_NONE: u32 = 0;
+pub const SIGEV_SIGNAL: u32 = 1;
+pub const SIGEV_THREAD: u32 = 3;
+pub const ILL_NOOP: u32 = 0;
+pub const ILL_ILLOPC: u32 = 1;
+pub const ILL_ILLTRP: u32 = 2;
+pub const ILL_PRVOPC: u32 = 3;
+pub const ILL_ILLOPN: u32 = 4;
+pub const ILL_ILLADR: u32 = 5;
+pub const ILL_PRVREG: u32 = 6;
+pub const ILL_COPROC: u32 = 7;
+pub const ILL_BADSTK: u32 = 8;
+pub const FPE_NOOP: u32 = 0;
+pub const FPE_FLTDIV: u32 = 1;
+pub const FPE_FLTOVF: u32 = 2;
+pub const FPE_FLTUND: u32 = 3;
+pub const FPE_FLTRES: u32 = 4;
+pub const FPE_FLTINV: u32 = 5;
+pub const FPE_FLTSUB: u32 = 6;
+pub const FPE_INTDIV: u32 = 7;
+pub const FPE_INTOVF: u32 = 8;
+pub const SEGV_NOOP: u32 = 0;
+pub const SEGV_MAPERR: u32 = 1;
+pub const SEGV_ACCERR: u32 = 2;
+pub const BUS_NOOP: u32 = 0;
+pub const BUS_ADRALN: u32 = 1;
+pub const BUS_ADRERR: u32 = 2;
+pub const BUS_OBJERR: u32 = 3;
+pub const TRAP_BRKPT: u32 = 1;
+pub const TRAP_TRACE: u32 = 2;
+pub const CLD_NOOP: u32 = 0;
+pub const CLD_EXITED: u32 = 1;
+pub const CLD_KILLED: u32 = 2;
+pub const CLD_DUMPED: u32 = 3;
+pub const CLD_TRAPPED: u32 = 4;
+pub const CLD_STOPPED: u32 = 5;
+pub const CLD_CONTINUED: u32 = 6;
+pub const POLL_IN: u32 = 1;
+pub const POLL_OUT: u32 = 2;
+pub const POLL_MSG: u32 = 3;
+pub const POLL_ERR: u32 = 4;
+pub const POLL_PRI: u32 = 5;
+pub const POLL_HUP: u32 = 6;
+pub const SA_ONSTACK: u32 = 1;
+pub const SA_RESTART: u32 = 2;
+pub const SA_RESETHAND: u32 = 4;
+pub const SA_NOCLDSTOP: u32 = 8;
+pub const SA_NODEFER: u32 = 16;
+pub const SA_NOCLDWAIT: u32 = 32;
+pub const SA_SIGINFO: u32 = 64;
+pub const SA_USERTRAMP: u32 = 256;
+pub const SA_64REGSET: u32 = 512;
+pub const SA_USERSPACE_MASK: u32 = 127;
+pub const SIG_BLOCK: u32 = 1;
+pub const SIG_UNBLOCK: u32 = 2;
+pub const SIG_SETMASK: u32 = 3;
+pub const SI_USER: u32 = 65537;
+pub const SI_QUEUE: u32 = 65538;
+pub const SI_TIMER: u32 = 65539;
+pub const SI_ASYNCIO: u32 = 65540;
+pub const SI_MESGQ: u32 = 65541;
+pub const SS_ONSTACK: u32 = 1;
+pub const SS_DISABLE: u32 = 4;
+pub const MINSIGSTKSZ: u32 = 32768;
+pub const SIGSTKSZ: u32 = 131072;
+pub const SV_ONSTACK: u32 = 1;
+pub const SV_INTERRUPT: u32 = 2;
+pub const SV_RESETHAND: u32 = 4;
+pub const SV_NODEFER: u32 = 16;
+pub const SV_NOCLDSTOP: u32 = 8;
+pub const SV_SIGINFO: u32 = 64;
+pub const __WORDSIZE: u32 = 64;
+pub const INT8_MAX: u32 = 127;
+pub const INT16_MAX: u32 = 32767;
+pub const INT32_MAX: u32 = 2147483647;
+pub const INT64_MAX: u64 = 9223372036854775807;
+pub const INT8_MIN: i32 = -128;
+pub const INT16_MIN: i32 = -32768;
+pub const INT32_MIN: i32 = -2147483648;
+pub const INT64_MIN: i64 = -9223372036854775808;
+pub const UINT8_MAX: u32 = 255;
+pub const UINT16_MAX: u32 = 65535;
+pub const UINT32_MAX: u32 = 4294967295;
+pub const UINT64_MAX: i32 = -1;
+pub const INT_LEAST8_MIN: i32 = -128;
+pub const INT_LEAST16_MIN: i32 = -32768;
+pub const INT_LEAST32_MIN: i32 = -2147483648;
+pub const INT_LEAST64_MIN: i64 = -9223372036854775808;
+pub const INT_LEAST8_MAX: u32 = 127;
+pub const INT_LEAST16_MAX: u32 = 32767;
+pub const INT_LEAST32_MAX: u32 = 2147483647;
+pub const INT_LEAST64_MAX: u64 = 9223372036854775807;
+pub const UINT_LEAST8_MAX: u32 = 255;
+pub const UINT_LEAST16_MAX: u32 = 65535;
+pub const UINT_LEAST32_MAX: u32 = 4294967295;
+pub const UINT_LEAST64_MAX: i32 = -1;
+pub const INT_FAST8_MIN: i32 = -128;
+pub const INT_FAST16_MIN: i32 = -32768;
+pub const INT_FAST32_MIN: i32 = -2147483648;
+pub const INT_FAST64_MIN: i64 = -9223372036854775808;
+pub const INT_FAST8_MAX: u32 = 127;
+pub const INT_FAST16_MAX: u32 = 32767;
+pub const INT_FAST32_MAX: u32 = 2147483647;
+pub const INT_FAST64_MAX: u64 = 9223372036854775807;
+pub const UINT_FAST8_MAX: u32 = 255;
+pub const UINT_FAST16_MAX: u32 = 65535;
+pub const UINT_FAST32_MAX: u32 = 4294967295;
+pub const UINT_FAST64_MAX: i32 = -1;
+pub const INTPTR_MAX: u64 = 9223372036854775807;
+pub const INTPTR_MIN: i64 = -9223372036854775808;
+pub const UINTPTR_MAX: i32 = -1;
+pub const SIZE_MAX: i32 = -1;
+pub const RSIZE_MAX: i32 = -1;
+pub const WINT_MIN: i32 = -2147483648;
+pub const WINT_MAX: u32 = 2147483647;
+pub const SIG_ATOMIC_MIN: i32 = -2147483648;
+pub const SIG_ATOMIC_MAX: u32 = 2147483647;
+pub const PRIO_PROCESS: u32 = 0;
+pub const PRIO_PGRP: u32 = 1;
+pub const PRIO_USER: u32 = 2;
+pub const PRIO_DARWIN_THREAD: u32 = 3;
+pub const PRIO_DARWIN_PROCESS: u32 = 4;
+pub const PRIO_MIN: i32 = -20;
+pub const PRIO_MAX: u32 = 20;
+pub const PRIO_DARWIN_BG: u32 = 4096;
+pub const PRIO_DARWIN_NONUI: u32 = 4097;
+pub const RUSAGE_SELF: u32 = 0;
+pub const RUSAGE_CHILDREN: i32 = -1;
+pub const RUSAGE_INFO_V0: u32 = 0;
+pub const RUSAGE_INFO_V1: u32 = 1;
+pub const RUSAGE_INFO_V2: u32 = 2;
+pub const RUSAGE_INFO_V3: u32 = 3;
+pub const RUSAGE_INFO_V4: u32 = 4;
+pub const RUSAGE_INFO_V5: u32 = 5;
+pub const RUSAGE_INFO_CURRENT: u32 = 5;
+pub const RU_PROC_RUNS_RESLIDE: u32 = 1;
+pub const RLIMIT_CPU: u32 = 0;
+pub const RLIMIT_FSIZE: u32 = 1;
+pub const RLIMIT_DATA: u32 = 2;
+pub const RLIMIT_STACK: u32 = 3;
+pub const RLIMIT_CORE: u32 = 4;
+pub const RLIMIT_AS: u32 = 5;
+pub const RLIMIT_RSS: u32 = 5;
+pub const RLIMIT_MEMLOCK: u32 = 6;
+pub const RLIMIT_NPROC: u32 = 7;
+pub const RLIMIT_NOFILE: u32 = 8;
+pub const RLIM_NLIMITS: u32 = 9;
+pub const _RLIMIT_POSIX_FLAG: u32 = 4096;
+pub const RLIMIT_WAKEUPS_MONITOR: u32 = 1;
+pub const RLIMIT_CPU_USAGE_MONITOR: u32 = 2;
+pub const RLIMIT_THREAD_CPULIMITS: u32 = 3;
+pub const RLIMIT_FOOTPRINT_INTERVAL: u32 = 4;
+pub const WAKEMON_ENABLE: u32 = 1;
+pub const WAKEMON_DISABLE: u32 = 2;
+pub const WAKEMON_GET_PARAMS: u32 = 4;
+pub const WAKEMON_SET_DEFAULTS: u32 = 8;
+pub const WAKEMON_MAKE_FATAL: u32 = 16;
+pub const CPUMON_MAKE_FATAL: u32 = 4096;
+pub const FOOTPRINT_INTERVAL_RESET: u32 = 1;
+pub const IOPOL_TYPE_DISK: u32 = 0;
+pub const IOPOL_TYPE_VFS_ATIME_UPDATES: u32 = 2;
+pub const IOPOL_TYPE_VFS_MATERIALIZE_DATALESS_FILES: u32 = 3;
+pub const IOPOL_TYPE_VFS_STATFS_NO_DATA_VOLUME: u32 = 4;
+pub const IOPOL_TYPE_VFS_TRIGGER_RESOLVE: u32 = 5;
+pub const IOPOL_TYPE_VFS_IGNORE_CONTENT_PROTECTION: u32 = 6;
+pub const IOPOL_TYPE_VFS_IGNORE_PERMISSIONS: u32 = 7;
+pub const IOPOL_TYPE_VFS_SKIP_MTIME_UPDATE: u32 = 8;
+pub const IOPOL_TYPE_VFS_ALLOW_LOW_SPACE_WRITES: u32 = 9;
+pub const IOPOL_SCOPE_PROCESS: u32 = 0;
+pub const IOPOL_SCOPE_THREAD: u32 = 1;
+pub const IOPOL_SCOPE_DARWIN_BG: u32 = 2;
+pub const IOPOL_DEFAULT: u32 = 0;
+pub const IOPOL_IMPORTANT: u32 = 1;
+pub const IOPOL_PASSIVE: u32 = 2;
+pub const IOPOL_THROTTLE: u32 = 3;
+pub const IOPOL_UTILITY: u32 = 4;
+pub const IOPOL_STANDARD: u32 = 5;
+pub const IOPOL_APPLICATION: u32 = 5;
+pub const IOPOL_NORMAL: u32 = 1;
+pub const IOPOL_ATIME_UPDATES_DEFAULT: u32 = 0;
+pub const IOPOL_ATIME_UPDATES_OFF: u32 = 1;
+pub const IOPOL_MATERIALIZE_DATALESS_FILES_DEFAULT: u32 = 0;
+pub const IOPOL_MATERIALIZE_DATALESS_FILES_OFF: u32 = 1;
+pub const IOPOL_MATERIALIZE_DATALESS_FILES_ON: u32 = 2;
+pub const IOPOL_VFS_STATFS_NO_DATA_VOLUME_DEFAULT: u32 = 0;
+pub const IOPOL_VFS_STATFS_FORCE_NO_DATA_VOLUME: u32 = 1;
+pub const IOPOL_VFS_TRIGGER_RESOLVE_DEFAULT: u32 = 0;
+pub const IOPOL_VFS_TRIGGER_RESOLVE_OFF: u32 = 1;
+pub const IOPOL_VFS_CONTENT_PROTECTION_DEFAULT: u32 = 0;
+pub const IOPOL_VFS_CONTENT_PROTECTION_IGNORE: u32 = 1;
+pub const IOPOL_VFS_IGNORE_PERMISSIONS_OFF: u32 = 0;
+pub const IOPOL_VFS_IGNORE_PERMISSIONS_ON: u32 = 1;
+pub const IOPOL_VFS_SKIP_MTIME_UPDATE_OFF: u32 = 0;
+pub const IOPOL_VFS_SKIP_MTIME_UPDATE_ON: u32 = 1;
+pub const IOPOL_VFS_ALLOW_LOW_SPACE_WRITES_OFF: u32 = 0;
+pub const IOPOL_VFS_ALLOW_LOW_SPACE_WRITES_ON: u32 = 1;
+pub const WNOHANG: u32 = 1;
+pub const WUNTRACED: u32 = 2;
+pub const WCOREFLAG: u32 = 128;
+pub const _WSTOPPED: u32 = 127;
+pub const WEXITED: u32 = 4;
+pub const WSTOPPED: u32 = 8;
+pub const WCONTINUED: u32 = 16;
+pub const WNOWAIT: u32 = 32;
+pub const WAIT_ANY: i32 = -1;
+pub const WAIT_MYPGRP: u32 = 0;
+pub const _QUAD_HIGHWORD: u32 = 1;
+pub const _QUAD_LOWWORD: u32 = 0;
+pub const __DARWIN_LITTLE_ENDIAN: u32 = 1234;
+pub const __DARWIN_BIG_ENDIAN: u32 = 4321;
+pub const __DARWIN_PDP_ENDIAN: u32 = 3412;
+pub const __DARWIN_BYTE_ORDER: u32 = 1234;
+pub const LITTLE_ENDIAN: u32 = 1234;
+pub const BIG_ENDIAN: u32 = 4321;
+pub const PDP_ENDIAN: u32 = 3412;
+pub const BYTE_ORDER: u32 = 1234;
+pub const EXIT_FAILURE: u32 = 1;
+pub const EXIT_SUCCESS: u32 = 0;
+pub const RAND_MAX: u32 = 2147483647;
+pub const _USE_FORTIFY_LEVEL: u32 = 2;
+pub const __HAS_FIXED_CHK_PROTOTYPES: u32 = 1;
+pub const ORT_API_VERSION: u32 = 12;
+pub type __int8_t = ::std::os::raw::c_schar;
+pub type __uint8_t = ::std::os::raw::c_uchar;
+pub type __int16_t = ::std::os::raw::c_short;
+pub type __uint16_t = ::std::os::raw::c_ushort;
+pub type __int32_t = ::std::os::raw::c_int;
+pub type __uint32_t = ::std::os::raw::c_uint;
+pub type __int64_t = ::std::os::raw::c_longlong;
+pub type __uint64_t = ::std::os::raw::c_ulonglong;
+pub type __darwin_intptr_t = ::std::os::raw::c_long;
+pub type __darwin_natural_t = ::std::os::raw::c_uint;
+pub type __darwin_ct_rune_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union __mbstate_t {
+    pub __mbstate8: [::std::os::raw::c_char; 128usize],
+    pub _mbstateL: ::std::os::raw::c_longlong,
+}
+#[test]
+fn bindgen_test_layout___mbstate_t() {
+    assert_eq!(
+        ::std::mem::size_of::<__mbstate_t>(),
+        128usize,
+        concat!("Size of: ", stringify!(__mbstate_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__mbstate_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__mbstate_t))
+    );
+    fn test_field___mbstate8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__mbstate_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__mbstate8) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__mbstate_t),
+                "::",
+                stringify!(__mbstate8)
+            )
+        );
+    }
+    test_field___mbstate8();
+    fn test_field__mbstateL() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__mbstate_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr)._mbstateL) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__mbstate_t),
+                "::",
+                stringify!(_mbstateL)
+            )
+        );
+    }
+    test_field__mbstateL();
+}
+pub type __darwin_mbstate_t = __mbstate_t;
+pub type __darwin_ptrdiff_t = ::std::os::raw::c_long;
+pub type __darwin_size_t = ::std::os::raw::c_ulong;
+pub type __darwin_va_list = __builtin_va_list;
+pub type __darwin_wchar_t = ::std::os::raw::c_int;
+pub type __darwin_rune_t = __darwin_wchar_t;
+pub type __darwin_wint_t = ::std::os::raw::c_int;
+pub type __darwin_clock_t = ::std::os::raw::c_ulong;
+pub type __darwin_socklen_t = __uint32_t;
+pub type __darwin_ssize_t = ::std::os::raw::c_long;
+pub type __darwin_time_t = ::std::os::raw::c_long;
+pub type __darwin_blkcnt_t = __int64_t;
+pub type __darwin_blksize_t = __int32_t;
+pub type __darwin_dev_t = __int32_t;
+pub type __darwin_fsblkcnt_t = ::std::os::raw::c_uint;
+pub type __darwin_fsfilcnt_t = ::std::os::raw::c_uint;
+pub type __darwin_gid_t = __uint32_t;
+pub type __darwin_id_t = __uint32_t;
+pub type __darwin_ino64_t = __uint64_t;
+pub type __darwin_ino_t = __darwin_ino64_t;
+pub type __darwin_mach_port_name_t = __darwin_natural_t;
+pub type __darwin_mach_port_t = __darwin_mach_port_name_t;
+pub type __darwin_mode_t = __uint16_t;
+pub type __darwin_off_t = __int64_t;
+pub type __darwin_pid_t = __int32_t;
+pub type __darwin_sigset_t = __uint32_t;
+pub type __darwin_suseconds_t = __int32_t;
+pub type __darwin_uid_t = __uint32_t;
+pub type __darwin_useconds_t = __uint32_t;
+pub type __darwin_uuid_t = [::std::os::raw::c_uchar; 16usize];
+pub type __darwin_uuid_string_t = [::std::os::raw::c_char; 37usize];
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_pthread_handler_rec {
+    pub __routine: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+    pub __arg: *mut ::std::os::raw::c_void,
+    pub __next: *mut __darwin_pthread_handler_rec,
+}
+#[test]
+fn bindgen_test_layout___darwin_pthread_handler_rec() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_pthread_handler_rec>(),
+        24usize,
+        concat!("Size of: ", stringify!(__darwin_pthread_handler_rec))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_pthread_handler_rec>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_pthread_handler_rec))
+    );
+    fn test_field___routine() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__routine) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_pthread_handler_rec),
+                "::",
+                stringify!(__routine)
+            )
+        );
+    }
+    test_field___routine();
+    fn test_field___arg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__arg) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_pthread_handler_rec),
+                "::",
+                stringify!(__arg)
+            )
+        );
+    }
+    test_field___arg();
+    fn test_field___next() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_pthread_handler_rec),
+                "::",
+                stringify!(__next)
+            )
+        );
+    }
+    test_field___next();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_attr_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 56usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_attr_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_attr_t>(),
+        64usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_attr_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_attr_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_attr_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_attr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_attr_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_attr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_attr_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_cond_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 40usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_cond_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_cond_t>(),
+        48usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_cond_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_cond_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_cond_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_cond_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_cond_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_cond_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_cond_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_condattr_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 8usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_condattr_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_condattr_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_condattr_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_condattr_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_condattr_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_condattr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_condattr_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_condattr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_condattr_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_mutex_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 56usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_mutex_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_mutex_t>(),
+        64usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_mutex_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_mutex_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_mutex_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutex_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_mutex_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutex_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_mutex_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_mutexattr_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 8usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_mutexattr_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_mutexattr_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_mutexattr_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_mutexattr_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_mutexattr_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutexattr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_mutexattr_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutexattr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_mutexattr_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_once_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 8usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_once_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_once_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_once_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_once_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_once_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_once_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_once_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_once_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_once_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_rwlock_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 192usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_rwlock_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_rwlock_t>(),
+        200usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_rwlock_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_rwlock_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_rwlock_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlock_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_rwlock_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlock_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_rwlock_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_rwlockattr_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __opaque: [::std::os::raw::c_char; 16usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_rwlockattr_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_rwlockattr_t>(),
+        24usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_rwlockattr_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_rwlockattr_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_rwlockattr_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlockattr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_rwlockattr_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlockattr_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_rwlockattr_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _opaque_pthread_t {
+    pub __sig: ::std::os::raw::c_long,
+    pub __cleanup_stack: *mut __darwin_pthread_handler_rec,
+    pub __opaque: [::std::os::raw::c_char; 8176usize],
+}
+#[test]
+fn bindgen_test_layout__opaque_pthread_t() {
+    assert_eq!(
+        ::std::mem::size_of::<_opaque_pthread_t>(),
+        8192usize,
+        concat!("Size of: ", stringify!(_opaque_pthread_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_opaque_pthread_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_opaque_pthread_t))
+    );
+    fn test_field___sig() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_t),
+                "::",
+                stringify!(__sig)
+            )
+        );
+    }
+    test_field___sig();
+    fn test_field___cleanup_stack() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__cleanup_stack) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_t),
+                "::",
+                stringify!(__cleanup_stack)
+            )
+        );
+    }
+    test_field___cleanup_stack();
+    fn test_field___opaque() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(_opaque_pthread_t),
+                "::",
+                stringify!(__opaque)
+            )
+        );
+    }
+    test_field___opaque();
+}
+pub type __darwin_pthread_attr_t = _opaque_pthread_attr_t;
+pub type __darwin_pthread_cond_t = _opaque_pthread_cond_t;
+pub type __darwin_pthread_condattr_t = _opaque_pthread_condattr_t;
+pub type __darwin_pthread_key_t = ::std::os::raw::c_ulong;
+pub type __darwin_pthread_mutex_t = _opaque_pthread_mutex_t;
+pub type __darwin_pthread_mutexattr_t = _opaque_pthread_mutexattr_t;
+pub type __darwin_pthread_once_t = _opaque_pthread_once_t;
+pub type __darwin_pthread_rwlock_t = _opaque_pthread_rwlock_t;
+pub type __darwin_pthread_rwlockattr_t = _opaque_pthread_rwlockattr_t;
+pub type __darwin_pthread_t = *mut _opaque_pthread_t;
+pub type __darwin_nl_item = ::std::os::raw::c_int;
+pub type __darwin_wctrans_t = ::std::os::raw::c_int;
+pub type __darwin_wctype_t = __uint32_t;
+pub const idtype_t_P_ALL: idtype_t = 0;
+pub const idtype_t_P_PID: idtype_t = 1;
+pub const idtype_t_P_PGID: idtype_t = 2;
+pub type idtype_t = ::std::os::raw::c_uint;
+pub type pid_t = __darwin_pid_t;
+pub type id_t = __darwin_id_t;
+pub type sig_atomic_t = ::std::os::raw::c_int;
+pub type u_int8_t = ::std::os::raw::c_uchar;
+pub type u_int16_t = ::std::os::raw::c_ushort;
+pub type u_int32_t = ::std::os::raw::c_uint;
+pub type u_int64_t = ::std::os::raw::c_ulonglong;
+pub type register_t = i64;
+pub type user_addr_t = u_int64_t;
+pub type user_size_t = u_int64_t;
+pub type user_ssize_t = i64;
+pub type user_long_t = i64;
+pub type user_ulong_t = u_int64_t;
+pub type user_time_t = i64;
+pub type user_off_t = i64;
+pub type syscall_arg_t = u_int64_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_i386_thread_state {
+    pub __eax: ::std::os::raw::c_uint,
+    pub __ebx: ::std::os::raw::c_uint,
+    pub __ecx: ::std::os::raw::c_uint,
+    pub __edx: ::std::os::raw::c_uint,
+    pub __edi: ::std::os::raw::c_uint,
+    pub __esi: ::std::os::raw::c_uint,
+    pub __ebp: ::std::os::raw::c_uint,
+    pub __esp: ::std::os::raw::c_uint,
+    pub __ss: ::std::os::raw::c_uint,
+    pub __eflags: ::std::os::raw::c_uint,
+    pub __eip: ::std::os::raw::c_uint,
+    pub __cs: ::std::os::raw::c_uint,
+    pub __ds: ::std::os::raw::c_uint,
+    pub __es: ::std::os::raw::c_uint,
+    pub __fs: ::std::os::raw::c_uint,
+    pub __gs: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout___darwin_i386_thread_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_i386_thread_state>(),
+        64usize,
+        concat!("Size of: ", stringify!(__darwin_i386_thread_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_i386_thread_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_i386_thread_state))
+    );
+    fn test_field___eax() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__eax) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__eax)
+            )
+        );
+    }
+    test_field___eax();
+    fn test_field___ebx() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ebx) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__ebx)
+            )
+        );
+    }
+    test_field___ebx();
+    fn test_field___ecx() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ecx) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__ecx)
+            )
+        );
+    }
+    test_field___ecx();
+    fn test_field___edx() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__edx) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__edx)
+            )
+        );
+    }
+    test_field___edx();
+    fn test_field___edi() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__edi) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__edi)
+            )
+        );
+    }
+    test_field___edi();
+    fn test_field___esi() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__esi) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__esi)
+            )
+        );
+    }
+    test_field___esi();
+    fn test_field___ebp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ebp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__ebp)
+            )
+        );
+    }
+    test_field___ebp();
+    fn test_field___esp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__esp) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__esp)
+            )
+        );
+    }
+    test_field___esp();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___eflags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__eflags) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__eflags)
+            )
+        );
+    }
+    test_field___eflags();
+    fn test_field___eip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__eip) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__eip)
+            )
+        );
+    }
+    test_field___eip();
+    fn test_field___cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__cs) as usize - ptr as usize
+            },
+            44usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__cs)
+            )
+        );
+    }
+    test_field___cs();
+    fn test_field___ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ds) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__ds)
+            )
+        );
+    }
+    test_field___ds();
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            52usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+    fn test_field___gs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__gs) as usize - ptr as usize
+            },
+            60usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_thread_state),
+                "::",
+                stringify!(__gs)
+            )
+        );
+    }
+    test_field___gs();
+}
+#[repr(C)]
+#[repr(align(2))]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_fp_control {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize]>,
+}
+#[test]
+fn bindgen_test_layout___darwin_fp_control() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_fp_control>(),
+        2usize,
+        concat!("Size of: ", stringify!(__darwin_fp_control))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_fp_control>(),
+        2usize,
+        concat!("Alignment of ", stringify!(__darwin_fp_control))
+    );
+}
+impl __darwin_fp_control {
+    #[inline]
+    pub fn __invalid(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___invalid(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __denorm(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___denorm(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __zdiv(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___zdiv(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __ovrfl(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___ovrfl(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(3usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __undfl(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___undfl(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(4usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __precis(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___precis(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(5usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __pc(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 2u8) as u16) }
+    }
+    #[inline]
+    pub fn set___pc(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(8usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __rc(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(10usize, 2u8) as u16) }
+    }
+    #[inline]
+    pub fn set___rc(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(10usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        __invalid: ::std::os::raw::c_ushort,
+        __denorm: ::std::os::raw::c_ushort,
+        __zdiv: ::std::os::raw::c_ushort,
+        __ovrfl: ::std::os::raw::c_ushort,
+        __undfl: ::std::os::raw::c_ushort,
+        __precis: ::std::os::raw::c_ushort,
+        __pc: ::std::os::raw::c_ushort,
+        __rc: ::std::os::raw::c_ushort,
+    ) -> __BindgenBitfieldUnit<[u8; 2usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 2usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let __invalid: u16 = unsafe { ::std::mem::transmute(__invalid) };
+            __invalid as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let __denorm: u16 = unsafe { ::std::mem::transmute(__denorm) };
+            __denorm as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let __zdiv: u16 = unsafe { ::std::mem::transmute(__zdiv) };
+            __zdiv as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 1u8, {
+            let __ovrfl: u16 = unsafe { ::std::mem::transmute(__ovrfl) };
+            __ovrfl as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 1u8, {
+            let __undfl: u16 = unsafe { ::std::mem::transmute(__undfl) };
+            __undfl as u64
+        });
+        __bindgen_bitfield_unit.set(5usize, 1u8, {
+            let __precis: u16 = unsafe { ::std::mem::transmute(__precis) };
+            __precis as u64
+        });
+        __bindgen_bitfield_unit.set(8usize, 2u8, {
+            let __pc: u16 = unsafe { ::std::mem::transmute(__pc) };
+            __pc as u64
+        });
+        __bindgen_bitfield_unit.set(10usize, 2u8, {
+            let __rc: u16 = unsafe { ::std::mem::transmute(__rc) };
+            __rc as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type __darwin_fp_control_t = __darwin_fp_control;
+#[repr(C)]
+#[repr(align(2))]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_fp_status {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize]>,
+}
+#[test]
+fn bindgen_test_layout___darwin_fp_status() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_fp_status>(),
+        2usize,
+        concat!("Size of: ", stringify!(__darwin_fp_status))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_fp_status>(),
+        2usize,
+        concat!("Alignment of ", stringify!(__darwin_fp_status))
+    );
+}
+impl __darwin_fp_status {
+    #[inline]
+    pub fn __invalid(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___invalid(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __denorm(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___denorm(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __zdiv(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___zdiv(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __ovrfl(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___ovrfl(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(3usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __undfl(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___undfl(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(4usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __precis(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___precis(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(5usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __stkflt(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(6usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___stkflt(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(6usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __errsumm(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___errsumm(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(7usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __c0(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___c0(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(8usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __c1(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(9usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___c1(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(9usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __c2(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(10usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___c2(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(10usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __tos(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(11usize, 3u8) as u16) }
+    }
+    #[inline]
+    pub fn set___tos(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(11usize, 3u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __c3(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(14usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___c3(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(14usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __busy(&self) -> ::std::os::raw::c_ushort {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(15usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set___busy(&mut self, val: ::std::os::raw::c_ushort) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(15usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        __invalid: ::std::os::raw::c_ushort,
+        __denorm: ::std::os::raw::c_ushort,
+        __zdiv: ::std::os::raw::c_ushort,
+        __ovrfl: ::std::os::raw::c_ushort,
+        __undfl: ::std::os::raw::c_ushort,
+        __precis: ::std::os::raw::c_ushort,
+        __stkflt: ::std::os::raw::c_ushort,
+        __errsumm: ::std::os::raw::c_ushort,
+        __c0: ::std::os::raw::c_ushort,
+        __c1: ::std::os::raw::c_ushort,
+        __c2: ::std::os::raw::c_ushort,
+        __tos: ::std::os::raw::c_ushort,
+        __c3: ::std::os::raw::c_ushort,
+        __busy: ::std::os::raw::c_ushort,
+    ) -> __BindgenBitfieldUnit<[u8; 2usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 2usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let __invalid: u16 = unsafe { ::std::mem::transmute(__invalid) };
+            __invalid as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let __denorm: u16 = unsafe { ::std::mem::transmute(__denorm) };
+            __denorm as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let __zdiv: u16 = unsafe { ::std::mem::transmute(__zdiv) };
+            __zdiv as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 1u8, {
+            let __ovrfl: u16 = unsafe { ::std::mem::transmute(__ovrfl) };
+            __ovrfl as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 1u8, {
+            let __undfl: u16 = unsafe { ::std::mem::transmute(__undfl) };
+            __undfl as u64
+        });
+        __bindgen_bitfield_unit.set(5usize, 1u8, {
+            let __precis: u16 = unsafe { ::std::mem::transmute(__precis) };
+            __precis as u64
+        });
+        __bindgen_bitfield_unit.set(6usize, 1u8, {
+            let __stkflt: u16 = unsafe { ::std::mem::transmute(__stkflt) };
+            __stkflt as u64
+        });
+        __bindgen_bitfield_unit.set(7usize, 1u8, {
+            let __errsumm: u16 = unsafe { ::std::mem::transmute(__errsumm) };
+            __errsumm as u64
+        });
+        __bindgen_bitfield_unit.set(8usize, 1u8, {
+            let __c0: u16 = unsafe { ::std::mem::transmute(__c0) };
+            __c0 as u64
+        });
+        __bindgen_bitfield_unit.set(9usize, 1u8, {
+            let __c1: u16 = unsafe { ::std::mem::transmute(__c1) };
+            __c1 as u64
+        });
+        __bindgen_bitfield_unit.set(10usize, 1u8, {
+            let __c2: u16 = unsafe { ::std::mem::transmute(__c2) };
+            __c2 as u64
+        });
+        __bindgen_bitfield_unit.set(11usize, 3u8, {
+            let __tos: u16 = unsafe { ::std::mem::transmute(__tos) };
+            __tos as u64
+        });
+        __bindgen_bitfield_unit.set(14usize, 1u8, {
+            let __c3: u16 = unsafe { ::std::mem::transmute(__c3) };
+            __c3 as u64
+        });
+        __bindgen_bitfield_unit.set(15usize, 1u8, {
+            let __busy: u16 = unsafe { ::std::mem::transmute(__busy) };
+            __busy as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type __darwin_fp_status_t = __darwin_fp_status;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mmst_reg {
+    pub __mmst_reg: [::std::os::raw::c_char; 10usize],
+    pub __mmst_rsrv: [::std::os::raw::c_char; 6usize],
+}
+#[test]
+fn bindgen_test_layout___darwin_mmst_reg() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mmst_reg>(),
+        16usize,
+        concat!("Size of: ", stringify!(__darwin_mmst_reg))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mmst_reg>(),
+        1usize,
+        concat!("Alignment of ", stringify!(__darwin_mmst_reg))
+    );
+    fn test_field___mmst_reg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mmst_reg>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__mmst_reg) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mmst_reg),
+                "::",
+                stringify!(__mmst_reg)
+            )
+        );
+    }
+    test_field___mmst_reg();
+    fn test_field___mmst_rsrv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mmst_reg>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__mmst_rsrv) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mmst_reg),
+                "::",
+                stringify!(__mmst_rsrv)
+            )
+        );
+    }
+    test_field___mmst_rsrv();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_xmm_reg {
+    pub __xmm_reg: [::std::os::raw::c_char; 16usize],
+}
+#[test]
+fn bindgen_test_layout___darwin_xmm_reg() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_xmm_reg>(),
+        16usize,
+        concat!("Size of: ", stringify!(__darwin_xmm_reg))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_xmm_reg>(),
+        1usize,
+        concat!("Alignment of ", stringify!(__darwin_xmm_reg))
+    );
+    fn test_field___xmm_reg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_xmm_reg>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__xmm_reg) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_xmm_reg),
+                "::",
+                stringify!(__xmm_reg)
+            )
+        );
+    }
+    test_field___xmm_reg();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_ymm_reg {
+    pub __ymm_reg: [::std::os::raw::c_char; 32usize],
+}
+#[test]
+fn bindgen_test_layout___darwin_ymm_reg() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_ymm_reg>(),
+        32usize,
+        concat!("Size of: ", stringify!(__darwin_ymm_reg))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_ymm_reg>(),
+        1usize,
+        concat!("Alignment of ", stringify!(__darwin_ymm_reg))
+    );
+    fn test_field___ymm_reg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ymm_reg>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ymm_reg) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ymm_reg),
+                "::",
+                stringify!(__ymm_reg)
+            )
+        );
+    }
+    test_field___ymm_reg();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_zmm_reg {
+    pub __zmm_reg: [::std::os::raw::c_char; 64usize],
+}
+#[test]
+fn bindgen_test_layout___darwin_zmm_reg() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_zmm_reg>(),
+        64usize,
+        concat!("Size of: ", stringify!(__darwin_zmm_reg))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_zmm_reg>(),
+        1usize,
+        concat!("Alignment of ", stringify!(__darwin_zmm_reg))
+    );
+    fn test_field___zmm_reg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_zmm_reg>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__zmm_reg) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_zmm_reg),
+                "::",
+                stringify!(__zmm_reg)
+            )
+        );
+    }
+    test_field___zmm_reg();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_opmask_reg {
+    pub __opmask_reg: [::std::os::raw::c_char; 8usize],
+}
+#[test]
+fn bindgen_test_layout___darwin_opmask_reg() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_opmask_reg>(),
+        8usize,
+        concat!("Size of: ", stringify!(__darwin_opmask_reg))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_opmask_reg>(),
+        1usize,
+        concat!("Alignment of ", stringify!(__darwin_opmask_reg))
+    );
+    fn test_field___opmask_reg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_opmask_reg>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__opmask_reg) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_opmask_reg),
+                "::",
+                stringify!(__opmask_reg)
+            )
+        );
+    }
+    test_field___opmask_reg();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_i386_float_state {
+    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_fcw: __darwin_fp_control,
+    pub __fpu_fsw: __darwin_fp_status,
+    pub __fpu_ftw: __uint8_t,
+    pub __fpu_rsrv1: __uint8_t,
+    pub __fpu_fop: __uint16_t,
+    pub __fpu_ip: __uint32_t,
+    pub __fpu_cs: __uint16_t,
+    pub __fpu_rsrv2: __uint16_t,
+    pub __fpu_dp: __uint32_t,
+    pub __fpu_ds: __uint16_t,
+    pub __fpu_rsrv3: __uint16_t,
+    pub __fpu_mxcsr: __uint32_t,
+    pub __fpu_mxcsrmask: __uint32_t,
+    pub __fpu_stmm0: __darwin_mmst_reg,
+    pub __fpu_stmm1: __darwin_mmst_reg,
+    pub __fpu_stmm2: __darwin_mmst_reg,
+    pub __fpu_stmm3: __darwin_mmst_reg,
+    pub __fpu_stmm4: __darwin_mmst_reg,
+    pub __fpu_stmm5: __darwin_mmst_reg,
+    pub __fpu_stmm6: __darwin_mmst_reg,
+    pub __fpu_stmm7: __darwin_mmst_reg,
+    pub __fpu_xmm0: __darwin_xmm_reg,
+    pub __fpu_xmm1: __darwin_xmm_reg,
+    pub __fpu_xmm2: __darwin_xmm_reg,
+    pub __fpu_xmm3: __darwin_xmm_reg,
+    pub __fpu_xmm4: __darwin_xmm_reg,
+    pub __fpu_xmm5: __darwin_xmm_reg,
+    pub __fpu_xmm6: __darwin_xmm_reg,
+    pub __fpu_xmm7: __darwin_xmm_reg,
+    pub __fpu_rsrv4: [::std::os::raw::c_char; 224usize],
+    pub __fpu_reserved1: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout___darwin_i386_float_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_i386_float_state>(),
+        524usize,
+        concat!("Size of: ", stringify!(__darwin_i386_float_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_i386_float_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_i386_float_state))
+    );
+    fn test_field___fpu_reserved() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_reserved)
+            )
+        );
+    }
+    test_field___fpu_reserved();
+    fn test_field___fpu_fcw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_fcw)
+            )
+        );
+    }
+    test_field___fpu_fcw();
+    fn test_field___fpu_fsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_fsw)
+            )
+        );
+    }
+    test_field___fpu_fsw();
+    fn test_field___fpu_ftw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_ftw)
+            )
+        );
+    }
+    test_field___fpu_ftw();
+    fn test_field___fpu_rsrv1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+            },
+            13usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_rsrv1)
+            )
+        );
+    }
+    test_field___fpu_rsrv1();
+    fn test_field___fpu_fop() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+            },
+            14usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_fop)
+            )
+        );
+    }
+    test_field___fpu_fop();
+    fn test_field___fpu_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_ip)
+            )
+        );
+    }
+    test_field___fpu_ip();
+    fn test_field___fpu_cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_cs)
+            )
+        );
+    }
+    test_field___fpu_cs();
+    fn test_field___fpu_rsrv2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+            },
+            22usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_rsrv2)
+            )
+        );
+    }
+    test_field___fpu_rsrv2();
+    fn test_field___fpu_dp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_dp)
+            )
+        );
+    }
+    test_field___fpu_dp();
+    fn test_field___fpu_ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_ds)
+            )
+        );
+    }
+    test_field___fpu_ds();
+    fn test_field___fpu_rsrv3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+            },
+            30usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_rsrv3)
+            )
+        );
+    }
+    test_field___fpu_rsrv3();
+    fn test_field___fpu_mxcsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_mxcsr)
+            )
+        );
+    }
+    test_field___fpu_mxcsr();
+    fn test_field___fpu_mxcsrmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_mxcsrmask)
+            )
+        );
+    }
+    test_field___fpu_mxcsrmask();
+    fn test_field___fpu_stmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm0)
+            )
+        );
+    }
+    test_field___fpu_stmm0();
+    fn test_field___fpu_stmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm1)
+            )
+        );
+    }
+    test_field___fpu_stmm1();
+    fn test_field___fpu_stmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm2)
+            )
+        );
+    }
+    test_field___fpu_stmm2();
+    fn test_field___fpu_stmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm3)
+            )
+        );
+    }
+    test_field___fpu_stmm3();
+    fn test_field___fpu_stmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm4)
+            )
+        );
+    }
+    test_field___fpu_stmm4();
+    fn test_field___fpu_stmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm5)
+            )
+        );
+    }
+    test_field___fpu_stmm5();
+    fn test_field___fpu_stmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm6)
+            )
+        );
+    }
+    test_field___fpu_stmm6();
+    fn test_field___fpu_stmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_stmm7)
+            )
+        );
+    }
+    test_field___fpu_stmm7();
+    fn test_field___fpu_xmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm0)
+            )
+        );
+    }
+    test_field___fpu_xmm0();
+    fn test_field___fpu_xmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm1)
+            )
+        );
+    }
+    test_field___fpu_xmm1();
+    fn test_field___fpu_xmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm2)
+            )
+        );
+    }
+    test_field___fpu_xmm2();
+    fn test_field___fpu_xmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm3)
+            )
+        );
+    }
+    test_field___fpu_xmm3();
+    fn test_field___fpu_xmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm4)
+            )
+        );
+    }
+    test_field___fpu_xmm4();
+    fn test_field___fpu_xmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm5)
+            )
+        );
+    }
+    test_field___fpu_xmm5();
+    fn test_field___fpu_xmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm6)
+            )
+        );
+    }
+    test_field___fpu_xmm6();
+    fn test_field___fpu_xmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_xmm7)
+            )
+        );
+    }
+    test_field___fpu_xmm7();
+    fn test_field___fpu_rsrv4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_rsrv4)
+            )
+        );
+    }
+    test_field___fpu_rsrv4();
+    fn test_field___fpu_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_float_state),
+                "::",
+                stringify!(__fpu_reserved1)
+            )
+        );
+    }
+    test_field___fpu_reserved1();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_i386_avx_state {
+    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_fcw: __darwin_fp_control,
+    pub __fpu_fsw: __darwin_fp_status,
+    pub __fpu_ftw: __uint8_t,
+    pub __fpu_rsrv1: __uint8_t,
+    pub __fpu_fop: __uint16_t,
+    pub __fpu_ip: __uint32_t,
+    pub __fpu_cs: __uint16_t,
+    pub __fpu_rsrv2: __uint16_t,
+    pub __fpu_dp: __uint32_t,
+    pub __fpu_ds: __uint16_t,
+    pub __fpu_rsrv3: __uint16_t,
+    pub __fpu_mxcsr: __uint32_t,
+    pub __fpu_mxcsrmask: __uint32_t,
+    pub __fpu_stmm0: __darwin_mmst_reg,
+    pub __fpu_stmm1: __darwin_mmst_reg,
+    pub __fpu_stmm2: __darwin_mmst_reg,
+    pub __fpu_stmm3: __darwin_mmst_reg,
+    pub __fpu_stmm4: __darwin_mmst_reg,
+    pub __fpu_stmm5: __darwin_mmst_reg,
+    pub __fpu_stmm6: __darwin_mmst_reg,
+    pub __fpu_stmm7: __darwin_mmst_reg,
+    pub __fpu_xmm0: __darwin_xmm_reg,
+    pub __fpu_xmm1: __darwin_xmm_reg,
+    pub __fpu_xmm2: __darwin_xmm_reg,
+    pub __fpu_xmm3: __darwin_xmm_reg,
+    pub __fpu_xmm4: __darwin_xmm_reg,
+    pub __fpu_xmm5: __darwin_xmm_reg,
+    pub __fpu_xmm6: __darwin_xmm_reg,
+    pub __fpu_xmm7: __darwin_xmm_reg,
+    pub __fpu_rsrv4: [::std::os::raw::c_char; 224usize],
+    pub __fpu_reserved1: ::std::os::raw::c_int,
+    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_ymmh0: __darwin_xmm_reg,
+    pub __fpu_ymmh1: __darwin_xmm_reg,
+    pub __fpu_ymmh2: __darwin_xmm_reg,
+    pub __fpu_ymmh3: __darwin_xmm_reg,
+    pub __fpu_ymmh4: __darwin_xmm_reg,
+    pub __fpu_ymmh5: __darwin_xmm_reg,
+    pub __fpu_ymmh6: __darwin_xmm_reg,
+    pub __fpu_ymmh7: __darwin_xmm_reg,
+}
+#[test]
+fn bindgen_test_layout___darwin_i386_avx_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_i386_avx_state>(),
+        716usize,
+        concat!("Size of: ", stringify!(__darwin_i386_avx_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_i386_avx_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_i386_avx_state))
+    );
+    fn test_field___fpu_reserved() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_reserved)
+            )
+        );
+    }
+    test_field___fpu_reserved();
+    fn test_field___fpu_fcw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_fcw)
+            )
+        );
+    }
+    test_field___fpu_fcw();
+    fn test_field___fpu_fsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_fsw)
+            )
+        );
+    }
+    test_field___fpu_fsw();
+    fn test_field___fpu_ftw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ftw)
+            )
+        );
+    }
+    test_field___fpu_ftw();
+    fn test_field___fpu_rsrv1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+            },
+            13usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_rsrv1)
+            )
+        );
+    }
+    test_field___fpu_rsrv1();
+    fn test_field___fpu_fop() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+            },
+            14usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_fop)
+            )
+        );
+    }
+    test_field___fpu_fop();
+    fn test_field___fpu_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ip)
+            )
+        );
+    }
+    test_field___fpu_ip();
+    fn test_field___fpu_cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_cs)
+            )
+        );
+    }
+    test_field___fpu_cs();
+    fn test_field___fpu_rsrv2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+            },
+            22usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_rsrv2)
+            )
+        );
+    }
+    test_field___fpu_rsrv2();
+    fn test_field___fpu_dp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_dp)
+            )
+        );
+    }
+    test_field___fpu_dp();
+    fn test_field___fpu_ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ds)
+            )
+        );
+    }
+    test_field___fpu_ds();
+    fn test_field___fpu_rsrv3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+            },
+            30usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_rsrv3)
+            )
+        );
+    }
+    test_field___fpu_rsrv3();
+    fn test_field___fpu_mxcsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_mxcsr)
+            )
+        );
+    }
+    test_field___fpu_mxcsr();
+    fn test_field___fpu_mxcsrmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_mxcsrmask)
+            )
+        );
+    }
+    test_field___fpu_mxcsrmask();
+    fn test_field___fpu_stmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm0)
+            )
+        );
+    }
+    test_field___fpu_stmm0();
+    fn test_field___fpu_stmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm1)
+            )
+        );
+    }
+    test_field___fpu_stmm1();
+    fn test_field___fpu_stmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm2)
+            )
+        );
+    }
+    test_field___fpu_stmm2();
+    fn test_field___fpu_stmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm3)
+            )
+        );
+    }
+    test_field___fpu_stmm3();
+    fn test_field___fpu_stmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm4)
+            )
+        );
+    }
+    test_field___fpu_stmm4();
+    fn test_field___fpu_stmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm5)
+            )
+        );
+    }
+    test_field___fpu_stmm5();
+    fn test_field___fpu_stmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm6)
+            )
+        );
+    }
+    test_field___fpu_stmm6();
+    fn test_field___fpu_stmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_stmm7)
+            )
+        );
+    }
+    test_field___fpu_stmm7();
+    fn test_field___fpu_xmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm0)
+            )
+        );
+    }
+    test_field___fpu_xmm0();
+    fn test_field___fpu_xmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm1)
+            )
+        );
+    }
+    test_field___fpu_xmm1();
+    fn test_field___fpu_xmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm2)
+            )
+        );
+    }
+    test_field___fpu_xmm2();
+    fn test_field___fpu_xmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm3)
+            )
+        );
+    }
+    test_field___fpu_xmm3();
+    fn test_field___fpu_xmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm4)
+            )
+        );
+    }
+    test_field___fpu_xmm4();
+    fn test_field___fpu_xmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm5)
+            )
+        );
+    }
+    test_field___fpu_xmm5();
+    fn test_field___fpu_xmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm6)
+            )
+        );
+    }
+    test_field___fpu_xmm6();
+    fn test_field___fpu_xmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_xmm7)
+            )
+        );
+    }
+    test_field___fpu_xmm7();
+    fn test_field___fpu_rsrv4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_rsrv4)
+            )
+        );
+    }
+    test_field___fpu_rsrv4();
+    fn test_field___fpu_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_reserved1)
+            )
+        );
+    }
+    test_field___fpu_reserved1();
+    fn test_field___avx_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+            },
+            524usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__avx_reserved1)
+            )
+        );
+    }
+    test_field___avx_reserved1();
+    fn test_field___fpu_ymmh0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+            },
+            588usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh0)
+            )
+        );
+    }
+    test_field___fpu_ymmh0();
+    fn test_field___fpu_ymmh1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+            },
+            604usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh1)
+            )
+        );
+    }
+    test_field___fpu_ymmh1();
+    fn test_field___fpu_ymmh2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+            },
+            620usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh2)
+            )
+        );
+    }
+    test_field___fpu_ymmh2();
+    fn test_field___fpu_ymmh3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+            },
+            636usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh3)
+            )
+        );
+    }
+    test_field___fpu_ymmh3();
+    fn test_field___fpu_ymmh4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+            },
+            652usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh4)
+            )
+        );
+    }
+    test_field___fpu_ymmh4();
+    fn test_field___fpu_ymmh5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+            },
+            668usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh5)
+            )
+        );
+    }
+    test_field___fpu_ymmh5();
+    fn test_field___fpu_ymmh6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+            },
+            684usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh6)
+            )
+        );
+    }
+    test_field___fpu_ymmh6();
+    fn test_field___fpu_ymmh7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+            },
+            700usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx_state),
+                "::",
+                stringify!(__fpu_ymmh7)
+            )
+        );
+    }
+    test_field___fpu_ymmh7();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_i386_avx512_state {
+    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_fcw: __darwin_fp_control,
+    pub __fpu_fsw: __darwin_fp_status,
+    pub __fpu_ftw: __uint8_t,
+    pub __fpu_rsrv1: __uint8_t,
+    pub __fpu_fop: __uint16_t,
+    pub __fpu_ip: __uint32_t,
+    pub __fpu_cs: __uint16_t,
+    pub __fpu_rsrv2: __uint16_t,
+    pub __fpu_dp: __uint32_t,
+    pub __fpu_ds: __uint16_t,
+    pub __fpu_rsrv3: __uint16_t,
+    pub __fpu_mxcsr: __uint32_t,
+    pub __fpu_mxcsrmask: __uint32_t,
+    pub __fpu_stmm0: __darwin_mmst_reg,
+    pub __fpu_stmm1: __darwin_mmst_reg,
+    pub __fpu_stmm2: __darwin_mmst_reg,
+    pub __fpu_stmm3: __darwin_mmst_reg,
+    pub __fpu_stmm4: __darwin_mmst_reg,
+    pub __fpu_stmm5: __darwin_mmst_reg,
+    pub __fpu_stmm6: __darwin_mmst_reg,
+    pub __fpu_stmm7: __darwin_mmst_reg,
+    pub __fpu_xmm0: __darwin_xmm_reg,
+    pub __fpu_xmm1: __darwin_xmm_reg,
+    pub __fpu_xmm2: __darwin_xmm_reg,
+    pub __fpu_xmm3: __darwin_xmm_reg,
+    pub __fpu_xmm4: __darwin_xmm_reg,
+    pub __fpu_xmm5: __darwin_xmm_reg,
+    pub __fpu_xmm6: __darwin_xmm_reg,
+    pub __fpu_xmm7: __darwin_xmm_reg,
+    pub __fpu_rsrv4: [::std::os::raw::c_char; 224usize],
+    pub __fpu_reserved1: ::std::os::raw::c_int,
+    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_ymmh0: __darwin_xmm_reg,
+    pub __fpu_ymmh1: __darwin_xmm_reg,
+    pub __fpu_ymmh2: __darwin_xmm_reg,
+    pub __fpu_ymmh3: __darwin_xmm_reg,
+    pub __fpu_ymmh4: __darwin_xmm_reg,
+    pub __fpu_ymmh5: __darwin_xmm_reg,
+    pub __fpu_ymmh6: __darwin_xmm_reg,
+    pub __fpu_ymmh7: __darwin_xmm_reg,
+    pub __fpu_k0: __darwin_opmask_reg,
+    pub __fpu_k1: __darwin_opmask_reg,
+    pub __fpu_k2: __darwin_opmask_reg,
+    pub __fpu_k3: __darwin_opmask_reg,
+    pub __fpu_k4: __darwin_opmask_reg,
+    pub __fpu_k5: __darwin_opmask_reg,
+    pub __fpu_k6: __darwin_opmask_reg,
+    pub __fpu_k7: __darwin_opmask_reg,
+    pub __fpu_zmmh0: __darwin_ymm_reg,
+    pub __fpu_zmmh1: __darwin_ymm_reg,
+    pub __fpu_zmmh2: __darwin_ymm_reg,
+    pub __fpu_zmmh3: __darwin_ymm_reg,
+    pub __fpu_zmmh4: __darwin_ymm_reg,
+    pub __fpu_zmmh5: __darwin_ymm_reg,
+    pub __fpu_zmmh6: __darwin_ymm_reg,
+    pub __fpu_zmmh7: __darwin_ymm_reg,
+}
+#[test]
+fn bindgen_test_layout___darwin_i386_avx512_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_i386_avx512_state>(),
+        1036usize,
+        concat!("Size of: ", stringify!(__darwin_i386_avx512_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_i386_avx512_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_i386_avx512_state))
+    );
+    fn test_field___fpu_reserved() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_reserved)
+            )
+        );
+    }
+    test_field___fpu_reserved();
+    fn test_field___fpu_fcw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_fcw)
+            )
+        );
+    }
+    test_field___fpu_fcw();
+    fn test_field___fpu_fsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_fsw)
+            )
+        );
+    }
+    test_field___fpu_fsw();
+    fn test_field___fpu_ftw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ftw)
+            )
+        );
+    }
+    test_field___fpu_ftw();
+    fn test_field___fpu_rsrv1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+            },
+            13usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_rsrv1)
+            )
+        );
+    }
+    test_field___fpu_rsrv1();
+    fn test_field___fpu_fop() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+            },
+            14usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_fop)
+            )
+        );
+    }
+    test_field___fpu_fop();
+    fn test_field___fpu_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ip)
+            )
+        );
+    }
+    test_field___fpu_ip();
+    fn test_field___fpu_cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_cs)
+            )
+        );
+    }
+    test_field___fpu_cs();
+    fn test_field___fpu_rsrv2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+            },
+            22usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_rsrv2)
+            )
+        );
+    }
+    test_field___fpu_rsrv2();
+    fn test_field___fpu_dp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_dp)
+            )
+        );
+    }
+    test_field___fpu_dp();
+    fn test_field___fpu_ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ds)
+            )
+        );
+    }
+    test_field___fpu_ds();
+    fn test_field___fpu_rsrv3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+            },
+            30usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_rsrv3)
+            )
+        );
+    }
+    test_field___fpu_rsrv3();
+    fn test_field___fpu_mxcsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_mxcsr)
+            )
+        );
+    }
+    test_field___fpu_mxcsr();
+    fn test_field___fpu_mxcsrmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_mxcsrmask)
+            )
+        );
+    }
+    test_field___fpu_mxcsrmask();
+    fn test_field___fpu_stmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm0)
+            )
+        );
+    }
+    test_field___fpu_stmm0();
+    fn test_field___fpu_stmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm1)
+            )
+        );
+    }
+    test_field___fpu_stmm1();
+    fn test_field___fpu_stmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm2)
+            )
+        );
+    }
+    test_field___fpu_stmm2();
+    fn test_field___fpu_stmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm3)
+            )
+        );
+    }
+    test_field___fpu_stmm3();
+    fn test_field___fpu_stmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm4)
+            )
+        );
+    }
+    test_field___fpu_stmm4();
+    fn test_field___fpu_stmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm5)
+            )
+        );
+    }
+    test_field___fpu_stmm5();
+    fn test_field___fpu_stmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm6)
+            )
+        );
+    }
+    test_field___fpu_stmm6();
+    fn test_field___fpu_stmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_stmm7)
+            )
+        );
+    }
+    test_field___fpu_stmm7();
+    fn test_field___fpu_xmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm0)
+            )
+        );
+    }
+    test_field___fpu_xmm0();
+    fn test_field___fpu_xmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm1)
+            )
+        );
+    }
+    test_field___fpu_xmm1();
+    fn test_field___fpu_xmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm2)
+            )
+        );
+    }
+    test_field___fpu_xmm2();
+    fn test_field___fpu_xmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm3)
+            )
+        );
+    }
+    test_field___fpu_xmm3();
+    fn test_field___fpu_xmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm4)
+            )
+        );
+    }
+    test_field___fpu_xmm4();
+    fn test_field___fpu_xmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm5)
+            )
+        );
+    }
+    test_field___fpu_xmm5();
+    fn test_field___fpu_xmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm6)
+            )
+        );
+    }
+    test_field___fpu_xmm6();
+    fn test_field___fpu_xmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_xmm7)
+            )
+        );
+    }
+    test_field___fpu_xmm7();
+    fn test_field___fpu_rsrv4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_rsrv4)
+            )
+        );
+    }
+    test_field___fpu_rsrv4();
+    fn test_field___fpu_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_reserved1)
+            )
+        );
+    }
+    test_field___fpu_reserved1();
+    fn test_field___avx_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+            },
+            524usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__avx_reserved1)
+            )
+        );
+    }
+    test_field___avx_reserved1();
+    fn test_field___fpu_ymmh0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+            },
+            588usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh0)
+            )
+        );
+    }
+    test_field___fpu_ymmh0();
+    fn test_field___fpu_ymmh1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+            },
+            604usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh1)
+            )
+        );
+    }
+    test_field___fpu_ymmh1();
+    fn test_field___fpu_ymmh2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+            },
+            620usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh2)
+            )
+        );
+    }
+    test_field___fpu_ymmh2();
+    fn test_field___fpu_ymmh3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+            },
+            636usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh3)
+            )
+        );
+    }
+    test_field___fpu_ymmh3();
+    fn test_field___fpu_ymmh4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+            },
+            652usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh4)
+            )
+        );
+    }
+    test_field___fpu_ymmh4();
+    fn test_field___fpu_ymmh5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+            },
+            668usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh5)
+            )
+        );
+    }
+    test_field___fpu_ymmh5();
+    fn test_field___fpu_ymmh6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+            },
+            684usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh6)
+            )
+        );
+    }
+    test_field___fpu_ymmh6();
+    fn test_field___fpu_ymmh7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+            },
+            700usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_ymmh7)
+            )
+        );
+    }
+    test_field___fpu_ymmh7();
+    fn test_field___fpu_k0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k0) as usize - ptr as usize
+            },
+            716usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k0)
+            )
+        );
+    }
+    test_field___fpu_k0();
+    fn test_field___fpu_k1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k1) as usize - ptr as usize
+            },
+            724usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k1)
+            )
+        );
+    }
+    test_field___fpu_k1();
+    fn test_field___fpu_k2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k2) as usize - ptr as usize
+            },
+            732usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k2)
+            )
+        );
+    }
+    test_field___fpu_k2();
+    fn test_field___fpu_k3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k3) as usize - ptr as usize
+            },
+            740usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k3)
+            )
+        );
+    }
+    test_field___fpu_k3();
+    fn test_field___fpu_k4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k4) as usize - ptr as usize
+            },
+            748usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k4)
+            )
+        );
+    }
+    test_field___fpu_k4();
+    fn test_field___fpu_k5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k5) as usize - ptr as usize
+            },
+            756usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k5)
+            )
+        );
+    }
+    test_field___fpu_k5();
+    fn test_field___fpu_k6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k6) as usize - ptr as usize
+            },
+            764usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k6)
+            )
+        );
+    }
+    test_field___fpu_k6();
+    fn test_field___fpu_k7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k7) as usize - ptr as usize
+            },
+            772usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_k7)
+            )
+        );
+    }
+    test_field___fpu_k7();
+    fn test_field___fpu_zmmh0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh0) as usize - ptr as usize
+            },
+            780usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh0)
+            )
+        );
+    }
+    test_field___fpu_zmmh0();
+    fn test_field___fpu_zmmh1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh1) as usize - ptr as usize
+            },
+            812usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh1)
+            )
+        );
+    }
+    test_field___fpu_zmmh1();
+    fn test_field___fpu_zmmh2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh2) as usize - ptr as usize
+            },
+            844usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh2)
+            )
+        );
+    }
+    test_field___fpu_zmmh2();
+    fn test_field___fpu_zmmh3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh3) as usize - ptr as usize
+            },
+            876usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh3)
+            )
+        );
+    }
+    test_field___fpu_zmmh3();
+    fn test_field___fpu_zmmh4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh4) as usize - ptr as usize
+            },
+            908usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh4)
+            )
+        );
+    }
+    test_field___fpu_zmmh4();
+    fn test_field___fpu_zmmh5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh5) as usize - ptr as usize
+            },
+            940usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh5)
+            )
+        );
+    }
+    test_field___fpu_zmmh5();
+    fn test_field___fpu_zmmh6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh6) as usize - ptr as usize
+            },
+            972usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh6)
+            )
+        );
+    }
+    test_field___fpu_zmmh6();
+    fn test_field___fpu_zmmh7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh7) as usize - ptr as usize
+            },
+            1004usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_avx512_state),
+                "::",
+                stringify!(__fpu_zmmh7)
+            )
+        );
+    }
+    test_field___fpu_zmmh7();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_i386_exception_state {
+    pub __trapno: __uint16_t,
+    pub __cpu: __uint16_t,
+    pub __err: __uint32_t,
+    pub __faultvaddr: __uint32_t,
+}
+#[test]
+fn bindgen_test_layout___darwin_i386_exception_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_i386_exception_state>(),
+        12usize,
+        concat!("Size of: ", stringify!(__darwin_i386_exception_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_i386_exception_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_i386_exception_state))
+    );
+    fn test_field___trapno() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__trapno) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_exception_state),
+                "::",
+                stringify!(__trapno)
+            )
+        );
+    }
+    test_field___trapno();
+    fn test_field___cpu() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__cpu) as usize - ptr as usize
+            },
+            2usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_exception_state),
+                "::",
+                stringify!(__cpu)
+            )
+        );
+    }
+    test_field___cpu();
+    fn test_field___err() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__err) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_exception_state),
+                "::",
+                stringify!(__err)
+            )
+        );
+    }
+    test_field___err();
+    fn test_field___faultvaddr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__faultvaddr) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_i386_exception_state),
+                "::",
+                stringify!(__faultvaddr)
+            )
+        );
+    }
+    test_field___faultvaddr();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_debug_state32 {
+    pub __dr0: ::std::os::raw::c_uint,
+    pub __dr1: ::std::os::raw::c_uint,
+    pub __dr2: ::std::os::raw::c_uint,
+    pub __dr3: ::std::os::raw::c_uint,
+    pub __dr4: ::std::os::raw::c_uint,
+    pub __dr5: ::std::os::raw::c_uint,
+    pub __dr6: ::std::os::raw::c_uint,
+    pub __dr7: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_debug_state32() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_debug_state32>(),
+        32usize,
+        concat!("Size of: ", stringify!(__darwin_x86_debug_state32))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_debug_state32>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_debug_state32))
+    );
+    fn test_field___dr0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr0) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr0)
+            )
+        );
+    }
+    test_field___dr0();
+    fn test_field___dr1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr1) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr1)
+            )
+        );
+    }
+    test_field___dr1();
+    fn test_field___dr2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr2) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr2)
+            )
+        );
+    }
+    test_field___dr2();
+    fn test_field___dr3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr3) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr3)
+            )
+        );
+    }
+    test_field___dr3();
+    fn test_field___dr4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr4) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr4)
+            )
+        );
+    }
+    test_field___dr4();
+    fn test_field___dr5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr5) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr5)
+            )
+        );
+    }
+    test_field___dr5();
+    fn test_field___dr6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr6) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr6)
+            )
+        );
+    }
+    test_field___dr6();
+    fn test_field___dr7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr7) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state32),
+                "::",
+                stringify!(__dr7)
+            )
+        );
+    }
+    test_field___dr7();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __x86_instruction_state {
+    pub __insn_stream_valid_bytes: ::std::os::raw::c_int,
+    pub __insn_offset: ::std::os::raw::c_int,
+    pub __out_of_synch: ::std::os::raw::c_int,
+    pub __insn_bytes: [__uint8_t; 2380usize],
+    pub __insn_cacheline: [__uint8_t; 64usize],
+}
+#[test]
+fn bindgen_test_layout___x86_instruction_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__x86_instruction_state>(),
+        2456usize,
+        concat!("Size of: ", stringify!(__x86_instruction_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__x86_instruction_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__x86_instruction_state))
+    );
+    fn test_field___insn_stream_valid_bytes() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__insn_stream_valid_bytes) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__x86_instruction_state),
+                "::",
+                stringify!(__insn_stream_valid_bytes)
+            )
+        );
+    }
+    test_field___insn_stream_valid_bytes();
+    fn test_field___insn_offset() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__insn_offset) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__x86_instruction_state),
+                "::",
+                stringify!(__insn_offset)
+            )
+        );
+    }
+    test_field___insn_offset();
+    fn test_field___out_of_synch() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__out_of_synch) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__x86_instruction_state),
+                "::",
+                stringify!(__out_of_synch)
+            )
+        );
+    }
+    test_field___out_of_synch();
+    fn test_field___insn_bytes() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__insn_bytes) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__x86_instruction_state),
+                "::",
+                stringify!(__insn_bytes)
+            )
+        );
+    }
+    test_field___insn_bytes();
+    fn test_field___insn_cacheline() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__insn_cacheline) as usize - ptr as usize
+            },
+            2392usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__x86_instruction_state),
+                "::",
+                stringify!(__insn_cacheline)
+            )
+        );
+    }
+    test_field___insn_cacheline();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __last_branch_record {
+    pub __from_ip: __uint64_t,
+    pub __to_ip: __uint64_t,
+    pub _bitfield_align_1: [u16; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+    pub __bindgen_padding_0: u32,
+}
+#[test]
+fn bindgen_test_layout___last_branch_record() {
+    assert_eq!(
+        ::std::mem::size_of::<__last_branch_record>(),
+        24usize,
+        concat!("Size of: ", stringify!(__last_branch_record))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__last_branch_record>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__last_branch_record))
+    );
+    fn test_field___from_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__last_branch_record>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__from_ip) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__last_branch_record),
+                "::",
+                stringify!(__from_ip)
+            )
+        );
+    }
+    test_field___from_ip();
+    fn test_field___to_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__last_branch_record>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__to_ip) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__last_branch_record),
+                "::",
+                stringify!(__to_ip)
+            )
+        );
+    }
+    test_field___to_ip();
+}
+impl __last_branch_record {
+    #[inline]
+    pub fn __mispredict(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set___mispredict(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __tsx_abort(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set___tsx_abort(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __in_tsx(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set___in_tsx(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __cycle_count(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 16u8) as u32) }
+    }
+    #[inline]
+    pub fn set___cycle_count(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(3usize, 16u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __reserved(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(19usize, 13u8) as u32) }
+    }
+    #[inline]
+    pub fn set___reserved(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(19usize, 13u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        __mispredict: __uint32_t,
+        __tsx_abort: __uint32_t,
+        __in_tsx: __uint32_t,
+        __cycle_count: __uint32_t,
+        __reserved: __uint32_t,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let __mispredict: u32 = unsafe { ::std::mem::transmute(__mispredict) };
+            __mispredict as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let __tsx_abort: u32 = unsafe { ::std::mem::transmute(__tsx_abort) };
+            __tsx_abort as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let __in_tsx: u32 = unsafe { ::std::mem::transmute(__in_tsx) };
+            __in_tsx as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 16u8, {
+            let __cycle_count: u32 = unsafe { ::std::mem::transmute(__cycle_count) };
+            __cycle_count as u64
+        });
+        __bindgen_bitfield_unit.set(19usize, 13u8, {
+            let __reserved: u32 = unsafe { ::std::mem::transmute(__reserved) };
+            __reserved as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __last_branch_state {
+    pub __lbr_count: ::std::os::raw::c_int,
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+    pub __lbrs: [__last_branch_record; 32usize],
+}
+#[test]
+fn bindgen_test_layout___last_branch_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__last_branch_state>(),
+        776usize,
+        concat!("Size of: ", stringify!(__last_branch_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__last_branch_state>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__last_branch_state))
+    );
+    fn test_field___lbr_count() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__last_branch_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__lbr_count) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__last_branch_state),
+                "::",
+                stringify!(__lbr_count)
+            )
+        );
+    }
+    test_field___lbr_count();
+    fn test_field___lbrs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__last_branch_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__lbrs) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__last_branch_state),
+                "::",
+                stringify!(__lbrs)
+            )
+        );
+    }
+    test_field___lbrs();
+}
+impl __last_branch_state {
+    #[inline]
+    pub fn __lbr_supported_tsx(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set___lbr_supported_tsx(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __lbr_supported_cycle_count(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set___lbr_supported_cycle_count(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn __reserved(&self) -> __uint32_t {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 30u8) as u32) }
+    }
+    #[inline]
+    pub fn set___reserved(&mut self, val: __uint32_t) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(2usize, 30u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        __lbr_supported_tsx: __uint32_t,
+        __lbr_supported_cycle_count: __uint32_t,
+        __reserved: __uint32_t,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let __lbr_supported_tsx: u32 = unsafe { ::std::mem::transmute(__lbr_supported_tsx) };
+            __lbr_supported_tsx as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let __lbr_supported_cycle_count: u32 =
+                unsafe { ::std::mem::transmute(__lbr_supported_cycle_count) };
+            __lbr_supported_cycle_count as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 30u8, {
+            let __reserved: u32 = unsafe { ::std::mem::transmute(__reserved) };
+            __reserved as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __x86_pagein_state {
+    pub __pagein_error: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout___x86_pagein_state() {
+    assert_eq!(
+        ::std::mem::size_of::<__x86_pagein_state>(),
+        4usize,
+        concat!("Size of: ", stringify!(__x86_pagein_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__x86_pagein_state>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__x86_pagein_state))
+    );
+    fn test_field___pagein_error() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__x86_pagein_state>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__pagein_error) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__x86_pagein_state),
+                "::",
+                stringify!(__pagein_error)
+            )
+        );
+    }
+    test_field___pagein_error();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_thread_state64 {
+    pub __rax: __uint64_t,
+    pub __rbx: __uint64_t,
+    pub __rcx: __uint64_t,
+    pub __rdx: __uint64_t,
+    pub __rdi: __uint64_t,
+    pub __rsi: __uint64_t,
+    pub __rbp: __uint64_t,
+    pub __rsp: __uint64_t,
+    pub __r8: __uint64_t,
+    pub __r9: __uint64_t,
+    pub __r10: __uint64_t,
+    pub __r11: __uint64_t,
+    pub __r12: __uint64_t,
+    pub __r13: __uint64_t,
+    pub __r14: __uint64_t,
+    pub __r15: __uint64_t,
+    pub __rip: __uint64_t,
+    pub __rflags: __uint64_t,
+    pub __cs: __uint64_t,
+    pub __fs: __uint64_t,
+    pub __gs: __uint64_t,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_thread_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_thread_state64>(),
+        168usize,
+        concat!("Size of: ", stringify!(__darwin_x86_thread_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_thread_state64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_thread_state64))
+    );
+    fn test_field___rax() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rax) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rax)
+            )
+        );
+    }
+    test_field___rax();
+    fn test_field___rbx() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rbx) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rbx)
+            )
+        );
+    }
+    test_field___rbx();
+    fn test_field___rcx() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rcx) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rcx)
+            )
+        );
+    }
+    test_field___rcx();
+    fn test_field___rdx() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rdx) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rdx)
+            )
+        );
+    }
+    test_field___rdx();
+    fn test_field___rdi() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rdi) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rdi)
+            )
+        );
+    }
+    test_field___rdi();
+    fn test_field___rsi() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rsi) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rsi)
+            )
+        );
+    }
+    test_field___rsi();
+    fn test_field___rbp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rbp) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rbp)
+            )
+        );
+    }
+    test_field___rbp();
+    fn test_field___rsp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rsp) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rsp)
+            )
+        );
+    }
+    test_field___rsp();
+    fn test_field___r8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r8) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r8)
+            )
+        );
+    }
+    test_field___r8();
+    fn test_field___r9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r9) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r9)
+            )
+        );
+    }
+    test_field___r9();
+    fn test_field___r10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r10) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r10)
+            )
+        );
+    }
+    test_field___r10();
+    fn test_field___r11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r11) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r11)
+            )
+        );
+    }
+    test_field___r11();
+    fn test_field___r12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r12) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r12)
+            )
+        );
+    }
+    test_field___r12();
+    fn test_field___r13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r13) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r13)
+            )
+        );
+    }
+    test_field___r13();
+    fn test_field___r14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r14) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r14)
+            )
+        );
+    }
+    test_field___r14();
+    fn test_field___r15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__r15) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__r15)
+            )
+        );
+    }
+    test_field___r15();
+    fn test_field___rip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rip) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rip)
+            )
+        );
+    }
+    test_field___rip();
+    fn test_field___rflags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__rflags) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__rflags)
+            )
+        );
+    }
+    test_field___rflags();
+    fn test_field___cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__cs) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__cs)
+            )
+        );
+    }
+    test_field___cs();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+    fn test_field___gs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__gs) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_state64),
+                "::",
+                stringify!(__gs)
+            )
+        );
+    }
+    test_field___gs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_thread_full_state64 {
+    pub __ss64: __darwin_x86_thread_state64,
+    pub __ds: __uint64_t,
+    pub __es: __uint64_t,
+    pub __ss: __uint64_t,
+    pub __gsbase: __uint64_t,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_thread_full_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_thread_full_state64>(),
+        200usize,
+        concat!("Size of: ", stringify!(__darwin_x86_thread_full_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_thread_full_state64>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(__darwin_x86_thread_full_state64)
+        )
+    );
+    fn test_field___ss64() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss64) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_full_state64),
+                "::",
+                stringify!(__ss64)
+            )
+        );
+    }
+    test_field___ss64();
+    fn test_field___ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ds) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_full_state64),
+                "::",
+                stringify!(__ds)
+            )
+        );
+    }
+    test_field___ds();
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_full_state64),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_full_state64),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___gsbase() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__gsbase) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_thread_full_state64),
+                "::",
+                stringify!(__gsbase)
+            )
+        );
+    }
+    test_field___gsbase();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_float_state64 {
+    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_fcw: __darwin_fp_control,
+    pub __fpu_fsw: __darwin_fp_status,
+    pub __fpu_ftw: __uint8_t,
+    pub __fpu_rsrv1: __uint8_t,
+    pub __fpu_fop: __uint16_t,
+    pub __fpu_ip: __uint32_t,
+    pub __fpu_cs: __uint16_t,
+    pub __fpu_rsrv2: __uint16_t,
+    pub __fpu_dp: __uint32_t,
+    pub __fpu_ds: __uint16_t,
+    pub __fpu_rsrv3: __uint16_t,
+    pub __fpu_mxcsr: __uint32_t,
+    pub __fpu_mxcsrmask: __uint32_t,
+    pub __fpu_stmm0: __darwin_mmst_reg,
+    pub __fpu_stmm1: __darwin_mmst_reg,
+    pub __fpu_stmm2: __darwin_mmst_reg,
+    pub __fpu_stmm3: __darwin_mmst_reg,
+    pub __fpu_stmm4: __darwin_mmst_reg,
+    pub __fpu_stmm5: __darwin_mmst_reg,
+    pub __fpu_stmm6: __darwin_mmst_reg,
+    pub __fpu_stmm7: __darwin_mmst_reg,
+    pub __fpu_xmm0: __darwin_xmm_reg,
+    pub __fpu_xmm1: __darwin_xmm_reg,
+    pub __fpu_xmm2: __darwin_xmm_reg,
+    pub __fpu_xmm3: __darwin_xmm_reg,
+    pub __fpu_xmm4: __darwin_xmm_reg,
+    pub __fpu_xmm5: __darwin_xmm_reg,
+    pub __fpu_xmm6: __darwin_xmm_reg,
+    pub __fpu_xmm7: __darwin_xmm_reg,
+    pub __fpu_xmm8: __darwin_xmm_reg,
+    pub __fpu_xmm9: __darwin_xmm_reg,
+    pub __fpu_xmm10: __darwin_xmm_reg,
+    pub __fpu_xmm11: __darwin_xmm_reg,
+    pub __fpu_xmm12: __darwin_xmm_reg,
+    pub __fpu_xmm13: __darwin_xmm_reg,
+    pub __fpu_xmm14: __darwin_xmm_reg,
+    pub __fpu_xmm15: __darwin_xmm_reg,
+    pub __fpu_rsrv4: [::std::os::raw::c_char; 96usize],
+    pub __fpu_reserved1: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_float_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_float_state64>(),
+        524usize,
+        concat!("Size of: ", stringify!(__darwin_x86_float_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_float_state64>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_float_state64))
+    );
+    fn test_field___fpu_reserved() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_reserved)
+            )
+        );
+    }
+    test_field___fpu_reserved();
+    fn test_field___fpu_fcw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_fcw)
+            )
+        );
+    }
+    test_field___fpu_fcw();
+    fn test_field___fpu_fsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_fsw)
+            )
+        );
+    }
+    test_field___fpu_fsw();
+    fn test_field___fpu_ftw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_ftw)
+            )
+        );
+    }
+    test_field___fpu_ftw();
+    fn test_field___fpu_rsrv1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+            },
+            13usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_rsrv1)
+            )
+        );
+    }
+    test_field___fpu_rsrv1();
+    fn test_field___fpu_fop() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+            },
+            14usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_fop)
+            )
+        );
+    }
+    test_field___fpu_fop();
+    fn test_field___fpu_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_ip)
+            )
+        );
+    }
+    test_field___fpu_ip();
+    fn test_field___fpu_cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_cs)
+            )
+        );
+    }
+    test_field___fpu_cs();
+    fn test_field___fpu_rsrv2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+            },
+            22usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_rsrv2)
+            )
+        );
+    }
+    test_field___fpu_rsrv2();
+    fn test_field___fpu_dp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_dp)
+            )
+        );
+    }
+    test_field___fpu_dp();
+    fn test_field___fpu_ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_ds)
+            )
+        );
+    }
+    test_field___fpu_ds();
+    fn test_field___fpu_rsrv3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+            },
+            30usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_rsrv3)
+            )
+        );
+    }
+    test_field___fpu_rsrv3();
+    fn test_field___fpu_mxcsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_mxcsr)
+            )
+        );
+    }
+    test_field___fpu_mxcsr();
+    fn test_field___fpu_mxcsrmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_mxcsrmask)
+            )
+        );
+    }
+    test_field___fpu_mxcsrmask();
+    fn test_field___fpu_stmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm0)
+            )
+        );
+    }
+    test_field___fpu_stmm0();
+    fn test_field___fpu_stmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm1)
+            )
+        );
+    }
+    test_field___fpu_stmm1();
+    fn test_field___fpu_stmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm2)
+            )
+        );
+    }
+    test_field___fpu_stmm2();
+    fn test_field___fpu_stmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm3)
+            )
+        );
+    }
+    test_field___fpu_stmm3();
+    fn test_field___fpu_stmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm4)
+            )
+        );
+    }
+    test_field___fpu_stmm4();
+    fn test_field___fpu_stmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm5)
+            )
+        );
+    }
+    test_field___fpu_stmm5();
+    fn test_field___fpu_stmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm6)
+            )
+        );
+    }
+    test_field___fpu_stmm6();
+    fn test_field___fpu_stmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_stmm7)
+            )
+        );
+    }
+    test_field___fpu_stmm7();
+    fn test_field___fpu_xmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm0)
+            )
+        );
+    }
+    test_field___fpu_xmm0();
+    fn test_field___fpu_xmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm1)
+            )
+        );
+    }
+    test_field___fpu_xmm1();
+    fn test_field___fpu_xmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm2)
+            )
+        );
+    }
+    test_field___fpu_xmm2();
+    fn test_field___fpu_xmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm3)
+            )
+        );
+    }
+    test_field___fpu_xmm3();
+    fn test_field___fpu_xmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm4)
+            )
+        );
+    }
+    test_field___fpu_xmm4();
+    fn test_field___fpu_xmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm5)
+            )
+        );
+    }
+    test_field___fpu_xmm5();
+    fn test_field___fpu_xmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm6)
+            )
+        );
+    }
+    test_field___fpu_xmm6();
+    fn test_field___fpu_xmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm7)
+            )
+        );
+    }
+    test_field___fpu_xmm7();
+    fn test_field___fpu_xmm8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm8)
+            )
+        );
+    }
+    test_field___fpu_xmm8();
+    fn test_field___fpu_xmm9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
+            },
+            312usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm9)
+            )
+        );
+    }
+    test_field___fpu_xmm9();
+    fn test_field___fpu_xmm10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
+            },
+            328usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm10)
+            )
+        );
+    }
+    test_field___fpu_xmm10();
+    fn test_field___fpu_xmm11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
+            },
+            344usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm11)
+            )
+        );
+    }
+    test_field___fpu_xmm11();
+    fn test_field___fpu_xmm12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
+            },
+            360usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm12)
+            )
+        );
+    }
+    test_field___fpu_xmm12();
+    fn test_field___fpu_xmm13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
+            },
+            376usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm13)
+            )
+        );
+    }
+    test_field___fpu_xmm13();
+    fn test_field___fpu_xmm14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
+            },
+            392usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm14)
+            )
+        );
+    }
+    test_field___fpu_xmm14();
+    fn test_field___fpu_xmm15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
+            },
+            408usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_xmm15)
+            )
+        );
+    }
+    test_field___fpu_xmm15();
+    fn test_field___fpu_rsrv4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+            },
+            424usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_rsrv4)
+            )
+        );
+    }
+    test_field___fpu_rsrv4();
+    fn test_field___fpu_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_float_state64),
+                "::",
+                stringify!(__fpu_reserved1)
+            )
+        );
+    }
+    test_field___fpu_reserved1();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_avx_state64 {
+    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_fcw: __darwin_fp_control,
+    pub __fpu_fsw: __darwin_fp_status,
+    pub __fpu_ftw: __uint8_t,
+    pub __fpu_rsrv1: __uint8_t,
+    pub __fpu_fop: __uint16_t,
+    pub __fpu_ip: __uint32_t,
+    pub __fpu_cs: __uint16_t,
+    pub __fpu_rsrv2: __uint16_t,
+    pub __fpu_dp: __uint32_t,
+    pub __fpu_ds: __uint16_t,
+    pub __fpu_rsrv3: __uint16_t,
+    pub __fpu_mxcsr: __uint32_t,
+    pub __fpu_mxcsrmask: __uint32_t,
+    pub __fpu_stmm0: __darwin_mmst_reg,
+    pub __fpu_stmm1: __darwin_mmst_reg,
+    pub __fpu_stmm2: __darwin_mmst_reg,
+    pub __fpu_stmm3: __darwin_mmst_reg,
+    pub __fpu_stmm4: __darwin_mmst_reg,
+    pub __fpu_stmm5: __darwin_mmst_reg,
+    pub __fpu_stmm6: __darwin_mmst_reg,
+    pub __fpu_stmm7: __darwin_mmst_reg,
+    pub __fpu_xmm0: __darwin_xmm_reg,
+    pub __fpu_xmm1: __darwin_xmm_reg,
+    pub __fpu_xmm2: __darwin_xmm_reg,
+    pub __fpu_xmm3: __darwin_xmm_reg,
+    pub __fpu_xmm4: __darwin_xmm_reg,
+    pub __fpu_xmm5: __darwin_xmm_reg,
+    pub __fpu_xmm6: __darwin_xmm_reg,
+    pub __fpu_xmm7: __darwin_xmm_reg,
+    pub __fpu_xmm8: __darwin_xmm_reg,
+    pub __fpu_xmm9: __darwin_xmm_reg,
+    pub __fpu_xmm10: __darwin_xmm_reg,
+    pub __fpu_xmm11: __darwin_xmm_reg,
+    pub __fpu_xmm12: __darwin_xmm_reg,
+    pub __fpu_xmm13: __darwin_xmm_reg,
+    pub __fpu_xmm14: __darwin_xmm_reg,
+    pub __fpu_xmm15: __darwin_xmm_reg,
+    pub __fpu_rsrv4: [::std::os::raw::c_char; 96usize],
+    pub __fpu_reserved1: ::std::os::raw::c_int,
+    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_ymmh0: __darwin_xmm_reg,
+    pub __fpu_ymmh1: __darwin_xmm_reg,
+    pub __fpu_ymmh2: __darwin_xmm_reg,
+    pub __fpu_ymmh3: __darwin_xmm_reg,
+    pub __fpu_ymmh4: __darwin_xmm_reg,
+    pub __fpu_ymmh5: __darwin_xmm_reg,
+    pub __fpu_ymmh6: __darwin_xmm_reg,
+    pub __fpu_ymmh7: __darwin_xmm_reg,
+    pub __fpu_ymmh8: __darwin_xmm_reg,
+    pub __fpu_ymmh9: __darwin_xmm_reg,
+    pub __fpu_ymmh10: __darwin_xmm_reg,
+    pub __fpu_ymmh11: __darwin_xmm_reg,
+    pub __fpu_ymmh12: __darwin_xmm_reg,
+    pub __fpu_ymmh13: __darwin_xmm_reg,
+    pub __fpu_ymmh14: __darwin_xmm_reg,
+    pub __fpu_ymmh15: __darwin_xmm_reg,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_avx_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_avx_state64>(),
+        844usize,
+        concat!("Size of: ", stringify!(__darwin_x86_avx_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_avx_state64>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_avx_state64))
+    );
+    fn test_field___fpu_reserved() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_reserved)
+            )
+        );
+    }
+    test_field___fpu_reserved();
+    fn test_field___fpu_fcw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_fcw)
+            )
+        );
+    }
+    test_field___fpu_fcw();
+    fn test_field___fpu_fsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_fsw)
+            )
+        );
+    }
+    test_field___fpu_fsw();
+    fn test_field___fpu_ftw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ftw)
+            )
+        );
+    }
+    test_field___fpu_ftw();
+    fn test_field___fpu_rsrv1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+            },
+            13usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_rsrv1)
+            )
+        );
+    }
+    test_field___fpu_rsrv1();
+    fn test_field___fpu_fop() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+            },
+            14usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_fop)
+            )
+        );
+    }
+    test_field___fpu_fop();
+    fn test_field___fpu_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ip)
+            )
+        );
+    }
+    test_field___fpu_ip();
+    fn test_field___fpu_cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_cs)
+            )
+        );
+    }
+    test_field___fpu_cs();
+    fn test_field___fpu_rsrv2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+            },
+            22usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_rsrv2)
+            )
+        );
+    }
+    test_field___fpu_rsrv2();
+    fn test_field___fpu_dp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_dp)
+            )
+        );
+    }
+    test_field___fpu_dp();
+    fn test_field___fpu_ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ds)
+            )
+        );
+    }
+    test_field___fpu_ds();
+    fn test_field___fpu_rsrv3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+            },
+            30usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_rsrv3)
+            )
+        );
+    }
+    test_field___fpu_rsrv3();
+    fn test_field___fpu_mxcsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_mxcsr)
+            )
+        );
+    }
+    test_field___fpu_mxcsr();
+    fn test_field___fpu_mxcsrmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_mxcsrmask)
+            )
+        );
+    }
+    test_field___fpu_mxcsrmask();
+    fn test_field___fpu_stmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm0)
+            )
+        );
+    }
+    test_field___fpu_stmm0();
+    fn test_field___fpu_stmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm1)
+            )
+        );
+    }
+    test_field___fpu_stmm1();
+    fn test_field___fpu_stmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm2)
+            )
+        );
+    }
+    test_field___fpu_stmm2();
+    fn test_field___fpu_stmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm3)
+            )
+        );
+    }
+    test_field___fpu_stmm3();
+    fn test_field___fpu_stmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm4)
+            )
+        );
+    }
+    test_field___fpu_stmm4();
+    fn test_field___fpu_stmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm5)
+            )
+        );
+    }
+    test_field___fpu_stmm5();
+    fn test_field___fpu_stmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm6)
+            )
+        );
+    }
+    test_field___fpu_stmm6();
+    fn test_field___fpu_stmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_stmm7)
+            )
+        );
+    }
+    test_field___fpu_stmm7();
+    fn test_field___fpu_xmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm0)
+            )
+        );
+    }
+    test_field___fpu_xmm0();
+    fn test_field___fpu_xmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm1)
+            )
+        );
+    }
+    test_field___fpu_xmm1();
+    fn test_field___fpu_xmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm2)
+            )
+        );
+    }
+    test_field___fpu_xmm2();
+    fn test_field___fpu_xmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm3)
+            )
+        );
+    }
+    test_field___fpu_xmm3();
+    fn test_field___fpu_xmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm4)
+            )
+        );
+    }
+    test_field___fpu_xmm4();
+    fn test_field___fpu_xmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm5)
+            )
+        );
+    }
+    test_field___fpu_xmm5();
+    fn test_field___fpu_xmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm6)
+            )
+        );
+    }
+    test_field___fpu_xmm6();
+    fn test_field___fpu_xmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm7)
+            )
+        );
+    }
+    test_field___fpu_xmm7();
+    fn test_field___fpu_xmm8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm8)
+            )
+        );
+    }
+    test_field___fpu_xmm8();
+    fn test_field___fpu_xmm9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
+            },
+            312usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm9)
+            )
+        );
+    }
+    test_field___fpu_xmm9();
+    fn test_field___fpu_xmm10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
+            },
+            328usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm10)
+            )
+        );
+    }
+    test_field___fpu_xmm10();
+    fn test_field___fpu_xmm11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
+            },
+            344usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm11)
+            )
+        );
+    }
+    test_field___fpu_xmm11();
+    fn test_field___fpu_xmm12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
+            },
+            360usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm12)
+            )
+        );
+    }
+    test_field___fpu_xmm12();
+    fn test_field___fpu_xmm13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
+            },
+            376usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm13)
+            )
+        );
+    }
+    test_field___fpu_xmm13();
+    fn test_field___fpu_xmm14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
+            },
+            392usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm14)
+            )
+        );
+    }
+    test_field___fpu_xmm14();
+    fn test_field___fpu_xmm15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
+            },
+            408usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_xmm15)
+            )
+        );
+    }
+    test_field___fpu_xmm15();
+    fn test_field___fpu_rsrv4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+            },
+            424usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_rsrv4)
+            )
+        );
+    }
+    test_field___fpu_rsrv4();
+    fn test_field___fpu_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_reserved1)
+            )
+        );
+    }
+    test_field___fpu_reserved1();
+    fn test_field___avx_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+            },
+            524usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__avx_reserved1)
+            )
+        );
+    }
+    test_field___avx_reserved1();
+    fn test_field___fpu_ymmh0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+            },
+            588usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh0)
+            )
+        );
+    }
+    test_field___fpu_ymmh0();
+    fn test_field___fpu_ymmh1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+            },
+            604usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh1)
+            )
+        );
+    }
+    test_field___fpu_ymmh1();
+    fn test_field___fpu_ymmh2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+            },
+            620usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh2)
+            )
+        );
+    }
+    test_field___fpu_ymmh2();
+    fn test_field___fpu_ymmh3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+            },
+            636usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh3)
+            )
+        );
+    }
+    test_field___fpu_ymmh3();
+    fn test_field___fpu_ymmh4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+            },
+            652usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh4)
+            )
+        );
+    }
+    test_field___fpu_ymmh4();
+    fn test_field___fpu_ymmh5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+            },
+            668usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh5)
+            )
+        );
+    }
+    test_field___fpu_ymmh5();
+    fn test_field___fpu_ymmh6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+            },
+            684usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh6)
+            )
+        );
+    }
+    test_field___fpu_ymmh6();
+    fn test_field___fpu_ymmh7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+            },
+            700usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh7)
+            )
+        );
+    }
+    test_field___fpu_ymmh7();
+    fn test_field___fpu_ymmh8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh8) as usize - ptr as usize
+            },
+            716usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh8)
+            )
+        );
+    }
+    test_field___fpu_ymmh8();
+    fn test_field___fpu_ymmh9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh9) as usize - ptr as usize
+            },
+            732usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh9)
+            )
+        );
+    }
+    test_field___fpu_ymmh9();
+    fn test_field___fpu_ymmh10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh10) as usize - ptr as usize
+            },
+            748usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh10)
+            )
+        );
+    }
+    test_field___fpu_ymmh10();
+    fn test_field___fpu_ymmh11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh11) as usize - ptr as usize
+            },
+            764usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh11)
+            )
+        );
+    }
+    test_field___fpu_ymmh11();
+    fn test_field___fpu_ymmh12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh12) as usize - ptr as usize
+            },
+            780usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh12)
+            )
+        );
+    }
+    test_field___fpu_ymmh12();
+    fn test_field___fpu_ymmh13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh13) as usize - ptr as usize
+            },
+            796usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh13)
+            )
+        );
+    }
+    test_field___fpu_ymmh13();
+    fn test_field___fpu_ymmh14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh14) as usize - ptr as usize
+            },
+            812usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh14)
+            )
+        );
+    }
+    test_field___fpu_ymmh14();
+    fn test_field___fpu_ymmh15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh15) as usize - ptr as usize
+            },
+            828usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx_state64),
+                "::",
+                stringify!(__fpu_ymmh15)
+            )
+        );
+    }
+    test_field___fpu_ymmh15();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_avx512_state64 {
+    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_fcw: __darwin_fp_control,
+    pub __fpu_fsw: __darwin_fp_status,
+    pub __fpu_ftw: __uint8_t,
+    pub __fpu_rsrv1: __uint8_t,
+    pub __fpu_fop: __uint16_t,
+    pub __fpu_ip: __uint32_t,
+    pub __fpu_cs: __uint16_t,
+    pub __fpu_rsrv2: __uint16_t,
+    pub __fpu_dp: __uint32_t,
+    pub __fpu_ds: __uint16_t,
+    pub __fpu_rsrv3: __uint16_t,
+    pub __fpu_mxcsr: __uint32_t,
+    pub __fpu_mxcsrmask: __uint32_t,
+    pub __fpu_stmm0: __darwin_mmst_reg,
+    pub __fpu_stmm1: __darwin_mmst_reg,
+    pub __fpu_stmm2: __darwin_mmst_reg,
+    pub __fpu_stmm3: __darwin_mmst_reg,
+    pub __fpu_stmm4: __darwin_mmst_reg,
+    pub __fpu_stmm5: __darwin_mmst_reg,
+    pub __fpu_stmm6: __darwin_mmst_reg,
+    pub __fpu_stmm7: __darwin_mmst_reg,
+    pub __fpu_xmm0: __darwin_xmm_reg,
+    pub __fpu_xmm1: __darwin_xmm_reg,
+    pub __fpu_xmm2: __darwin_xmm_reg,
+    pub __fpu_xmm3: __darwin_xmm_reg,
+    pub __fpu_xmm4: __darwin_xmm_reg,
+    pub __fpu_xmm5: __darwin_xmm_reg,
+    pub __fpu_xmm6: __darwin_xmm_reg,
+    pub __fpu_xmm7: __darwin_xmm_reg,
+    pub __fpu_xmm8: __darwin_xmm_reg,
+    pub __fpu_xmm9: __darwin_xmm_reg,
+    pub __fpu_xmm10: __darwin_xmm_reg,
+    pub __fpu_xmm11: __darwin_xmm_reg,
+    pub __fpu_xmm12: __darwin_xmm_reg,
+    pub __fpu_xmm13: __darwin_xmm_reg,
+    pub __fpu_xmm14: __darwin_xmm_reg,
+    pub __fpu_xmm15: __darwin_xmm_reg,
+    pub __fpu_rsrv4: [::std::os::raw::c_char; 96usize],
+    pub __fpu_reserved1: ::std::os::raw::c_int,
+    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_ymmh0: __darwin_xmm_reg,
+    pub __fpu_ymmh1: __darwin_xmm_reg,
+    pub __fpu_ymmh2: __darwin_xmm_reg,
+    pub __fpu_ymmh3: __darwin_xmm_reg,
+    pub __fpu_ymmh4: __darwin_xmm_reg,
+    pub __fpu_ymmh5: __darwin_xmm_reg,
+    pub __fpu_ymmh6: __darwin_xmm_reg,
+    pub __fpu_ymmh7: __darwin_xmm_reg,
+    pub __fpu_ymmh8: __darwin_xmm_reg,
+    pub __fpu_ymmh9: __darwin_xmm_reg,
+    pub __fpu_ymmh10: __darwin_xmm_reg,
+    pub __fpu_ymmh11: __darwin_xmm_reg,
+    pub __fpu_ymmh12: __darwin_xmm_reg,
+    pub __fpu_ymmh13: __darwin_xmm_reg,
+    pub __fpu_ymmh14: __darwin_xmm_reg,
+    pub __fpu_ymmh15: __darwin_xmm_reg,
+    pub __fpu_k0: __darwin_opmask_reg,
+    pub __fpu_k1: __darwin_opmask_reg,
+    pub __fpu_k2: __darwin_opmask_reg,
+    pub __fpu_k3: __darwin_opmask_reg,
+    pub __fpu_k4: __darwin_opmask_reg,
+    pub __fpu_k5: __darwin_opmask_reg,
+    pub __fpu_k6: __darwin_opmask_reg,
+    pub __fpu_k7: __darwin_opmask_reg,
+    pub __fpu_zmmh0: __darwin_ymm_reg,
+    pub __fpu_zmmh1: __darwin_ymm_reg,
+    pub __fpu_zmmh2: __darwin_ymm_reg,
+    pub __fpu_zmmh3: __darwin_ymm_reg,
+    pub __fpu_zmmh4: __darwin_ymm_reg,
+    pub __fpu_zmmh5: __darwin_ymm_reg,
+    pub __fpu_zmmh6: __darwin_ymm_reg,
+    pub __fpu_zmmh7: __darwin_ymm_reg,
+    pub __fpu_zmmh8: __darwin_ymm_reg,
+    pub __fpu_zmmh9: __darwin_ymm_reg,
+    pub __fpu_zmmh10: __darwin_ymm_reg,
+    pub __fpu_zmmh11: __darwin_ymm_reg,
+    pub __fpu_zmmh12: __darwin_ymm_reg,
+    pub __fpu_zmmh13: __darwin_ymm_reg,
+    pub __fpu_zmmh14: __darwin_ymm_reg,
+    pub __fpu_zmmh15: __darwin_ymm_reg,
+    pub __fpu_zmm16: __darwin_zmm_reg,
+    pub __fpu_zmm17: __darwin_zmm_reg,
+    pub __fpu_zmm18: __darwin_zmm_reg,
+    pub __fpu_zmm19: __darwin_zmm_reg,
+    pub __fpu_zmm20: __darwin_zmm_reg,
+    pub __fpu_zmm21: __darwin_zmm_reg,
+    pub __fpu_zmm22: __darwin_zmm_reg,
+    pub __fpu_zmm23: __darwin_zmm_reg,
+    pub __fpu_zmm24: __darwin_zmm_reg,
+    pub __fpu_zmm25: __darwin_zmm_reg,
+    pub __fpu_zmm26: __darwin_zmm_reg,
+    pub __fpu_zmm27: __darwin_zmm_reg,
+    pub __fpu_zmm28: __darwin_zmm_reg,
+    pub __fpu_zmm29: __darwin_zmm_reg,
+    pub __fpu_zmm30: __darwin_zmm_reg,
+    pub __fpu_zmm31: __darwin_zmm_reg,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_avx512_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_avx512_state64>(),
+        2444usize,
+        concat!("Size of: ", stringify!(__darwin_x86_avx512_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_avx512_state64>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_avx512_state64))
+    );
+    fn test_field___fpu_reserved() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_reserved)
+            )
+        );
+    }
+    test_field___fpu_reserved();
+    fn test_field___fpu_fcw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_fcw)
+            )
+        );
+    }
+    test_field___fpu_fcw();
+    fn test_field___fpu_fsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_fsw)
+            )
+        );
+    }
+    test_field___fpu_fsw();
+    fn test_field___fpu_ftw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ftw)
+            )
+        );
+    }
+    test_field___fpu_ftw();
+    fn test_field___fpu_rsrv1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+            },
+            13usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_rsrv1)
+            )
+        );
+    }
+    test_field___fpu_rsrv1();
+    fn test_field___fpu_fop() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+            },
+            14usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_fop)
+            )
+        );
+    }
+    test_field___fpu_fop();
+    fn test_field___fpu_ip() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ip)
+            )
+        );
+    }
+    test_field___fpu_ip();
+    fn test_field___fpu_cs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_cs)
+            )
+        );
+    }
+    test_field___fpu_cs();
+    fn test_field___fpu_rsrv2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+            },
+            22usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_rsrv2)
+            )
+        );
+    }
+    test_field___fpu_rsrv2();
+    fn test_field___fpu_dp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_dp)
+            )
+        );
+    }
+    test_field___fpu_dp();
+    fn test_field___fpu_ds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ds)
+            )
+        );
+    }
+    test_field___fpu_ds();
+    fn test_field___fpu_rsrv3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+            },
+            30usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_rsrv3)
+            )
+        );
+    }
+    test_field___fpu_rsrv3();
+    fn test_field___fpu_mxcsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_mxcsr)
+            )
+        );
+    }
+    test_field___fpu_mxcsr();
+    fn test_field___fpu_mxcsrmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_mxcsrmask)
+            )
+        );
+    }
+    test_field___fpu_mxcsrmask();
+    fn test_field___fpu_stmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm0)
+            )
+        );
+    }
+    test_field___fpu_stmm0();
+    fn test_field___fpu_stmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm1)
+            )
+        );
+    }
+    test_field___fpu_stmm1();
+    fn test_field___fpu_stmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm2)
+            )
+        );
+    }
+    test_field___fpu_stmm2();
+    fn test_field___fpu_stmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm3)
+            )
+        );
+    }
+    test_field___fpu_stmm3();
+    fn test_field___fpu_stmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm4)
+            )
+        );
+    }
+    test_field___fpu_stmm4();
+    fn test_field___fpu_stmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm5)
+            )
+        );
+    }
+    test_field___fpu_stmm5();
+    fn test_field___fpu_stmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm6)
+            )
+        );
+    }
+    test_field___fpu_stmm6();
+    fn test_field___fpu_stmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_stmm7)
+            )
+        );
+    }
+    test_field___fpu_stmm7();
+    fn test_field___fpu_xmm0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm0)
+            )
+        );
+    }
+    test_field___fpu_xmm0();
+    fn test_field___fpu_xmm1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm1)
+            )
+        );
+    }
+    test_field___fpu_xmm1();
+    fn test_field___fpu_xmm2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm2)
+            )
+        );
+    }
+    test_field___fpu_xmm2();
+    fn test_field___fpu_xmm3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm3)
+            )
+        );
+    }
+    test_field___fpu_xmm3();
+    fn test_field___fpu_xmm4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm4)
+            )
+        );
+    }
+    test_field___fpu_xmm4();
+    fn test_field___fpu_xmm5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm5)
+            )
+        );
+    }
+    test_field___fpu_xmm5();
+    fn test_field___fpu_xmm6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm6)
+            )
+        );
+    }
+    test_field___fpu_xmm6();
+    fn test_field___fpu_xmm7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm7)
+            )
+        );
+    }
+    test_field___fpu_xmm7();
+    fn test_field___fpu_xmm8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm8)
+            )
+        );
+    }
+    test_field___fpu_xmm8();
+    fn test_field___fpu_xmm9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
+            },
+            312usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm9)
+            )
+        );
+    }
+    test_field___fpu_xmm9();
+    fn test_field___fpu_xmm10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
+            },
+            328usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm10)
+            )
+        );
+    }
+    test_field___fpu_xmm10();
+    fn test_field___fpu_xmm11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
+            },
+            344usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm11)
+            )
+        );
+    }
+    test_field___fpu_xmm11();
+    fn test_field___fpu_xmm12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
+            },
+            360usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm12)
+            )
+        );
+    }
+    test_field___fpu_xmm12();
+    fn test_field___fpu_xmm13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
+            },
+            376usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm13)
+            )
+        );
+    }
+    test_field___fpu_xmm13();
+    fn test_field___fpu_xmm14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
+            },
+            392usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm14)
+            )
+        );
+    }
+    test_field___fpu_xmm14();
+    fn test_field___fpu_xmm15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
+            },
+            408usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_xmm15)
+            )
+        );
+    }
+    test_field___fpu_xmm15();
+    fn test_field___fpu_rsrv4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+            },
+            424usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_rsrv4)
+            )
+        );
+    }
+    test_field___fpu_rsrv4();
+    fn test_field___fpu_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_reserved1)
+            )
+        );
+    }
+    test_field___fpu_reserved1();
+    fn test_field___avx_reserved1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+            },
+            524usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__avx_reserved1)
+            )
+        );
+    }
+    test_field___avx_reserved1();
+    fn test_field___fpu_ymmh0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+            },
+            588usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh0)
+            )
+        );
+    }
+    test_field___fpu_ymmh0();
+    fn test_field___fpu_ymmh1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+            },
+            604usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh1)
+            )
+        );
+    }
+    test_field___fpu_ymmh1();
+    fn test_field___fpu_ymmh2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+            },
+            620usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh2)
+            )
+        );
+    }
+    test_field___fpu_ymmh2();
+    fn test_field___fpu_ymmh3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+            },
+            636usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh3)
+            )
+        );
+    }
+    test_field___fpu_ymmh3();
+    fn test_field___fpu_ymmh4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+            },
+            652usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh4)
+            )
+        );
+    }
+    test_field___fpu_ymmh4();
+    fn test_field___fpu_ymmh5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+            },
+            668usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh5)
+            )
+        );
+    }
+    test_field___fpu_ymmh5();
+    fn test_field___fpu_ymmh6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+            },
+            684usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh6)
+            )
+        );
+    }
+    test_field___fpu_ymmh6();
+    fn test_field___fpu_ymmh7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+            },
+            700usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh7)
+            )
+        );
+    }
+    test_field___fpu_ymmh7();
+    fn test_field___fpu_ymmh8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh8) as usize - ptr as usize
+            },
+            716usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh8)
+            )
+        );
+    }
+    test_field___fpu_ymmh8();
+    fn test_field___fpu_ymmh9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh9) as usize - ptr as usize
+            },
+            732usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh9)
+            )
+        );
+    }
+    test_field___fpu_ymmh9();
+    fn test_field___fpu_ymmh10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh10) as usize - ptr as usize
+            },
+            748usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh10)
+            )
+        );
+    }
+    test_field___fpu_ymmh10();
+    fn test_field___fpu_ymmh11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh11) as usize - ptr as usize
+            },
+            764usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh11)
+            )
+        );
+    }
+    test_field___fpu_ymmh11();
+    fn test_field___fpu_ymmh12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh12) as usize - ptr as usize
+            },
+            780usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh12)
+            )
+        );
+    }
+    test_field___fpu_ymmh12();
+    fn test_field___fpu_ymmh13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh13) as usize - ptr as usize
+            },
+            796usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh13)
+            )
+        );
+    }
+    test_field___fpu_ymmh13();
+    fn test_field___fpu_ymmh14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh14) as usize - ptr as usize
+            },
+            812usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh14)
+            )
+        );
+    }
+    test_field___fpu_ymmh14();
+    fn test_field___fpu_ymmh15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_ymmh15) as usize - ptr as usize
+            },
+            828usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_ymmh15)
+            )
+        );
+    }
+    test_field___fpu_ymmh15();
+    fn test_field___fpu_k0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k0) as usize - ptr as usize
+            },
+            844usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k0)
+            )
+        );
+    }
+    test_field___fpu_k0();
+    fn test_field___fpu_k1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k1) as usize - ptr as usize
+            },
+            852usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k1)
+            )
+        );
+    }
+    test_field___fpu_k1();
+    fn test_field___fpu_k2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k2) as usize - ptr as usize
+            },
+            860usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k2)
+            )
+        );
+    }
+    test_field___fpu_k2();
+    fn test_field___fpu_k3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k3) as usize - ptr as usize
+            },
+            868usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k3)
+            )
+        );
+    }
+    test_field___fpu_k3();
+    fn test_field___fpu_k4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k4) as usize - ptr as usize
+            },
+            876usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k4)
+            )
+        );
+    }
+    test_field___fpu_k4();
+    fn test_field___fpu_k5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k5) as usize - ptr as usize
+            },
+            884usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k5)
+            )
+        );
+    }
+    test_field___fpu_k5();
+    fn test_field___fpu_k6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k6) as usize - ptr as usize
+            },
+            892usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k6)
+            )
+        );
+    }
+    test_field___fpu_k6();
+    fn test_field___fpu_k7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_k7) as usize - ptr as usize
+            },
+            900usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_k7)
+            )
+        );
+    }
+    test_field___fpu_k7();
+    fn test_field___fpu_zmmh0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh0) as usize - ptr as usize
+            },
+            908usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh0)
+            )
+        );
+    }
+    test_field___fpu_zmmh0();
+    fn test_field___fpu_zmmh1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh1) as usize - ptr as usize
+            },
+            940usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh1)
+            )
+        );
+    }
+    test_field___fpu_zmmh1();
+    fn test_field___fpu_zmmh2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh2) as usize - ptr as usize
+            },
+            972usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh2)
+            )
+        );
+    }
+    test_field___fpu_zmmh2();
+    fn test_field___fpu_zmmh3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh3) as usize - ptr as usize
+            },
+            1004usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh3)
+            )
+        );
+    }
+    test_field___fpu_zmmh3();
+    fn test_field___fpu_zmmh4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh4) as usize - ptr as usize
+            },
+            1036usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh4)
+            )
+        );
+    }
+    test_field___fpu_zmmh4();
+    fn test_field___fpu_zmmh5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh5) as usize - ptr as usize
+            },
+            1068usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh5)
+            )
+        );
+    }
+    test_field___fpu_zmmh5();
+    fn test_field___fpu_zmmh6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh6) as usize - ptr as usize
+            },
+            1100usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh6)
+            )
+        );
+    }
+    test_field___fpu_zmmh6();
+    fn test_field___fpu_zmmh7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh7) as usize - ptr as usize
+            },
+            1132usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh7)
+            )
+        );
+    }
+    test_field___fpu_zmmh7();
+    fn test_field___fpu_zmmh8() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh8) as usize - ptr as usize
+            },
+            1164usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh8)
+            )
+        );
+    }
+    test_field___fpu_zmmh8();
+    fn test_field___fpu_zmmh9() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh9) as usize - ptr as usize
+            },
+            1196usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh9)
+            )
+        );
+    }
+    test_field___fpu_zmmh9();
+    fn test_field___fpu_zmmh10() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh10) as usize - ptr as usize
+            },
+            1228usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh10)
+            )
+        );
+    }
+    test_field___fpu_zmmh10();
+    fn test_field___fpu_zmmh11() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh11) as usize - ptr as usize
+            },
+            1260usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh11)
+            )
+        );
+    }
+    test_field___fpu_zmmh11();
+    fn test_field___fpu_zmmh12() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh12) as usize - ptr as usize
+            },
+            1292usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh12)
+            )
+        );
+    }
+    test_field___fpu_zmmh12();
+    fn test_field___fpu_zmmh13() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh13) as usize - ptr as usize
+            },
+            1324usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh13)
+            )
+        );
+    }
+    test_field___fpu_zmmh13();
+    fn test_field___fpu_zmmh14() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh14) as usize - ptr as usize
+            },
+            1356usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh14)
+            )
+        );
+    }
+    test_field___fpu_zmmh14();
+    fn test_field___fpu_zmmh15() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmmh15) as usize - ptr as usize
+            },
+            1388usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmmh15)
+            )
+        );
+    }
+    test_field___fpu_zmmh15();
+    fn test_field___fpu_zmm16() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm16) as usize - ptr as usize
+            },
+            1420usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm16)
+            )
+        );
+    }
+    test_field___fpu_zmm16();
+    fn test_field___fpu_zmm17() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm17) as usize - ptr as usize
+            },
+            1484usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm17)
+            )
+        );
+    }
+    test_field___fpu_zmm17();
+    fn test_field___fpu_zmm18() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm18) as usize - ptr as usize
+            },
+            1548usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm18)
+            )
+        );
+    }
+    test_field___fpu_zmm18();
+    fn test_field___fpu_zmm19() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm19) as usize - ptr as usize
+            },
+            1612usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm19)
+            )
+        );
+    }
+    test_field___fpu_zmm19();
+    fn test_field___fpu_zmm20() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm20) as usize - ptr as usize
+            },
+            1676usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm20)
+            )
+        );
+    }
+    test_field___fpu_zmm20();
+    fn test_field___fpu_zmm21() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm21) as usize - ptr as usize
+            },
+            1740usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm21)
+            )
+        );
+    }
+    test_field___fpu_zmm21();
+    fn test_field___fpu_zmm22() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm22) as usize - ptr as usize
+            },
+            1804usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm22)
+            )
+        );
+    }
+    test_field___fpu_zmm22();
+    fn test_field___fpu_zmm23() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm23) as usize - ptr as usize
+            },
+            1868usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm23)
+            )
+        );
+    }
+    test_field___fpu_zmm23();
+    fn test_field___fpu_zmm24() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm24) as usize - ptr as usize
+            },
+            1932usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm24)
+            )
+        );
+    }
+    test_field___fpu_zmm24();
+    fn test_field___fpu_zmm25() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm25) as usize - ptr as usize
+            },
+            1996usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm25)
+            )
+        );
+    }
+    test_field___fpu_zmm25();
+    fn test_field___fpu_zmm26() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm26) as usize - ptr as usize
+            },
+            2060usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm26)
+            )
+        );
+    }
+    test_field___fpu_zmm26();
+    fn test_field___fpu_zmm27() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm27) as usize - ptr as usize
+            },
+            2124usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm27)
+            )
+        );
+    }
+    test_field___fpu_zmm27();
+    fn test_field___fpu_zmm28() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm28) as usize - ptr as usize
+            },
+            2188usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm28)
+            )
+        );
+    }
+    test_field___fpu_zmm28();
+    fn test_field___fpu_zmm29() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm29) as usize - ptr as usize
+            },
+            2252usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm29)
+            )
+        );
+    }
+    test_field___fpu_zmm29();
+    fn test_field___fpu_zmm30() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm30) as usize - ptr as usize
+            },
+            2316usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm30)
+            )
+        );
+    }
+    test_field___fpu_zmm30();
+    fn test_field___fpu_zmm31() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fpu_zmm31) as usize - ptr as usize
+            },
+            2380usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_avx512_state64),
+                "::",
+                stringify!(__fpu_zmm31)
+            )
+        );
+    }
+    test_field___fpu_zmm31();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_exception_state64 {
+    pub __trapno: __uint16_t,
+    pub __cpu: __uint16_t,
+    pub __err: __uint32_t,
+    pub __faultvaddr: __uint64_t,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_exception_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_exception_state64>(),
+        16usize,
+        concat!("Size of: ", stringify!(__darwin_x86_exception_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_exception_state64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_exception_state64))
+    );
+    fn test_field___trapno() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__trapno) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_exception_state64),
+                "::",
+                stringify!(__trapno)
+            )
+        );
+    }
+    test_field___trapno();
+    fn test_field___cpu() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__cpu) as usize - ptr as usize
+            },
+            2usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_exception_state64),
+                "::",
+                stringify!(__cpu)
+            )
+        );
+    }
+    test_field___cpu();
+    fn test_field___err() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__err) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_exception_state64),
+                "::",
+                stringify!(__err)
+            )
+        );
+    }
+    test_field___err();
+    fn test_field___faultvaddr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__faultvaddr) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_exception_state64),
+                "::",
+                stringify!(__faultvaddr)
+            )
+        );
+    }
+    test_field___faultvaddr();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_debug_state64 {
+    pub __dr0: __uint64_t,
+    pub __dr1: __uint64_t,
+    pub __dr2: __uint64_t,
+    pub __dr3: __uint64_t,
+    pub __dr4: __uint64_t,
+    pub __dr5: __uint64_t,
+    pub __dr6: __uint64_t,
+    pub __dr7: __uint64_t,
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_debug_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_debug_state64>(),
+        64usize,
+        concat!("Size of: ", stringify!(__darwin_x86_debug_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_debug_state64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_debug_state64))
+    );
+    fn test_field___dr0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr0) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr0)
+            )
+        );
+    }
+    test_field___dr0();
+    fn test_field___dr1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr1) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr1)
+            )
+        );
+    }
+    test_field___dr1();
+    fn test_field___dr2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr2) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr2)
+            )
+        );
+    }
+    test_field___dr2();
+    fn test_field___dr3() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr3) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr3)
+            )
+        );
+    }
+    test_field___dr3();
+    fn test_field___dr4() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr4) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr4)
+            )
+        );
+    }
+    test_field___dr4();
+    fn test_field___dr5() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr5) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr5)
+            )
+        );
+    }
+    test_field___dr5();
+    fn test_field___dr6() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr6) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr6)
+            )
+        );
+    }
+    test_field___dr6();
+    fn test_field___dr7() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__dr7) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_debug_state64),
+                "::",
+                stringify!(__dr7)
+            )
+        );
+    }
+    test_field___dr7();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_x86_cpmu_state64 {
+    pub __ctrs: [__uint64_t; 16usize],
+}
+#[test]
+fn bindgen_test_layout___darwin_x86_cpmu_state64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_x86_cpmu_state64>(),
+        128usize,
+        concat!("Size of: ", stringify!(__darwin_x86_cpmu_state64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_x86_cpmu_state64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_x86_cpmu_state64))
+    );
+    fn test_field___ctrs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_cpmu_state64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ctrs) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_x86_cpmu_state64),
+                "::",
+                stringify!(__ctrs)
+            )
+        );
+    }
+    test_field___ctrs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext32 {
+    pub __es: __darwin_i386_exception_state,
+    pub __ss: __darwin_i386_thread_state,
+    pub __fs: __darwin_i386_float_state,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext32() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext32>(),
+        600usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext32))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext32>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext32))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext32),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext32),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            76usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext32),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext_avx32 {
+    pub __es: __darwin_i386_exception_state,
+    pub __ss: __darwin_i386_thread_state,
+    pub __fs: __darwin_i386_avx_state,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext_avx32() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext_avx32>(),
+        792usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext_avx32))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext_avx32>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext_avx32))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx32),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx32),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            76usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx32),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext_avx512_32 {
+    pub __es: __darwin_i386_exception_state,
+    pub __ss: __darwin_i386_thread_state,
+    pub __fs: __darwin_i386_avx512_state,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext_avx512_32() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext_avx512_32>(),
+        1112usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext_avx512_32))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext_avx512_32>(),
+        4usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext_avx512_32))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_32),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_32),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            76usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_32),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext64 {
+    pub __es: __darwin_x86_exception_state64,
+    pub __ss: __darwin_x86_thread_state64,
+    pub __fs: __darwin_x86_float_state64,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext64>(),
+        712usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext64))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext64),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext64),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext64),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext64_full {
+    pub __es: __darwin_x86_exception_state64,
+    pub __ss: __darwin_x86_thread_full_state64,
+    pub __fs: __darwin_x86_float_state64,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext64_full() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext64_full>(),
+        744usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext64_full))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext64_full>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext64_full))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext64_full),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext64_full),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext64_full),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext_avx64 {
+    pub __es: __darwin_x86_exception_state64,
+    pub __ss: __darwin_x86_thread_state64,
+    pub __fs: __darwin_x86_avx_state64,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext_avx64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext_avx64>(),
+        1032usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext_avx64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext_avx64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext_avx64))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx64),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx64),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx64),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext_avx64_full {
+    pub __es: __darwin_x86_exception_state64,
+    pub __ss: __darwin_x86_thread_full_state64,
+    pub __fs: __darwin_x86_avx_state64,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext_avx64_full() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext_avx64_full>(),
+        1064usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext_avx64_full))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext_avx64_full>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext_avx64_full))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx64_full),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx64_full),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx64_full),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext_avx512_64 {
+    pub __es: __darwin_x86_exception_state64,
+    pub __ss: __darwin_x86_thread_state64,
+    pub __fs: __darwin_x86_avx512_state64,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext_avx512_64() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext_avx512_64>(),
+        2632usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext_avx512_64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext_avx512_64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_mcontext_avx512_64))
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_64),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_64),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_64),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_mcontext_avx512_64_full {
+    pub __es: __darwin_x86_exception_state64,
+    pub __ss: __darwin_x86_thread_full_state64,
+    pub __fs: __darwin_x86_avx512_state64,
+}
+#[test]
+fn bindgen_test_layout___darwin_mcontext_avx512_64_full() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_mcontext_avx512_64_full>(),
+        2664usize,
+        concat!("Size of: ", stringify!(__darwin_mcontext_avx512_64_full))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_mcontext_avx512_64_full>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(__darwin_mcontext_avx512_64_full)
+        )
+    );
+    fn test_field___es() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_64_full),
+                "::",
+                stringify!(__es)
+            )
+        );
+    }
+    test_field___es();
+    fn test_field___ss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_64_full),
+                "::",
+                stringify!(__ss)
+            )
+        );
+    }
+    test_field___ss();
+    fn test_field___fs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_mcontext_avx512_64_full),
+                "::",
+                stringify!(__fs)
+            )
+        );
+    }
+    test_field___fs();
+}
+pub type mcontext_t = *mut __darwin_mcontext64;
+pub type pthread_attr_t = __darwin_pthread_attr_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_sigaltstack {
+    pub ss_sp: *mut ::std::os::raw::c_void,
+    pub ss_size: __darwin_size_t,
+    pub ss_flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout___darwin_sigaltstack() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_sigaltstack>(),
+        24usize,
+        concat!("Size of: ", stringify!(__darwin_sigaltstack))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_sigaltstack>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_sigaltstack))
+    );
+    fn test_field_ss_sp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ss_sp) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_sigaltstack),
+                "::",
+                stringify!(ss_sp)
+            )
+        );
+    }
+    test_field_ss_sp();
+    fn test_field_ss_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ss_size) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_sigaltstack),
+                "::",
+                stringify!(ss_size)
+            )
+        );
+    }
+    test_field_ss_size();
+    fn test_field_ss_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ss_flags) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_sigaltstack),
+                "::",
+                stringify!(ss_flags)
+            )
+        );
+    }
+    test_field_ss_flags();
+}
+pub type stack_t = __darwin_sigaltstack;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __darwin_ucontext {
+    pub uc_onstack: ::std::os::raw::c_int,
+    pub uc_sigmask: __darwin_sigset_t,
+    pub uc_stack: __darwin_sigaltstack,
+    pub uc_link: *mut __darwin_ucontext,
+    pub uc_mcsize: __darwin_size_t,
+    pub uc_mcontext: *mut __darwin_mcontext64,
+}
+#[test]
+fn bindgen_test_layout___darwin_ucontext() {
+    assert_eq!(
+        ::std::mem::size_of::<__darwin_ucontext>(),
+        56usize,
+        concat!("Size of: ", stringify!(__darwin_ucontext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__darwin_ucontext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__darwin_ucontext))
+    );
+    fn test_field_uc_onstack() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).uc_onstack) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ucontext),
+                "::",
+                stringify!(uc_onstack)
+            )
+        );
+    }
+    test_field_uc_onstack();
+    fn test_field_uc_sigmask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).uc_sigmask) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ucontext),
+                "::",
+                stringify!(uc_sigmask)
+            )
+        );
+    }
+    test_field_uc_sigmask();
+    fn test_field_uc_stack() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).uc_stack) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ucontext),
+                "::",
+                stringify!(uc_stack)
+            )
+        );
+    }
+    test_field_uc_stack();
+    fn test_field_uc_link() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).uc_link) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ucontext),
+                "::",
+                stringify!(uc_link)
+            )
+        );
+    }
+    test_field_uc_link();
+    fn test_field_uc_mcsize() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).uc_mcsize) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ucontext),
+                "::",
+                stringify!(uc_mcsize)
+            )
+        );
+    }
+    test_field_uc_mcsize();
+    fn test_field_uc_mcontext() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).uc_mcontext) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__darwin_ucontext),
+                "::",
+                stringify!(uc_mcontext)
+            )
+        );
+    }
+    test_field_uc_mcontext();
+}
+pub type ucontext_t = __darwin_ucontext;
+pub type sigset_t = __darwin_sigset_t;
+pub type uid_t = __darwin_uid_t;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union sigval {
+    pub sival_int: ::std::os::raw::c_int,
+    pub sival_ptr: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout_sigval() {
+    assert_eq!(
+        ::std::mem::size_of::<sigval>(),
+        8usize,
+        concat!("Size of: ", stringify!(sigval))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<sigval>(),
+        8usize,
+        concat!("Alignment of ", stringify!(sigval))
+    );
+    fn test_field_sival_int() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigval>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sival_int) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigval),
+                "::",
+                stringify!(sival_int)
+            )
+        );
+    }
+    test_field_sival_int();
+    fn test_field_sival_ptr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigval>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sival_ptr) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigval),
+                "::",
+                stringify!(sival_ptr)
+            )
+        );
+    }
+    test_field_sival_ptr();
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sigevent {
+    pub sigev_notify: ::std::os::raw::c_int,
+    pub sigev_signo: ::std::os::raw::c_int,
+    pub sigev_value: sigval,
+    pub sigev_notify_function: ::std::option::Option<unsafe extern "C" fn(arg1: sigval)>,
+    pub sigev_notify_attributes: *mut pthread_attr_t,
+}
+#[test]
+fn bindgen_test_layout_sigevent() {
+    assert_eq!(
+        ::std::mem::size_of::<sigevent>(),
+        32usize,
+        concat!("Size of: ", stringify!(sigevent))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<sigevent>(),
+        8usize,
+        concat!("Alignment of ", stringify!(sigevent))
+    );
+    fn test_field_sigev_notify() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sigev_notify) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigevent),
+                "::",
+                stringify!(sigev_notify)
+            )
+        );
+    }
+    test_field_sigev_notify();
+    fn test_field_sigev_signo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sigev_signo) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigevent),
+                "::",
+                stringify!(sigev_signo)
+            )
+        );
+    }
+    test_field_sigev_signo();
+    fn test_field_sigev_value() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sigev_value) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigevent),
+                "::",
+                stringify!(sigev_value)
+            )
+        );
+    }
+    test_field_sigev_value();
+    fn test_field_sigev_notify_function() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sigev_notify_function) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigevent),
+                "::",
+                stringify!(sigev_notify_function)
+            )
+        );
+    }
+    test_field_sigev_notify_function();
+    fn test_field_sigev_notify_attributes() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sigev_notify_attributes) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigevent),
+                "::",
+                stringify!(sigev_notify_attributes)
+            )
+        );
+    }
+    test_field_sigev_notify_attributes();
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct __siginfo {
+    pub si_signo: ::std::os::raw::c_int,
+    pub si_errno: ::std::os::raw::c_int,
+    pub si_code: ::std::os::raw::c_int,
+    pub si_pid: pid_t,
+    pub si_uid: uid_t,
+    pub si_status: ::std::os::raw::c_int,
+    pub si_addr: *mut ::std::os::raw::c_void,
+    pub si_value: sigval,
+    pub si_band: ::std::os::raw::c_long,
+    pub __pad: [::std::os::raw::c_ulong; 7usize],
+}
+#[test]
+fn bindgen_test_layout___siginfo() {
+    assert_eq!(
+        ::std::mem::size_of::<__siginfo>(),
+        104usize,
+        concat!("Size of: ", stringify!(__siginfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__siginfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__siginfo))
+    );
+    fn test_field_si_signo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_signo) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_signo)
+            )
+        );
+    }
+    test_field_si_signo();
+    fn test_field_si_errno() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_errno) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_errno)
+            )
+        );
+    }
+    test_field_si_errno();
+    fn test_field_si_code() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_code) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_code)
+            )
+        );
+    }
+    test_field_si_code();
+    fn test_field_si_pid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_pid) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_pid)
+            )
+        );
+    }
+    test_field_si_pid();
+    fn test_field_si_uid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_uid) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_uid)
+            )
+        );
+    }
+    test_field_si_uid();
+    fn test_field_si_status() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_status) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_status)
+            )
+        );
+    }
+    test_field_si_status();
+    fn test_field_si_addr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_addr) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_addr)
+            )
+        );
+    }
+    test_field_si_addr();
+    fn test_field_si_value() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_value) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_value)
+            )
+        );
+    }
+    test_field_si_value();
+    fn test_field_si_band() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).si_band) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(si_band)
+            )
+        );
+    }
+    test_field_si_band();
+    fn test_field___pad() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__pad) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__siginfo),
+                "::",
+                stringify!(__pad)
+            )
+        );
+    }
+    test_field___pad();
+}
+pub type siginfo_t = __siginfo;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union __sigaction_u {
+    pub __sa_handler: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+    pub __sa_sigaction: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: ::std::os::raw::c_int,
+            arg2: *mut __siginfo,
+            arg3: *mut ::std::os::raw::c_void,
+        ),
+    >,
+}
+#[test]
+fn bindgen_test_layout___sigaction_u() {
+    assert_eq!(
+        ::std::mem::size_of::<__sigaction_u>(),
+        8usize,
+        concat!("Size of: ", stringify!(__sigaction_u))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__sigaction_u>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__sigaction_u))
+    );
+    fn test_field___sa_handler() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__sigaction_u>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sa_handler) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__sigaction_u),
+                "::",
+                stringify!(__sa_handler)
+            )
+        );
+    }
+    test_field___sa_handler();
+    fn test_field___sa_sigaction() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__sigaction_u>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sa_sigaction) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__sigaction_u),
+                "::",
+                stringify!(__sa_sigaction)
+            )
+        );
+    }
+    test_field___sa_sigaction();
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct __sigaction {
+    pub __sigaction_u: __sigaction_u,
+    pub sa_tramp: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut ::std::os::raw::c_void,
+            arg2: ::std::os::raw::c_int,
+            arg3: ::std::os::raw::c_int,
+            arg4: *mut siginfo_t,
+            arg5: *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub sa_mask: sigset_t,
+    pub sa_flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout___sigaction() {
+    assert_eq!(
+        ::std::mem::size_of::<__sigaction>(),
+        24usize,
+        concat!("Size of: ", stringify!(__sigaction))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__sigaction>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__sigaction))
+    );
+    fn test_field___sigaction_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sigaction_u) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__sigaction),
+                "::",
+                stringify!(__sigaction_u)
+            )
+        );
+    }
+    test_field___sigaction_u();
+    fn test_field_sa_tramp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sa_tramp) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__sigaction),
+                "::",
+                stringify!(sa_tramp)
+            )
+        );
+    }
+    test_field_sa_tramp();
+    fn test_field_sa_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sa_mask) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__sigaction),
+                "::",
+                stringify!(sa_mask)
+            )
+        );
+    }
+    test_field_sa_mask();
+    fn test_field_sa_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sa_flags) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__sigaction),
+                "::",
+                stringify!(sa_flags)
+            )
+        );
+    }
+    test_field_sa_flags();
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sigaction {
+    pub __sigaction_u: __sigaction_u,
+    pub sa_mask: sigset_t,
+    pub sa_flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_sigaction() {
+    assert_eq!(
+        ::std::mem::size_of::<sigaction>(),
+        16usize,
+        concat!("Size of: ", stringify!(sigaction))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<sigaction>(),
+        8usize,
+        concat!("Alignment of ", stringify!(sigaction))
+    );
+    fn test_field___sigaction_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__sigaction_u) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigaction),
+                "::",
+                stringify!(__sigaction_u)
+            )
+        );
+    }
+    test_field___sigaction_u();
+    fn test_field_sa_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sa_mask) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigaction),
+                "::",
+                stringify!(sa_mask)
+            )
+        );
+    }
+    test_field_sa_mask();
+    fn test_field_sa_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigaction>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sa_flags) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigaction),
+                "::",
+                stringify!(sa_flags)
+            )
+        );
+    }
+    test_field_sa_flags();
+}
+pub type sig_t = ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct sigvec {
+    pub sv_handler: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+    pub sv_mask: ::std::os::raw::c_int,
+    pub sv_flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_sigvec() {
+    assert_eq!(
+        ::std::mem::size_of::<sigvec>(),
+        16usize,
+        concat!("Size of: ", stringify!(sigvec))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<sigvec>(),
+        8usize,
+        concat!("Alignment of ", stringify!(sigvec))
+    );
+    fn test_field_sv_handler() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigvec>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sv_handler) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigvec),
+                "::",
+                stringify!(sv_handler)
+            )
+        );
+    }
+    test_field_sv_handler();
+    fn test_field_sv_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigvec>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sv_mask) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigvec),
+                "::",
+                stringify!(sv_mask)
+            )
+        );
+    }
+    test_field_sv_mask();
+    fn test_field_sv_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigvec>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sv_flags) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigvec),
+                "::",
+                stringify!(sv_flags)
+            )
+        );
+    }
+    test_field_sv_flags();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct sigstack {
+    pub ss_sp: *mut ::std::os::raw::c_char,
+    pub ss_onstack: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_sigstack() {
+    assert_eq!(
+        ::std::mem::size_of::<sigstack>(),
+        16usize,
+        concat!("Size of: ", stringify!(sigstack))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<sigstack>(),
+        8usize,
+        concat!("Alignment of ", stringify!(sigstack))
+    );
+    fn test_field_ss_sp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigstack>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ss_sp) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigstack),
+                "::",
+                stringify!(ss_sp)
+            )
+        );
+    }
+    test_field_ss_sp();
+    fn test_field_ss_onstack() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<sigstack>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ss_onstack) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(sigstack),
+                "::",
+                stringify!(ss_onstack)
+            )
+        );
+    }
+    test_field_ss_onstack();
+}
+pub type int_least8_t = i8;
+pub type int_least16_t = i16;
+pub type int_least32_t = i32;
+pub type int_least64_t = i64;
+pub type uint_least8_t = u8;
+pub type uint_least16_t = u16;
+pub type uint_least32_t = u32;
+pub type uint_least64_t = u64;
+pub type int_fast8_t = i8;
+pub type int_fast16_t = i16;
+pub type int_fast32_t = i32;
+pub type int_fast64_t = i64;
+pub type uint_fast8_t = u8;
+pub type uint_fast16_t = u16;
+pub type uint_fast32_t = u32;
+pub type uint_fast64_t = u64;
+pub type intmax_t = ::std::os::raw::c_long;
+pub type uintmax_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct timeval {
+    pub tv_sec: __darwin_time_t,
+    pub tv_usec: __darwin_suseconds_t,
+}
+#[test]
+fn bindgen_test_layout_timeval() {
+    assert_eq!(
+        ::std::mem::size_of::<timeval>(),
+        16usize,
+        concat!("Size of: ", stringify!(timeval))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<timeval>(),
+        8usize,
+        concat!("Alignment of ", stringify!(timeval))
+    );
+    fn test_field_tv_sec() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<timeval>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(timeval),
+                "::",
+                stringify!(tv_sec)
+            )
+        );
+    }
+    test_field_tv_sec();
+    fn test_field_tv_usec() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<timeval>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tv_usec) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(timeval),
+                "::",
+                stringify!(tv_usec)
+            )
+        );
+    }
+    test_field_tv_usec();
+}
+pub type rlim_t = __uint64_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage {
+    pub ru_utime: timeval,
+    pub ru_stime: timeval,
+    pub ru_maxrss: ::std::os::raw::c_long,
+    pub ru_ixrss: ::std::os::raw::c_long,
+    pub ru_idrss: ::std::os::raw::c_long,
+    pub ru_isrss: ::std::os::raw::c_long,
+    pub ru_minflt: ::std::os::raw::c_long,
+    pub ru_majflt: ::std::os::raw::c_long,
+    pub ru_nswap: ::std::os::raw::c_long,
+    pub ru_inblock: ::std::os::raw::c_long,
+    pub ru_oublock: ::std::os::raw::c_long,
+    pub ru_msgsnd: ::std::os::raw::c_long,
+    pub ru_msgrcv: ::std::os::raw::c_long,
+    pub ru_nsignals: ::std::os::raw::c_long,
+    pub ru_nvcsw: ::std::os::raw::c_long,
+    pub ru_nivcsw: ::std::os::raw::c_long,
+}
+#[test]
+fn bindgen_test_layout_rusage() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage>(),
+        144usize,
+        concat!("Size of: ", stringify!(rusage))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage))
+    );
+    fn test_field_ru_utime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_utime) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_utime)
+            )
+        );
+    }
+    test_field_ru_utime();
+    fn test_field_ru_stime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_stime) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_stime)
+            )
+        );
+    }
+    test_field_ru_stime();
+    fn test_field_ru_maxrss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_maxrss) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_maxrss)
+            )
+        );
+    }
+    test_field_ru_maxrss();
+    fn test_field_ru_ixrss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_ixrss) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_ixrss)
+            )
+        );
+    }
+    test_field_ru_ixrss();
+    fn test_field_ru_idrss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_idrss) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_idrss)
+            )
+        );
+    }
+    test_field_ru_idrss();
+    fn test_field_ru_isrss() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_isrss) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_isrss)
+            )
+        );
+    }
+    test_field_ru_isrss();
+    fn test_field_ru_minflt() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_minflt) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_minflt)
+            )
+        );
+    }
+    test_field_ru_minflt();
+    fn test_field_ru_majflt() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_majflt) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_majflt)
+            )
+        );
+    }
+    test_field_ru_majflt();
+    fn test_field_ru_nswap() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_nswap) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_nswap)
+            )
+        );
+    }
+    test_field_ru_nswap();
+    fn test_field_ru_inblock() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_inblock) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_inblock)
+            )
+        );
+    }
+    test_field_ru_inblock();
+    fn test_field_ru_oublock() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_oublock) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_oublock)
+            )
+        );
+    }
+    test_field_ru_oublock();
+    fn test_field_ru_msgsnd() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_msgsnd) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_msgsnd)
+            )
+        );
+    }
+    test_field_ru_msgsnd();
+    fn test_field_ru_msgrcv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_msgrcv) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_msgrcv)
+            )
+        );
+    }
+    test_field_ru_msgrcv();
+    fn test_field_ru_nsignals() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_nsignals) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_nsignals)
+            )
+        );
+    }
+    test_field_ru_nsignals();
+    fn test_field_ru_nvcsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_nvcsw) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_nvcsw)
+            )
+        );
+    }
+    test_field_ru_nvcsw();
+    fn test_field_ru_nivcsw() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ru_nivcsw) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage),
+                "::",
+                stringify!(ru_nivcsw)
+            )
+        );
+    }
+    test_field_ru_nivcsw();
+}
+pub type rusage_info_t = *mut ::std::os::raw::c_void;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage_info_v0 {
+    pub ri_uuid: [u8; 16usize],
+    pub ri_user_time: u64,
+    pub ri_system_time: u64,
+    pub ri_pkg_idle_wkups: u64,
+    pub ri_interrupt_wkups: u64,
+    pub ri_pageins: u64,
+    pub ri_wired_size: u64,
+    pub ri_resident_size: u64,
+    pub ri_phys_footprint: u64,
+    pub ri_proc_start_abstime: u64,
+    pub ri_proc_exit_abstime: u64,
+}
+#[test]
+fn bindgen_test_layout_rusage_info_v0() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage_info_v0>(),
+        96usize,
+        concat!("Size of: ", stringify!(rusage_info_v0))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage_info_v0>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage_info_v0))
+    );
+    fn test_field_ri_uuid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_uuid)
+            )
+        );
+    }
+    test_field_ri_uuid();
+    fn test_field_ri_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_user_time)
+            )
+        );
+    }
+    test_field_ri_user_time();
+    fn test_field_ri_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_system_time)
+            )
+        );
+    }
+    test_field_ri_system_time();
+    fn test_field_ri_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_pkg_idle_wkups();
+    fn test_field_ri_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_interrupt_wkups();
+    fn test_field_ri_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_pageins)
+            )
+        );
+    }
+    test_field_ri_pageins();
+    fn test_field_ri_wired_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_wired_size)
+            )
+        );
+    }
+    test_field_ri_wired_size();
+    fn test_field_ri_resident_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_resident_size)
+            )
+        );
+    }
+    test_field_ri_resident_size();
+    fn test_field_ri_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_phys_footprint();
+    fn test_field_ri_proc_start_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_proc_start_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_start_abstime();
+    fn test_field_ri_proc_exit_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v0),
+                "::",
+                stringify!(ri_proc_exit_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_exit_abstime();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage_info_v1 {
+    pub ri_uuid: [u8; 16usize],
+    pub ri_user_time: u64,
+    pub ri_system_time: u64,
+    pub ri_pkg_idle_wkups: u64,
+    pub ri_interrupt_wkups: u64,
+    pub ri_pageins: u64,
+    pub ri_wired_size: u64,
+    pub ri_resident_size: u64,
+    pub ri_phys_footprint: u64,
+    pub ri_proc_start_abstime: u64,
+    pub ri_proc_exit_abstime: u64,
+    pub ri_child_user_time: u64,
+    pub ri_child_system_time: u64,
+    pub ri_child_pkg_idle_wkups: u64,
+    pub ri_child_interrupt_wkups: u64,
+    pub ri_child_pageins: u64,
+    pub ri_child_elapsed_abstime: u64,
+}
+#[test]
+fn bindgen_test_layout_rusage_info_v1() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage_info_v1>(),
+        144usize,
+        concat!("Size of: ", stringify!(rusage_info_v1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage_info_v1>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage_info_v1))
+    );
+    fn test_field_ri_uuid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_uuid)
+            )
+        );
+    }
+    test_field_ri_uuid();
+    fn test_field_ri_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_user_time)
+            )
+        );
+    }
+    test_field_ri_user_time();
+    fn test_field_ri_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_system_time)
+            )
+        );
+    }
+    test_field_ri_system_time();
+    fn test_field_ri_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_pkg_idle_wkups();
+    fn test_field_ri_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_interrupt_wkups();
+    fn test_field_ri_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_pageins)
+            )
+        );
+    }
+    test_field_ri_pageins();
+    fn test_field_ri_wired_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_wired_size)
+            )
+        );
+    }
+    test_field_ri_wired_size();
+    fn test_field_ri_resident_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_resident_size)
+            )
+        );
+    }
+    test_field_ri_resident_size();
+    fn test_field_ri_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_phys_footprint();
+    fn test_field_ri_proc_start_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_proc_start_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_start_abstime();
+    fn test_field_ri_proc_exit_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_proc_exit_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_exit_abstime();
+    fn test_field_ri_child_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_child_user_time)
+            )
+        );
+    }
+    test_field_ri_child_user_time();
+    fn test_field_ri_child_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_child_system_time)
+            )
+        );
+    }
+    test_field_ri_child_system_time();
+    fn test_field_ri_child_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_child_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_child_pkg_idle_wkups();
+    fn test_field_ri_child_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_child_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_child_interrupt_wkups();
+    fn test_field_ri_child_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_child_pageins)
+            )
+        );
+    }
+    test_field_ri_child_pageins();
+    fn test_field_ri_child_elapsed_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v1),
+                "::",
+                stringify!(ri_child_elapsed_abstime)
+            )
+        );
+    }
+    test_field_ri_child_elapsed_abstime();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage_info_v2 {
+    pub ri_uuid: [u8; 16usize],
+    pub ri_user_time: u64,
+    pub ri_system_time: u64,
+    pub ri_pkg_idle_wkups: u64,
+    pub ri_interrupt_wkups: u64,
+    pub ri_pageins: u64,
+    pub ri_wired_size: u64,
+    pub ri_resident_size: u64,
+    pub ri_phys_footprint: u64,
+    pub ri_proc_start_abstime: u64,
+    pub ri_proc_exit_abstime: u64,
+    pub ri_child_user_time: u64,
+    pub ri_child_system_time: u64,
+    pub ri_child_pkg_idle_wkups: u64,
+    pub ri_child_interrupt_wkups: u64,
+    pub ri_child_pageins: u64,
+    pub ri_child_elapsed_abstime: u64,
+    pub ri_diskio_bytesread: u64,
+    pub ri_diskio_byteswritten: u64,
+}
+#[test]
+fn bindgen_test_layout_rusage_info_v2() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage_info_v2>(),
+        160usize,
+        concat!("Size of: ", stringify!(rusage_info_v2))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage_info_v2>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage_info_v2))
+    );
+    fn test_field_ri_uuid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_uuid)
+            )
+        );
+    }
+    test_field_ri_uuid();
+    fn test_field_ri_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_user_time)
+            )
+        );
+    }
+    test_field_ri_user_time();
+    fn test_field_ri_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_system_time)
+            )
+        );
+    }
+    test_field_ri_system_time();
+    fn test_field_ri_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_pkg_idle_wkups();
+    fn test_field_ri_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_interrupt_wkups();
+    fn test_field_ri_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_pageins)
+            )
+        );
+    }
+    test_field_ri_pageins();
+    fn test_field_ri_wired_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_wired_size)
+            )
+        );
+    }
+    test_field_ri_wired_size();
+    fn test_field_ri_resident_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_resident_size)
+            )
+        );
+    }
+    test_field_ri_resident_size();
+    fn test_field_ri_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_phys_footprint();
+    fn test_field_ri_proc_start_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_proc_start_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_start_abstime();
+    fn test_field_ri_proc_exit_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_proc_exit_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_exit_abstime();
+    fn test_field_ri_child_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_child_user_time)
+            )
+        );
+    }
+    test_field_ri_child_user_time();
+    fn test_field_ri_child_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_child_system_time)
+            )
+        );
+    }
+    test_field_ri_child_system_time();
+    fn test_field_ri_child_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_child_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_child_pkg_idle_wkups();
+    fn test_field_ri_child_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_child_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_child_interrupt_wkups();
+    fn test_field_ri_child_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_child_pageins)
+            )
+        );
+    }
+    test_field_ri_child_pageins();
+    fn test_field_ri_child_elapsed_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_child_elapsed_abstime)
+            )
+        );
+    }
+    test_field_ri_child_elapsed_abstime();
+    fn test_field_ri_diskio_bytesread() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_diskio_bytesread)
+            )
+        );
+    }
+    test_field_ri_diskio_bytesread();
+    fn test_field_ri_diskio_byteswritten() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v2),
+                "::",
+                stringify!(ri_diskio_byteswritten)
+            )
+        );
+    }
+    test_field_ri_diskio_byteswritten();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage_info_v3 {
+    pub ri_uuid: [u8; 16usize],
+    pub ri_user_time: u64,
+    pub ri_system_time: u64,
+    pub ri_pkg_idle_wkups: u64,
+    pub ri_interrupt_wkups: u64,
+    pub ri_pageins: u64,
+    pub ri_wired_size: u64,
+    pub ri_resident_size: u64,
+    pub ri_phys_footprint: u64,
+    pub ri_proc_start_abstime: u64,
+    pub ri_proc_exit_abstime: u64,
+    pub ri_child_user_time: u64,
+    pub ri_child_system_time: u64,
+    pub ri_child_pkg_idle_wkups: u64,
+    pub ri_child_interrupt_wkups: u64,
+    pub ri_child_pageins: u64,
+    pub ri_child_elapsed_abstime: u64,
+    pub ri_diskio_bytesread: u64,
+    pub ri_diskio_byteswritten: u64,
+    pub ri_cpu_time_qos_default: u64,
+    pub ri_cpu_time_qos_maintenance: u64,
+    pub ri_cpu_time_qos_background: u64,
+    pub ri_cpu_time_qos_utility: u64,
+    pub ri_cpu_time_qos_legacy: u64,
+    pub ri_cpu_time_qos_user_initiated: u64,
+    pub ri_cpu_time_qos_user_interactive: u64,
+    pub ri_billed_system_time: u64,
+    pub ri_serviced_system_time: u64,
+}
+#[test]
+fn bindgen_test_layout_rusage_info_v3() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage_info_v3>(),
+        232usize,
+        concat!("Size of: ", stringify!(rusage_info_v3))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage_info_v3>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage_info_v3))
+    );
+    fn test_field_ri_uuid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_uuid)
+            )
+        );
+    }
+    test_field_ri_uuid();
+    fn test_field_ri_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_user_time)
+            )
+        );
+    }
+    test_field_ri_user_time();
+    fn test_field_ri_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_system_time)
+            )
+        );
+    }
+    test_field_ri_system_time();
+    fn test_field_ri_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_pkg_idle_wkups();
+    fn test_field_ri_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_interrupt_wkups();
+    fn test_field_ri_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_pageins)
+            )
+        );
+    }
+    test_field_ri_pageins();
+    fn test_field_ri_wired_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_wired_size)
+            )
+        );
+    }
+    test_field_ri_wired_size();
+    fn test_field_ri_resident_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_resident_size)
+            )
+        );
+    }
+    test_field_ri_resident_size();
+    fn test_field_ri_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_phys_footprint();
+    fn test_field_ri_proc_start_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_proc_start_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_start_abstime();
+    fn test_field_ri_proc_exit_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_proc_exit_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_exit_abstime();
+    fn test_field_ri_child_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_child_user_time)
+            )
+        );
+    }
+    test_field_ri_child_user_time();
+    fn test_field_ri_child_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_child_system_time)
+            )
+        );
+    }
+    test_field_ri_child_system_time();
+    fn test_field_ri_child_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_child_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_child_pkg_idle_wkups();
+    fn test_field_ri_child_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_child_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_child_interrupt_wkups();
+    fn test_field_ri_child_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_child_pageins)
+            )
+        );
+    }
+    test_field_ri_child_pageins();
+    fn test_field_ri_child_elapsed_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_child_elapsed_abstime)
+            )
+        );
+    }
+    test_field_ri_child_elapsed_abstime();
+    fn test_field_ri_diskio_bytesread() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_diskio_bytesread)
+            )
+        );
+    }
+    test_field_ri_diskio_bytesread();
+    fn test_field_ri_diskio_byteswritten() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_diskio_byteswritten)
+            )
+        );
+    }
+    test_field_ri_diskio_byteswritten();
+    fn test_field_ri_cpu_time_qos_default() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_default)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_default();
+    fn test_field_ri_cpu_time_qos_maintenance() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_maintenance)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_maintenance();
+    fn test_field_ri_cpu_time_qos_background() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
+            },
+            176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_background)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_background();
+    fn test_field_ri_cpu_time_qos_utility() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_utility)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_utility();
+    fn test_field_ri_cpu_time_qos_legacy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_legacy)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_legacy();
+    fn test_field_ri_cpu_time_qos_user_initiated() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_user_initiated)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_user_initiated();
+    fn test_field_ri_cpu_time_qos_user_interactive() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
+                    - ptr as usize
+            },
+            208usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_cpu_time_qos_user_interactive)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_user_interactive();
+    fn test_field_ri_billed_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_billed_system_time)
+            )
+        );
+    }
+    test_field_ri_billed_system_time();
+    fn test_field_ri_serviced_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
+            },
+            224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v3),
+                "::",
+                stringify!(ri_serviced_system_time)
+            )
+        );
+    }
+    test_field_ri_serviced_system_time();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage_info_v4 {
+    pub ri_uuid: [u8; 16usize],
+    pub ri_user_time: u64,
+    pub ri_system_time: u64,
+    pub ri_pkg_idle_wkups: u64,
+    pub ri_interrupt_wkups: u64,
+    pub ri_pageins: u64,
+    pub ri_wired_size: u64,
+    pub ri_resident_size: u64,
+    pub ri_phys_footprint: u64,
+    pub ri_proc_start_abstime: u64,
+    pub ri_proc_exit_abstime: u64,
+    pub ri_child_user_time: u64,
+    pub ri_child_system_time: u64,
+    pub ri_child_pkg_idle_wkups: u64,
+    pub ri_child_interrupt_wkups: u64,
+    pub ri_child_pageins: u64,
+    pub ri_child_elapsed_abstime: u64,
+    pub ri_diskio_bytesread: u64,
+    pub ri_diskio_byteswritten: u64,
+    pub ri_cpu_time_qos_default: u64,
+    pub ri_cpu_time_qos_maintenance: u64,
+    pub ri_cpu_time_qos_background: u64,
+    pub ri_cpu_time_qos_utility: u64,
+    pub ri_cpu_time_qos_legacy: u64,
+    pub ri_cpu_time_qos_user_initiated: u64,
+    pub ri_cpu_time_qos_user_interactive: u64,
+    pub ri_billed_system_time: u64,
+    pub ri_serviced_system_time: u64,
+    pub ri_logical_writes: u64,
+    pub ri_lifetime_max_phys_footprint: u64,
+    pub ri_instructions: u64,
+    pub ri_cycles: u64,
+    pub ri_billed_energy: u64,
+    pub ri_serviced_energy: u64,
+    pub ri_interval_max_phys_footprint: u64,
+    pub ri_runnable_time: u64,
+}
+#[test]
+fn bindgen_test_layout_rusage_info_v4() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage_info_v4>(),
+        296usize,
+        concat!("Size of: ", stringify!(rusage_info_v4))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage_info_v4>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage_info_v4))
+    );
+    fn test_field_ri_uuid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_uuid)
+            )
+        );
+    }
+    test_field_ri_uuid();
+    fn test_field_ri_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_user_time)
+            )
+        );
+    }
+    test_field_ri_user_time();
+    fn test_field_ri_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_system_time)
+            )
+        );
+    }
+    test_field_ri_system_time();
+    fn test_field_ri_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_pkg_idle_wkups();
+    fn test_field_ri_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_interrupt_wkups();
+    fn test_field_ri_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_pageins)
+            )
+        );
+    }
+    test_field_ri_pageins();
+    fn test_field_ri_wired_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_wired_size)
+            )
+        );
+    }
+    test_field_ri_wired_size();
+    fn test_field_ri_resident_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_resident_size)
+            )
+        );
+    }
+    test_field_ri_resident_size();
+    fn test_field_ri_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_phys_footprint();
+    fn test_field_ri_proc_start_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_proc_start_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_start_abstime();
+    fn test_field_ri_proc_exit_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_proc_exit_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_exit_abstime();
+    fn test_field_ri_child_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_child_user_time)
+            )
+        );
+    }
+    test_field_ri_child_user_time();
+    fn test_field_ri_child_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_child_system_time)
+            )
+        );
+    }
+    test_field_ri_child_system_time();
+    fn test_field_ri_child_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_child_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_child_pkg_idle_wkups();
+    fn test_field_ri_child_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_child_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_child_interrupt_wkups();
+    fn test_field_ri_child_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_child_pageins)
+            )
+        );
+    }
+    test_field_ri_child_pageins();
+    fn test_field_ri_child_elapsed_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_child_elapsed_abstime)
+            )
+        );
+    }
+    test_field_ri_child_elapsed_abstime();
+    fn test_field_ri_diskio_bytesread() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_diskio_bytesread)
+            )
+        );
+    }
+    test_field_ri_diskio_bytesread();
+    fn test_field_ri_diskio_byteswritten() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_diskio_byteswritten)
+            )
+        );
+    }
+    test_field_ri_diskio_byteswritten();
+    fn test_field_ri_cpu_time_qos_default() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_default)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_default();
+    fn test_field_ri_cpu_time_qos_maintenance() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_maintenance)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_maintenance();
+    fn test_field_ri_cpu_time_qos_background() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
+            },
+            176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_background)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_background();
+    fn test_field_ri_cpu_time_qos_utility() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_utility)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_utility();
+    fn test_field_ri_cpu_time_qos_legacy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_legacy)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_legacy();
+    fn test_field_ri_cpu_time_qos_user_initiated() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_user_initiated)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_user_initiated();
+    fn test_field_ri_cpu_time_qos_user_interactive() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
+                    - ptr as usize
+            },
+            208usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cpu_time_qos_user_interactive)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_user_interactive();
+    fn test_field_ri_billed_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_billed_system_time)
+            )
+        );
+    }
+    test_field_ri_billed_system_time();
+    fn test_field_ri_serviced_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
+            },
+            224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_serviced_system_time)
+            )
+        );
+    }
+    test_field_ri_serviced_system_time();
+    fn test_field_ri_logical_writes() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_logical_writes) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_logical_writes)
+            )
+        );
+    }
+    test_field_ri_logical_writes();
+    fn test_field_ri_lifetime_max_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_lifetime_max_phys_footprint) as usize - ptr as usize
+            },
+            240usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_lifetime_max_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_lifetime_max_phys_footprint();
+    fn test_field_ri_instructions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_instructions) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_instructions)
+            )
+        );
+    }
+    test_field_ri_instructions();
+    fn test_field_ri_cycles() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cycles) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_cycles)
+            )
+        );
+    }
+    test_field_ri_cycles();
+    fn test_field_ri_billed_energy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_billed_energy) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_billed_energy)
+            )
+        );
+    }
+    test_field_ri_billed_energy();
+    fn test_field_ri_serviced_energy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_serviced_energy) as usize - ptr as usize
+            },
+            272usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_serviced_energy)
+            )
+        );
+    }
+    test_field_ri_serviced_energy();
+    fn test_field_ri_interval_max_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interval_max_phys_footprint) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_interval_max_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_interval_max_phys_footprint();
+    fn test_field_ri_runnable_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_runnable_time) as usize - ptr as usize
+            },
+            288usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v4),
+                "::",
+                stringify!(ri_runnable_time)
+            )
+        );
+    }
+    test_field_ri_runnable_time();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rusage_info_v5 {
+    pub ri_uuid: [u8; 16usize],
+    pub ri_user_time: u64,
+    pub ri_system_time: u64,
+    pub ri_pkg_idle_wkups: u64,
+    pub ri_interrupt_wkups: u64,
+    pub ri_pageins: u64,
+    pub ri_wired_size: u64,
+    pub ri_resident_size: u64,
+    pub ri_phys_footprint: u64,
+    pub ri_proc_start_abstime: u64,
+    pub ri_proc_exit_abstime: u64,
+    pub ri_child_user_time: u64,
+    pub ri_child_system_time: u64,
+    pub ri_child_pkg_idle_wkups: u64,
+    pub ri_child_interrupt_wkups: u64,
+    pub ri_child_pageins: u64,
+    pub ri_child_elapsed_abstime: u64,
+    pub ri_diskio_bytesread: u64,
+    pub ri_diskio_byteswritten: u64,
+    pub ri_cpu_time_qos_default: u64,
+    pub ri_cpu_time_qos_maintenance: u64,
+    pub ri_cpu_time_qos_background: u64,
+    pub ri_cpu_time_qos_utility: u64,
+    pub ri_cpu_time_qos_legacy: u64,
+    pub ri_cpu_time_qos_user_initiated: u64,
+    pub ri_cpu_time_qos_user_interactive: u64,
+    pub ri_billed_system_time: u64,
+    pub ri_serviced_system_time: u64,
+    pub ri_logical_writes: u64,
+    pub ri_lifetime_max_phys_footprint: u64,
+    pub ri_instructions: u64,
+    pub ri_cycles: u64,
+    pub ri_billed_energy: u64,
+    pub ri_serviced_energy: u64,
+    pub ri_interval_max_phys_footprint: u64,
+    pub ri_runnable_time: u64,
+    pub ri_flags: u64,
+}
+#[test]
+fn bindgen_test_layout_rusage_info_v5() {
+    assert_eq!(
+        ::std::mem::size_of::<rusage_info_v5>(),
+        304usize,
+        concat!("Size of: ", stringify!(rusage_info_v5))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rusage_info_v5>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rusage_info_v5))
+    );
+    fn test_field_ri_uuid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_uuid)
+            )
+        );
+    }
+    test_field_ri_uuid();
+    fn test_field_ri_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_user_time)
+            )
+        );
+    }
+    test_field_ri_user_time();
+    fn test_field_ri_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_system_time)
+            )
+        );
+    }
+    test_field_ri_system_time();
+    fn test_field_ri_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_pkg_idle_wkups();
+    fn test_field_ri_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_interrupt_wkups();
+    fn test_field_ri_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_pageins)
+            )
+        );
+    }
+    test_field_ri_pageins();
+    fn test_field_ri_wired_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_wired_size)
+            )
+        );
+    }
+    test_field_ri_wired_size();
+    fn test_field_ri_resident_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_resident_size)
+            )
+        );
+    }
+    test_field_ri_resident_size();
+    fn test_field_ri_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_phys_footprint();
+    fn test_field_ri_proc_start_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_proc_start_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_start_abstime();
+    fn test_field_ri_proc_exit_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_proc_exit_abstime)
+            )
+        );
+    }
+    test_field_ri_proc_exit_abstime();
+    fn test_field_ri_child_user_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_child_user_time)
+            )
+        );
+    }
+    test_field_ri_child_user_time();
+    fn test_field_ri_child_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_child_system_time)
+            )
+        );
+    }
+    test_field_ri_child_system_time();
+    fn test_field_ri_child_pkg_idle_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_child_pkg_idle_wkups)
+            )
+        );
+    }
+    test_field_ri_child_pkg_idle_wkups();
+    fn test_field_ri_child_interrupt_wkups() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_child_interrupt_wkups)
+            )
+        );
+    }
+    test_field_ri_child_interrupt_wkups();
+    fn test_field_ri_child_pageins() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_child_pageins)
+            )
+        );
+    }
+    test_field_ri_child_pageins();
+    fn test_field_ri_child_elapsed_abstime() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_child_elapsed_abstime)
+            )
+        );
+    }
+    test_field_ri_child_elapsed_abstime();
+    fn test_field_ri_diskio_bytesread() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_diskio_bytesread)
+            )
+        );
+    }
+    test_field_ri_diskio_bytesread();
+    fn test_field_ri_diskio_byteswritten() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_diskio_byteswritten)
+            )
+        );
+    }
+    test_field_ri_diskio_byteswritten();
+    fn test_field_ri_cpu_time_qos_default() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_default)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_default();
+    fn test_field_ri_cpu_time_qos_maintenance() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_maintenance)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_maintenance();
+    fn test_field_ri_cpu_time_qos_background() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
+            },
+            176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_background)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_background();
+    fn test_field_ri_cpu_time_qos_utility() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_utility)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_utility();
+    fn test_field_ri_cpu_time_qos_legacy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_legacy)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_legacy();
+    fn test_field_ri_cpu_time_qos_user_initiated() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_user_initiated)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_user_initiated();
+    fn test_field_ri_cpu_time_qos_user_interactive() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
+                    - ptr as usize
+            },
+            208usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cpu_time_qos_user_interactive)
+            )
+        );
+    }
+    test_field_ri_cpu_time_qos_user_interactive();
+    fn test_field_ri_billed_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_billed_system_time)
+            )
+        );
+    }
+    test_field_ri_billed_system_time();
+    fn test_field_ri_serviced_system_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
+            },
+            224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_serviced_system_time)
+            )
+        );
+    }
+    test_field_ri_serviced_system_time();
+    fn test_field_ri_logical_writes() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_logical_writes) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_logical_writes)
+            )
+        );
+    }
+    test_field_ri_logical_writes();
+    fn test_field_ri_lifetime_max_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_lifetime_max_phys_footprint) as usize - ptr as usize
+            },
+            240usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_lifetime_max_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_lifetime_max_phys_footprint();
+    fn test_field_ri_instructions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_instructions) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_instructions)
+            )
+        );
+    }
+    test_field_ri_instructions();
+    fn test_field_ri_cycles() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_cycles) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_cycles)
+            )
+        );
+    }
+    test_field_ri_cycles();
+    fn test_field_ri_billed_energy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_billed_energy) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_billed_energy)
+            )
+        );
+    }
+    test_field_ri_billed_energy();
+    fn test_field_ri_serviced_energy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_serviced_energy) as usize - ptr as usize
+            },
+            272usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_serviced_energy)
+            )
+        );
+    }
+    test_field_ri_serviced_energy();
+    fn test_field_ri_interval_max_phys_footprint() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_interval_max_phys_footprint) as usize - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_interval_max_phys_footprint)
+            )
+        );
+    }
+    test_field_ri_interval_max_phys_footprint();
+    fn test_field_ri_runnable_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_runnable_time) as usize - ptr as usize
+            },
+            288usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_runnable_time)
+            )
+        );
+    }
+    test_field_ri_runnable_time();
+    fn test_field_ri_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ri_flags) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rusage_info_v5),
+                "::",
+                stringify!(ri_flags)
+            )
+        );
+    }
+    test_field_ri_flags();
+}
+pub type rusage_info_current = rusage_info_v5;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rlimit {
+    pub rlim_cur: rlim_t,
+    pub rlim_max: rlim_t,
+}
+#[test]
+fn bindgen_test_layout_rlimit() {
+    assert_eq!(
+        ::std::mem::size_of::<rlimit>(),
+        16usize,
+        concat!("Size of: ", stringify!(rlimit))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rlimit>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rlimit))
+    );
+    fn test_field_rlim_cur() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rlimit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).rlim_cur) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rlimit),
+                "::",
+                stringify!(rlim_cur)
+            )
+        );
+    }
+    test_field_rlim_cur();
+    fn test_field_rlim_max() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<rlimit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).rlim_max) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(rlimit),
+                "::",
+                stringify!(rlim_max)
+            )
+        );
+    }
+    test_field_rlim_max();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct proc_rlimit_control_wakeupmon {
+    pub wm_flags: u32,
+    pub wm_rate: i32,
+}
+#[test]
+fn bindgen_test_layout_proc_rlimit_control_wakeupmon() {
+    assert_eq!(
+        ::std::mem::size_of::<proc_rlimit_control_wakeupmon>(),
+        8usize,
+        concat!("Size of: ", stringify!(proc_rlimit_control_wakeupmon))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<proc_rlimit_control_wakeupmon>(),
+        4usize,
+        concat!("Alignment of ", stringify!(proc_rlimit_control_wakeupmon))
+    );
+    fn test_field_wm_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<proc_rlimit_control_wakeupmon>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).wm_flags) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(proc_rlimit_control_wakeupmon),
+                "::",
+                stringify!(wm_flags)
+            )
+        );
+    }
+    test_field_wm_flags();
+    fn test_field_wm_rate() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<proc_rlimit_control_wakeupmon>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).wm_rate) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(proc_rlimit_control_wakeupmon),
+                "::",
+                stringify!(wm_rate)
+            )
+        );
+    }
+    test_field_wm_rate();
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union wait {
+    pub w_status: ::std::os::raw::c_int,
+    pub w_T: wait__bindgen_ty_1,
+    pub w_S: wait__bindgen_ty_2,
+}
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct wait__bindgen_ty_1 {
+    pub _bitfield_align_1: [u16; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+}
+#[test]
+fn bindgen_test_layout_wait__bindgen_ty_1() {
+    assert_eq!(
+        ::std::mem::size_of::<wait__bindgen_ty_1>(),
+        4usize,
+        concat!("Size of: ", stringify!(wait__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<wait__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(wait__bindgen_ty_1))
+    );
+}
+impl wait__bindgen_ty_1 {
+    #[inline]
+    pub fn w_Termsig(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 7u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Termsig(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 7u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn w_Coredump(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Coredump(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(7usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn w_Retcode(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Retcode(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(8usize, 8u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn w_Filler(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(16usize, 16u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Filler(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(16usize, 16u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        w_Termsig: ::std::os::raw::c_uint,
+        w_Coredump: ::std::os::raw::c_uint,
+        w_Retcode: ::std::os::raw::c_uint,
+        w_Filler: ::std::os::raw::c_uint,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 7u8, {
+            let w_Termsig: u32 = unsafe { ::std::mem::transmute(w_Termsig) };
+            w_Termsig as u64
+        });
+        __bindgen_bitfield_unit.set(7usize, 1u8, {
+            let w_Coredump: u32 = unsafe { ::std::mem::transmute(w_Coredump) };
+            w_Coredump as u64
+        });
+        __bindgen_bitfield_unit.set(8usize, 8u8, {
+            let w_Retcode: u32 = unsafe { ::std::mem::transmute(w_Retcode) };
+            w_Retcode as u64
+        });
+        __bindgen_bitfield_unit.set(16usize, 16u8, {
+            let w_Filler: u32 = unsafe { ::std::mem::transmute(w_Filler) };
+            w_Filler as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct wait__bindgen_ty_2 {
+    pub _bitfield_align_1: [u16; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+}
+#[test]
+fn bindgen_test_layout_wait__bindgen_ty_2() {
+    assert_eq!(
+        ::std::mem::size_of::<wait__bindgen_ty_2>(),
+        4usize,
+        concat!("Size of: ", stringify!(wait__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<wait__bindgen_ty_2>(),
+        4usize,
+        concat!("Alignment of ", stringify!(wait__bindgen_ty_2))
+    );
+}
+impl wait__bindgen_ty_2 {
+    #[inline]
+    pub fn w_Stopval(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 8u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Stopval(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 8u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn w_Stopsig(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Stopsig(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(8usize, 8u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn w_Filler(&self) -> ::std::os::raw::c_uint {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(16usize, 16u8) as u32) }
+    }
+    #[inline]
+    pub fn set_w_Filler(&mut self, val: ::std::os::raw::c_uint) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(16usize, 16u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        w_Stopval: ::std::os::raw::c_uint,
+        w_Stopsig: ::std::os::raw::c_uint,
+        w_Filler: ::std::os::raw::c_uint,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 8u8, {
+            let w_Stopval: u32 = unsafe { ::std::mem::transmute(w_Stopval) };
+            w_Stopval as u64
+        });
+        __bindgen_bitfield_unit.set(8usize, 8u8, {
+            let w_Stopsig: u32 = unsafe { ::std::mem::transmute(w_Stopsig) };
+            w_Stopsig as u64
+        });
+        __bindgen_bitfield_unit.set(16usize, 16u8, {
+            let w_Filler: u32 = unsafe { ::std::mem::transmute(w_Filler) };
+            w_Filler as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[test]
+fn bindgen_test_layout_wait() {
+    assert_eq!(
+        ::std::mem::size_of::<wait>(),
+        4usize,
+        concat!("Size of: ", stringify!(wait))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<wait>(),
+        4usize,
+        concat!("Alignment of ", stringify!(wait))
+    );
+    fn test_field_w_status() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<wait>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).w_status) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(wait),
+                "::",
+                stringify!(w_status)
+            )
+        );
+    }
+    test_field_w_status();
+    fn test_field_w_T() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<wait>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).w_T) as usize - ptr as usize
+            },
+            0usize,
+            concat!("Offset of field: ", stringify!(wait), "::", stringify!(w_T))
+        );
+    }
+    test_field_w_T();
+    fn test_field_w_S() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<wait>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).w_S) as usize - ptr as usize
+            },
+            0usize,
+            concat!("Offset of field: ", stringify!(wait), "::", stringify!(w_S))
+        );
+    }
+    test_field_w_S();
+}
+pub type ct_rune_t = __darwin_ct_rune_t;
+pub type rune_t = __darwin_rune_t;
+pub type wchar_t = __darwin_wchar_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct div_t {
+    pub quot: ::std::os::raw::c_int,
+    pub rem: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_div_t() {
+    assert_eq!(
+        ::std::mem::size_of::<div_t>(),
+        8usize,
+        concat!("Size of: ", stringify!(div_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<div_t>(),
+        4usize,
+        concat!("Alignment of ", stringify!(div_t))
+    );
+    fn test_field_quot() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<div_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(div_t),
+                "::",
+                stringify!(quot)
+            )
+        );
+    }
+    test_field_quot();
+    fn test_field_rem() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<div_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(div_t),
+                "::",
+                stringify!(rem)
+            )
+        );
+    }
+    test_field_rem();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ldiv_t {
+    pub quot: ::std::os::raw::c_long,
+    pub rem: ::std::os::raw::c_long,
+}
+#[test]
+fn bindgen_test_layout_ldiv_t() {
+    assert_eq!(
+        ::std::mem::size_of::<ldiv_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(ldiv_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ldiv_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ldiv_t))
+    );
+    fn test_field_quot() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<ldiv_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(ldiv_t),
+                "::",
+                stringify!(quot)
+            )
+        );
+    }
+    test_field_quot();
+    fn test_field_rem() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<ldiv_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(ldiv_t),
+                "::",
+                stringify!(rem)
+            )
+        );
+    }
+    test_field_rem();
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct lldiv_t {
+    pub quot: ::std::os::raw::c_longlong,
+    pub rem: ::std::os::raw::c_longlong,
+}
+#[test]
+fn bindgen_test_layout_lldiv_t() {
+    assert_eq!(
+        ::std::mem::size_of::<lldiv_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(lldiv_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<lldiv_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(lldiv_t))
+    );
+    fn test_field_quot() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<lldiv_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(lldiv_t),
+                "::",
+                stringify!(quot)
+            )
+        );
+    }
+    test_field_quot();
+    fn test_field_rem() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<lldiv_t>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(lldiv_t),
+                "::",
+                stringify!(rem)
+            )
+        );
+    }
+    test_field_rem();
+}
+extern "C" {
+    pub static mut __mb_cur_max: ::std::os::raw::c_int;
+}
+pub type dev_t = __darwin_dev_t;
+pub type mode_t = __darwin_mode_t;
+extern "C" {
+    pub static mut suboptarg: *mut ::std::os::raw::c_char;
+}
+pub type rsize_t = __darwin_size_t;
+pub type errno_t = ::std::os::raw::c_int;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED:
+    ONNXTensorElementDataType = 0;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT: ONNXTensorElementDataType =
+    1;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8: ONNXTensorElementDataType =
+    2;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8: ONNXTensorElementDataType =
+    3;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16:
+    ONNXTensorElementDataType = 4;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16: ONNXTensorElementDataType =
+    5;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32: ONNXTensorElementDataType =
+    6;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64: ONNXTensorElementDataType =
+    7;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING:
+    ONNXTensorElementDataType = 8;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL: ONNXTensorElementDataType =
+    9;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16:
+    ONNXTensorElementDataType = 10;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE:
+    ONNXTensorElementDataType = 11;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32:
+    ONNXTensorElementDataType = 12;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64:
+    ONNXTensorElementDataType = 13;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64:
+    ONNXTensorElementDataType = 14;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128:
+    ONNXTensorElementDataType = 15;
+pub const ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16:
+    ONNXTensorElementDataType = 16;
+#[doc = " Copied from TensorProto::DataType"]
+#[doc = " Currently, Ort doesn't support complex64, complex128"]
+pub type ONNXTensorElementDataType = ::std::os::raw::c_uint;
+pub const ONNXType_ONNX_TYPE_UNKNOWN: ONNXType = 0;
+pub const ONNXType_ONNX_TYPE_TENSOR: ONNXType = 1;
+pub const ONNXType_ONNX_TYPE_SEQUENCE: ONNXType = 2;
+pub const ONNXType_ONNX_TYPE_MAP: ONNXType = 3;
+pub const ONNXType_ONNX_TYPE_OPAQUE: ONNXType = 4;
+pub const ONNXType_ONNX_TYPE_SPARSETENSOR: ONNXType = 5;
+pub const ONNXType_ONNX_TYPE_OPTIONAL: ONNXType = 6;
+pub type ONNXType = ::std::os::raw::c_uint;
+pub const OrtSparseFormat_ORT_SPARSE_UNDEFINED: OrtSparseFormat = 0;
+pub const OrtSparseFormat_ORT_SPARSE_COO: OrtSparseFormat = 1;
+pub const OrtSparseFormat_ORT_SPARSE_CSRC: OrtSparseFormat = 2;
+pub const OrtSparseFormat_ORT_SPARSE_BLOCK_SPARSE: OrtSparseFormat = 4;
+pub type OrtSparseFormat = ::std::os::raw::c_uint;
+pub const OrtSparseIndicesFormat_ORT_SPARSE_COO_INDICES: OrtSparseIndicesFormat = 0;
+pub const OrtSparseIndicesFormat_ORT_SPARSE_CSR_INNER_INDICES: OrtSparseIndicesFormat = 1;
+pub const OrtSparseIndicesFormat_ORT_SPARSE_CSR_OUTER_INDICES: OrtSparseIndicesFormat = 2;
+pub const OrtSparseIndicesFormat_ORT_SPARSE_BLOCK_SPARSE_INDICES: OrtSparseIndicesFormat = 3;
+pub type OrtSparseIndicesFormat = ::std::os::raw::c_uint;
+#[doc = "< Verbose informational messages (least severe)."]
+pub const OrtLoggingLevel_ORT_LOGGING_LEVEL_VERBOSE: OrtLoggingLevel = 0;
+#[doc = "< Informational messages."]
+pub const OrtLoggingLevel_ORT_LOGGING_LEVEL_INFO: OrtLoggingLevel = 1;
+#[doc = "< Warning messages."]
+pub const OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING: OrtLoggingLevel = 2;
+#[doc = "< Error messages."]
+pub const OrtLoggingLevel_ORT_LOGGING_LEVEL_ERROR: OrtLoggingLevel = 3;
+#[doc = "< Fatal error messages (most severe)."]
+pub const OrtLoggingLevel_ORT_LOGGING_LEVEL_FATAL: OrtLoggingLevel = 4;
+#[doc = " \\brief Logging severity levels"]
+#[doc = ""]
+#[doc = " In typical API usage, specifying a logging severity level specifies the minimum severity of log messages to show."]
+pub type OrtLoggingLevel = ::std::os::raw::c_uint;
+pub const OrtErrorCode_ORT_OK: OrtErrorCode = 0;
+pub const OrtErrorCode_ORT_FAIL: OrtErrorCode = 1;
+pub const OrtErrorCode_ORT_INVALID_ARGUMENT: OrtErrorCode = 2;
+pub const OrtErrorCode_ORT_NO_SUCHFILE: OrtErrorCode = 3;
+pub const OrtErrorCode_ORT_NO_MODEL: OrtErrorCode = 4;
+pub const OrtErrorCode_ORT_ENGINE_ERROR: OrtErrorCode = 5;
+pub const OrtErrorCode_ORT_RUNTIME_EXCEPTION: OrtErrorCode = 6;
+pub const OrtErrorCode_ORT_INVALID_PROTOBUF: OrtErrorCode = 7;
+pub const OrtErrorCode_ORT_MODEL_LOADED: OrtErrorCode = 8;
+pub const OrtErrorCode_ORT_NOT_IMPLEMENTED: OrtErrorCode = 9;
+pub const OrtErrorCode_ORT_INVALID_GRAPH: OrtErrorCode = 10;
+pub const OrtErrorCode_ORT_EP_FAIL: OrtErrorCode = 11;
+pub type OrtErrorCode = ::std::os::raw::c_uint;
+pub const OrtOpAttrType_ORT_OP_ATTR_UNDEFINED: OrtOpAttrType = 0;
+pub const OrtOpAttrType_ORT_OP_ATTR_INT: OrtOpAttrType = 1;
+pub const OrtOpAttrType_ORT_OP_ATTR_INTS: OrtOpAttrType = 2;
+pub const OrtOpAttrType_ORT_OP_ATTR_FLOAT: OrtOpAttrType = 3;
+pub const OrtOpAttrType_ORT_OP_ATTR_FLOATS: OrtOpAttrType = 4;
+pub const OrtOpAttrType_ORT_OP_ATTR_STRING: OrtOpAttrType = 5;
+pub const OrtOpAttrType_ORT_OP_ATTR_STRINGS: OrtOpAttrType = 6;
+pub type OrtOpAttrType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtEnv {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtStatus {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtMemoryInfo {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtIoBinding {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtSession {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtValue {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtRunOptions {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtTypeInfo {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtTensorTypeAndShapeInfo {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtSessionOptions {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtCustomOpDomain {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtMapTypeInfo {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtSequenceTypeInfo {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtModelMetadata {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtThreadPoolParams {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtThreadingOptions {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtArenaCfg {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtPrepackedWeightsContainer {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtTensorRTProviderOptionsV2 {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtCUDAProviderOptionsV2 {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtOp {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtOpAttr {
+    _unused: [u8; 0],
+}
+pub type OrtStatusPtr = *mut OrtStatus;
+#[doc = " \\brief Memory allocation interface"]
+#[doc = ""]
+#[doc = " Structure of function pointers that defines a memory allocator. This can be created and filled in by the user for custom allocators."]
+#[doc = ""]
+#[doc = " When an allocator is passed to any function, be sure that the allocator object is not destroyed until the last allocated object using it is freed."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtAllocator {
+    #[doc = "< Must be initialized to ORT_API_VERSION"]
+    pub version: u32,
+    #[doc = "< Returns a pointer to an allocated block of `size` bytes"]
+    pub Alloc: ::std::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::std::os::raw::c_void,
+    >,
+    #[doc = "< Free a block of memory previously allocated with OrtAllocator::Alloc"]
+    pub Free: ::std::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::std::os::raw::c_void),
+    >,
+    #[doc = "< Return a pointer to an ::OrtMemoryInfo that describes this allocator"]
+    pub Info: ::std::option::Option<
+        unsafe extern "C" fn(this_: *const OrtAllocator) -> *const OrtMemoryInfo,
+    >,
+}
+#[test]
+fn bindgen_test_layout_OrtAllocator() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtAllocator>(),
+        32usize,
+        concat!("Size of: ", stringify!(OrtAllocator))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtAllocator>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtAllocator))
+    );
+    fn test_field_version() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtAllocator),
+                "::",
+                stringify!(version)
+            )
+        );
+    }
+    test_field_version();
+    fn test_field_Alloc() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtAllocator),
+                "::",
+                stringify!(Alloc)
+            )
+        );
+    }
+    test_field_Alloc();
+    fn test_field_Free() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtAllocator),
+                "::",
+                stringify!(Free)
+            )
+        );
+    }
+    test_field_Free();
+    fn test_field_Info() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtAllocator),
+                "::",
+                stringify!(Info)
+            )
+        );
+    }
+    test_field_Info();
+}
+pub type OrtLoggingFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        param: *mut ::std::os::raw::c_void,
+        severity: OrtLoggingLevel,
+        category: *const ::std::os::raw::c_char,
+        logid: *const ::std::os::raw::c_char,
+        code_location: *const ::std::os::raw::c_char,
+        message: *const ::std::os::raw::c_char,
+    ),
+>;
+pub const GraphOptimizationLevel_ORT_DISABLE_ALL: GraphOptimizationLevel = 0;
+pub const GraphOptimizationLevel_ORT_ENABLE_BASIC: GraphOptimizationLevel = 1;
+pub const GraphOptimizationLevel_ORT_ENABLE_EXTENDED: GraphOptimizationLevel = 2;
+pub const GraphOptimizationLevel_ORT_ENABLE_ALL: GraphOptimizationLevel = 99;
+#[doc = " \\brief Graph optimization level"]
+#[doc = ""]
+#[doc = " Refer to https://www.onnxruntime.ai/docs/resources/graph-optimizations.html"]
+#[doc = " for an in-depth understanding of Graph Optimizations"]
+pub type GraphOptimizationLevel = ::std::os::raw::c_uint;
+pub const ExecutionMode_ORT_SEQUENTIAL: ExecutionMode = 0;
+pub const ExecutionMode_ORT_PARALLEL: ExecutionMode = 1;
+pub type ExecutionMode = ::std::os::raw::c_uint;
+pub const OrtLanguageProjection_ORT_PROJECTION_C: OrtLanguageProjection = 0;
+pub const OrtLanguageProjection_ORT_PROJECTION_CPLUSPLUS: OrtLanguageProjection = 1;
+pub const OrtLanguageProjection_ORT_PROJECTION_CSHARP: OrtLanguageProjection = 2;
+pub const OrtLanguageProjection_ORT_PROJECTION_PYTHON: OrtLanguageProjection = 3;
+pub const OrtLanguageProjection_ORT_PROJECTION_JAVA: OrtLanguageProjection = 4;
+pub const OrtLanguageProjection_ORT_PROJECTION_WINML: OrtLanguageProjection = 5;
+pub const OrtLanguageProjection_ORT_PROJECTION_NODEJS: OrtLanguageProjection = 6;
+#[doc = " \\brief Language projection identifiers"]
+#[doc = " /see OrtApi::SetLanguageProjection"]
+pub type OrtLanguageProjection = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtKernelInfo {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtKernelContext {
+    _unused: [u8; 0],
+}
+pub const OrtAllocatorType_OrtInvalidAllocator: OrtAllocatorType = -1;
+pub const OrtAllocatorType_OrtDeviceAllocator: OrtAllocatorType = 0;
+pub const OrtAllocatorType_OrtArenaAllocator: OrtAllocatorType = 1;
+pub type OrtAllocatorType = ::std::os::raw::c_int;
+#[doc = "< Any CPU memory used by non-CPU execution provider"]
+pub const OrtMemType_OrtMemTypeCPUInput: OrtMemType = -2;
+#[doc = "< CPU accessible memory outputted by non-CPU execution provider, i.e. CUDA_PINNED"]
+pub const OrtMemType_OrtMemTypeCPUOutput: OrtMemType = -1;
+#[doc = "< Temporary CPU accessible memory allocated by non-CPU execution provider, i.e. CUDA_PINNED"]
+pub const OrtMemType_OrtMemTypeCPU: OrtMemType = -1;
+#[doc = "< The default allocator for execution provider"]
+pub const OrtMemType_OrtMemTypeDefault: OrtMemType = 0;
+#[doc = " \\brief Memory types for allocated memory, execution provider specific types should be extended in each provider."]
+pub type OrtMemType = ::std::os::raw::c_int;
+pub const OrtCudnnConvAlgoSearch_OrtCudnnConvAlgoSearchExhaustive: OrtCudnnConvAlgoSearch = 0;
+pub const OrtCudnnConvAlgoSearch_OrtCudnnConvAlgoSearchHeuristic: OrtCudnnConvAlgoSearch = 1;
+pub const OrtCudnnConvAlgoSearch_OrtCudnnConvAlgoSearchDefault: OrtCudnnConvAlgoSearch = 2;
+#[doc = " \\brief Algorithm to use for cuDNN Convolution Op"]
+pub type OrtCudnnConvAlgoSearch = ::std::os::raw::c_uint;
+#[doc = " \\brief CUDA Provider Options"]
+#[doc = ""]
+#[doc = " \\see OrtApi::SessionOptionsAppendExecutionProvider_CUDA"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtCUDAProviderOptions {
+    #[doc = " \\brief CUDA device Id"]
+    #[doc = "   Defaults to 0."]
+    pub device_id: ::std::os::raw::c_int,
+    #[doc = " \\brief CUDA Convolution algorithm search configuration."]
+    #[doc = "   See enum OrtCudnnConvAlgoSearch for more details."]
+    #[doc = "   Defaults to OrtCudnnConvAlgoSearchExhaustive."]
+    pub cudnn_conv_algo_search: OrtCudnnConvAlgoSearch,
+    #[doc = " \\brief CUDA memory limit (To use all possible memory pass in maximum size_t)"]
+    #[doc = "   Defaults to SIZE_MAX."]
+    #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
+    pub gpu_mem_limit: usize,
+    #[doc = " \\brief Strategy used to grow the memory arena"]
+    #[doc = "   0 = kNextPowerOfTwo<br>"]
+    #[doc = "   1 = kSameAsRequested<br>"]
+    #[doc = "   Defaults to 0."]
+    #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
+    pub arena_extend_strategy: ::std::os::raw::c_int,
+    #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the CUDA EP"]
+    #[doc = "   0 = Use separate streams for copying and compute."]
+    #[doc = "   1 = Use the same stream for copying and compute."]
+    #[doc = "   Defaults to 1."]
+    #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
+    #[doc = "   Please see issue #4829 for more details."]
+    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
+    #[doc = "   Defaults to 0."]
+    pub has_user_compute_stream: ::std::os::raw::c_int,
+    #[doc = " \\brief User provided compute stream."]
+    #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
+    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    #[doc = " \\brief CUDA memory arena configuration parameters"]
+    pub default_memory_arena_cfg: *mut OrtArenaCfg,
+}
+#[test]
+fn bindgen_test_layout_OrtCUDAProviderOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtCUDAProviderOptions>(),
+        48usize,
+        concat!("Size of: ", stringify!(OrtCUDAProviderOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtCUDAProviderOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtCUDAProviderOptions))
+    );
+    fn test_field_device_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(device_id)
+            )
+        );
+    }
+    test_field_device_id();
+    fn test_field_cudnn_conv_algo_search() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(cudnn_conv_algo_search)
+            )
+        );
+    }
+    test_field_cudnn_conv_algo_search();
+    fn test_field_gpu_mem_limit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(gpu_mem_limit)
+            )
+        );
+    }
+    test_field_gpu_mem_limit();
+    fn test_field_arena_extend_strategy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(arena_extend_strategy)
+            )
+        );
+    }
+    test_field_arena_extend_strategy();
+    fn test_field_do_copy_in_default_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(do_copy_in_default_stream)
+            )
+        );
+    }
+    test_field_do_copy_in_default_stream();
+    fn test_field_has_user_compute_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(has_user_compute_stream)
+            )
+        );
+    }
+    test_field_has_user_compute_stream();
+    fn test_field_user_compute_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(user_compute_stream)
+            )
+        );
+    }
+    test_field_user_compute_stream();
+    fn test_field_default_memory_arena_cfg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCUDAProviderOptions),
+                "::",
+                stringify!(default_memory_arena_cfg)
+            )
+        );
+    }
+    test_field_default_memory_arena_cfg();
+}
+#[doc = " \\brief ROCM Provider Options"]
+#[doc = ""]
+#[doc = " \\see OrtApi::SessionOptionsAppendExecutionProvider_ROCM"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtROCMProviderOptions {
+    #[doc = " \\brief ROCM device Id"]
+    #[doc = "   Defaults to 0."]
+    pub device_id: ::std::os::raw::c_int,
+    #[doc = " \\brief ROCM MIOpen Convolution algorithm exaustive search option."]
+    #[doc = "   Defaults to 0 (false)."]
+    pub miopen_conv_exhaustive_search: ::std::os::raw::c_int,
+    #[doc = " \\brief ROCM memory limit (To use all possible memory pass in maximum size_t)"]
+    #[doc = "   Defaults to SIZE_MAX."]
+    #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
+    pub gpu_mem_limit: usize,
+    #[doc = " \\brief Strategy used to grow the memory arena"]
+    #[doc = "   0 = kNextPowerOfTwo<br>"]
+    #[doc = "   1 = kSameAsRequested<br>"]
+    #[doc = "   Defaults to 0."]
+    #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
+    pub arena_extend_strategy: ::std::os::raw::c_int,
+    #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the ROCM EP"]
+    #[doc = "   0 = Use separate streams for copying and compute."]
+    #[doc = "   1 = Use the same stream for copying and compute."]
+    #[doc = "   Defaults to 1."]
+    #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
+    #[doc = "   Please see issue #4829 for more details."]
+    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
+    #[doc = "   Defaults to 0."]
+    pub has_user_compute_stream: ::std::os::raw::c_int,
+    #[doc = " \\brief User provided compute stream."]
+    #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
+    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    #[doc = " \\brief ROCM memory arena configuration parameters"]
+    pub default_memory_arena_cfg: *mut OrtArenaCfg,
+}
+#[test]
+fn bindgen_test_layout_OrtROCMProviderOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtROCMProviderOptions>(),
+        48usize,
+        concat!("Size of: ", stringify!(OrtROCMProviderOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtROCMProviderOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtROCMProviderOptions))
+    );
+    fn test_field_device_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(device_id)
+            )
+        );
+    }
+    test_field_device_id();
+    fn test_field_miopen_conv_exhaustive_search() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(miopen_conv_exhaustive_search)
+            )
+        );
+    }
+    test_field_miopen_conv_exhaustive_search();
+    fn test_field_gpu_mem_limit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(gpu_mem_limit)
+            )
+        );
+    }
+    test_field_gpu_mem_limit();
+    fn test_field_arena_extend_strategy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(arena_extend_strategy)
+            )
+        );
+    }
+    test_field_arena_extend_strategy();
+    fn test_field_do_copy_in_default_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(do_copy_in_default_stream)
+            )
+        );
+    }
+    test_field_do_copy_in_default_stream();
+    fn test_field_has_user_compute_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(has_user_compute_stream)
+            )
+        );
+    }
+    test_field_has_user_compute_stream();
+    fn test_field_user_compute_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(user_compute_stream)
+            )
+        );
+    }
+    test_field_user_compute_stream();
+    fn test_field_default_memory_arena_cfg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtROCMProviderOptions),
+                "::",
+                stringify!(default_memory_arena_cfg)
+            )
+        );
+    }
+    test_field_default_memory_arena_cfg();
+}
+#[doc = " \\brief TensorRT Provider Options"]
+#[doc = ""]
+#[doc = " \\see OrtApi::SessionOptionsAppendExecutionProvider_TensorRT"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtTensorRTProviderOptions {
+    #[doc = "< CUDA device id (0 = default device)"]
+    pub device_id: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub trt_max_partition_iterations: ::std::os::raw::c_int,
+    pub trt_min_subgraph_size: ::std::os::raw::c_int,
+    pub trt_max_workspace_size: usize,
+    pub trt_fp16_enable: ::std::os::raw::c_int,
+    pub trt_int8_enable: ::std::os::raw::c_int,
+    pub trt_int8_calibration_table_name: *const ::std::os::raw::c_char,
+    pub trt_int8_use_native_calibration_table: ::std::os::raw::c_int,
+    pub trt_dla_enable: ::std::os::raw::c_int,
+    pub trt_dla_core: ::std::os::raw::c_int,
+    pub trt_dump_subgraphs: ::std::os::raw::c_int,
+    pub trt_engine_cache_enable: ::std::os::raw::c_int,
+    pub trt_engine_cache_path: *const ::std::os::raw::c_char,
+    pub trt_engine_decryption_enable: ::std::os::raw::c_int,
+    pub trt_engine_decryption_lib_path: *const ::std::os::raw::c_char,
+    pub trt_force_sequential_engine_build: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_OrtTensorRTProviderOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtTensorRTProviderOptions>(),
+        104usize,
+        concat!("Size of: ", stringify!(OrtTensorRTProviderOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtTensorRTProviderOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtTensorRTProviderOptions))
+    );
+    fn test_field_device_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(device_id)
+            )
+        );
+    }
+    test_field_device_id();
+    fn test_field_has_user_compute_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(has_user_compute_stream)
+            )
+        );
+    }
+    test_field_has_user_compute_stream();
+    fn test_field_user_compute_stream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(user_compute_stream)
+            )
+        );
+    }
+    test_field_user_compute_stream();
+    fn test_field_trt_max_partition_iterations() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_max_partition_iterations)
+            )
+        );
+    }
+    test_field_trt_max_partition_iterations();
+    fn test_field_trt_min_subgraph_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_min_subgraph_size)
+            )
+        );
+    }
+    test_field_trt_min_subgraph_size();
+    fn test_field_trt_max_workspace_size() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_max_workspace_size)
+            )
+        );
+    }
+    test_field_trt_max_workspace_size();
+    fn test_field_trt_fp16_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_fp16_enable)
+            )
+        );
+    }
+    test_field_trt_fp16_enable();
+    fn test_field_trt_int8_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_int8_enable)
+            )
+        );
+    }
+    test_field_trt_int8_enable();
+    fn test_field_trt_int8_calibration_table_name() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_int8_calibration_table_name)
+            )
+        );
+    }
+    test_field_trt_int8_calibration_table_name();
+    fn test_field_trt_int8_use_native_calibration_table() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
+                    - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_int8_use_native_calibration_table)
+            )
+        );
+    }
+    test_field_trt_int8_use_native_calibration_table();
+    fn test_field_trt_dla_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
+            },
+            52usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_dla_enable)
+            )
+        );
+    }
+    test_field_trt_dla_enable();
+    fn test_field_trt_dla_core() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_dla_core)
+            )
+        );
+    }
+    test_field_trt_dla_core();
+    fn test_field_trt_dump_subgraphs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
+            },
+            60usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_dump_subgraphs)
+            )
+        );
+    }
+    test_field_trt_dump_subgraphs();
+    fn test_field_trt_engine_cache_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_engine_cache_enable)
+            )
+        );
+    }
+    test_field_trt_engine_cache_enable();
+    fn test_field_trt_engine_cache_path() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_engine_cache_path)
+            )
+        );
+    }
+    test_field_trt_engine_cache_path();
+    fn test_field_trt_engine_decryption_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_engine_decryption_enable)
+            )
+        );
+    }
+    test_field_trt_engine_decryption_enable();
+    fn test_field_trt_engine_decryption_lib_path() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_engine_decryption_lib_path)
+            )
+        );
+    }
+    test_field_trt_engine_decryption_lib_path();
+    fn test_field_trt_force_sequential_engine_build() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
+                    - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtTensorRTProviderOptions),
+                "::",
+                stringify!(trt_force_sequential_engine_build)
+            )
+        );
+    }
+    test_field_trt_force_sequential_engine_build();
+}
+#[doc = " \\brief MIGraphX Provider Options"]
+#[doc = ""]
+#[doc = " \\see OrtApi::SessionOptionsAppendExecutionProvider_MIGraphX"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtMIGraphXProviderOptions {
+    pub device_id: ::std::os::raw::c_int,
+    pub migraphx_fp16_enable: ::std::os::raw::c_int,
+    pub migraphx_int8_enable: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtMIGraphXProviderOptions>(),
+        12usize,
+        concat!("Size of: ", stringify!(OrtMIGraphXProviderOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtMIGraphXProviderOptions>(),
+        4usize,
+        concat!("Alignment of ", stringify!(OrtMIGraphXProviderOptions))
+    );
+    fn test_field_device_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtMIGraphXProviderOptions),
+                "::",
+                stringify!(device_id)
+            )
+        );
+    }
+    test_field_device_id();
+    fn test_field_migraphx_fp16_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtMIGraphXProviderOptions),
+                "::",
+                stringify!(migraphx_fp16_enable)
+            )
+        );
+    }
+    test_field_migraphx_fp16_enable();
+    fn test_field_migraphx_int8_enable() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtMIGraphXProviderOptions),
+                "::",
+                stringify!(migraphx_int8_enable)
+            )
+        );
+    }
+    test_field_migraphx_int8_enable();
+}
+#[doc = " \\brief OpenVINO Provider Options"]
+#[doc = ""]
+#[doc = " \\see OrtApi::SessionOptionsAppendExecutionProvider_OpenVINO"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtOpenVINOProviderOptions {
+    #[doc = " \\brief Device type string"]
+    #[doc = ""]
+    #[doc = " Valid settings are one of: \"CPU_FP32\", \"GPU_FP32\", \"GPU_FP16\", \"MYRIAD_FP16\", \"VAD-M_FP16\" or \"VAD-F_FP32\""]
+    pub device_type: *const ::std::os::raw::c_char,
+    #[doc = "< 0 = disabled, nonzero = enabled"]
+    pub enable_vpu_fast_compile: ::std::os::raw::c_uchar,
+    pub device_id: *const ::std::os::raw::c_char,
+    #[doc = "< 0 = Use default number of threads"]
+    pub num_of_threads: usize,
+    #[doc = "< 0 = disabled, nonzero = enabled"]
+    pub use_compiled_network: ::std::os::raw::c_uchar,
+    pub blob_dump_path: *const ::std::os::raw::c_char,
+    pub context: *mut ::std::os::raw::c_void,
+    #[doc = "< 0 = disabled, nonzero = enabled"]
+    pub enable_opencl_throttling: ::std::os::raw::c_uchar,
+    #[doc = "< 0 = disabled, nonzero = enabled"]
+    pub enable_dynamic_shapes: ::std::os::raw::c_uchar,
+}
+#[test]
+fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtOpenVINOProviderOptions>(),
+        64usize,
+        concat!("Size of: ", stringify!(OrtOpenVINOProviderOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtOpenVINOProviderOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtOpenVINOProviderOptions))
+    );
+    fn test_field_device_type() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(device_type)
+            )
+        );
+    }
+    test_field_device_type();
+    fn test_field_enable_vpu_fast_compile() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(enable_vpu_fast_compile)
+            )
+        );
+    }
+    test_field_enable_vpu_fast_compile();
+    fn test_field_device_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(device_id)
+            )
+        );
+    }
+    test_field_device_id();
+    fn test_field_num_of_threads() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(num_of_threads)
+            )
+        );
+    }
+    test_field_num_of_threads();
+    fn test_field_use_compiled_network() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).use_compiled_network) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(use_compiled_network)
+            )
+        );
+    }
+    test_field_use_compiled_network();
+    fn test_field_blob_dump_path() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).blob_dump_path) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(blob_dump_path)
+            )
+        );
+    }
+    test_field_blob_dump_path();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_enable_opencl_throttling() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(enable_opencl_throttling)
+            )
+        );
+    }
+    test_field_enable_opencl_throttling();
+    fn test_field_enable_dynamic_shapes() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
+            },
+            57usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtOpenVINOProviderOptions),
+                "::",
+                stringify!(enable_dynamic_shapes)
+            )
+        );
+    }
+    test_field_enable_dynamic_shapes();
+}
+#[doc = " \\brief The helper interface to get the right version of OrtApi"]
+#[doc = ""]
+#[doc = " Get a pointer to this structure through ::OrtGetApiBase"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtApiBase {
+    #[doc = " \\brief Get a pointer to the requested version of the ::OrtApi"]
+    #[doc = ""]
+    #[doc = " \\param[in] version Must be ::ORT_API_VERSION"]
+    #[doc = " \\return The ::OrtApi for the version requested, nullptr will be returned if this version is unsupported, for example when using a runtime"]
+    #[doc = "   older than the version created with this header file."]
+    pub GetApi: ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
+    #[doc = "< Returns a null terminated string of the version of the Onnxruntime library (eg: \"1.8.1\")"]
+    pub GetVersionString:
+        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+}
+#[test]
+fn bindgen_test_layout_OrtApiBase() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtApiBase>(),
+        16usize,
+        concat!("Size of: ", stringify!(OrtApiBase))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtApiBase>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtApiBase))
+    );
+    fn test_field_GetApi() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApiBase),
+                "::",
+                stringify!(GetApi)
+            )
+        );
+    }
+    test_field_GetApi();
+    fn test_field_GetVersionString() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApiBase),
+                "::",
+                stringify!(GetVersionString)
+            )
+        );
+    }
+    test_field_GetVersionString();
+}
+#[doc = " \\brief Thread work loop function"]
+#[doc = ""]
+#[doc = " Onnxruntime will provide the working loop on custom thread creation"]
+#[doc = " Argument is an onnxruntime built-in type which will be provided when thread pool calls OrtCustomCreateThreadFn"]
+pub type OrtThreadWorkerFn =
+    ::std::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::std::os::raw::c_void)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtCustomHandleType {
+    pub __place_holder: ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_OrtCustomHandleType() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtCustomHandleType>(),
+        1usize,
+        concat!("Size of: ", stringify!(OrtCustomHandleType))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtCustomHandleType>(),
+        1usize,
+        concat!("Alignment of ", stringify!(OrtCustomHandleType))
+    );
+    fn test_field___place_holder() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomHandleType),
+                "::",
+                stringify!(__place_holder)
+            )
+        );
+    }
+    test_field___place_holder();
+}
+pub type OrtCustomThreadHandle = *const OrtCustomHandleType;
+#[doc = " \\brief Ort custom thread creation function"]
+#[doc = ""]
+#[doc = " The function should return a thread handle to be used in onnxruntime thread pools"]
+#[doc = " Onnxruntime will throw exception on return value of nullptr or 0, indicating that the function failed to create a thread"]
+pub type OrtCustomCreateThreadFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+        ort_thread_worker_fn: OrtThreadWorkerFn,
+        ort_worker_fn_param: *mut ::std::os::raw::c_void,
+    ) -> OrtCustomThreadHandle,
+>;
+#[doc = " \\brief Custom thread join function"]
+#[doc = ""]
+#[doc = " Onnxruntime thread pool destructor will call the function to join a custom thread."]
+#[doc = " Argument ort_custom_thread_handle is the value returned by OrtCustomCreateThreadFn"]
+pub type OrtCustomJoinThreadFn =
+    ::std::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
+#[doc = " \\brief The C API"]
+#[doc = ""]
+#[doc = " All C API functions are defined inside this structure as pointers to functions."]
+#[doc = " Call OrtApiBase::GetApi to get a pointer to it"]
+#[doc = ""]
+#[doc = " \\nosubgrouping"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtApi {
+    #[doc = " \\brief Create an OrtStatus from a null terminated string"]
+    #[doc = ""]
+    #[doc = " \\param[in] code"]
+    #[doc = " \\param[in] msg A null-terminated string. Its contents will be copied."]
+    #[doc = " \\return A new OrtStatus object, must be destroyed with OrtApi::ReleaseStatus"]
+    pub CreateStatus: ::std::option::Option<
+        unsafe extern "C" fn(
+            code: OrtErrorCode,
+            msg: *const ::std::os::raw::c_char,
+        ) -> *mut OrtStatus,
+    >,
+    #[doc = " \\brief Get OrtErrorCode from OrtStatus"]
+    #[doc = ""]
+    #[doc = " \\param[in] status"]
+    #[doc = " \\return OrtErrorCode that \\p status was created with"]
+    pub GetErrorCode:
+        ::std::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
+    #[doc = " \\brief Get error string from OrtStatus"]
+    #[doc = ""]
+    #[doc = " \\param[in] status"]
+    #[doc = " \\return The error message inside the `status`. Do not free the returned value."]
+    pub GetErrorMessage: ::std::option::Option<
+        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::std::os::raw::c_char,
+    >,
+    pub CreateEnv: ::std::option::Option<
+        unsafe extern "C" fn(
+            log_severity_level: OrtLoggingLevel,
+            logid: *const ::std::os::raw::c_char,
+            out: *mut *mut OrtEnv,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateEnvWithCustomLogger: ::std::option::Option<
+        unsafe extern "C" fn(
+            logging_function: OrtLoggingFunction,
+            logger_param: *mut ::std::os::raw::c_void,
+            log_severity_level: OrtLoggingLevel,
+            logid: *const ::std::os::raw::c_char,
+            out: *mut *mut OrtEnv,
+        ) -> OrtStatusPtr,
+    >,
+    pub EnableTelemetryEvents:
+        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+    pub DisableTelemetryEvents:
+        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+    pub CreateSession: ::std::option::Option<
+        unsafe extern "C" fn(
+            env: *const OrtEnv,
+            model_path: *const ::std::os::raw::c_char,
+            options: *const OrtSessionOptions,
+            out: *mut *mut OrtSession,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateSessionFromArray: ::std::option::Option<
+        unsafe extern "C" fn(
+            env: *const OrtEnv,
+            model_data: *const ::std::os::raw::c_void,
+            model_data_length: usize,
+            options: *const OrtSessionOptions,
+            out: *mut *mut OrtSession,
+        ) -> OrtStatusPtr,
+    >,
+    pub Run: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *mut OrtSession,
+            run_options: *const OrtRunOptions,
+            input_names: *const *const ::std::os::raw::c_char,
+            inputs: *const *const OrtValue,
+            input_len: usize,
+            output_names: *const *const ::std::os::raw::c_char,
+            output_names_len: usize,
+            outputs: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateSessionOptions: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub SetOptimizedModelFilePath: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            optimized_model_filepath: *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub CloneSessionOptions: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_options: *const OrtSessionOptions,
+            out_options: *mut *mut OrtSessionOptions,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetSessionExecutionMode: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            execution_mode: ExecutionMode,
+        ) -> OrtStatusPtr,
+    >,
+    pub EnableProfiling: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            profile_file_prefix: *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub DisableProfiling: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub EnableMemPattern: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub DisableMemPattern: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub EnableCpuMemArena: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub DisableCpuMemArena: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub SetSessionLogId: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            logid: *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetSessionLogVerbosityLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            session_log_verbosity_level: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetSessionLogSeverityLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            session_log_severity_level: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetSessionGraphOptimizationLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            graph_optimization_level: GraphOptimizationLevel,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetIntraOpNumThreads: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            intra_op_num_threads: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetInterOpNumThreads: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            inter_op_num_threads: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateCustomOpDomain: ::std::option::Option<
+        unsafe extern "C" fn(
+            domain: *const ::std::os::raw::c_char,
+            out: *mut *mut OrtCustomOpDomain,
+        ) -> OrtStatusPtr,
+    >,
+    pub CustomOpDomain_Add: ::std::option::Option<
+        unsafe extern "C" fn(
+            custom_op_domain: *mut OrtCustomOpDomain,
+            op: *const OrtCustomOp,
+        ) -> OrtStatusPtr,
+    >,
+    pub AddCustomOpDomain: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            custom_op_domain: *mut OrtCustomOpDomain,
+        ) -> OrtStatusPtr,
+    >,
+    pub RegisterCustomOpsLibrary: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            library_path: *const ::std::os::raw::c_char,
+            library_handle: *mut *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetInputCount: ::std::option::Option<
+        unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub SessionGetOutputCount: ::std::option::Option<
+        unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub SessionGetOverridableInitializerCount: ::std::option::Option<
+        unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub SessionGetInputTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            index: usize,
+            type_info: *mut *mut OrtTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetOutputTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            index: usize,
+            type_info: *mut *mut OrtTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetOverridableInitializerTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            index: usize,
+            type_info: *mut *mut OrtTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetInputName: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            index: usize,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetOutputName: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            index: usize,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetOverridableInitializerName: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            index: usize,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateRunOptions:
+        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub RunOptionsSetRunLogVerbosityLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtRunOptions,
+            log_verbosity_level: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub RunOptionsSetRunLogSeverityLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtRunOptions,
+            log_severity_level: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub RunOptionsSetRunTag: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtRunOptions,
+            run_tag: *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub RunOptionsGetRunLogVerbosityLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *const OrtRunOptions,
+            log_verbosity_level: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub RunOptionsGetRunLogSeverityLevel: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *const OrtRunOptions,
+            log_severity_level: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub RunOptionsGetRunTag: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *const OrtRunOptions,
+            run_tag: *mut *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub RunOptionsSetTerminate:
+        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub RunOptionsUnsetTerminate:
+        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub CreateTensorAsOrtValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            allocator: *mut OrtAllocator,
+            shape: *const i64,
+            shape_len: usize,
+            type_: ONNXTensorElementDataType,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateTensorWithDataAsOrtValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtMemoryInfo,
+            p_data: *mut ::std::os::raw::c_void,
+            p_data_len: usize,
+            shape: *const i64,
+            shape_len: usize,
+            type_: ONNXTensorElementDataType,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub IsTensor: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            out: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTensorMutableData: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *mut OrtValue,
+            out: *mut *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub FillStringTensor: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *mut OrtValue,
+            s: *const *const ::std::os::raw::c_char,
+            s_len: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetStringTensorDataLength: ::std::option::Option<
+        unsafe extern "C" fn(value: *const OrtValue, len: *mut usize) -> OrtStatusPtr,
+    >,
+    pub GetStringTensorContent: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            s: *mut ::std::os::raw::c_void,
+            s_len: usize,
+            offsets: *mut usize,
+            offsets_len: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub CastTypeInfoToTensorInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            type_info: *const OrtTypeInfo,
+            out: *mut *const OrtTensorTypeAndShapeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetOnnxTypeFromTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(type_info: *const OrtTypeInfo, out: *mut ONNXType) -> OrtStatusPtr,
+    >,
+    pub CreateTensorTypeAndShapeInfo: ::std::option::Option<
+        unsafe extern "C" fn(out: *mut *mut OrtTensorTypeAndShapeInfo) -> OrtStatusPtr,
+    >,
+    pub SetTensorElementType: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *mut OrtTensorTypeAndShapeInfo,
+            type_: ONNXTensorElementDataType,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetDimensions: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *mut OrtTensorTypeAndShapeInfo,
+            dim_values: *const i64,
+            dim_count: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTensorElementType: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtTensorTypeAndShapeInfo,
+            out: *mut ONNXTensorElementDataType,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetDimensionsCount: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtTensorTypeAndShapeInfo,
+            out: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetDimensions: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtTensorTypeAndShapeInfo,
+            dim_values: *mut i64,
+            dim_values_length: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetSymbolicDimensions: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtTensorTypeAndShapeInfo,
+            dim_params: *mut *const ::std::os::raw::c_char,
+            dim_params_length: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTensorShapeElementCount: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtTensorTypeAndShapeInfo,
+            out: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTensorTypeAndShape: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            out: *mut *mut OrtTensorTypeAndShapeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(value: *const OrtValue, out: *mut *mut OrtTypeInfo) -> OrtStatusPtr,
+    >,
+    pub GetValueType: ::std::option::Option<
+        unsafe extern "C" fn(value: *const OrtValue, out: *mut ONNXType) -> OrtStatusPtr,
+    >,
+    pub CreateMemoryInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            name: *const ::std::os::raw::c_char,
+            type_: OrtAllocatorType,
+            id: ::std::os::raw::c_int,
+            mem_type: OrtMemType,
+            out: *mut *mut OrtMemoryInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateCpuMemoryInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            type_: OrtAllocatorType,
+            mem_type: OrtMemType,
+            out: *mut *mut OrtMemoryInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub CompareMemoryInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            info1: *const OrtMemoryInfo,
+            info2: *const OrtMemoryInfo,
+            out: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub MemoryInfoGetName: ::std::option::Option<
+        unsafe extern "C" fn(
+            ptr: *const OrtMemoryInfo,
+            out: *mut *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub MemoryInfoGetId: ::std::option::Option<
+        unsafe extern "C" fn(
+            ptr: *const OrtMemoryInfo,
+            out: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub MemoryInfoGetMemType: ::std::option::Option<
+        unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemType) -> OrtStatusPtr,
+    >,
+    pub MemoryInfoGetType: ::std::option::Option<
+        unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtAllocatorType) -> OrtStatusPtr,
+    >,
+    pub AllocatorAlloc: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_allocator: *mut OrtAllocator,
+            size: usize,
+            out: *mut *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub AllocatorFree: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_allocator: *mut OrtAllocator,
+            p: *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub AllocatorGetInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_allocator: *const OrtAllocator,
+            out: *mut *const OrtMemoryInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetAllocatorWithDefaultOptions:
+        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
+    pub AddFreeDimensionOverride: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            dim_denotation: *const ::std::os::raw::c_char,
+            dim_value: i64,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            index: ::std::os::raw::c_int,
+            allocator: *mut OrtAllocator,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetValueCount: ::std::option::Option<
+        unsafe extern "C" fn(value: *const OrtValue, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub CreateValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_: *const *const OrtValue,
+            num_values: usize,
+            value_type: ONNXType,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateOpaqueValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            domain_name: *const ::std::os::raw::c_char,
+            type_name: *const ::std::os::raw::c_char,
+            data_container: *const ::std::os::raw::c_void,
+            data_container_size: usize,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetOpaqueValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            domain_name: *const ::std::os::raw::c_char,
+            type_name: *const ::std::os::raw::c_char,
+            in_: *const OrtValue,
+            data_container: *mut ::std::os::raw::c_void,
+            data_container_size: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelInfoGetAttribute_float: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            name: *const ::std::os::raw::c_char,
+            out: *mut f32,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelInfoGetAttribute_int64: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            name: *const ::std::os::raw::c_char,
+            out: *mut i64,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelInfoGetAttribute_string: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            name: *const ::std::os::raw::c_char,
+            out: *mut ::std::os::raw::c_char,
+            size: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelContext_GetInputCount: ::std::option::Option<
+        unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub KernelContext_GetOutputCount: ::std::option::Option<
+        unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub KernelContext_GetInput: ::std::option::Option<
+        unsafe extern "C" fn(
+            context: *const OrtKernelContext,
+            index: usize,
+            out: *mut *const OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelContext_GetOutput: ::std::option::Option<
+        unsafe extern "C" fn(
+            context: *mut OrtKernelContext,
+            index: usize,
+            dim_values: *const i64,
+            dim_count: usize,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseEnv: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
+    pub ReleaseStatus: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
+    pub ReleaseMemoryInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
+    pub ReleaseSession: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
+    pub ReleaseValue: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
+    pub ReleaseRunOptions: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
+    pub ReleaseTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
+    pub ReleaseTensorTypeAndShapeInfo:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
+    pub ReleaseSessionOptions:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
+    pub ReleaseCustomOpDomain:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
+    pub GetDenotationFromTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            type_info: *const OrtTypeInfo,
+            denotation: *mut *const ::std::os::raw::c_char,
+            len: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub CastTypeInfoToMapTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            type_info: *const OrtTypeInfo,
+            out: *mut *const OrtMapTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub CastTypeInfoToSequenceTypeInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            type_info: *const OrtTypeInfo,
+            out: *mut *const OrtSequenceTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetMapKeyType: ::std::option::Option<
+        unsafe extern "C" fn(
+            map_type_info: *const OrtMapTypeInfo,
+            out: *mut ONNXTensorElementDataType,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetMapValueType: ::std::option::Option<
+        unsafe extern "C" fn(
+            map_type_info: *const OrtMapTypeInfo,
+            type_info: *mut *mut OrtTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetSequenceElementType: ::std::option::Option<
+        unsafe extern "C" fn(
+            sequence_type_info: *const OrtSequenceTypeInfo,
+            type_info: *mut *mut OrtTypeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseMapTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
+    pub ReleaseSequenceTypeInfo:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
+    pub SessionEndProfiling: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *mut OrtSession,
+            allocator: *mut OrtAllocator,
+            out: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetModelMetadata: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            out: *mut *mut OrtModelMetadata,
+        ) -> OrtStatusPtr,
+    >,
+    pub ModelMetadataGetProducerName: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub ModelMetadataGetGraphName: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub ModelMetadataGetDomain: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub ModelMetadataGetDescription: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub ModelMetadataLookupCustomMetadataMap: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            key: *const ::std::os::raw::c_char,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub ModelMetadataGetVersion: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            value: *mut i64,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseModelMetadata:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
+    pub CreateEnvWithGlobalThreadPools: ::std::option::Option<
+        unsafe extern "C" fn(
+            log_severity_level: OrtLoggingLevel,
+            logid: *const ::std::os::raw::c_char,
+            tp_options: *const OrtThreadingOptions,
+            out: *mut *mut OrtEnv,
+        ) -> OrtStatusPtr,
+    >,
+    pub DisablePerSessionThreads: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub CreateThreadingOptions: ::std::option::Option<
+        unsafe extern "C" fn(out: *mut *mut OrtThreadingOptions) -> OrtStatusPtr,
+    >,
+    pub ReleaseThreadingOptions:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
+    pub ModelMetadataGetCustomMetadataMapKeys: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            keys: *mut *mut *mut ::std::os::raw::c_char,
+            num_keys: *mut i64,
+        ) -> OrtStatusPtr,
+    >,
+    pub AddFreeDimensionOverrideByName: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            dim_name: *const ::std::os::raw::c_char,
+            dim_value: i64,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetAvailableProviders: ::std::option::Option<
+        unsafe extern "C" fn(
+            out_ptr: *mut *mut *mut ::std::os::raw::c_char,
+            provider_length: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseAvailableProviders: ::std::option::Option<
+        unsafe extern "C" fn(
+            ptr: *mut *mut ::std::os::raw::c_char,
+            providers_length: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetStringTensorElementLength: ::std::option::Option<
+        unsafe extern "C" fn(value: *const OrtValue, index: usize, out: *mut usize) -> OrtStatusPtr,
+    >,
+    pub GetStringTensorElement: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            s_len: usize,
+            index: usize,
+            s: *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub FillStringTensorElement: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *mut OrtValue,
+            s: *const ::std::os::raw::c_char,
+            index: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub AddSessionConfigEntry: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            config_key: *const ::std::os::raw::c_char,
+            config_value: *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateAllocator: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *const OrtSession,
+            mem_info: *const OrtMemoryInfo,
+            out: *mut *mut OrtAllocator,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseAllocator: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
+    pub RunWithBinding: ::std::option::Option<
+        unsafe extern "C" fn(
+            session: *mut OrtSession,
+            run_options: *const OrtRunOptions,
+            binding_ptr: *const OrtIoBinding,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateIoBinding: ::std::option::Option<
+        unsafe extern "C" fn(session: *mut OrtSession, out: *mut *mut OrtIoBinding) -> OrtStatusPtr,
+    >,
+    pub ReleaseIoBinding: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
+    pub BindInput: ::std::option::Option<
+        unsafe extern "C" fn(
+            binding_ptr: *mut OrtIoBinding,
+            name: *const ::std::os::raw::c_char,
+            val_ptr: *const OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub BindOutput: ::std::option::Option<
+        unsafe extern "C" fn(
+            binding_ptr: *mut OrtIoBinding,
+            name: *const ::std::os::raw::c_char,
+            val_ptr: *const OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub BindOutputToDevice: ::std::option::Option<
+        unsafe extern "C" fn(
+            binding_ptr: *mut OrtIoBinding,
+            name: *const ::std::os::raw::c_char,
+            mem_info_ptr: *const OrtMemoryInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetBoundOutputNames: ::std::option::Option<
+        unsafe extern "C" fn(
+            binding_ptr: *const OrtIoBinding,
+            allocator: *mut OrtAllocator,
+            buffer: *mut *mut ::std::os::raw::c_char,
+            lengths: *mut *mut usize,
+            count: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetBoundOutputValues: ::std::option::Option<
+        unsafe extern "C" fn(
+            binding_ptr: *const OrtIoBinding,
+            allocator: *mut OrtAllocator,
+            output: *mut *mut *mut OrtValue,
+            output_count: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    #[doc = " \\brief Clears any previously set Inputs for an ::OrtIoBinding"]
+    pub ClearBoundInputs:
+        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+    #[doc = " \\brief Clears any previously set Outputs for an ::OrtIoBinding"]
+    pub ClearBoundOutputs:
+        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+    pub TensorAt: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *mut OrtValue,
+            location_values: *const i64,
+            location_values_count: usize,
+            out: *mut *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateAndRegisterAllocator: ::std::option::Option<
+        unsafe extern "C" fn(
+            env: *mut OrtEnv,
+            mem_info: *const OrtMemoryInfo,
+            arena_cfg: *const OrtArenaCfg,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetLanguageProjection: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_env: *const OrtEnv,
+            projection: OrtLanguageProjection,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionGetProfilingStartTimeNs: ::std::option::Option<
+        unsafe extern "C" fn(session: *const OrtSession, out: *mut u64) -> OrtStatusPtr,
+    >,
+    pub SetGlobalIntraOpNumThreads: ::std::option::Option<
+        unsafe extern "C" fn(
+            tp_options: *mut OrtThreadingOptions,
+            intra_op_num_threads: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetGlobalInterOpNumThreads: ::std::option::Option<
+        unsafe extern "C" fn(
+            tp_options: *mut OrtThreadingOptions,
+            inter_op_num_threads: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetGlobalSpinControl: ::std::option::Option<
+        unsafe extern "C" fn(
+            tp_options: *mut OrtThreadingOptions,
+            allow_spinning: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub AddInitializer: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            name: *const ::std::os::raw::c_char,
+            val: *const OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::std::option::Option<
+        unsafe extern "C" fn(
+            logging_function: OrtLoggingFunction,
+            logger_param: *mut ::std::os::raw::c_void,
+            log_severity_level: OrtLoggingLevel,
+            logid: *const ::std::os::raw::c_char,
+            tp_options: *const OrtThreadingOptions,
+            out: *mut *mut OrtEnv,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsAppendExecutionProvider_CUDA: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            cuda_options: *const OrtCUDAProviderOptions,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsAppendExecutionProvider_ROCM: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            rocm_options: *const OrtROCMProviderOptions,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            provider_options: *const OrtOpenVINOProviderOptions,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetGlobalDenormalAsZero: ::std::option::Option<
+        unsafe extern "C" fn(tp_options: *mut OrtThreadingOptions) -> OrtStatusPtr,
+    >,
+    pub CreateArenaCfg: ::std::option::Option<
+        unsafe extern "C" fn(
+            max_mem: usize,
+            arena_extend_strategy: ::std::os::raw::c_int,
+            initial_chunk_size_bytes: ::std::os::raw::c_int,
+            max_dead_bytes_per_chunk: ::std::os::raw::c_int,
+            out: *mut *mut OrtArenaCfg,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseArenaCfg: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
+    pub ModelMetadataGetGraphDescription: ::std::option::Option<
+        unsafe extern "C" fn(
+            model_metadata: *const OrtModelMetadata,
+            allocator: *mut OrtAllocator,
+            value: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsAppendExecutionProvider_TensorRT: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            tensorrt_options: *const OrtTensorRTProviderOptions,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetCurrentGpuDeviceId: ::std::option::Option<
+        unsafe extern "C" fn(device_id: ::std::os::raw::c_int) -> OrtStatusPtr,
+    >,
+    pub GetCurrentGpuDeviceId: ::std::option::Option<
+        unsafe extern "C" fn(device_id: *mut ::std::os::raw::c_int) -> OrtStatusPtr,
+    >,
+    pub KernelInfoGetAttributeArray_float: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            name: *const ::std::os::raw::c_char,
+            out: *mut f32,
+            size: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelInfoGetAttributeArray_int64: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            name: *const ::std::os::raw::c_char,
+            out: *mut i64,
+            size: *mut usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateArenaCfgV2: ::std::option::Option<
+        unsafe extern "C" fn(
+            arena_config_keys: *const *const ::std::os::raw::c_char,
+            arena_config_values: *const usize,
+            num_keys: usize,
+            out: *mut *mut OrtArenaCfg,
+        ) -> OrtStatusPtr,
+    >,
+    pub AddRunConfigEntry: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtRunOptions,
+            config_key: *const ::std::os::raw::c_char,
+            config_value: *const ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreatePrepackedWeightsContainer: ::std::option::Option<
+        unsafe extern "C" fn(out: *mut *mut OrtPrepackedWeightsContainer) -> OrtStatusPtr,
+    >,
+    pub ReleasePrepackedWeightsContainer:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
+    pub CreateSessionWithPrepackedWeightsContainer: ::std::option::Option<
+        unsafe extern "C" fn(
+            env: *const OrtEnv,
+            model_path: *const ::std::os::raw::c_char,
+            options: *const OrtSessionOptions,
+            prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
+            out: *mut *mut OrtSession,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::std::option::Option<
+        unsafe extern "C" fn(
+            env: *const OrtEnv,
+            model_data: *const ::std::os::raw::c_void,
+            model_data_length: usize,
+            options: *const OrtSessionOptions,
+            prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
+            out: *mut *mut OrtSession,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            tensorrt_options: *const OrtTensorRTProviderOptionsV2,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateTensorRTProviderOptions: ::std::option::Option<
+        unsafe extern "C" fn(out: *mut *mut OrtTensorRTProviderOptionsV2) -> OrtStatusPtr,
+    >,
+    pub UpdateTensorRTProviderOptions: ::std::option::Option<
+        unsafe extern "C" fn(
+            tensorrt_options: *mut OrtTensorRTProviderOptionsV2,
+            provider_options_keys: *const *const ::std::os::raw::c_char,
+            provider_options_values: *const *const ::std::os::raw::c_char,
+            num_keys: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTensorRTProviderOptionsAsString: ::std::option::Option<
+        unsafe extern "C" fn(
+            tensorrt_options: *const OrtTensorRTProviderOptionsV2,
+            allocator: *mut OrtAllocator,
+            ptr: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    #[doc = " \\brief Release an ::OrtTensorRTProviderOptionsV2"]
+    #[doc = ""]
+    #[doc = " \\note This is an exception in the naming convention of other Release* functions, as the name of the method does not have the V2 suffix, but the type does"]
+    pub ReleaseTensorRTProviderOptions:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
+    pub EnableOrtCustomOps: ::std::option::Option<
+        unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
+    >,
+    pub RegisterAllocator: ::std::option::Option<
+        unsafe extern "C" fn(env: *mut OrtEnv, allocator: *mut OrtAllocator) -> OrtStatusPtr,
+    >,
+    pub UnregisterAllocator: ::std::option::Option<
+        unsafe extern "C" fn(env: *mut OrtEnv, mem_info: *const OrtMemoryInfo) -> OrtStatusPtr,
+    >,
+    pub IsSparseTensor: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            out: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateSparseTensorAsOrtValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            allocator: *mut OrtAllocator,
+            dense_shape: *const i64,
+            dense_shape_len: usize,
+            type_: ONNXTensorElementDataType,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub FillSparseTensorCoo: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *mut OrtValue,
+            data_mem_info: *const OrtMemoryInfo,
+            values_shape: *const i64,
+            values_shape_len: usize,
+            values: *const ::std::os::raw::c_void,
+            indices_data: *const i64,
+            indices_num: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub FillSparseTensorCsr: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *mut OrtValue,
+            data_mem_info: *const OrtMemoryInfo,
+            values_shape: *const i64,
+            values_shape_len: usize,
+            values: *const ::std::os::raw::c_void,
+            inner_indices_data: *const i64,
+            inner_indices_num: usize,
+            outer_indices_data: *const i64,
+            outer_indices_num: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub FillSparseTensorBlockSparse: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *mut OrtValue,
+            data_mem_info: *const OrtMemoryInfo,
+            values_shape: *const i64,
+            values_shape_len: usize,
+            values: *const ::std::os::raw::c_void,
+            indices_shape_data: *const i64,
+            indices_shape_len: usize,
+            indices_data: *const i32,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateSparseTensorWithValuesAsOrtValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtMemoryInfo,
+            p_data: *mut ::std::os::raw::c_void,
+            dense_shape: *const i64,
+            dense_shape_len: usize,
+            values_shape: *const i64,
+            values_shape_len: usize,
+            type_: ONNXTensorElementDataType,
+            out: *mut *mut OrtValue,
+        ) -> OrtStatusPtr,
+    >,
+    pub UseCooIndices: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *mut OrtValue,
+            indices_data: *mut i64,
+            indices_num: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub UseCsrIndices: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *mut OrtValue,
+            inner_data: *mut i64,
+            inner_num: usize,
+            outer_data: *mut i64,
+            outer_num: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub UseBlockSparseIndices: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *mut OrtValue,
+            indices_shape: *const i64,
+            indices_shape_len: usize,
+            indices_data: *mut i32,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetSparseTensorFormat: ::std::option::Option<
+        unsafe extern "C" fn(ort_value: *const OrtValue, out: *mut OrtSparseFormat) -> OrtStatusPtr,
+    >,
+    pub GetSparseTensorValuesTypeAndShape: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *const OrtValue,
+            out: *mut *mut OrtTensorTypeAndShapeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetSparseTensorValues: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *const OrtValue,
+            out: *mut *const ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetSparseTensorIndicesTypeShape: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *const OrtValue,
+            indices_format: OrtSparseIndicesFormat,
+            out: *mut *mut OrtTensorTypeAndShapeInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetSparseTensorIndices: ::std::option::Option<
+        unsafe extern "C" fn(
+            ort_value: *const OrtValue,
+            indices_format: OrtSparseIndicesFormat,
+            num_indices: *mut usize,
+            indices: *mut *const ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub HasValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            out: *mut ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub KernelContext_GetGPUComputeStream: ::std::option::Option<
+        unsafe extern "C" fn(
+            context: *const OrtKernelContext,
+            out: *mut *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetTensorMemoryInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            value: *const OrtValue,
+            mem_info: *mut *const OrtMemoryInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetExecutionProviderApi: ::std::option::Option<
+        unsafe extern "C" fn(
+            provider_name: *const ::std::os::raw::c_char,
+            version: u32,
+            provider_api: *mut *const ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsSetCustomCreateThreadFn: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsSetCustomThreadCreationOptions: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub SessionOptionsSetCustomJoinThreadFn: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetGlobalCustomCreateThreadFn: ::std::option::Option<
+        unsafe extern "C" fn(
+            tp_options: *mut OrtThreadingOptions,
+            ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetGlobalCustomThreadCreationOptions: ::std::option::Option<
+        unsafe extern "C" fn(
+            tp_options: *mut OrtThreadingOptions,
+            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+        ) -> OrtStatusPtr,
+    >,
+    pub SetGlobalCustomJoinThreadFn: ::std::option::Option<
+        unsafe extern "C" fn(
+            tp_options: *mut OrtThreadingOptions,
+            ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
+        ) -> OrtStatusPtr,
+    >,
+    pub SynchronizeBoundInputs:
+        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+    pub SynchronizeBoundOutputs:
+        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            cuda_options: *const OrtCUDAProviderOptionsV2,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateCUDAProviderOptions: ::std::option::Option<
+        unsafe extern "C" fn(out: *mut *mut OrtCUDAProviderOptionsV2) -> OrtStatusPtr,
+    >,
+    pub UpdateCUDAProviderOptions: ::std::option::Option<
+        unsafe extern "C" fn(
+            cuda_options: *mut OrtCUDAProviderOptionsV2,
+            provider_options_keys: *const *const ::std::os::raw::c_char,
+            provider_options_values: *const *const ::std::os::raw::c_char,
+            num_keys: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub GetCUDAProviderOptionsAsString: ::std::option::Option<
+        unsafe extern "C" fn(
+            cuda_options: *const OrtCUDAProviderOptionsV2,
+            allocator: *mut OrtAllocator,
+            ptr: *mut *mut ::std::os::raw::c_char,
+        ) -> OrtStatusPtr,
+    >,
+    #[doc = " \\brief Release an ::OrtCUDAProviderOptionsV2"]
+    #[doc = ""]
+    #[doc = " \\note This is an exception in the naming convention of other Release* functions, as the name of the method does not have the V2 suffix, but the type does"]
+    #[doc = ""]
+    #[doc = " \\since Version 1.11."]
+    pub ReleaseCUDAProviderOptions:
+        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
+    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            migraphx_options: *const OrtMIGraphXProviderOptions,
+        ) -> OrtStatusPtr,
+    >,
+    pub AddExternalInitializers: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            initializer_names: *const *const ::std::os::raw::c_char,
+            initializers: *const *const OrtValue,
+            initializers_num: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub CreateOpAttr: ::std::option::Option<
+        unsafe extern "C" fn(
+            name: *const ::std::os::raw::c_char,
+            data: *const ::std::os::raw::c_void,
+            len: ::std::os::raw::c_int,
+            type_: OrtOpAttrType,
+            op_attr: *mut *mut OrtOpAttr,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseOpAttr: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
+    pub CreateOp: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            op_name: *const ::std::os::raw::c_char,
+            domain: *const ::std::os::raw::c_char,
+            version: ::std::os::raw::c_int,
+            type_constraint_names: *mut *const ::std::os::raw::c_char,
+            type_constraint_values: *const ONNXTensorElementDataType,
+            type_constraint_count: ::std::os::raw::c_int,
+            attr_values: *const *const OrtOpAttr,
+            attr_count: ::std::os::raw::c_int,
+            input_count: ::std::os::raw::c_int,
+            output_count: ::std::os::raw::c_int,
+            ort_op: *mut *mut OrtOp,
+        ) -> OrtStatusPtr,
+    >,
+    pub InvokeOp: ::std::option::Option<
+        unsafe extern "C" fn(
+            context: *const OrtKernelContext,
+            ort_op: *const OrtOp,
+            input_values: *const *const OrtValue,
+            input_count: ::std::os::raw::c_int,
+            output_values: *const *mut OrtValue,
+            output_count: ::std::os::raw::c_int,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseOp: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
+    pub SessionOptionsAppendExecutionProvider: ::std::option::Option<
+        unsafe extern "C" fn(
+            options: *mut OrtSessionOptions,
+            provider_name: *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::std::os::raw::c_char,
+            provider_options_values: *const *const ::std::os::raw::c_char,
+            num_keys: usize,
+        ) -> OrtStatusPtr,
+    >,
+    pub CopyKernelInfo: ::std::option::Option<
+        unsafe extern "C" fn(
+            info: *const OrtKernelInfo,
+            info_copy: *mut *mut OrtKernelInfo,
+        ) -> OrtStatusPtr,
+    >,
+    pub ReleaseKernelInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
+}
+#[test]
+fn bindgen_test_layout_OrtApi() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtApi>(),
+        1752usize,
+        concat!("Size of: ", stringify!(OrtApi))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtApi>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtApi))
+    );
+    fn test_field_CreateStatus() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateStatus)
+            )
+        );
+    }
+    test_field_CreateStatus();
+    fn test_field_GetErrorCode() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetErrorCode)
+            )
+        );
+    }
+    test_field_GetErrorCode();
+    fn test_field_GetErrorMessage() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetErrorMessage)
+            )
+        );
+    }
+    test_field_GetErrorMessage();
+    fn test_field_CreateEnv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateEnv)
+            )
+        );
+    }
+    test_field_CreateEnv();
+    fn test_field_CreateEnvWithCustomLogger() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateEnvWithCustomLogger)
+            )
+        );
+    }
+    test_field_CreateEnvWithCustomLogger();
+    fn test_field_EnableTelemetryEvents() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(EnableTelemetryEvents)
+            )
+        );
+    }
+    test_field_EnableTelemetryEvents();
+    fn test_field_DisableTelemetryEvents() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(DisableTelemetryEvents)
+            )
+        );
+    }
+    test_field_DisableTelemetryEvents();
+    fn test_field_CreateSession() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSession)
+            )
+        );
+    }
+    test_field_CreateSession();
+    fn test_field_CreateSessionFromArray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSessionFromArray)
+            )
+        );
+    }
+    test_field_CreateSessionFromArray();
+    fn test_field_Run() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(Run)
+            )
+        );
+    }
+    test_field_Run();
+    fn test_field_CreateSessionOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSessionOptions)
+            )
+        );
+    }
+    test_field_CreateSessionOptions();
+    fn test_field_SetOptimizedModelFilePath() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetOptimizedModelFilePath)
+            )
+        );
+    }
+    test_field_SetOptimizedModelFilePath();
+    fn test_field_CloneSessionOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CloneSessionOptions)
+            )
+        );
+    }
+    test_field_CloneSessionOptions();
+    fn test_field_SetSessionExecutionMode() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetSessionExecutionMode)
+            )
+        );
+    }
+    test_field_SetSessionExecutionMode();
+    fn test_field_EnableProfiling() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(EnableProfiling)
+            )
+        );
+    }
+    test_field_EnableProfiling();
+    fn test_field_DisableProfiling() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(DisableProfiling)
+            )
+        );
+    }
+    test_field_DisableProfiling();
+    fn test_field_EnableMemPattern() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(EnableMemPattern)
+            )
+        );
+    }
+    test_field_EnableMemPattern();
+    fn test_field_DisableMemPattern() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(DisableMemPattern)
+            )
+        );
+    }
+    test_field_DisableMemPattern();
+    fn test_field_EnableCpuMemArena() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(EnableCpuMemArena)
+            )
+        );
+    }
+    test_field_EnableCpuMemArena();
+    fn test_field_DisableCpuMemArena() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
+            },
+            152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(DisableCpuMemArena)
+            )
+        );
+    }
+    test_field_DisableCpuMemArena();
+    fn test_field_SetSessionLogId() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetSessionLogId)
+            )
+        );
+    }
+    test_field_SetSessionLogId();
+    fn test_field_SetSessionLogVerbosityLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
+            },
+            168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetSessionLogVerbosityLevel)
+            )
+        );
+    }
+    test_field_SetSessionLogVerbosityLevel();
+    fn test_field_SetSessionLogSeverityLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
+            },
+            176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetSessionLogSeverityLevel)
+            )
+        );
+    }
+    test_field_SetSessionLogSeverityLevel();
+    fn test_field_SetSessionGraphOptimizationLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
+                    - ptr as usize
+            },
+            184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetSessionGraphOptimizationLevel)
+            )
+        );
+    }
+    test_field_SetSessionGraphOptimizationLevel();
+    fn test_field_SetIntraOpNumThreads() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetIntraOpNumThreads)
+            )
+        );
+    }
+    test_field_SetIntraOpNumThreads();
+    fn test_field_SetInterOpNumThreads() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
+            },
+            200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetInterOpNumThreads)
+            )
+        );
+    }
+    test_field_SetInterOpNumThreads();
+    fn test_field_CreateCustomOpDomain() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
+            },
+            208usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateCustomOpDomain)
+            )
+        );
+    }
+    test_field_CreateCustomOpDomain();
+    fn test_field_CustomOpDomain_Add() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
+            },
+            216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CustomOpDomain_Add)
+            )
+        );
+    }
+    test_field_CustomOpDomain_Add();
+    fn test_field_AddCustomOpDomain() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
+            },
+            224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddCustomOpDomain)
+            )
+        );
+    }
+    test_field_AddCustomOpDomain();
+    fn test_field_RegisterCustomOpsLibrary() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
+            },
+            232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RegisterCustomOpsLibrary)
+            )
+        );
+    }
+    test_field_RegisterCustomOpsLibrary();
+    fn test_field_SessionGetInputCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
+            },
+            240usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetInputCount)
+            )
+        );
+    }
+    test_field_SessionGetInputCount();
+    fn test_field_SessionGetOutputCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
+            },
+            248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetOutputCount)
+            )
+        );
+    }
+    test_field_SessionGetOutputCount();
+    fn test_field_SessionGetOverridableInitializerCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
+                    - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetOverridableInitializerCount)
+            )
+        );
+    }
+    test_field_SessionGetOverridableInitializerCount();
+    fn test_field_SessionGetInputTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
+            },
+            264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetInputTypeInfo)
+            )
+        );
+    }
+    test_field_SessionGetInputTypeInfo();
+    fn test_field_SessionGetOutputTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
+            },
+            272usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetOutputTypeInfo)
+            )
+        );
+    }
+    test_field_SessionGetOutputTypeInfo();
+    fn test_field_SessionGetOverridableInitializerTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
+                    - ptr as usize
+            },
+            280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetOverridableInitializerTypeInfo)
+            )
+        );
+    }
+    test_field_SessionGetOverridableInitializerTypeInfo();
+    fn test_field_SessionGetInputName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
+            },
+            288usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetInputName)
+            )
+        );
+    }
+    test_field_SessionGetInputName();
+    fn test_field_SessionGetOutputName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
+            },
+            296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetOutputName)
+            )
+        );
+    }
+    test_field_SessionGetOutputName();
+    fn test_field_SessionGetOverridableInitializerName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
+                    - ptr as usize
+            },
+            304usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetOverridableInitializerName)
+            )
+        );
+    }
+    test_field_SessionGetOverridableInitializerName();
+    fn test_field_CreateRunOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
+            },
+            312usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateRunOptions)
+            )
+        );
+    }
+    test_field_CreateRunOptions();
+    fn test_field_RunOptionsSetRunLogVerbosityLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
+                    - ptr as usize
+            },
+            320usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsSetRunLogVerbosityLevel)
+            )
+        );
+    }
+    test_field_RunOptionsSetRunLogVerbosityLevel();
+    fn test_field_RunOptionsSetRunLogSeverityLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
+                    - ptr as usize
+            },
+            328usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsSetRunLogSeverityLevel)
+            )
+        );
+    }
+    test_field_RunOptionsSetRunLogSeverityLevel();
+    fn test_field_RunOptionsSetRunTag() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
+            },
+            336usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsSetRunTag)
+            )
+        );
+    }
+    test_field_RunOptionsSetRunTag();
+    fn test_field_RunOptionsGetRunLogVerbosityLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
+                    - ptr as usize
+            },
+            344usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsGetRunLogVerbosityLevel)
+            )
+        );
+    }
+    test_field_RunOptionsGetRunLogVerbosityLevel();
+    fn test_field_RunOptionsGetRunLogSeverityLevel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
+                    - ptr as usize
+            },
+            352usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsGetRunLogSeverityLevel)
+            )
+        );
+    }
+    test_field_RunOptionsGetRunLogSeverityLevel();
+    fn test_field_RunOptionsGetRunTag() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
+            },
+            360usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsGetRunTag)
+            )
+        );
+    }
+    test_field_RunOptionsGetRunTag();
+    fn test_field_RunOptionsSetTerminate() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
+            },
+            368usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsSetTerminate)
+            )
+        );
+    }
+    test_field_RunOptionsSetTerminate();
+    fn test_field_RunOptionsUnsetTerminate() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
+            },
+            376usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunOptionsUnsetTerminate)
+            )
+        );
+    }
+    test_field_RunOptionsUnsetTerminate();
+    fn test_field_CreateTensorAsOrtValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
+            },
+            384usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateTensorAsOrtValue)
+            )
+        );
+    }
+    test_field_CreateTensorAsOrtValue();
+    fn test_field_CreateTensorWithDataAsOrtValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
+            },
+            392usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateTensorWithDataAsOrtValue)
+            )
+        );
+    }
+    test_field_CreateTensorWithDataAsOrtValue();
+    fn test_field_IsTensor() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
+            },
+            400usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(IsTensor)
+            )
+        );
+    }
+    test_field_IsTensor();
+    fn test_field_GetTensorMutableData() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
+            },
+            408usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTensorMutableData)
+            )
+        );
+    }
+    test_field_GetTensorMutableData();
+    fn test_field_FillStringTensor() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
+            },
+            416usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(FillStringTensor)
+            )
+        );
+    }
+    test_field_FillStringTensor();
+    fn test_field_GetStringTensorDataLength() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
+            },
+            424usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetStringTensorDataLength)
+            )
+        );
+    }
+    test_field_GetStringTensorDataLength();
+    fn test_field_GetStringTensorContent() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
+            },
+            432usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetStringTensorContent)
+            )
+        );
+    }
+    test_field_GetStringTensorContent();
+    fn test_field_CastTypeInfoToTensorInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
+            },
+            440usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CastTypeInfoToTensorInfo)
+            )
+        );
+    }
+    test_field_CastTypeInfoToTensorInfo();
+    fn test_field_GetOnnxTypeFromTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
+            },
+            448usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetOnnxTypeFromTypeInfo)
+            )
+        );
+    }
+    test_field_GetOnnxTypeFromTypeInfo();
+    fn test_field_CreateTensorTypeAndShapeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
+            },
+            456usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateTensorTypeAndShapeInfo)
+            )
+        );
+    }
+    test_field_CreateTensorTypeAndShapeInfo();
+    fn test_field_SetTensorElementType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
+            },
+            464usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetTensorElementType)
+            )
+        );
+    }
+    test_field_SetTensorElementType();
+    fn test_field_SetDimensions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
+            },
+            472usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetDimensions)
+            )
+        );
+    }
+    test_field_SetDimensions();
+    fn test_field_GetTensorElementType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
+            },
+            480usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTensorElementType)
+            )
+        );
+    }
+    test_field_GetTensorElementType();
+    fn test_field_GetDimensionsCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
+            },
+            488usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetDimensionsCount)
+            )
+        );
+    }
+    test_field_GetDimensionsCount();
+    fn test_field_GetDimensions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
+            },
+            496usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetDimensions)
+            )
+        );
+    }
+    test_field_GetDimensions();
+    fn test_field_GetSymbolicDimensions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
+            },
+            504usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSymbolicDimensions)
+            )
+        );
+    }
+    test_field_GetSymbolicDimensions();
+    fn test_field_GetTensorShapeElementCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
+            },
+            512usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTensorShapeElementCount)
+            )
+        );
+    }
+    test_field_GetTensorShapeElementCount();
+    fn test_field_GetTensorTypeAndShape() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
+            },
+            520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTensorTypeAndShape)
+            )
+        );
+    }
+    test_field_GetTensorTypeAndShape();
+    fn test_field_GetTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
+            },
+            528usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTypeInfo)
+            )
+        );
+    }
+    test_field_GetTypeInfo();
+    fn test_field_GetValueType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
+            },
+            536usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetValueType)
+            )
+        );
+    }
+    test_field_GetValueType();
+    fn test_field_CreateMemoryInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
+            },
+            544usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateMemoryInfo)
+            )
+        );
+    }
+    test_field_CreateMemoryInfo();
+    fn test_field_CreateCpuMemoryInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
+            },
+            552usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateCpuMemoryInfo)
+            )
+        );
+    }
+    test_field_CreateCpuMemoryInfo();
+    fn test_field_CompareMemoryInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
+            },
+            560usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CompareMemoryInfo)
+            )
+        );
+    }
+    test_field_CompareMemoryInfo();
+    fn test_field_MemoryInfoGetName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
+            },
+            568usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(MemoryInfoGetName)
+            )
+        );
+    }
+    test_field_MemoryInfoGetName();
+    fn test_field_MemoryInfoGetId() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
+            },
+            576usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(MemoryInfoGetId)
+            )
+        );
+    }
+    test_field_MemoryInfoGetId();
+    fn test_field_MemoryInfoGetMemType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
+            },
+            584usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(MemoryInfoGetMemType)
+            )
+        );
+    }
+    test_field_MemoryInfoGetMemType();
+    fn test_field_MemoryInfoGetType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
+            },
+            592usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(MemoryInfoGetType)
+            )
+        );
+    }
+    test_field_MemoryInfoGetType();
+    fn test_field_AllocatorAlloc() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
+            },
+            600usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AllocatorAlloc)
+            )
+        );
+    }
+    test_field_AllocatorAlloc();
+    fn test_field_AllocatorFree() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
+            },
+            608usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AllocatorFree)
+            )
+        );
+    }
+    test_field_AllocatorFree();
+    fn test_field_AllocatorGetInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
+            },
+            616usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AllocatorGetInfo)
+            )
+        );
+    }
+    test_field_AllocatorGetInfo();
+    fn test_field_GetAllocatorWithDefaultOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
+            },
+            624usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetAllocatorWithDefaultOptions)
+            )
+        );
+    }
+    test_field_GetAllocatorWithDefaultOptions();
+    fn test_field_AddFreeDimensionOverride() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
+            },
+            632usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddFreeDimensionOverride)
+            )
+        );
+    }
+    test_field_AddFreeDimensionOverride();
+    fn test_field_GetValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
+            },
+            640usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetValue)
+            )
+        );
+    }
+    test_field_GetValue();
+    fn test_field_GetValueCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
+            },
+            648usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetValueCount)
+            )
+        );
+    }
+    test_field_GetValueCount();
+    fn test_field_CreateValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
+            },
+            656usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateValue)
+            )
+        );
+    }
+    test_field_CreateValue();
+    fn test_field_CreateOpaqueValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
+            },
+            664usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateOpaqueValue)
+            )
+        );
+    }
+    test_field_CreateOpaqueValue();
+    fn test_field_GetOpaqueValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
+            },
+            672usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetOpaqueValue)
+            )
+        );
+    }
+    test_field_GetOpaqueValue();
+    fn test_field_KernelInfoGetAttribute_float() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
+            },
+            680usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelInfoGetAttribute_float)
+            )
+        );
+    }
+    test_field_KernelInfoGetAttribute_float();
+    fn test_field_KernelInfoGetAttribute_int64() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
+            },
+            688usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelInfoGetAttribute_int64)
+            )
+        );
+    }
+    test_field_KernelInfoGetAttribute_int64();
+    fn test_field_KernelInfoGetAttribute_string() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
+            },
+            696usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelInfoGetAttribute_string)
+            )
+        );
+    }
+    test_field_KernelInfoGetAttribute_string();
+    fn test_field_KernelContext_GetInputCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
+            },
+            704usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelContext_GetInputCount)
+            )
+        );
+    }
+    test_field_KernelContext_GetInputCount();
+    fn test_field_KernelContext_GetOutputCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
+            },
+            712usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelContext_GetOutputCount)
+            )
+        );
+    }
+    test_field_KernelContext_GetOutputCount();
+    fn test_field_KernelContext_GetInput() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
+            },
+            720usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelContext_GetInput)
+            )
+        );
+    }
+    test_field_KernelContext_GetInput();
+    fn test_field_KernelContext_GetOutput() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
+            },
+            728usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelContext_GetOutput)
+            )
+        );
+    }
+    test_field_KernelContext_GetOutput();
+    fn test_field_ReleaseEnv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
+            },
+            736usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseEnv)
+            )
+        );
+    }
+    test_field_ReleaseEnv();
+    fn test_field_ReleaseStatus() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
+            },
+            744usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseStatus)
+            )
+        );
+    }
+    test_field_ReleaseStatus();
+    fn test_field_ReleaseMemoryInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
+            },
+            752usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseMemoryInfo)
+            )
+        );
+    }
+    test_field_ReleaseMemoryInfo();
+    fn test_field_ReleaseSession() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
+            },
+            760usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseSession)
+            )
+        );
+    }
+    test_field_ReleaseSession();
+    fn test_field_ReleaseValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
+            },
+            768usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseValue)
+            )
+        );
+    }
+    test_field_ReleaseValue();
+    fn test_field_ReleaseRunOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
+            },
+            776usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseRunOptions)
+            )
+        );
+    }
+    test_field_ReleaseRunOptions();
+    fn test_field_ReleaseTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
+            },
+            784usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseTypeInfo)
+            )
+        );
+    }
+    test_field_ReleaseTypeInfo();
+    fn test_field_ReleaseTensorTypeAndShapeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
+            },
+            792usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseTensorTypeAndShapeInfo)
+            )
+        );
+    }
+    test_field_ReleaseTensorTypeAndShapeInfo();
+    fn test_field_ReleaseSessionOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
+            },
+            800usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseSessionOptions)
+            )
+        );
+    }
+    test_field_ReleaseSessionOptions();
+    fn test_field_ReleaseCustomOpDomain() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
+            },
+            808usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseCustomOpDomain)
+            )
+        );
+    }
+    test_field_ReleaseCustomOpDomain();
+    fn test_field_GetDenotationFromTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
+            },
+            816usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetDenotationFromTypeInfo)
+            )
+        );
+    }
+    test_field_GetDenotationFromTypeInfo();
+    fn test_field_CastTypeInfoToMapTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
+            },
+            824usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CastTypeInfoToMapTypeInfo)
+            )
+        );
+    }
+    test_field_CastTypeInfoToMapTypeInfo();
+    fn test_field_CastTypeInfoToSequenceTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
+            },
+            832usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CastTypeInfoToSequenceTypeInfo)
+            )
+        );
+    }
+    test_field_CastTypeInfoToSequenceTypeInfo();
+    fn test_field_GetMapKeyType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
+            },
+            840usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetMapKeyType)
+            )
+        );
+    }
+    test_field_GetMapKeyType();
+    fn test_field_GetMapValueType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
+            },
+            848usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetMapValueType)
+            )
+        );
+    }
+    test_field_GetMapValueType();
+    fn test_field_GetSequenceElementType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
+            },
+            856usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSequenceElementType)
+            )
+        );
+    }
+    test_field_GetSequenceElementType();
+    fn test_field_ReleaseMapTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
+            },
+            864usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseMapTypeInfo)
+            )
+        );
+    }
+    test_field_ReleaseMapTypeInfo();
+    fn test_field_ReleaseSequenceTypeInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
+            },
+            872usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseSequenceTypeInfo)
+            )
+        );
+    }
+    test_field_ReleaseSequenceTypeInfo();
+    fn test_field_SessionEndProfiling() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
+            },
+            880usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionEndProfiling)
+            )
+        );
+    }
+    test_field_SessionEndProfiling();
+    fn test_field_SessionGetModelMetadata() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
+            },
+            888usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetModelMetadata)
+            )
+        );
+    }
+    test_field_SessionGetModelMetadata();
+    fn test_field_ModelMetadataGetProducerName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
+            },
+            896usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetProducerName)
+            )
+        );
+    }
+    test_field_ModelMetadataGetProducerName();
+    fn test_field_ModelMetadataGetGraphName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
+            },
+            904usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetGraphName)
+            )
+        );
+    }
+    test_field_ModelMetadataGetGraphName();
+    fn test_field_ModelMetadataGetDomain() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
+            },
+            912usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetDomain)
+            )
+        );
+    }
+    test_field_ModelMetadataGetDomain();
+    fn test_field_ModelMetadataGetDescription() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
+            },
+            920usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetDescription)
+            )
+        );
+    }
+    test_field_ModelMetadataGetDescription();
+    fn test_field_ModelMetadataLookupCustomMetadataMap() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
+                    - ptr as usize
+            },
+            928usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataLookupCustomMetadataMap)
+            )
+        );
+    }
+    test_field_ModelMetadataLookupCustomMetadataMap();
+    fn test_field_ModelMetadataGetVersion() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
+            },
+            936usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetVersion)
+            )
+        );
+    }
+    test_field_ModelMetadataGetVersion();
+    fn test_field_ReleaseModelMetadata() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
+            },
+            944usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseModelMetadata)
+            )
+        );
+    }
+    test_field_ReleaseModelMetadata();
+    fn test_field_CreateEnvWithGlobalThreadPools() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
+            },
+            952usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateEnvWithGlobalThreadPools)
+            )
+        );
+    }
+    test_field_CreateEnvWithGlobalThreadPools();
+    fn test_field_DisablePerSessionThreads() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
+            },
+            960usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(DisablePerSessionThreads)
+            )
+        );
+    }
+    test_field_DisablePerSessionThreads();
+    fn test_field_CreateThreadingOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
+            },
+            968usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateThreadingOptions)
+            )
+        );
+    }
+    test_field_CreateThreadingOptions();
+    fn test_field_ReleaseThreadingOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
+            },
+            976usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseThreadingOptions)
+            )
+        );
+    }
+    test_field_ReleaseThreadingOptions();
+    fn test_field_ModelMetadataGetCustomMetadataMapKeys() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
+                    - ptr as usize
+            },
+            984usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetCustomMetadataMapKeys)
+            )
+        );
+    }
+    test_field_ModelMetadataGetCustomMetadataMapKeys();
+    fn test_field_AddFreeDimensionOverrideByName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
+            },
+            992usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddFreeDimensionOverrideByName)
+            )
+        );
+    }
+    test_field_AddFreeDimensionOverrideByName();
+    fn test_field_GetAvailableProviders() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
+            },
+            1000usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetAvailableProviders)
+            )
+        );
+    }
+    test_field_GetAvailableProviders();
+    fn test_field_ReleaseAvailableProviders() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
+            },
+            1008usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseAvailableProviders)
+            )
+        );
+    }
+    test_field_ReleaseAvailableProviders();
+    fn test_field_GetStringTensorElementLength() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
+            },
+            1016usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetStringTensorElementLength)
+            )
+        );
+    }
+    test_field_GetStringTensorElementLength();
+    fn test_field_GetStringTensorElement() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
+            },
+            1024usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetStringTensorElement)
+            )
+        );
+    }
+    test_field_GetStringTensorElement();
+    fn test_field_FillStringTensorElement() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
+            },
+            1032usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(FillStringTensorElement)
+            )
+        );
+    }
+    test_field_FillStringTensorElement();
+    fn test_field_AddSessionConfigEntry() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
+            },
+            1040usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddSessionConfigEntry)
+            )
+        );
+    }
+    test_field_AddSessionConfigEntry();
+    fn test_field_CreateAllocator() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
+            },
+            1048usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateAllocator)
+            )
+        );
+    }
+    test_field_CreateAllocator();
+    fn test_field_ReleaseAllocator() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
+            },
+            1056usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseAllocator)
+            )
+        );
+    }
+    test_field_ReleaseAllocator();
+    fn test_field_RunWithBinding() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
+            },
+            1064usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RunWithBinding)
+            )
+        );
+    }
+    test_field_RunWithBinding();
+    fn test_field_CreateIoBinding() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
+            },
+            1072usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateIoBinding)
+            )
+        );
+    }
+    test_field_CreateIoBinding();
+    fn test_field_ReleaseIoBinding() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
+            },
+            1080usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseIoBinding)
+            )
+        );
+    }
+    test_field_ReleaseIoBinding();
+    fn test_field_BindInput() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
+            },
+            1088usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(BindInput)
+            )
+        );
+    }
+    test_field_BindInput();
+    fn test_field_BindOutput() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
+            },
+            1096usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(BindOutput)
+            )
+        );
+    }
+    test_field_BindOutput();
+    fn test_field_BindOutputToDevice() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
+            },
+            1104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(BindOutputToDevice)
+            )
+        );
+    }
+    test_field_BindOutputToDevice();
+    fn test_field_GetBoundOutputNames() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
+            },
+            1112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetBoundOutputNames)
+            )
+        );
+    }
+    test_field_GetBoundOutputNames();
+    fn test_field_GetBoundOutputValues() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
+            },
+            1120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetBoundOutputValues)
+            )
+        );
+    }
+    test_field_GetBoundOutputValues();
+    fn test_field_ClearBoundInputs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
+            },
+            1128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ClearBoundInputs)
+            )
+        );
+    }
+    test_field_ClearBoundInputs();
+    fn test_field_ClearBoundOutputs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
+            },
+            1136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ClearBoundOutputs)
+            )
+        );
+    }
+    test_field_ClearBoundOutputs();
+    fn test_field_TensorAt() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
+            },
+            1144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(TensorAt)
+            )
+        );
+    }
+    test_field_TensorAt();
+    fn test_field_CreateAndRegisterAllocator() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
+            },
+            1152usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateAndRegisterAllocator)
+            )
+        );
+    }
+    test_field_CreateAndRegisterAllocator();
+    fn test_field_SetLanguageProjection() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
+            },
+            1160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetLanguageProjection)
+            )
+        );
+    }
+    test_field_SetLanguageProjection();
+    fn test_field_SessionGetProfilingStartTimeNs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
+            },
+            1168usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionGetProfilingStartTimeNs)
+            )
+        );
+    }
+    test_field_SessionGetProfilingStartTimeNs();
+    fn test_field_SetGlobalIntraOpNumThreads() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
+            },
+            1176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalIntraOpNumThreads)
+            )
+        );
+    }
+    test_field_SetGlobalIntraOpNumThreads();
+    fn test_field_SetGlobalInterOpNumThreads() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
+            },
+            1184usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalInterOpNumThreads)
+            )
+        );
+    }
+    test_field_SetGlobalInterOpNumThreads();
+    fn test_field_SetGlobalSpinControl() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
+            },
+            1192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalSpinControl)
+            )
+        );
+    }
+    test_field_SetGlobalSpinControl();
+    fn test_field_AddInitializer() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
+            },
+            1200usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddInitializer)
+            )
+        );
+    }
+    test_field_AddInitializer();
+    fn test_field_CreateEnvWithCustomLoggerAndGlobalThreadPools() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
+                    - ptr as usize
+            },
+            1208usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateEnvWithCustomLoggerAndGlobalThreadPools)
+            )
+        );
+    }
+    test_field_CreateEnvWithCustomLoggerAndGlobalThreadPools();
+    fn test_field_SessionOptionsAppendExecutionProvider_CUDA() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
+                    - ptr as usize
+            },
+            1216usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_CUDA)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_CUDA();
+    fn test_field_SessionOptionsAppendExecutionProvider_ROCM() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
+                    - ptr as usize
+            },
+            1224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_ROCM)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_ROCM();
+    fn test_field_SessionOptionsAppendExecutionProvider_OpenVINO() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
+                    - ptr as usize
+            },
+            1232usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_OpenVINO)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_OpenVINO();
+    fn test_field_SetGlobalDenormalAsZero() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
+            },
+            1240usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalDenormalAsZero)
+            )
+        );
+    }
+    test_field_SetGlobalDenormalAsZero();
+    fn test_field_CreateArenaCfg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
+            },
+            1248usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateArenaCfg)
+            )
+        );
+    }
+    test_field_CreateArenaCfg();
+    fn test_field_ReleaseArenaCfg() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
+            },
+            1256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseArenaCfg)
+            )
+        );
+    }
+    test_field_ReleaseArenaCfg();
+    fn test_field_ModelMetadataGetGraphDescription() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
+                    - ptr as usize
+            },
+            1264usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ModelMetadataGetGraphDescription)
+            )
+        );
+    }
+    test_field_ModelMetadataGetGraphDescription();
+    fn test_field_SessionOptionsAppendExecutionProvider_TensorRT() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
+                    - ptr as usize
+            },
+            1272usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_TensorRT)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_TensorRT();
+    fn test_field_SetCurrentGpuDeviceId() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
+            },
+            1280usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetCurrentGpuDeviceId)
+            )
+        );
+    }
+    test_field_SetCurrentGpuDeviceId();
+    fn test_field_GetCurrentGpuDeviceId() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
+            },
+            1288usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetCurrentGpuDeviceId)
+            )
+        );
+    }
+    test_field_GetCurrentGpuDeviceId();
+    fn test_field_KernelInfoGetAttributeArray_float() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
+                    - ptr as usize
+            },
+            1296usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelInfoGetAttributeArray_float)
+            )
+        );
+    }
+    test_field_KernelInfoGetAttributeArray_float();
+    fn test_field_KernelInfoGetAttributeArray_int64() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
+                    - ptr as usize
+            },
+            1304usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelInfoGetAttributeArray_int64)
+            )
+        );
+    }
+    test_field_KernelInfoGetAttributeArray_int64();
+    fn test_field_CreateArenaCfgV2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
+            },
+            1312usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateArenaCfgV2)
+            )
+        );
+    }
+    test_field_CreateArenaCfgV2();
+    fn test_field_AddRunConfigEntry() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
+            },
+            1320usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddRunConfigEntry)
+            )
+        );
+    }
+    test_field_AddRunConfigEntry();
+    fn test_field_CreatePrepackedWeightsContainer() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
+            },
+            1328usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreatePrepackedWeightsContainer)
+            )
+        );
+    }
+    test_field_CreatePrepackedWeightsContainer();
+    fn test_field_ReleasePrepackedWeightsContainer() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
+                    - ptr as usize
+            },
+            1336usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleasePrepackedWeightsContainer)
+            )
+        );
+    }
+    test_field_ReleasePrepackedWeightsContainer();
+    fn test_field_CreateSessionWithPrepackedWeightsContainer() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
+                    - ptr as usize
+            },
+            1344usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSessionWithPrepackedWeightsContainer)
+            )
+        );
+    }
+    test_field_CreateSessionWithPrepackedWeightsContainer();
+    fn test_field_CreateSessionFromArrayWithPrepackedWeightsContainer() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
+                    as usize
+                    - ptr as usize
+            },
+            1352usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSessionFromArrayWithPrepackedWeightsContainer)
+            )
+        );
+    }
+    test_field_CreateSessionFromArrayWithPrepackedWeightsContainer();
+    fn test_field_SessionOptionsAppendExecutionProvider_TensorRT_V2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
+                    as usize
+                    - ptr as usize
+            },
+            1360usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_TensorRT_V2)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_TensorRT_V2();
+    fn test_field_CreateTensorRTProviderOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
+            },
+            1368usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateTensorRTProviderOptions)
+            )
+        );
+    }
+    test_field_CreateTensorRTProviderOptions();
+    fn test_field_UpdateTensorRTProviderOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
+            },
+            1376usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(UpdateTensorRTProviderOptions)
+            )
+        );
+    }
+    test_field_UpdateTensorRTProviderOptions();
+    fn test_field_GetTensorRTProviderOptionsAsString() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
+                    - ptr as usize
+            },
+            1384usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTensorRTProviderOptionsAsString)
+            )
+        );
+    }
+    test_field_GetTensorRTProviderOptionsAsString();
+    fn test_field_ReleaseTensorRTProviderOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
+            },
+            1392usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseTensorRTProviderOptions)
+            )
+        );
+    }
+    test_field_ReleaseTensorRTProviderOptions();
+    fn test_field_EnableOrtCustomOps() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
+            },
+            1400usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(EnableOrtCustomOps)
+            )
+        );
+    }
+    test_field_EnableOrtCustomOps();
+    fn test_field_RegisterAllocator() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
+            },
+            1408usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(RegisterAllocator)
+            )
+        );
+    }
+    test_field_RegisterAllocator();
+    fn test_field_UnregisterAllocator() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
+            },
+            1416usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(UnregisterAllocator)
+            )
+        );
+    }
+    test_field_UnregisterAllocator();
+    fn test_field_IsSparseTensor() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
+            },
+            1424usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(IsSparseTensor)
+            )
+        );
+    }
+    test_field_IsSparseTensor();
+    fn test_field_CreateSparseTensorAsOrtValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
+            },
+            1432usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSparseTensorAsOrtValue)
+            )
+        );
+    }
+    test_field_CreateSparseTensorAsOrtValue();
+    fn test_field_FillSparseTensorCoo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
+            },
+            1440usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(FillSparseTensorCoo)
+            )
+        );
+    }
+    test_field_FillSparseTensorCoo();
+    fn test_field_FillSparseTensorCsr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
+            },
+            1448usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(FillSparseTensorCsr)
+            )
+        );
+    }
+    test_field_FillSparseTensorCsr();
+    fn test_field_FillSparseTensorBlockSparse() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
+            },
+            1456usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(FillSparseTensorBlockSparse)
+            )
+        );
+    }
+    test_field_FillSparseTensorBlockSparse();
+    fn test_field_CreateSparseTensorWithValuesAsOrtValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
+                    - ptr as usize
+            },
+            1464usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateSparseTensorWithValuesAsOrtValue)
+            )
+        );
+    }
+    test_field_CreateSparseTensorWithValuesAsOrtValue();
+    fn test_field_UseCooIndices() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
+            },
+            1472usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(UseCooIndices)
+            )
+        );
+    }
+    test_field_UseCooIndices();
+    fn test_field_UseCsrIndices() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
+            },
+            1480usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(UseCsrIndices)
+            )
+        );
+    }
+    test_field_UseCsrIndices();
+    fn test_field_UseBlockSparseIndices() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
+            },
+            1488usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(UseBlockSparseIndices)
+            )
+        );
+    }
+    test_field_UseBlockSparseIndices();
+    fn test_field_GetSparseTensorFormat() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
+            },
+            1496usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSparseTensorFormat)
+            )
+        );
+    }
+    test_field_GetSparseTensorFormat();
+    fn test_field_GetSparseTensorValuesTypeAndShape() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
+                    - ptr as usize
+            },
+            1504usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSparseTensorValuesTypeAndShape)
+            )
+        );
+    }
+    test_field_GetSparseTensorValuesTypeAndShape();
+    fn test_field_GetSparseTensorValues() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
+            },
+            1512usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSparseTensorValues)
+            )
+        );
+    }
+    test_field_GetSparseTensorValues();
+    fn test_field_GetSparseTensorIndicesTypeShape() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
+            },
+            1520usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSparseTensorIndicesTypeShape)
+            )
+        );
+    }
+    test_field_GetSparseTensorIndicesTypeShape();
+    fn test_field_GetSparseTensorIndices() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
+            },
+            1528usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetSparseTensorIndices)
+            )
+        );
+    }
+    test_field_GetSparseTensorIndices();
+    fn test_field_HasValue() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
+            },
+            1536usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(HasValue)
+            )
+        );
+    }
+    test_field_HasValue();
+    fn test_field_KernelContext_GetGPUComputeStream() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
+                    - ptr as usize
+            },
+            1544usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(KernelContext_GetGPUComputeStream)
+            )
+        );
+    }
+    test_field_KernelContext_GetGPUComputeStream();
+    fn test_field_GetTensorMemoryInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
+            },
+            1552usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetTensorMemoryInfo)
+            )
+        );
+    }
+    test_field_GetTensorMemoryInfo();
+    fn test_field_GetExecutionProviderApi() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
+            },
+            1560usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetExecutionProviderApi)
+            )
+        );
+    }
+    test_field_GetExecutionProviderApi();
+    fn test_field_SessionOptionsSetCustomCreateThreadFn() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
+                    - ptr as usize
+            },
+            1568usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsSetCustomCreateThreadFn)
+            )
+        );
+    }
+    test_field_SessionOptionsSetCustomCreateThreadFn();
+    fn test_field_SessionOptionsSetCustomThreadCreationOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
+                    - ptr as usize
+            },
+            1576usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsSetCustomThreadCreationOptions)
+            )
+        );
+    }
+    test_field_SessionOptionsSetCustomThreadCreationOptions();
+    fn test_field_SessionOptionsSetCustomJoinThreadFn() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
+                    - ptr as usize
+            },
+            1584usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsSetCustomJoinThreadFn)
+            )
+        );
+    }
+    test_field_SessionOptionsSetCustomJoinThreadFn();
+    fn test_field_SetGlobalCustomCreateThreadFn() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
+            },
+            1592usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalCustomCreateThreadFn)
+            )
+        );
+    }
+    test_field_SetGlobalCustomCreateThreadFn();
+    fn test_field_SetGlobalCustomThreadCreationOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
+                    - ptr as usize
+            },
+            1600usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalCustomThreadCreationOptions)
+            )
+        );
+    }
+    test_field_SetGlobalCustomThreadCreationOptions();
+    fn test_field_SetGlobalCustomJoinThreadFn() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
+            },
+            1608usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SetGlobalCustomJoinThreadFn)
+            )
+        );
+    }
+    test_field_SetGlobalCustomJoinThreadFn();
+    fn test_field_SynchronizeBoundInputs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
+            },
+            1616usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SynchronizeBoundInputs)
+            )
+        );
+    }
+    test_field_SynchronizeBoundInputs();
+    fn test_field_SynchronizeBoundOutputs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
+            },
+            1624usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SynchronizeBoundOutputs)
+            )
+        );
+    }
+    test_field_SynchronizeBoundOutputs();
+    fn test_field_SessionOptionsAppendExecutionProvider_CUDA_V2() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
+                    - ptr as usize
+            },
+            1632usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_CUDA_V2)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_CUDA_V2();
+    fn test_field_CreateCUDAProviderOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
+            },
+            1640usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateCUDAProviderOptions)
+            )
+        );
+    }
+    test_field_CreateCUDAProviderOptions();
+    fn test_field_UpdateCUDAProviderOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
+            },
+            1648usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(UpdateCUDAProviderOptions)
+            )
+        );
+    }
+    test_field_UpdateCUDAProviderOptions();
+    fn test_field_GetCUDAProviderOptionsAsString() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
+            },
+            1656usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(GetCUDAProviderOptionsAsString)
+            )
+        );
+    }
+    test_field_GetCUDAProviderOptionsAsString();
+    fn test_field_ReleaseCUDAProviderOptions() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
+            },
+            1664usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseCUDAProviderOptions)
+            )
+        );
+    }
+    test_field_ReleaseCUDAProviderOptions();
+    fn test_field_SessionOptionsAppendExecutionProvider_MIGraphX() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
+                    - ptr as usize
+            },
+            1672usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider_MIGraphX)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider_MIGraphX();
+    fn test_field_AddExternalInitializers() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
+            },
+            1680usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(AddExternalInitializers)
+            )
+        );
+    }
+    test_field_AddExternalInitializers();
+    fn test_field_CreateOpAttr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
+            },
+            1688usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateOpAttr)
+            )
+        );
+    }
+    test_field_CreateOpAttr();
+    fn test_field_ReleaseOpAttr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
+            },
+            1696usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseOpAttr)
+            )
+        );
+    }
+    test_field_ReleaseOpAttr();
+    fn test_field_CreateOp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
+            },
+            1704usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CreateOp)
+            )
+        );
+    }
+    test_field_CreateOp();
+    fn test_field_InvokeOp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
+            },
+            1712usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(InvokeOp)
+            )
+        );
+    }
+    test_field_InvokeOp();
+    fn test_field_ReleaseOp() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
+            },
+            1720usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseOp)
+            )
+        );
+    }
+    test_field_ReleaseOp();
+    fn test_field_SessionOptionsAppendExecutionProvider() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
+                    - ptr as usize
+            },
+            1728usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(SessionOptionsAppendExecutionProvider)
+            )
+        );
+    }
+    test_field_SessionOptionsAppendExecutionProvider();
+    fn test_field_CopyKernelInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
+            },
+            1736usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(CopyKernelInfo)
+            )
+        );
+    }
+    test_field_CopyKernelInfo();
+    fn test_field_ReleaseKernelInfo() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
+            },
+            1744usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtApi),
+                "::",
+                stringify!(ReleaseKernelInfo)
+            )
+        );
+    }
+    test_field_ReleaseKernelInfo();
+}
+pub const OrtCustomOpInputOutputCharacteristic_INPUT_OUTPUT_REQUIRED:
+    OrtCustomOpInputOutputCharacteristic = 0;
+pub const OrtCustomOpInputOutputCharacteristic_INPUT_OUTPUT_OPTIONAL:
+    OrtCustomOpInputOutputCharacteristic = 1;
+pub type OrtCustomOpInputOutputCharacteristic = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OrtCustomOp {
+    pub version: u32,
+    pub CreateKernel: ::std::option::Option<
+        unsafe extern "C" fn(
+            op: *const OrtCustomOp,
+            api: *const OrtApi,
+            info: *const OrtKernelInfo,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub GetName: ::std::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    >,
+    pub GetExecutionProviderType: ::std::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    >,
+    pub GetInputType: ::std::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
+    >,
+    pub GetInputTypeCount:
+        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub GetOutputType: ::std::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
+    >,
+    pub GetOutputTypeCount:
+        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub KernelCompute: ::std::option::Option<
+        unsafe extern "C" fn(
+            op_kernel: *mut ::std::os::raw::c_void,
+            context: *mut OrtKernelContext,
+        ),
+    >,
+    pub KernelDestroy:
+        ::std::option::Option<unsafe extern "C" fn(op_kernel: *mut ::std::os::raw::c_void)>,
+    pub GetInputCharacteristic: ::std::option::Option<
+        unsafe extern "C" fn(
+            op: *const OrtCustomOp,
+            index: usize,
+        ) -> OrtCustomOpInputOutputCharacteristic,
+    >,
+    pub GetOutputCharacteristic: ::std::option::Option<
+        unsafe extern "C" fn(
+            op: *const OrtCustomOp,
+            index: usize,
+        ) -> OrtCustomOpInputOutputCharacteristic,
+    >,
+}
+#[test]
+fn bindgen_test_layout_OrtCustomOp() {
+    assert_eq!(
+        ::std::mem::size_of::<OrtCustomOp>(),
+        96usize,
+        concat!("Size of: ", stringify!(OrtCustomOp))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<OrtCustomOp>(),
+        8usize,
+        concat!("Alignment of ", stringify!(OrtCustomOp))
+    );
+    fn test_field_version() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(version)
+            )
+        );
+    }
+    test_field_version();
+    fn test_field_CreateKernel() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(CreateKernel)
+            )
+        );
+    }
+    test_field_CreateKernel();
+    fn test_field_GetName() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetName)
+            )
+        );
+    }
+    test_field_GetName();
+    fn test_field_GetExecutionProviderType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetExecutionProviderType)
+            )
+        );
+    }
+    test_field_GetExecutionProviderType();
+    fn test_field_GetInputType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetInputType)
+            )
+        );
+    }
+    test_field_GetInputType();
+    fn test_field_GetInputTypeCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetInputTypeCount)
+            )
+        );
+    }
+    test_field_GetInputTypeCount();
+    fn test_field_GetOutputType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetOutputType)
+            )
+        );
+    }
+    test_field_GetOutputType();
+    fn test_field_GetOutputTypeCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetOutputTypeCount)
+            )
+        );
+    }
+    test_field_GetOutputTypeCount();
+    fn test_field_KernelCompute() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(KernelCompute)
+            )
+        );
+    }
+    test_field_KernelCompute();
+    fn test_field_KernelDestroy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(KernelDestroy)
+            )
+        );
+    }
+    test_field_KernelDestroy();
+    fn test_field_GetInputCharacteristic() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetInputCharacteristic)
+            )
+        );
+    }
+    test_field_GetInputCharacteristic();
+    fn test_field_GetOutputCharacteristic() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(OrtCustomOp),
+                "::",
+                stringify!(GetOutputCharacteristic)
+            )
+        );
+    }
+    test_field_GetOutputCharacteristic();
+}
+pub type __builtin_va_list = [__va_list_tag; 1usize];
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __va_list_tag {
+    pub gp_offset: ::std::os::raw::c_uint,
+    pub fp_offset: ::std::os::raw::c_uint,
+    pub overflow_arg_area: *mut ::std::os::raw::c_void,
+    pub reg_save_area: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout___va_list_tag() {
+    assert_eq!(
+        ::std::mem::size_of::<__va_list_tag>(),
+        24usize,
+        concat!("Size of: ", stringify!(__va_list_tag))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__va_list_tag>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__va_list_tag))
+    );
+    fn test_field_gp_offset() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).gp_offset) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__va_list_tag),
+                "::",
+                stringify!(gp_offset)
+            )
+        );
+    }
+    test_field_gp_offset();
+    fn test_field_fp_offset() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).fp_offset) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__va_list_tag),
+                "::",
+                stringify!(fp_offset)
+            )
+        );
+    }
+    test_field_fp_offset();
+    fn test_field_overflow_arg_area() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).overflow_arg_area) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__va_list_tag),
+                "::",
+                stringify!(overflow_arg_area)
+            )
+        );
+    }
+    test_field_overflow_arg_area();
+    fn test_field_reg_save_area() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).reg_save_area) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(__va_list_tag),
+                "::",
+                stringify!(reg_save_area)
+            )
+        );
+    }
+    test_field_reg_save_area();
+}
+extern "C" {
+    pub fn signal(
+        arg1: ::std::os::raw::c_int,
+        arg2: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+    ) -> ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: ::std::os::raw::c_int,
+            arg2: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+        ),
+    >;
+}
+extern "C" {
+    pub fn getpriority(arg1: ::std::os::raw::c_int, arg2: id_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn getiopolicy_np(
+        arg1: ::std::os::raw::c_int,
+        arg2: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn getrlimit(arg1: ::std::os::raw::c_int, arg2: *mut rlimit) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn getrusage(arg1: ::std::os::raw::c_int, arg2: *mut rusage) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn setpriority(
+        arg1: ::std::os::raw::c_int,
+        arg2: id_t,
+        arg3: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn setiopolicy_np(
+        arg1: ::std::os::raw::c_int,
+        arg2: ::std::os::raw::c_int,
+        arg3: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn setrlimit(arg1: ::std::os::raw::c_int, arg2: *const rlimit) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wait(arg1: *mut ::std::os::raw::c_int) -> pid_t;
+}
+extern "C" {
+    pub fn waitpid(
+        arg1: pid_t,
+        arg2: *mut ::std::os::raw::c_int,
+        arg3: ::std::os::raw::c_int,
+    ) -> pid_t;
+}
+extern "C" {
+    pub fn waitid(
+        arg1: idtype_t,
+        arg2: id_t,
+        arg3: *mut siginfo_t,
+        arg4: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wait3(
+        arg1: *mut ::std::os::raw::c_int,
+        arg2: ::std::os::raw::c_int,
+        arg3: *mut rusage,
+    ) -> pid_t;
+}
+extern "C" {
+    pub fn wait4(
+        arg1: pid_t,
+        arg2: *mut ::std::os::raw::c_int,
+        arg3: ::std::os::raw::c_int,
+        arg4: *mut rusage,
+    ) -> pid_t;
+}
+extern "C" {
+    pub fn alloca(arg1: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn malloc(__size: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn calloc(
+        __count: ::std::os::raw::c_ulong,
+        __size: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn free(arg1: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn realloc(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn valloc(arg1: usize) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn aligned_alloc(__alignment: usize, __size: usize) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn posix_memalign(
+        __memptr: *mut *mut ::std::os::raw::c_void,
+        __alignment: usize,
+        __size: usize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn abort();
+}
+extern "C" {
+    pub fn abs(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn atexit(arg1: ::std::option::Option<unsafe extern "C" fn()>) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn atof(arg1: *const ::std::os::raw::c_char) -> f64;
+}
+extern "C" {
+    pub fn atoi(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn atol(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn atoll(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn bsearch(
+        __key: *const ::std::os::raw::c_void,
+        __base: *const ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *const ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn div(arg1: ::std::os::raw::c_int, arg2: ::std::os::raw::c_int) -> div_t;
+}
+extern "C" {
+    pub fn exit(arg1: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn getenv(arg1: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn labs(arg1: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn ldiv(arg1: ::std::os::raw::c_long, arg2: ::std::os::raw::c_long) -> ldiv_t;
+}
+extern "C" {
+    pub fn llabs(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn lldiv(arg1: ::std::os::raw::c_longlong, arg2: ::std::os::raw::c_longlong) -> lldiv_t;
+}
+extern "C" {
+    pub fn mblen(__s: *const ::std::os::raw::c_char, __n: usize) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn mbstowcs(arg1: *mut wchar_t, arg2: *const ::std::os::raw::c_char, arg3: usize) -> usize;
+}
+extern "C" {
+    pub fn mbtowc(
+        arg1: *mut wchar_t,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: usize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qsort(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *const ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    );
+}
+extern "C" {
+    pub fn rand() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn srand(arg1: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn strtod(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *mut *mut ::std::os::raw::c_char,
+    ) -> f64;
+}
+extern "C" {
+    pub fn strtof(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *mut *mut ::std::os::raw::c_char,
+    ) -> f32;
+}
+extern "C" {
+    pub fn strtol(
+        __str: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn strtold(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *mut *mut ::std::os::raw::c_char,
+    ) -> u128;
+}
+extern "C" {
+    pub fn strtoll(
+        __str: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn strtoul(
+        __str: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strtoull(
+        __str: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_ulonglong;
+}
+extern "C" {
+    pub fn system(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wcstombs(arg1: *mut ::std::os::raw::c_char, arg2: *const wchar_t, arg3: usize) -> usize;
+}
+extern "C" {
+    pub fn wctomb(arg1: *mut ::std::os::raw::c_char, arg2: wchar_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn _Exit(arg1: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn a64l(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn drand48() -> f64;
+}
+extern "C" {
+    pub fn ecvt(
+        arg1: f64,
+        arg2: ::std::os::raw::c_int,
+        arg3: *mut ::std::os::raw::c_int,
+        arg4: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn erand48(arg1: *mut ::std::os::raw::c_ushort) -> f64;
+}
+extern "C" {
+    pub fn fcvt(
+        arg1: f64,
+        arg2: ::std::os::raw::c_int,
+        arg3: *mut ::std::os::raw::c_int,
+        arg4: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn gcvt(
+        arg1: f64,
+        arg2: ::std::os::raw::c_int,
+        arg3: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn getsubopt(
+        arg1: *mut *mut ::std::os::raw::c_char,
+        arg2: *const *mut ::std::os::raw::c_char,
+        arg3: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn grantpt(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn initstate(
+        arg1: ::std::os::raw::c_uint,
+        arg2: *mut ::std::os::raw::c_char,
+        arg3: usize,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn jrand48(arg1: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn l64a(arg1: ::std::os::raw::c_long) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn lcong48(arg1: *mut ::std::os::raw::c_ushort);
+}
+extern "C" {
+    pub fn lrand48() -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn mktemp(arg1: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn mkstemp(arg1: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn mrand48() -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn nrand48(arg1: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn posix_openpt(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn ptsname(arg1: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ptsname_r(
+        fildes: ::std::os::raw::c_int,
+        buffer: *mut ::std::os::raw::c_char,
+        buflen: usize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn putenv(arg1: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn random() -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn rand_r(arg1: *mut ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn realpath(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn seed48(arg1: *mut ::std::os::raw::c_ushort) -> *mut ::std::os::raw::c_ushort;
+}
+extern "C" {
+    pub fn setenv(
+        __name: *const ::std::os::raw::c_char,
+        __value: *const ::std::os::raw::c_char,
+        __overwrite: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn setkey(arg1: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn setstate(arg1: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn srand48(arg1: ::std::os::raw::c_long);
+}
+extern "C" {
+    pub fn srandom(arg1: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn unlockpt(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn unsetenv(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn arc4random() -> u32;
+}
+extern "C" {
+    pub fn arc4random_addrandom(arg1: *mut ::std::os::raw::c_uchar, arg2: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn arc4random_buf(__buf: *mut ::std::os::raw::c_void, __nbytes: usize);
+}
+extern "C" {
+    pub fn arc4random_stir();
+}
+extern "C" {
+    pub fn arc4random_uniform(__upper_bound: u32) -> u32;
+}
+extern "C" {
+    pub fn atexit_b(arg1: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bsearch_b(
+        __key: *const ::std::os::raw::c_void,
+        __base: *const ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn cgetcap(
+        arg1: *mut ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn cgetclose() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetent(
+        arg1: *mut *mut ::std::os::raw::c_char,
+        arg2: *mut *mut ::std::os::raw::c_char,
+        arg3: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetfirst(
+        arg1: *mut *mut ::std::os::raw::c_char,
+        arg2: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetmatch(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetnext(
+        arg1: *mut *mut ::std::os::raw::c_char,
+        arg2: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetnum(
+        arg1: *mut ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetset(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetstr(
+        arg1: *mut ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cgetustr(
+        arg1: *mut ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn daemon(
+        arg1: ::std::os::raw::c_int,
+        arg2: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn devname(arg1: dev_t, arg2: mode_t) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn devname_r(
+        arg1: dev_t,
+        arg2: mode_t,
+        buf: *mut ::std::os::raw::c_char,
+        len: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn getbsize(
+        arg1: *mut ::std::os::raw::c_int,
+        arg2: *mut ::std::os::raw::c_long,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn getloadavg(arg1: *mut f64, arg2: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn getprogname() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn setprogname(arg1: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn heapsort(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *const ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn heapsort_b(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn mergesort(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *const ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn mergesort_b(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn psort(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *const ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    );
+}
+extern "C" {
+    pub fn psort_b(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn psort_r(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        arg1: *mut ::std::os::raw::c_void,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+                arg3: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    );
+}
+extern "C" {
+    pub fn qsort_b(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        __compar: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn qsort_r(
+        __base: *mut ::std::os::raw::c_void,
+        __nel: usize,
+        __width: usize,
+        arg1: *mut ::std::os::raw::c_void,
+        __compar: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: *const ::std::os::raw::c_void,
+                arg3: *const ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+    );
+}
+extern "C" {
+    pub fn radixsort(
+        __base: *mut *const ::std::os::raw::c_uchar,
+        __nel: ::std::os::raw::c_int,
+        __table: *const ::std::os::raw::c_uchar,
+        __endbyte: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rpmatch(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sradixsort(
+        __base: *mut *const ::std::os::raw::c_uchar,
+        __nel: ::std::os::raw::c_int,
+        __table: *const ::std::os::raw::c_uchar,
+        __endbyte: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sranddev();
+}
+extern "C" {
+    pub fn srandomdev();
+}
+extern "C" {
+    pub fn reallocf(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn strtonum(
+        __numstr: *const ::std::os::raw::c_char,
+        __minval: ::std::os::raw::c_longlong,
+        __maxval: ::std::os::raw::c_longlong,
+        __errstrp: *mut *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn strtoq(
+        __str: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn strtouq(
+        __str: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_ulonglong;
+}
+extern "C" {
+    pub fn memchr(
+        __s: *const ::std::os::raw::c_void,
+        __c: ::std::os::raw::c_int,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn memcmp(
+        __s1: *const ::std::os::raw::c_void,
+        __s2: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn memcpy(
+        __dst: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn memmove(
+        __dst: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __len: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn memset(
+        __b: *mut ::std::os::raw::c_void,
+        __c: ::std::os::raw::c_int,
+        __len: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn strcat(
+        __s1: *mut ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strchr(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strcmp(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strcoll(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strcpy(
+        __dst: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strcspn(
+        __s: *const ::std::os::raw::c_char,
+        __charset: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strerror(__errnum: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strlen(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strncat(
+        __s1: *mut ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strncmp(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strncpy(
+        __dst: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strpbrk(
+        __s: *const ::std::os::raw::c_char,
+        __charset: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strrchr(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strspn(
+        __s: *const ::std::os::raw::c_char,
+        __charset: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strstr(
+        __big: *const ::std::os::raw::c_char,
+        __little: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strtok(
+        __str: *mut ::std::os::raw::c_char,
+        __sep: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strxfrm(
+        __s1: *mut ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strtok_r(
+        __str: *mut ::std::os::raw::c_char,
+        __sep: *const ::std::os::raw::c_char,
+        __lasts: *mut *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strerror_r(
+        __errnum: ::std::os::raw::c_int,
+        __strerrbuf: *mut ::std::os::raw::c_char,
+        __buflen: usize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strdup(__s1: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn memccpy(
+        __dst: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __c: ::std::os::raw::c_int,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn stpcpy(
+        __dst: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn stpncpy(
+        __dst: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strndup(
+        __s1: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strnlen(__s1: *const ::std::os::raw::c_char, __n: usize) -> usize;
+}
+extern "C" {
+    pub fn strsignal(__sig: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn memset_s(
+        __s: *mut ::std::os::raw::c_void,
+        __smax: rsize_t,
+        __c: ::std::os::raw::c_int,
+        __n: rsize_t,
+    ) -> errno_t;
+}
+extern "C" {
+    pub fn memmem(
+        __big: *const ::std::os::raw::c_void,
+        __big_len: usize,
+        __little: *const ::std::os::raw::c_void,
+        __little_len: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn memset_pattern4(
+        __b: *mut ::std::os::raw::c_void,
+        __pattern4: *const ::std::os::raw::c_void,
+        __len: usize,
+    );
+}
+extern "C" {
+    pub fn memset_pattern8(
+        __b: *mut ::std::os::raw::c_void,
+        __pattern8: *const ::std::os::raw::c_void,
+        __len: usize,
+    );
+}
+extern "C" {
+    pub fn memset_pattern16(
+        __b: *mut ::std::os::raw::c_void,
+        __pattern16: *const ::std::os::raw::c_void,
+        __len: usize,
+    );
+}
+extern "C" {
+    pub fn strcasestr(
+        __big: *const ::std::os::raw::c_char,
+        __little: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strnstr(
+        __big: *const ::std::os::raw::c_char,
+        __little: *const ::std::os::raw::c_char,
+        __len: usize,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn strlcat(
+        __dst: *mut ::std::os::raw::c_char,
+        __source: *const ::std::os::raw::c_char,
+        __size: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strlcpy(
+        __dst: *mut ::std::os::raw::c_char,
+        __source: *const ::std::os::raw::c_char,
+        __size: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    pub fn strmode(__mode: ::std::os::raw::c_int, __bp: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn strsep(
+        __stringp: *mut *mut ::std::os::raw::c_char,
+        __delim: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn swab(
+        arg1: *const ::std::os::raw::c_void,
+        arg2: *mut ::std::os::raw::c_void,
+        arg3: isize,
+    );
+}
+extern "C" {
+    pub fn timingsafe_bcmp(
+        __b1: *const ::std::os::raw::c_void,
+        __b2: *const ::std::os::raw::c_void,
+        __len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strsignal_r(
+        __sig: ::std::os::raw::c_int,
+        __strsignalbuf: *mut ::std::os::raw::c_char,
+        __buflen: usize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bcmp(
+        arg1: *const ::std::os::raw::c_void,
+        arg2: *const ::std::os::raw::c_void,
+        arg3: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bcopy(
+        arg1: *const ::std::os::raw::c_void,
+        arg2: *mut ::std::os::raw::c_void,
+        arg3: usize,
+    );
+}
+extern "C" {
+    pub fn bzero(arg1: *mut ::std::os::raw::c_void, arg2: ::std::os::raw::c_ulong);
+}
+extern "C" {
+    pub fn index(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn rindex(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ffs(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strcasecmp(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn strncasecmp(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn ffsl(arg1: ::std::os::raw::c_long) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn ffsll(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn fls(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn flsl(arg1: ::std::os::raw::c_long) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn flsll(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn OrtGetApiBase() -> *const OrtApiBase;
+}
+extern "C" {
+    pub fn OrtSessionOptionsAppendExecutionProvider_CUDA(
+        options: *mut OrtSessionOptions,
+        device_id: ::std::os::raw::c_int,
+    ) -> OrtStatusPtr;
+}
+extern "C" {
+    pub fn OrtSessionOptionsAppendExecutionProvider_MIGraphX(
+        options: *mut OrtSessionOptions,
+        device_id: ::std::os::raw::c_int,
+    ) -> OrtStatusPtr;
+}
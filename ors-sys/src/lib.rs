@@ -15,3 +15,8 @@ include!(concat!(
 pub type OnnxEnumInt = i32;
 #[cfg(not(target_os = "windows"))]
 pub type OnnxEnumInt = u32;
+
+/// Directory of the onnxruntime shared library which is prepared by the build script.
+///
+/// It's the extracted prebuilt library when `ORT_STRATEGY=download`, or `$ORT_LIB_LOCATION/lib` when `ORT_STRATEGY=system`
+pub const ORT_BUILD_LIB_DIR: &str = env!("ORS_SYS_LIB_DIR");
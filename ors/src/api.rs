@@ -1,121 +1,264 @@
-use anyhow::{anyhow, Result};
-use lazy_static::lazy_static;
+use crate::error::{Error, Result};
+use crate::runtime::{Runtime, RuntimeBuilder, RuntimeCell, TrackedRuntime};
+use crate::version::RuntimeInfo;
 use ors_sys::*;
-use std::mem::ManuallyDrop;
 use std::path::Path;
-use std::ptr::null_mut;
-use std::sync::{
-    atomic::{AtomicBool, AtomicPtr, Ordering},
-    Arc, Mutex,
-};
+use std::sync::Mutex;
 use tracing::debug;
 
-lazy_static! {
-    static ref API: Arc<Mutex<AtomicPtr<OrtApi>>> =
-        Arc::new(Mutex::new(AtomicPtr::new(null_mut())));
-    static ref LIB: Arc<Mutex<AtomicPtr<ManuallyDrop<onnxruntime>>>> =
-        Arc::new(Mutex::new(AtomicPtr::new(null_mut())));
-    static ref INITIALIZED: AtomicBool = AtomicBool::new(false);
-}
+/// The default runtime, which is read without locking
+static DEFAULT_RUNTIME: RuntimeCell = RuntimeCell::new();
+/// Serializes loading and unloading of the default runtime, readers never take it
+static DEFAULT_RUNTIME_INIT: Mutex<()> = Mutex::new(());
 
 /// Initialize onnxruntime from a shared lib
 /// This function MUST be called before accessing any APIs of onnxruntime
 /// Note: Only the fist call of `initialize_runtime` makes sense
+///
+/// The loaded runtime becomes the default runtime, which is used by sessions and tensors
+/// created without an explicit [`Runtime`]
 /// ## Example
-/// ```rust
+/// ```no_run
+/// use ors::api::initialize_runtime;
+/// use std::path::Path;
+///
 /// initialize_runtime(Path::new("/path/to/onnxruntime")).expect("Failed to load onnxruntime");
-/// test_macro();
 /// ```
+///
+/// Without the `dynamic-loading` feature, onnxruntime is linked at compile time and the path is ignored.
 pub fn initialize_runtime(path: &Path) -> Result<()> {
+    let _init = lock_default_runtime();
     // If the runtime has been initialized, just return
-    let initialized = INITIALIZED.load(Ordering::SeqCst);
-    if initialized {
+    if DEFAULT_RUNTIME.get().is_some() {
         debug!("onnxruntime has been initialized");
         return Ok(());
     }
 
     // Otherwise, load onnxruntime shared library
-    let mut ort = match unsafe { onnxruntime::new(path) } {
-        Ok(ort) => ManuallyDrop::new(ort),
-        Err(err) => return Err(anyhow!("Failed to load onnxruntime shared library {}", err)),
-    };
+    DEFAULT_RUNTIME.set(Some(Runtime::new(path)?));
+    Ok(())
+}
+
+/// Initialize onnxruntime from a shared lib found automatically
+///
+/// The library is searched in `ORS_DYLIB_PATH`, the directory prepared by `ors-sys` build script,
+/// the directory of current executable and the standard system loader paths.
+/// See [`crate::discovery::candidates`] for details.
+/// If onnxruntime cannot be found, the returned error lists all locations which have been tried.
+/// ## Example
+/// ```no_run
+/// use ors::api::initialize_runtime_auto;
+///
+/// initialize_runtime_auto().expect("Failed to load onnxruntime");
+/// ```
+pub fn initialize_runtime_auto() -> Result<()> {
+    default_runtime().map(|_| ())
+}
 
-    // Wrap the lib using ManuallyDrop
-    // The loaded lib is dropped only when we call drop manually
-    let mut lib_ptr = LIB.lock().expect("Failed to get lib");
-    *lib_ptr = AtomicPtr::new(&mut ort);
-
-    // Set the api entry then
-    let api_base = unsafe { ort.OrtGetApiBase() };
-    let api: *const OrtApi = unsafe { (&(*api_base).GetApi.unwrap())(ORT_API_VERSION) };
-    let mut g_api = API.lock().expect("Failed to get api");
-    *g_api = AtomicPtr::new(api as *mut OrtApi);
-    let _b = INITIALIZED
-        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(true))
-        .unwrap();
+/// Initialize the default runtime with a [`RuntimeBuilder`], for example to give it global thread pools
+///
+/// Unlike `initialize_runtime()`, the options must not be ignored, so it returns
+/// [`Error::DefaultRuntimeExists`] if the default runtime has been initialized, including by the first use
+/// of onnxruntime. Call it before anything else, or unload the default runtime with `drop_lib()` first.
+/// ## Example
+/// ```no_run
+/// use ors::api::initialize_runtime_with;
+/// use ors::env::{EnvironmentBuilder, ThreadingOptions};
+/// use ors::runtime::Runtime;
+///
+/// initialize_runtime_with(Runtime::builder().environment(
+///     EnvironmentBuilder::new().global_thread_pools(ThreadingOptions::new().intra_op_num_threads(4)),
+/// ))
+/// .expect("Failed to load onnxruntime");
+/// ```
+pub fn initialize_runtime_with(builder: RuntimeBuilder) -> Result<()> {
+    let _init = lock_default_runtime();
+    if DEFAULT_RUNTIME.get().is_some() {
+        return Err(Error::DefaultRuntimeExists);
+    }
+
+    DEFAULT_RUNTIME.set(Some(builder.build()?));
     Ok(())
 }
 
+/// Get the default runtime, which is used by sessions and tensors created without an explicit [`Runtime`]
+///
+/// If the runtime has not been initialized, onnxruntime is searched and loaded using `initialize_runtime_auto()`
+pub fn default_runtime() -> Result<Runtime> {
+    if let Some(runtime) = DEFAULT_RUNTIME.get() {
+        return Ok(runtime);
+    }
+
+    let _init = lock_default_runtime();
+    // Another thread may have initialized it while waiting for the lock
+    if let Some(runtime) = DEFAULT_RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime = Runtime::auto()?;
+    DEFAULT_RUNTIME.set(Some(runtime.clone()));
+    Ok(runtime)
+}
+
+/// Replace the default runtime, the old one is unloaded after all its handles are dropped
+pub(crate) fn set_default_runtime(runtime: Runtime) {
+    let _init = lock_default_runtime();
+    DEFAULT_RUNTIME.set(Some(runtime));
+}
+
+/// Get information of the default runtime, including its version, the negotiated API version,
+/// available execution providers and the path of the shared library
+///
+/// The runtime is initialized automatically if it has not been initialized
+pub fn runtime_info() -> Result<RuntimeInfo> {
+    default_runtime()?.info()
+}
+
+/// Release the inference environment and unload the default runtime
+///
+/// Returns an error if any session, tensor, memory info or allocator created from the default runtime
+/// is still alive, or other handles of it are kept by the caller. In that case nothing is released.
+/// After the runtime is unloaded, `initialize_runtime()` can load it again.
 pub fn drop_lib() -> Result<()> {
-    // If the runtime has not been initialized, just return
-    let initialized = INITIALIZED.load(Ordering::SeqCst);
-    if !initialized {
-        return Ok(());
+    let _init = lock_default_runtime();
+    unload_runtime(&DEFAULT_RUNTIME)
+}
+
+/// Unload the runtime of the cell if nothing else uses it, otherwise it's kept in the cell
+fn unload_runtime(cell: &RuntimeCell) -> Result<()> {
+    match cell.set(None) {
+        // If the runtime has not been initialized, just return
+        None => Ok(()),
+        Some(runtime) => {
+            if let Err(e) = runtime.check_unloadable() {
+                // Put it back, threads which want to initialize the runtime are waiting for the lock
+                cell.set(Some(runtime));
+                return Err(e);
+            }
+            // This is the last handle, the environment is released and then the library is unloaded
+            std::mem::drop(runtime);
+            Ok(())
+        }
+    }
+}
+
+fn lock_default_runtime() -> std::sync::MutexGuard<'static, ()> {
+    // The lock only protects loading, a panic while holding it leaves nothing inconsistent
+    DEFAULT_RUNTIME_INIT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Types which provide an onnxruntime API table, like [`Runtime`] and `OrtApi` itself
+pub trait AsOrtApi {
+    fn as_ort_api(&self) -> &OrtApi;
+
+    /// Called by `call_ort!` before calling an API function, the mock backend records it
+    #[doc(hidden)]
+    fn record_call(&self, _function: &'static str) {}
+}
+
+impl AsOrtApi for OrtApi {
+    fn as_ort_api(&self) -> &OrtApi {
+        self
     }
+}
 
-    // Set LIB to null ptr and drop the loaded lib
-    let lib_atomic_ptr = LIB.lock().expect("Failed to get lib");
-    let lib_raw_ptr = lib_atomic_ptr
-        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |m| Some(null_mut()))
-        .map_err(|e| anyhow!("Failed to get library ptr"))?;
-    unsafe { lib_raw_ptr.drop_in_place() };
+impl AsOrtApi for Runtime {
+    fn as_ort_api(&self) -> &OrtApi {
+        self.api()
+    }
 
-    // Set initialized to false
-    let _b = INITIALIZED
-        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(false))
-        .unwrap();
-    Ok(())
+    fn record_call(&self, function: &'static str) {
+        Runtime::record_call(self, function)
+    }
+}
+
+impl AsOrtApi for TrackedRuntime {
+    fn as_ort_api(&self) -> &OrtApi {
+        self.api()
+    }
+
+    fn record_call(&self, function: &'static str) {
+        Runtime::record_call(self, function)
+    }
 }
 
-/// Macro for calling unsafe methods using get_api()
+impl<T: AsOrtApi + ?Sized> AsOrtApi for &T {
+    fn as_ort_api(&self) -> &OrtApi {
+        (**self).as_ort_api()
+    }
+
+    fn record_call(&self, function: &'static str) {
+        (**self).record_call(function)
+    }
+}
+
+/// Macro for calling unsafe methods of the default runtime
 /// You can also use this macro to call onnxruntime api
 /// ## Arguments
 ///
-/// The first argument is the API name, and the others are parameters
+/// The first argument is the API name, and the others are parameters.
+/// To call the API of a specific runtime, put the runtime (or an `OrtApi`) and `=>` before the API name
+///
+/// The macro returns `Result` of the API's return value. It's an error if the default runtime
+/// cannot be initialized, or the function is not available in the negotiated API version.
+/// An `OrtStatus` returned by the API should still be checked.
 ///
 /// ## Example
 /// Call OrtApi::CreateStatus
-/// ```
+/// ```no_run
+/// use ors::{call_ort, runtime::Runtime};
+/// use ors_sys::*;
+/// use std::ffi::CString;
+///
 /// let msg = CString::new("error msg").unwrap();
-/// let status = call_ort!(CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr());
+/// let status = call_ort!(CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr()).unwrap();
+///
+/// let runtime = Runtime::auto().unwrap();
+/// let status =
+///     call_ort!(runtime => CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr()).unwrap();
 /// ```
 #[macro_export]
 macro_rules! call_ort {
     ($api_name:ident, $($parameter:expr),*) => {
-        unsafe {
-            get_api().$api_name.unwrap()($($parameter),*)
+        match $crate::api::try_default_runtime() {
+            Ok(runtime) => $crate::call_ort!(runtime => $api_name, $($parameter),*),
+            Err(e) => Err(e),
+        }
+    };
+    ($runtime:expr => $api_name:ident, $($parameter:expr),*) => {
+        match &$runtime {
+            runtime => {
+                $crate::api::AsOrtApi::record_call(runtime, stringify!($api_name));
+                match $crate::api::AsOrtApi::as_ort_api(runtime).$api_name {
+                    Some(function) => {
+                        Ok::<_, $crate::error::Error>(unsafe { function($($parameter),*) })
+                    }
+                    None => Err($crate::error::Error::FunctionUnavailable {
+                        function: stringify!($api_name),
+                    }),
+                }
+            }
         }
     };
 }
 
-/// get_api exposes an OrtApi instance in crate
-pub(crate) fn get_api() -> OrtApi {
-    let initialized = INITIALIZED.load(Ordering::SeqCst);
-    // If the library is not initialize, just panic
-    if !initialized {
-        panic!("The library has not been initialized, you should initialize it first using initialize_runtime()");
-    }
-    let p = *(API.try_lock().unwrap()).get_mut();
-    unsafe { *p }
+/// Get the default runtime for `call_ort!`, the error is wrapped as `Error::NotInitialized`
+#[doc(hidden)]
+pub fn try_default_runtime() -> Result<Runtime> {
+    default_runtime().map_err(|e| Error::NotInitialized(Box::new(e)))
 }
 
 #[cfg(test)]
 mod test {
     use std::ffi::CString;
 
+    use ndarray::{ArrayD, IxDyn};
     use tracing_test::traced_test;
 
     use super::*;
+    #[cfg(feature = "mock")]
+    use crate::mock::MockBackend;
 
     #[test]
     #[traced_test]
@@ -133,32 +276,132 @@ mod test {
 
     #[test]
     #[traced_test]
-    #[should_panic(
-        expected = "The library has not been initialized, you should initialize it first using initialize_runtime()"
-    )]
     fn test_drop_loaded_lib() {
+        // Tests running in parallel use the default runtime, this one is only used by the test
+        check_unload_runtime(|| Runtime::auto().unwrap());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_mock_drop_loaded_lib() {
+        let backend = MockBackend::new();
+        check_unload_runtime(|| backend.runtime().unwrap());
+    }
+
+    fn check_unload_runtime(load: impl Fn() -> Runtime) {
+        let cell = RuntimeCell::new();
+        unload_runtime(&cell).unwrap();
+
+        cell.set(Some(load()));
+        // A handle is kept by the caller
+        let runtime = cell.get().unwrap();
+        let err = unload_runtime(&cell).err().unwrap();
+        assert!(matches!(err, Error::RuntimeInUse { handles: 1, .. }));
+        assert!(cell.get().is_some());
+
+        // An object created from the runtime is alive
+        let tensor = runtime
+            .create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[2])))
+            .unwrap();
+        std::mem::drop(runtime);
+        match unload_runtime(&cell).err().unwrap() {
+            Error::RuntimeInUse { live_objects, .. } => assert_eq!(1, live_objects.tensors),
+            e => panic!("Unexpected error {}", e),
+        }
+        std::mem::drop(tensor);
+        unload_runtime(&cell).unwrap();
+        assert!(cell.get().is_none());
+
+        // The runtime can be loaded again after it's unloaded
+        cell.set(Some(load()));
+        let runtime = cell.get().unwrap();
+        let msg = CString::new("error msg").unwrap();
+        let status =
+            call_ort!(runtime => CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr())
+                .unwrap();
+        call_ort!(runtime => ReleaseStatus, status).unwrap();
+        std::mem::drop(runtime);
+        unload_runtime(&cell).unwrap();
+    }
+
+    #[test]
+    fn test_initialize_runtime_with_existing_default() {
         setup_runtime();
-        test_macro();
-        drop_lib().unwrap();
+        let err = initialize_runtime_with(Runtime::builder()).err().unwrap();
+        assert!(matches!(err, Error::DefaultRuntimeExists));
+    }
+
+    #[test]
+    fn test_runtime_info() {
+        setup_runtime();
+        let info = runtime_info().unwrap();
+        assert!(info.api_version <= ORT_API_VERSION);
+        assert!(info
+            .available_providers
+            .contains(&"CPUExecutionProvider".to_string()));
+    }
+
+    #[test]
+    fn test_macro_with_runtime() {
+        let runtime = default_runtime().unwrap();
+        let msg = CString::new("error msg").unwrap();
+        let status =
+            call_ort!(runtime => CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr())
+                .unwrap();
+        let error_code = call_ort!(runtime => GetErrorCode, status).unwrap();
+        assert_eq!(error_code, 5);
+        call_ort!(runtime => ReleaseStatus, status).unwrap();
+    }
+
+    #[test]
+    fn test_initialize_runtime_auto() {
+        initialize_runtime_auto().unwrap();
         test_macro();
     }
 
     #[test]
-    #[should_panic]
     fn test_macro() {
         let msg = CString::new("error msg").unwrap();
-        let status = call_ort!(CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr());
-        let error_code = call_ort!(GetErrorCode, status);
+        let status = call_ort!(CreateStatus, OrtErrorCode_ORT_ENGINE_ERROR, msg.as_ptr()).unwrap();
+        let error_code = call_ort!(GetErrorCode, status).unwrap();
         assert_eq!(error_code, 5);
+        call_ort!(ReleaseStatus, status).unwrap();
+    }
+
+    #[test]
+    fn test_missing_function() {
+        // A table of an older onnxruntime, which doesn't have any function
+        let api: OrtApi = unsafe { std::mem::zeroed() };
+        let err = call_ort!(api => GetErrorCode, std::ptr::null())
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            Error::FunctionUnavailable {
+                function: "GetErrorCode"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_get_api_from_threads() {
+        setup_runtime();
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    for _ in 0..100 {
+                        assert!(default_runtime().unwrap().api().CreateStatus.is_some());
+                        test_macro();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
     }
 
     fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).expect("Failed to initialize runtime");
+        initialize_runtime_auto().unwrap();
     }
 }
@@ -0,0 +1,424 @@
+//! Caching models optimized by onnxruntime across restarts
+//!
+//! Graph optimization can dominate the creation of sessions of large models. [`ModelCache`] saves the
+//! optimized graph when a model is loaded for the first time, and later loads the saved graph with
+//! optimizations disabled. Cached models are keyed by the hash of the source model, the path, size and
+//! modification time of its external data files, the version of onnxruntime and the [`SessionConfig`],
+//! so changing any of them optimizes the model again.
+//!
+//! Initializers stored in external data are saved to a file next to the optimized ONNX model.
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use tracing::{debug, warn};
+
+use crate::api::default_runtime;
+use crate::config::{SessionConfig, SessionConfigKey, SessionGraphOptimizationLevel};
+use crate::error::{Error, Result};
+use crate::model::external_tensors;
+use crate::runtime::Runtime;
+use crate::session::{Session, SessionBuilder};
+
+/// Format of optimized models saved in a [`ModelCache`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptimizedModelFormat {
+    /// ONNX format, which can be loaded by any version of onnxruntime
+    Onnx,
+    /// ORT format, which is smaller and faster to load
+    Ort,
+}
+
+impl OptimizedModelFormat {
+    /// Value of the `session.save_model_format` and `session.load_model_format` entries
+    fn config_value(&self) -> &'static str {
+        match self {
+            OptimizedModelFormat::Onnx => "ONNX",
+            OptimizedModelFormat::Ort => "ORT",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            OptimizedModelFormat::Onnx => "onnx",
+            OptimizedModelFormat::Ort => "ort",
+        }
+    }
+}
+
+/// Files of a model in the cache
+struct CacheEntry {
+    model: PathBuf,
+    /// File name of the initializers saved out of the optimized model, if the model has external data
+    external_initializers: Option<String>,
+}
+
+/// A directory of optimized models
+///
+/// ## Example
+/// ```no_run
+/// use ors::cache::{ModelCache, OptimizedModelFormat};
+/// use ors::config::{SessionConfig, SessionGraphOptimizationLevel};
+///
+/// let cache = ModelCache::new("/var/cache/models")
+///     .unwrap()
+///     .format(OptimizedModelFormat::Ort);
+/// let config = SessionConfig {
+///     graph_optimization_level: Some(SessionGraphOptimizationLevel::All),
+///     ..Default::default()
+/// };
+/// let session = cache.load(&config, "gpt2.onnx").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModelCache {
+    dir: PathBuf,
+    format: OptimizedModelFormat,
+}
+
+impl ModelCache {
+    /// Use the directory as the cache, it's created if it doesn't exist
+    pub fn new<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).map_err(|source| Error::ModelCache {
+            path: dir.clone(),
+            source,
+        })?;
+        Ok(Self {
+            dir,
+            format: OptimizedModelFormat::Onnx,
+        })
+    }
+
+    /// Set the format of saved models, ONNX by default
+    pub fn format(mut self, format: OptimizedModelFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Create a session of the model in the default runtime, loading its optimized graph from the cache if possible
+    pub fn load<P: AsRef<Path>>(&self, config: &SessionConfig, model_path: P) -> Result<Session> {
+        self.load_with_runtime(&default_runtime()?, config, model_path)
+    }
+
+    /// Create a session of the model in the given runtime, loading its optimized graph from the cache if possible
+    pub fn load_with_runtime<P: AsRef<Path>>(
+        &self,
+        runtime: &Runtime,
+        config: &SessionConfig,
+        model_path: P,
+    ) -> Result<Session> {
+        let model_path = model_path.as_ref();
+        let entry = self.cache_entry(runtime, config, model_path)?;
+        let cached_path = &entry.model;
+        if cached_path.exists() {
+            match self.load_cached(runtime, config, cached_path) {
+                Ok(session) => {
+                    debug!("Loaded optimized model {}", cached_path.display());
+                    return Ok(session);
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to load optimized model {}, optimizing {} again: {}",
+                        cached_path.display(),
+                        model_path.display(),
+                        e
+                    );
+                    let _ = fs::remove_file(cached_path);
+                }
+            }
+        }
+
+        // Save to a temporary file first, so that other processes never load a partially written model
+        let tmp_path = cached_path.with_extension(format!(
+            "{}.{}.tmp",
+            self.format.extension(),
+            std::process::id()
+        ));
+        let mut builder = SessionBuilder::from_config_with_runtime(runtime, config)?.config(
+            SessionConfigKey::SaveModelFormat,
+            self.format.config_value(),
+        )?;
+        // The optimized model refers to the file by its name, so it's written with the final name
+        if let Some(file_name) = &entry.external_initializers {
+            builder = builder.config(
+                SessionConfigKey::OptimizedModelExternalInitializersFileName,
+                file_name,
+            )?;
+        }
+        let session = builder
+            .save_optimized_model(&tmp_path)?
+            .build_with_model_from_file(model_path)?;
+        match fs::rename(&tmp_path, cached_path) {
+            Ok(()) => debug!("Saved optimized model {}", cached_path.display()),
+            Err(e) => {
+                warn!(
+                    "Failed to save optimized model {}: {}",
+                    cached_path.display(),
+                    e
+                );
+                let _ = fs::remove_file(&tmp_path);
+            }
+        }
+        Ok(session)
+    }
+
+    /// Path of the optimized model in the cache, which may not exist yet
+    pub fn cached_model_path<P: AsRef<Path>>(
+        &self,
+        runtime: &Runtime,
+        config: &SessionConfig,
+        model_path: P,
+    ) -> Result<PathBuf> {
+        Ok(self
+            .cache_entry(runtime, config, model_path.as_ref())?
+            .model)
+    }
+
+    fn cache_entry(
+        &self,
+        runtime: &Runtime,
+        config: &SessionConfig,
+        model_path: &Path,
+    ) -> Result<CacheEntry> {
+        let model = fs::read(model_path).map_err(|source| match source.kind() {
+            ErrorKind::NotFound => Error::ModelNotFound(model_path.to_path_buf()),
+            _ => Error::ModelCache {
+                path: model_path.to_path_buf(),
+                source,
+            },
+        })?;
+        let mut settings = format!("{}\0{:?}\0{:?}", runtime.version(), self.format, config);
+        // External data files are identified by their metadata, hashing them would read the whole weights
+        let mut locations: Vec<String> = external_tensors(&model)?
+            .into_iter()
+            .map(|tensor| tensor.location)
+            .collect();
+        locations.sort_unstable();
+        locations.dedup();
+        let model_dir = model_path.parent().unwrap_or_else(|| Path::new(""));
+        for location in &locations {
+            let path = model_dir.join(location);
+            let cache_error = |source| Error::ModelCache {
+                path: path.clone(),
+                source,
+            };
+            let metadata = fs::metadata(&path).map_err(cache_error)?;
+            let modified = metadata
+                .modified()
+                .map_err(cache_error)?
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            settings.push_str(&format!(
+                "\0{}\0{}\0{}",
+                location,
+                metadata.len(),
+                modified.as_nanos()
+            ));
+        }
+        let file_name = format!(
+            "{:016x}{:016x}-{:016x}.{}",
+            fnv1a(&model),
+            model.len(),
+            fnv1a(settings.as_bytes()),
+            self.format.extension()
+        );
+        // ORT format models keep all initializers
+        let external_initializers = match (self.format, locations.is_empty()) {
+            (OptimizedModelFormat::Onnx, false) => Some(format!("{}.data", file_name)),
+            _ => None,
+        };
+        Ok(CacheEntry {
+            model: self.dir.join(file_name),
+            external_initializers,
+        })
+    }
+
+    fn load_cached(
+        &self,
+        runtime: &Runtime,
+        config: &SessionConfig,
+        cached_path: &Path,
+    ) -> Result<Session> {
+        let mut config = config.clone();
+        config.graph_optimization_level = Some(SessionGraphOptimizationLevel::DisableAll);
+        config.config_entries.insert(
+            SessionConfigKey::LoadModelFormat.key().to_string(),
+            self.format.config_value().to_string(),
+        );
+        SessionBuilder::from_config_with_runtime(runtime, &config)?
+            .build_with_model_from_file(cached_path)
+    }
+}
+
+/// 64-bit FNV-1a, which is stable across Rust versions unlike the hasher of std
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod test {
+    use ors_sys::*;
+
+    use super::*;
+    use crate::mock::{MockBackend, MockModel};
+    use crate::model::{encode, ExternalTensorProto};
+
+    #[test]
+    fn test_model_cache() {
+        // Models are read to find their external data, so they must be valid protobuf
+        let model = String::from_utf8(encode::model(&[])).unwrap();
+        let backend = MockBackend::new();
+        backend.add_model(
+            &model,
+            MockModel::new()
+                .input(
+                    "x",
+                    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+                    &[None],
+                )
+                .output(
+                    "y",
+                    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+                    &[None],
+                ),
+        );
+        let runtime = backend.runtime().unwrap();
+        let dir = std::env::temp_dir().join(format!("ors-model-cache-{}", std::process::id()));
+        let model_path = dir.join("identity.onnx");
+        let cache = ModelCache::new(dir.join("cache"))
+            .unwrap()
+            .format(OptimizedModelFormat::Ort);
+        fs::write(&model_path, &model).unwrap();
+        let config = SessionConfig::default();
+
+        let cached_path = cache
+            .cached_model_path(&runtime, &config, &model_path)
+            .unwrap();
+        assert_eq!(Some("ort".as_ref()), cached_path.extension());
+        cache
+            .load_with_runtime(&runtime, &config, &model_path)
+            .unwrap();
+        assert!(cached_path.exists());
+        assert!(backend.calls().contains(&"SetOptimizedModelFilePath"));
+
+        backend.clear_calls();
+        cache
+            .load_with_runtime(&runtime, &config, &model_path)
+            .unwrap();
+        assert!(!backend.calls().contains(&"SetOptimizedModelFilePath"));
+
+        // Other options are cached separately
+        let config = SessionConfig {
+            intra_op_num_threads: Some(2),
+            ..Default::default()
+        };
+        assert_ne!(
+            cached_path,
+            cache
+                .cached_model_path(&runtime, &config, &model_path)
+                .unwrap()
+        );
+
+        // A broken cached model is replaced
+        fs::write(&cached_path, "broken").unwrap();
+        backend.clear_calls();
+        let config = SessionConfig::default();
+        cache
+            .load_with_runtime(&runtime, &config, &model_path)
+            .unwrap();
+        assert!(backend.calls().contains(&"SetOptimizedModelFilePath"));
+        assert_eq!(model, fs::read_to_string(&cached_path).unwrap());
+
+        let err = cache
+            .load_with_runtime(&runtime, &config, dir.join("missing.onnx"))
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::ModelNotFound(_)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_model_cache_external_data() {
+        let model = encode::model(&[ExternalTensorProto {
+            name: "weight".to_string(),
+            data_type: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+            dims: vec![2],
+            location: "weights.bin".to_string(),
+            offset: 0,
+            length: Some(8),
+        }]);
+        let model = String::from_utf8(model).unwrap();
+        let backend = MockBackend::new();
+        backend.add_model(
+            &model,
+            MockModel::new()
+                .input(
+                    "x",
+                    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+                    &[Some(2)],
+                )
+                .output(
+                    "y",
+                    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+                    &[Some(2)],
+                ),
+        );
+        let runtime = backend.runtime().unwrap();
+        let dir = std::env::temp_dir().join(format!(
+            "ors-model-cache-external-data-{}",
+            std::process::id()
+        ));
+        let model_path = dir.join("weighted.onnx");
+        let weights_path = dir.join("weights.bin");
+        let cache = ModelCache::new(dir.join("cache")).unwrap();
+        fs::write(&model_path, &model).unwrap();
+        let config = SessionConfig::default();
+
+        // External data is required to find the cached model
+        let err = cache
+            .cached_model_path(&runtime, &config, &model_path)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::ModelCache { .. }));
+
+        fs::write(&weights_path, [0u8; 8]).unwrap();
+        let cached_path = cache
+            .cached_model_path(&runtime, &config, &model_path)
+            .unwrap();
+        cache
+            .load_with_runtime(&runtime, &config, &model_path)
+            .unwrap();
+        // Initializers are saved next to the optimized model
+        let mut initializers_path = cached_path.clone().into_os_string();
+        initializers_path.push(".data");
+        assert!(PathBuf::from(initializers_path).exists());
+
+        // Other weights are cached separately
+        fs::write(&weights_path, [0u8; 16]).unwrap();
+        assert_ne!(
+            cached_path,
+            cache
+                .cached_model_path(&runtime, &config, &model_path)
+                .unwrap()
+        );
+        // The ORT format keeps initializers in the model
+        let cache = cache.format(OptimizedModelFormat::Ort);
+        let cached_path = cache
+            .cached_model_path(&runtime, &config, &model_path)
+            .unwrap();
+        cache
+            .load_with_runtime(&runtime, &config, &model_path)
+            .unwrap();
+        let mut initializers_path = cached_path.into_os_string();
+        initializers_path.push(".data");
+        assert!(!PathBuf::from(initializers_path).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,10 +1,22 @@
+use std::collections::BTreeMap;
+#[cfg(feature = "config-file")]
+use std::path::Path;
+
 use ors_sys::*;
 
+use crate::error::{Error, Result};
+use crate::providers::ExecutionProvider;
+
 /// Optimization level performed by ONNX Runtime of the loaded graph
 ///
 /// See the [official documentation](https://github.com/microsoft/onnxruntime/blob/master/docs/ONNX_Runtime_Graph_Optimizations.md)
 /// for more information on the different optimization levels.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 #[cfg_attr(not(windows), repr(u32))]
 #[cfg_attr(windows, repr(i32))]
 pub enum SessionGraphOptimizationLevel {
@@ -29,7 +41,12 @@ impl From<SessionGraphOptimizationLevel> for GraphOptimizationLevel {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 #[cfg_attr(not(windows), repr(u32))]
 #[cfg_attr(windows, repr(i32))]
 pub enum SessionExecutionMode {
@@ -45,3 +62,438 @@ impl From<SessionExecutionMode> for ExecutionMode {
         }
     }
 }
+
+/// Keys of session configuration entries, which are set with `AddSessionConfigEntry`
+///
+/// See `onnxruntime_session_options_config_keys.h` of onnxruntime for details.
+/// Keys which are not listed here can be set with [`SessionBuilder::config_entry`](crate::session::SessionBuilder::config_entry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SessionConfigKey {
+    /// Disable prepacking of constant initializers, `0` or `1`
+    DisablePrepacking,
+    /// Use allocators registered in the environment, `0` or `1`
+    UseEnvAllocators,
+    /// Format of the model to load, `ORT` or `ONNX`
+    LoadModelFormat,
+    /// Format of the optimized model to save, `ORT` or `ONNX`
+    SaveModelFormat,
+    /// Flush denormal floats to zero, `0` or `1`
+    SetDenormalAsZero,
+    /// Disable the quantization of QDQ node groups, `0` or `1`
+    DisableQuantQdq,
+    /// Remove QuantizeLinear/DequantizeLinear pairs which are not fused, `0` or `1`
+    EnableQuantQdqCleanup,
+    /// Use the approximated Gelu, `0` or `1`
+    EnableGeluApproximation,
+    /// Use the bytes of an ORT format model directly instead of copying them, `0` or `1`
+    UseOrtModelBytesDirectly,
+    /// Allow threads of the intra-op thread pool to spin, `0` or `1`
+    IntraOpAllowSpinning,
+    /// Allow threads of the inter-op thread pool to spin, `0` or `1`
+    InterOpAllowSpinning,
+    /// Processor affinities of intra-op threads, like `1,2;3,4`
+    IntraOpThreadAffinities,
+    /// Block size of the dynamic scheduling of the thread pool, a non-negative integer
+    DynamicBlockBase,
+    /// Stop spinning of threads when a run ends, `0` or `1`
+    ForceSpinningStop,
+    /// Fail on shape or type inference errors instead of warning, `0` or `1`
+    StrictShapeTypeInference,
+    /// Allocate initializers with the device allocator instead of an arena, `0` or `1`
+    UseDeviceAllocatorForInitializers,
+    /// Fail if any node can't be assigned to the requested execution providers, `0` or `1`
+    DisableCpuEpFallback,
+    /// Names of optimizers to disable, separated by commas
+    DisableSpecifiedOptimizers,
+    /// File name of initializers saved out of the optimized ONNX model, in the directory of the model
+    OptimizedModelExternalInitializersFileName,
+    /// Initializers smaller than this size in bytes are kept in the optimized ONNX model, a non-negative integer
+    OptimizedModelExternalInitializersMinSizeInBytes,
+}
+
+/// Expected format of the value of a configuration entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigValueKind {
+    Bool,
+    ModelFormat,
+    NonNegativeInteger,
+    Any,
+}
+
+impl ConfigValueKind {
+    fn validate(self, key: &'static str, value: &str) -> Result<()> {
+        let (valid, expected) = match self {
+            ConfigValueKind::Bool => (value == "0" || value == "1", "0 or 1"),
+            ConfigValueKind::ModelFormat => (value == "ORT" || value == "ONNX", "ORT or ONNX"),
+            ConfigValueKind::NonNegativeInteger => {
+                (value.parse::<u64>().is_ok(), "a non-negative integer")
+            }
+            ConfigValueKind::Any => (true, ""),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidConfigValue {
+                key,
+                value: value.to_string(),
+                expected,
+            })
+        }
+    }
+}
+
+impl SessionConfigKey {
+    /// All known keys
+    pub const ALL: [SessionConfigKey; 20] = [
+        SessionConfigKey::DisablePrepacking,
+        SessionConfigKey::UseEnvAllocators,
+        SessionConfigKey::LoadModelFormat,
+        SessionConfigKey::SaveModelFormat,
+        SessionConfigKey::SetDenormalAsZero,
+        SessionConfigKey::DisableQuantQdq,
+        SessionConfigKey::EnableQuantQdqCleanup,
+        SessionConfigKey::EnableGeluApproximation,
+        SessionConfigKey::UseOrtModelBytesDirectly,
+        SessionConfigKey::IntraOpAllowSpinning,
+        SessionConfigKey::InterOpAllowSpinning,
+        SessionConfigKey::IntraOpThreadAffinities,
+        SessionConfigKey::DynamicBlockBase,
+        SessionConfigKey::ForceSpinningStop,
+        SessionConfigKey::StrictShapeTypeInference,
+        SessionConfigKey::UseDeviceAllocatorForInitializers,
+        SessionConfigKey::DisableCpuEpFallback,
+        SessionConfigKey::DisableSpecifiedOptimizers,
+        SessionConfigKey::OptimizedModelExternalInitializersFileName,
+        SessionConfigKey::OptimizedModelExternalInitializersMinSizeInBytes,
+    ];
+
+    /// Find the key by its string used by onnxruntime
+    pub fn from_key(key: &str) -> Option<SessionConfigKey> {
+        Self::ALL.iter().copied().find(|k| k.key() == key)
+    }
+
+    /// The key string used by onnxruntime
+    pub fn key(&self) -> &'static str {
+        match self {
+            SessionConfigKey::DisablePrepacking => "session.disable_prepacking",
+            SessionConfigKey::UseEnvAllocators => "session.use_env_allocators",
+            SessionConfigKey::LoadModelFormat => "session.load_model_format",
+            SessionConfigKey::SaveModelFormat => "session.save_model_format",
+            SessionConfigKey::SetDenormalAsZero => "session.set_denormal_as_zero",
+            SessionConfigKey::DisableQuantQdq => "session.disable_quant_qdq",
+            SessionConfigKey::EnableQuantQdqCleanup => "session.enable_quant_qdq_cleanup",
+            SessionConfigKey::EnableGeluApproximation => "optimization.enable_gelu_approximation",
+            SessionConfigKey::UseOrtModelBytesDirectly => "session.use_ort_model_bytes_directly",
+            SessionConfigKey::IntraOpAllowSpinning => "session.intra_op.allow_spinning",
+            SessionConfigKey::InterOpAllowSpinning => "session.inter_op.allow_spinning",
+            SessionConfigKey::IntraOpThreadAffinities => "session.intra_op_thread_affinities",
+            SessionConfigKey::DynamicBlockBase => "session.dynamic_block_base",
+            SessionConfigKey::ForceSpinningStop => "session.force_spinning_stop",
+            SessionConfigKey::StrictShapeTypeInference => "session.strict_shape_type_inference",
+            SessionConfigKey::UseDeviceAllocatorForInitializers => {
+                "session.use_device_allocator_for_initializers"
+            }
+            SessionConfigKey::DisableCpuEpFallback => "session.disable_cpu_ep_fallback",
+            SessionConfigKey::DisableSpecifiedOptimizers => {
+                "optimization.disable_specified_optimizers"
+            }
+            SessionConfigKey::OptimizedModelExternalInitializersFileName => {
+                "session.optimized_model_external_initializers_file_name"
+            }
+            SessionConfigKey::OptimizedModelExternalInitializersMinSizeInBytes => {
+                "session.optimized_model_external_initializers_min_size_in_bytes"
+            }
+        }
+    }
+
+    fn value_kind(&self) -> ConfigValueKind {
+        match self {
+            SessionConfigKey::LoadModelFormat | SessionConfigKey::SaveModelFormat => {
+                ConfigValueKind::ModelFormat
+            }
+            SessionConfigKey::DynamicBlockBase
+            | SessionConfigKey::OptimizedModelExternalInitializersMinSizeInBytes => {
+                ConfigValueKind::NonNegativeInteger
+            }
+            SessionConfigKey::IntraOpThreadAffinities
+            | SessionConfigKey::DisableSpecifiedOptimizers
+            | SessionConfigKey::OptimizedModelExternalInitializersFileName => ConfigValueKind::Any,
+            _ => ConfigValueKind::Bool,
+        }
+    }
+
+    /// Check whether the value is valid for this key
+    pub fn validate(&self, value: &str) -> Result<()> {
+        self.value_kind().validate(self.key(), value)
+    }
+}
+
+/// Options of sessions as plain data, which can be cloned, inspected and loaded from config files
+///
+/// Fields which are `None` keep the defaults of onnxruntime.
+/// Create a [`SessionBuilder`](crate::session::SessionBuilder) with it by
+/// [`SessionBuilder::from_config`](crate::session::SessionBuilder::from_config), or a
+/// [`SessionTemplate`](crate::session::SessionTemplate) to build the options once for many sessions.
+///
+/// ## Example
+/// A TOML file loaded by `SessionConfig::from_file` with the `config-file` feature:
+/// ```toml
+/// graph_optimization_level = "all"
+/// execution_mode = "sequential"
+/// intra_op_num_threads = 4
+/// cpu_mem_arena = false
+///
+/// [config_entries]
+/// "session.intra_op.allow_spinning" = "0"
+///
+/// [[providers]]
+/// name = "XNNPACK"
+/// options = { intra_op_num_threads = "2" }
+///
+/// [[providers]]
+/// name = "CPU"
+/// options = { use_arena = "0" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct SessionConfig {
+    pub graph_optimization_level: Option<SessionGraphOptimizationLevel>,
+    pub execution_mode: Option<SessionExecutionMode>,
+    pub intra_op_num_threads: Option<i32>,
+    pub inter_op_num_threads: Option<i32>,
+    /// Enable or disable the memory arena on CPU
+    pub cpu_mem_arena: Option<bool>,
+    /// Enable or disable the memory pattern optimization
+    pub mem_pattern: Option<bool>,
+    /// Session configuration entries, values of keys in [`SessionConfigKey`] are validated
+    pub config_entries: BTreeMap<String, String>,
+    /// Execution providers in the order of preference
+    pub providers: Vec<ProviderConfig>,
+    /// Fall back to the CPU provider when a provider is not available or fails to initialize
+    pub cpu_fallback: Option<bool>,
+}
+
+/// An execution provider, which is converted to an [`ExecutionProvider`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct ProviderConfig {
+    /// Name of the provider, like `XNNPACK` or `CPU`
+    pub name: String,
+    /// Provider specific options
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub options: BTreeMap<String, String>,
+}
+
+impl ProviderConfig {
+    /// Find the provider by its name, returning [`Error::UnknownProvider`] if it's not known
+    pub fn execution_provider(&self) -> Result<ExecutionProvider> {
+        let provider =
+            ExecutionProvider::from_name(&self.name, self.options.clone()).ok_or_else(|| {
+                Error::UnknownProvider {
+                    name: self.name.clone(),
+                }
+            })?;
+        if let ExecutionProvider::Cpu(options) = &provider {
+            ExecutionProvider::cpu_arena_option(options)?;
+        }
+        Ok(provider)
+    }
+}
+
+impl SessionConfig {
+    /// Check the values of known configuration entries and the execution providers
+    pub fn validate(&self) -> Result<()> {
+        for (key, value) in &self.config_entries {
+            if let Some(key) = SessionConfigKey::from_key(key) {
+                key.validate(value)?;
+            }
+        }
+        for provider in &self.providers {
+            provider.execution_provider()?;
+        }
+        Ok(())
+    }
+
+    /// Parse the config from TOML
+    #[cfg(feature = "config-file")]
+    pub fn from_toml(s: &str) -> Result<SessionConfig> {
+        let config: SessionConfig =
+            toml::from_str(s).map_err(|e| Error::InvalidConfigFile(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse the config from JSON
+    #[cfg(feature = "config-file")]
+    pub fn from_json(s: &str) -> Result<SessionConfig> {
+        let config: SessionConfig =
+            serde_json::from_str(s).map_err(|e| Error::InvalidConfigFile(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Load the config from a `.json` file, or a TOML file with any other extension
+    #[cfg(feature = "config-file")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SessionConfig> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidConfigFile(format!("{}: {}", path.display(), e)))?;
+        match path.extension() {
+            Some(ext) if ext == "json" => Self::from_json(&content),
+            _ => Self::from_toml(&content),
+        }
+    }
+}
+
+/// Keys of run configuration entries, which are set with `AddRunConfigEntry`
+///
+/// See `onnxruntime_run_options_config_keys.h` of onnxruntime for details.
+/// Keys which are not listed here can be set with [`RunOptions::config_entry`](crate::session::RunOptions::config_entry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunConfigKey {
+    /// Shrink memory arenas of the given devices after the run, like `cpu:0;gpu:0`
+    EnableMemoryArenaShrinkage,
+}
+
+impl RunConfigKey {
+    /// The key string used by onnxruntime
+    pub fn key(&self) -> &'static str {
+        match self {
+            RunConfigKey::EnableMemoryArenaShrinkage => "memory.enable_memory_arena_shrinkage",
+        }
+    }
+
+    /// Check whether the value is valid for this key
+    pub fn validate(&self, value: &str) -> Result<()> {
+        match self {
+            RunConfigKey::EnableMemoryArenaShrinkage => {
+                ConfigValueKind::Any.validate(self.key(), value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session_config_key() {
+        assert_eq!(
+            "session.intra_op.allow_spinning",
+            SessionConfigKey::IntraOpAllowSpinning.key()
+        );
+        SessionConfigKey::IntraOpAllowSpinning
+            .validate("0")
+            .unwrap();
+        SessionConfigKey::LoadModelFormat.validate("ORT").unwrap();
+        SessionConfigKey::DynamicBlockBase.validate("4").unwrap();
+        SessionConfigKey::IntraOpThreadAffinities
+            .validate("1,2;3,4")
+            .unwrap();
+
+        let err = SessionConfigKey::DisablePrepacking
+            .validate("true")
+            .err()
+            .unwrap();
+        assert!(err.is_bad_request());
+        assert_eq!(
+            "Invalid value true of config entry session.disable_prepacking, expected 0 or 1",
+            err.to_string()
+        );
+        assert!(SessionConfigKey::DynamicBlockBase.validate("-1").is_err());
+        assert!(SessionConfigKey::SaveModelFormat.validate("onnx").is_err());
+
+        for key in SessionConfigKey::ALL {
+            assert_eq!(Some(key), SessionConfigKey::from_key(key.key()));
+        }
+        assert_eq!(None, SessionConfigKey::from_key("session.unknown"));
+    }
+
+    #[test]
+    fn test_session_config_validate() {
+        let mut config = SessionConfig::default();
+        config
+            .config_entries
+            .insert("session.unknown".to_string(), "anything".to_string());
+        config.validate().unwrap();
+        config
+            .config_entries
+            .insert("session.disable_prepacking".to_string(), "yes".to_string());
+        assert!(config.validate().is_err());
+
+        let mut config = SessionConfig {
+            providers: vec![ProviderConfig {
+                name: "CPU".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        config.providers[0]
+            .options
+            .insert("use_arena".to_string(), "0".to_string());
+        assert_eq!(
+            ExecutionProvider::cpu().option("use_arena", "0"),
+            config.providers[0].execution_provider().unwrap()
+        );
+        config.validate().unwrap();
+        config.providers[0]
+            .options
+            .insert("use_arena".to_string(), "no".to_string());
+        assert!(config.validate().unwrap_err().is_bad_request());
+        config.providers[0].name = "CUDA".to_string();
+        assert!(matches!(
+            config.validate(),
+            Err(Error::UnknownProvider { name }) if name == "CUDA"
+        ));
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn test_session_config_from_file() {
+        let toml = r#"
+            graph_optimization_level = "extended"
+            execution_mode = "parallel"
+            intra_op_num_threads = 2
+            mem_pattern = false
+
+            [config_entries]
+            "session.intra_op.allow_spinning" = "0"
+
+            [[providers]]
+            name = "XNNPACK"
+            options = { intra_op_num_threads = "2" }
+        "#;
+        let config = SessionConfig::from_toml(toml).unwrap();
+        assert_eq!(
+            Some(SessionGraphOptimizationLevel::Extended),
+            config.graph_optimization_level
+        );
+        assert_eq!(Some(SessionExecutionMode::Parallel), config.execution_mode);
+        assert_eq!(Some(2), config.intra_op_num_threads);
+        assert_eq!(None, config.inter_op_num_threads);
+        assert_eq!(Some(false), config.mem_pattern);
+        assert_eq!("XNNPACK", config.providers[0].name);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(config, SessionConfig::from_json(&json).unwrap());
+
+        let err = SessionConfig::from_toml("intra_op_threads = 2")
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::InvalidConfigFile(_)));
+        let err =
+            SessionConfig::from_json(r#"{"config_entries": {"session.load_model_format": "PB"}}"#)
+                .err()
+                .unwrap();
+        assert!(matches!(err, Error::InvalidConfigValue { .. }));
+    }
+}
@@ -0,0 +1,914 @@
+//! Operators implemented in Rust
+//!
+//! A [`CustomOp`] declares the op type, its inputs and outputs, and computes its outputs from a [`KernelContext`].
+//! Ops are grouped into a [`CustomOpDomain`], which is registered into sessions by
+//! [`SessionBuilder::with_custom_op_domain`](crate::session::SessionBuilder::with_custom_op_domain).
+//! Ops built as shared libraries, like onnxruntime-extensions, are loaded by
+//! [`SessionBuilder::register_custom_ops_library`](crate::session::SessionBuilder::register_custom_ops_library).
+//!
+//! ## Example
+//! ```no_run
+//! use ors::custom_op::{CustomOp, CustomOpDomain, KernelContext};
+//! use ors::session::SessionBuilder;
+//! use ors_sys::*;
+//!
+//! struct Double;
+//!
+//! impl CustomOp for Double {
+//!     fn name(&self) -> &str {
+//!         "Double"
+//!     }
+//!
+//!     fn input_types(&self) -> Vec<ONNXTensorElementDataType> {
+//!         vec![ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT]
+//!     }
+//!
+//!     fn output_types(&self) -> Vec<ONNXTensorElementDataType> {
+//!         vec![ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT]
+//!     }
+//!
+//!     fn compute(&self, context: &KernelContext) -> ors::Result<()> {
+//!         let x = context.input::<f32>(0)?;
+//!         let mut y = context.output::<f32>(0, x.shape())?;
+//!         y.zip_mut_with(&x, |y, x| *y = x * 2.);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let domain = CustomOpDomain::new("com.example").add_op(Double);
+//! let session = SessionBuilder::new()
+//!     .unwrap()
+//!     .with_custom_op_domain(domain)
+//!     .unwrap()
+//!     .build_with_model_from_file("double.onnx")
+//!     .unwrap();
+//! ```
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr, CString};
+use std::fmt::Debug;
+use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::ptr::null_mut;
+use std::sync::Arc;
+
+use ndarray::{ArrayViewD, ArrayViewMutD, IxDyn};
+use ors_sys::*;
+use tracing::{debug, error, warn};
+
+use crate::call_ort;
+use crate::error::{Error, Result};
+use crate::runtime::Runtime;
+use crate::session::io::{get_dimension_count, get_dimensions, get_tensor_element_type};
+use crate::status::check_status;
+use crate::types::TypeToTensorElementDataType;
+
+/// An operator implemented in Rust
+///
+/// `compute` is called concurrently by the threads of onnxruntime, once per run of every node of the op type.
+/// onnxruntime 1.12 can't receive errors from custom ops: an error returned by `compute` is logged,
+/// and the outputs keep whatever was written to them.
+pub trait CustomOp: Send + Sync + 'static {
+    /// Op type used by nodes of the model
+    fn name(&self) -> &str;
+
+    /// Element types of the inputs
+    fn input_types(&self) -> Vec<ONNXTensorElementDataType>;
+
+    /// Element types of the outputs
+    fn output_types(&self) -> Vec<ONNXTensorElementDataType>;
+
+    /// Attributes of the node which are read when the kernel is created, see [`KernelContext::attribute`]
+    fn attributes(&self) -> Vec<(&'static str, AttributeKind)> {
+        vec![]
+    }
+
+    /// Execution provider which runs the op, `None` for the CPU
+    fn execution_provider(&self) -> Option<&str> {
+        None
+    }
+
+    /// Compute the outputs of a node
+    fn compute(&self, context: &KernelContext) -> Result<()>;
+}
+
+/// Type of a node attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeKind {
+    Int,
+    Float,
+    String,
+    Ints,
+    Floats,
+}
+
+/// Value of a node attribute
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    Int(i64),
+    Float(f32),
+    String(String),
+    Ints(Vec<i64>),
+    Floats(Vec<f32>),
+}
+
+/// A domain of custom ops, like `ai.onnx.contrib`
+#[derive(Clone)]
+pub struct CustomOpDomain {
+    name: String,
+    ops: Vec<Arc<dyn CustomOp>>,
+}
+
+impl CustomOpDomain {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ops: vec![],
+        }
+    }
+
+    /// Add an op to the domain
+    pub fn add_op<O: CustomOp>(mut self, op: O) -> Self {
+        self.ops.push(Arc::new(op));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Debug for CustomOpDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomOpDomain")
+            .field("name", &self.name)
+            .field(
+                "ops",
+                &self.ops.iter().map(|op| op.name()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// The `OrtCustomOp` of a Rust op, `ort_op` must be the first field so that onnxruntime's pointer can be cast back
+#[repr(C)]
+struct OpWrapper {
+    ort_op: OrtCustomOp,
+    op: Arc<dyn CustomOp>,
+    name: CString,
+    provider: Option<CString>,
+    input_types: Vec<ONNXTensorElementDataType>,
+    output_types: Vec<ONNXTensorElementDataType>,
+    attributes: Vec<(CString, AttributeKind)>,
+}
+
+impl OpWrapper {
+    /// Wrap the op for a runtime, whose negotiated API version is the version of the op
+    ///
+    /// onnxruntime rejects ops newer than itself, even if the bindings are newer.
+    fn new(op: Arc<dyn CustomOp>, api_version: u32) -> Result<Box<Self>> {
+        let name = CString::new(op.name())?;
+        let provider = op.execution_provider().map(CString::new).transpose()?;
+        let attributes = op
+            .attributes()
+            .into_iter()
+            .map(|(name, kind)| Ok((CString::new(name)?, kind)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Box::new(OpWrapper {
+            ort_op: OrtCustomOp {
+                version: api_version,
+                CreateKernel: Some(create_kernel),
+                GetName: Some(get_name),
+                GetExecutionProviderType: Some(get_execution_provider_type),
+                GetInputType: Some(get_input_type),
+                GetInputTypeCount: Some(get_input_type_count),
+                GetOutputType: Some(get_output_type),
+                GetOutputTypeCount: Some(get_output_type_count),
+                KernelCompute: Some(kernel_compute),
+                KernelDestroy: Some(kernel_destroy),
+                GetInputCharacteristic: Some(get_input_characteristic),
+                GetOutputCharacteristic: Some(get_output_characteristic),
+            },
+            input_types: op.input_types(),
+            output_types: op.output_types(),
+            op,
+            name,
+            provider,
+            attributes,
+        }))
+    }
+
+    unsafe fn from_ort<'a>(op: *const OrtCustomOp) -> &'a OpWrapper {
+        &*(op as *const OpWrapper)
+    }
+}
+
+/// A `CustomOpDomain` registered into session options
+///
+/// onnxruntime keeps pointers to the domain and its ops, so it's kept alive by the session options and
+/// every session created from them.
+struct RegisteredDomain {
+    runtime: Runtime,
+    ptr: *mut OrtCustomOpDomain,
+    // onnxruntime keeps pointers to the ops, which must not move
+    #[allow(clippy::vec_box)]
+    ops: Vec<Box<OpWrapper>>,
+}
+
+// The ops are `Send + Sync`, the domain is only read by onnxruntime after it's created
+unsafe impl Send for RegisteredDomain {}
+unsafe impl Sync for RegisteredDomain {}
+
+impl RegisteredDomain {
+    fn new(runtime: &Runtime, domain: &CustomOpDomain) -> Result<Self> {
+        let c_name = CString::new(domain.name.as_str())?;
+        let mut ptr: *mut OrtCustomOpDomain = null_mut();
+        let status = call_ort!(runtime => CreateCustomOpDomain, c_name.as_ptr(), &mut ptr)?;
+        check_status(runtime, status, "CreateCustomOpDomain")?;
+        // Released by Drop if adding an op fails
+        let mut registered = RegisteredDomain {
+            runtime: runtime.clone(),
+            ptr,
+            ops: vec![],
+        };
+        for op in &domain.ops {
+            let wrapper = OpWrapper::new(op.clone(), runtime.api_version())?;
+            let status = call_ort!(runtime => CustomOpDomain_Add, ptr, &wrapper.ort_op)?;
+            check_status(runtime, status, "CustomOpDomain_Add")?;
+            registered.ops.push(wrapper);
+        }
+        Ok(registered)
+    }
+}
+
+impl Debug for RegisteredDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredDomain").finish_non_exhaustive()
+    }
+}
+
+impl Drop for RegisteredDomain {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            if let Err(e) = call_ort!(self.runtime => ReleaseCustomOpDomain, self.ptr) {
+                warn!("Failed to release custom op domain: {}", e);
+            }
+        }
+        self.ptr = null_mut();
+    }
+}
+
+/// Custom ops registered into session options
+///
+/// They are kept alive by the session options and every session created from them,
+/// because onnxruntime keeps pointers to the ops until the sessions are released.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CustomOps {
+    domains: Vec<Arc<RegisteredDomain>>,
+    libraries: Vec<Arc<CustomOpsLibrary>>,
+}
+
+impl CustomOps {
+    pub(crate) fn add_domain(
+        &mut self,
+        runtime: &Runtime,
+        options: *mut OrtSessionOptions,
+        domain: &CustomOpDomain,
+    ) -> Result<()> {
+        let registered = RegisteredDomain::new(runtime, domain)?;
+        let status = call_ort!(runtime => AddCustomOpDomain, options, registered.ptr)?;
+        check_status(runtime, status, "AddCustomOpDomain")?;
+        self.domains.push(Arc::new(registered));
+        Ok(())
+    }
+
+    pub(crate) fn add_library(
+        &mut self,
+        runtime: &Runtime,
+        options: *mut OrtSessionOptions,
+        path: &Path,
+    ) -> Result<()> {
+        let library = CustomOpsLibrary::register(runtime, options, path)?;
+        self.libraries.push(Arc::new(library));
+        Ok(())
+    }
+}
+
+/// Signature of the entry point of custom ops libraries
+pub(crate) type RegisterCustomOpsFn =
+    unsafe extern "C" fn(options: *mut OrtSessionOptions, api: *const OrtApiBase) -> OrtStatusPtr;
+
+/// Name of the entry point of custom ops libraries
+pub(crate) const REGISTER_CUSTOM_OPS: &[u8] = b"RegisterCustomOps\0";
+
+/// A shared library loaded by `RegisterCustomOpsLibrary`, which is unloaded when it's dropped
+struct CustomOpsLibrary {
+    path: PathBuf,
+    library: Option<libloading::Library>,
+}
+
+impl CustomOpsLibrary {
+    fn register(runtime: &Runtime, options: *mut OrtSessionOptions, path: &Path) -> Result<Self> {
+        let c_path = CString::new(path_bytes(path))?;
+        let mut handle: *mut c_void = null_mut();
+        let status = call_ort!(
+            runtime => RegisterCustomOpsLibrary,
+            options,
+            c_path.as_ptr(),
+            &mut handle
+        )?;
+        let result = check_status(runtime, status, "RegisterCustomOpsLibrary");
+        // onnxruntime leaves the library loaded if registering fails, it's unloaded by Drop
+        let library = CustomOpsLibrary {
+            path: path.to_path_buf(),
+            library: (!handle.is_null()).then(|| unsafe { library_from_raw(handle) }),
+        };
+        let error = match result {
+            Ok(()) => {
+                debug!("Registered custom ops library {}", path.display());
+                return Ok(library);
+            }
+            Err(Error::Ort { message, .. }) => message,
+            Err(e) => return Err(e),
+        };
+        let message = match &library.library {
+            None => format!("the library cannot be loaded: {}", error),
+            Some(lib) => match unsafe { lib.get::<RegisterCustomOpsFn>(REGISTER_CUSTOM_OPS) } {
+                Err(_) => "the library doesn't export RegisterCustomOps".to_string(),
+                Ok(_) => format!("RegisterCustomOps failed: {}", error),
+            },
+        };
+        Err(Error::CustomOpsLibrary {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+}
+
+impl Debug for CustomOpsLibrary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomOpsLibrary")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for CustomOpsLibrary {
+    fn drop(&mut self) {
+        if let Some(library) = self.library.take() {
+            debug!("Unloading custom ops library {}", self.path.display());
+            if let Err(e) = library.close() {
+                warn!("Failed to unload custom ops library: {}", e);
+            }
+        }
+    }
+}
+
+/// Path of a library in the encoding of `RegisterCustomOpsLibrary`
+#[cfg(not(target_family = "windows"))]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(target_family = "windows")]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().into_owned().into_bytes()
+}
+
+/// Take the ownership of a handle returned by `dlopen` or `LoadLibrary`
+#[cfg(not(target_family = "windows"))]
+pub(crate) unsafe fn library_from_raw(handle: *mut c_void) -> libloading::Library {
+    libloading::os::unix::Library::from_raw(handle).into()
+}
+
+#[cfg(target_family = "windows")]
+pub(crate) unsafe fn library_from_raw(handle: *mut c_void) -> libloading::Library {
+    libloading::os::windows::Library::from_raw(handle as _).into()
+}
+
+/// Release the ownership of a library as a raw handle
+#[cfg(not(target_family = "windows"))]
+pub(crate) fn library_into_raw(library: libloading::Library) -> *mut c_void {
+    libloading::os::unix::Library::from(library).into_raw()
+}
+
+#[cfg(target_family = "windows")]
+pub(crate) fn library_into_raw(library: libloading::Library) -> *mut c_void {
+    libloading::os::windows::Library::from(library).into_raw() as *mut c_void
+}
+
+/// Attributes of a node read from `OrtKernelInfo`
+struct KernelInfo<'a> {
+    api: &'a OrtApi,
+    ptr: *const OrtKernelInfo,
+}
+
+impl KernelInfo<'_> {
+    fn attribute(&self, name: &CStr, kind: AttributeKind) -> Result<AttributeValue> {
+        match kind {
+            AttributeKind::Int => {
+                let mut value = 0;
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttribute_int64,
+                    self.ptr,
+                    name.as_ptr(),
+                    &mut value
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttribute_int64")?;
+                Ok(AttributeValue::Int(value))
+            }
+            AttributeKind::Float => {
+                let mut value = 0.;
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttribute_float,
+                    self.ptr,
+                    name.as_ptr(),
+                    &mut value
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttribute_float")?;
+                Ok(AttributeValue::Float(value))
+            }
+            AttributeKind::String => {
+                // Get the size first, which includes the nul byte
+                let mut size = 0;
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttribute_string,
+                    self.ptr,
+                    name.as_ptr(),
+                    null_mut(),
+                    &mut size
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttribute_string")?;
+                let mut buffer = vec![0u8; size];
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttribute_string,
+                    self.ptr,
+                    name.as_ptr(),
+                    buffer.as_mut_ptr() as *mut c_char,
+                    &mut size
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttribute_string")?;
+                buffer.truncate(size.saturating_sub(1));
+                Ok(AttributeValue::String(
+                    String::from_utf8_lossy(&buffer).to_string(),
+                ))
+            }
+            AttributeKind::Ints => {
+                let mut size = 0;
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttributeArray_int64,
+                    self.ptr,
+                    name.as_ptr(),
+                    null_mut(),
+                    &mut size
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttributeArray_int64")?;
+                let mut values = vec![0; size];
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttributeArray_int64,
+                    self.ptr,
+                    name.as_ptr(),
+                    values.as_mut_ptr(),
+                    &mut size
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttributeArray_int64")?;
+                values.truncate(size);
+                Ok(AttributeValue::Ints(values))
+            }
+            AttributeKind::Floats => {
+                let mut size = 0;
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttributeArray_float,
+                    self.ptr,
+                    name.as_ptr(),
+                    null_mut(),
+                    &mut size
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttributeArray_float")?;
+                let mut values = vec![0.; size];
+                let status = call_ort!(
+                    self.api => KernelInfoGetAttributeArray_float,
+                    self.ptr,
+                    name.as_ptr(),
+                    values.as_mut_ptr(),
+                    &mut size
+                )?;
+                check_status(self.api, status, "KernelInfoGetAttributeArray_float")?;
+                values.truncate(size);
+                Ok(AttributeValue::Floats(values))
+            }
+        }
+    }
+}
+
+/// The kernel of a node, created by onnxruntime for every node of a custom op
+struct Kernel {
+    api: *const OrtApi,
+    op: Arc<dyn CustomOp>,
+    attributes: HashMap<String, AttributeValue>,
+}
+
+/// Inputs, outputs and attributes of a node being computed
+pub struct KernelContext<'a> {
+    api: &'a OrtApi,
+    ptr: *mut OrtKernelContext,
+    op_name: &'a str,
+    attributes: &'a HashMap<String, AttributeValue>,
+    // Outputs which are borrowed mutably, each output can be borrowed once
+    outputs: RefCell<Vec<usize>>,
+}
+
+impl<'a> KernelContext<'a> {
+    pub fn input_count(&self) -> Result<usize> {
+        let mut count = 0;
+        let status = call_ort!(self.api => KernelContext_GetInputCount, self.ptr, &mut count)?;
+        check_status(self.api, status, "KernelContext_GetInputCount")?;
+        Ok(count)
+    }
+
+    pub fn output_count(&self) -> Result<usize> {
+        let mut count = 0;
+        let status = call_ort!(self.api => KernelContext_GetOutputCount, self.ptr, &mut count)?;
+        check_status(self.api, status, "KernelContext_GetOutputCount")?;
+        Ok(count)
+    }
+
+    /// Value of an attribute declared by [`CustomOp::attributes`], `None` if the node doesn't set it
+    pub fn attribute(&self, name: &str) -> Option<&'a AttributeValue> {
+        self.attributes.get(name)
+    }
+
+    /// View an input, `T` must match its element type
+    pub fn input<T: TypeToTensorElementDataType>(&self, index: usize) -> Result<ArrayViewD<'a, T>> {
+        let mut value: *const OrtValue = std::ptr::null();
+        let status = call_ort!(self.api => KernelContext_GetInput, self.ptr, index, &mut value)?;
+        check_status(self.api, status, "KernelContext_GetInput")?;
+        if value.is_null() {
+            return Err(self.error(format!("input {} is missing", index)));
+        }
+        let shape = self.tensor_shape::<T>(value, "input", index)?;
+        let data = self.tensor_data(value as *mut OrtValue)?;
+        // The input is owned by onnxruntime and isn't changed while the node is computed
+        Ok(unsafe { ArrayViewD::from_shape_ptr(IxDyn(&shape), data as *const T) })
+    }
+
+    /// Allocate an output with the shape and view it mutably, `T` must match its element type
+    ///
+    /// Each output can only be allocated once.
+    pub fn output<T: TypeToTensorElementDataType>(
+        &self,
+        index: usize,
+        shape: &[usize],
+    ) -> Result<ArrayViewMutD<'a, T>> {
+        if self.outputs.borrow().contains(&index) {
+            return Err(self.error(format!("output {} is already allocated", index)));
+        }
+        let dims: Vec<i64> = shape.iter().map(|d| *d as i64).collect();
+        let mut value: *mut OrtValue = null_mut();
+        let status = call_ort!(
+            self.api => KernelContext_GetOutput,
+            self.ptr,
+            index,
+            dims.as_ptr(),
+            dims.len(),
+            &mut value
+        )?;
+        check_status(self.api, status, "KernelContext_GetOutput")?;
+        if value.is_null() {
+            return Err(self.error(format!("output {} can't be allocated", index)));
+        }
+        self.tensor_shape::<T>(value, "output", index)?;
+        let data = self.tensor_data(value)?;
+        self.outputs.borrow_mut().push(index);
+        // The output is only borrowed once
+        Ok(unsafe { ArrayViewMutD::from_shape_ptr(IxDyn(shape), data as *mut T) })
+    }
+
+    /// Check the element type of a tensor and get its shape
+    fn tensor_shape<T: TypeToTensorElementDataType>(
+        &self,
+        value: *const OrtValue,
+        kind: &str,
+        index: usize,
+    ) -> Result<Vec<usize>> {
+        let mut info: *mut OrtTensorTypeAndShapeInfo = null_mut();
+        let status = call_ort!(self.api => GetTensorTypeAndShape, value, &mut info)?;
+        check_status(self.api, status, "GetTensorTypeAndShape")?;
+        let shape = get_tensor_element_type(self.api, info).and_then(|element_type| {
+            let dims = get_dimensions(self.api, info, get_dimension_count(self.api, info)?)?;
+            Ok((element_type, dims))
+        });
+        call_ort!(self.api => ReleaseTensorTypeAndShapeInfo, info)?;
+        let (element_type, dims) = shape?;
+        let expected = T::tensor_element_data_type();
+        if element_type != expected
+            || expected == ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING
+        {
+            return Err(self.error(format!(
+                "{} {} has element type {}, but it's read as {}",
+                kind, index, element_type, expected
+            )));
+        }
+        Ok(dims.into_iter().map(|d| d.max(0) as usize).collect())
+    }
+
+    fn tensor_data(&self, value: *mut OrtValue) -> Result<*mut c_void> {
+        let mut data: *mut c_void = null_mut();
+        let status = call_ort!(self.api => GetTensorMutableData, value, &mut data)?;
+        check_status(self.api, status, "GetTensorMutableData")?;
+        // Views of empty tensors need an aligned non-null pointer
+        if data.is_null() {
+            data = std::ptr::NonNull::<u64>::dangling().as_ptr() as *mut c_void;
+        }
+        Ok(data)
+    }
+
+    fn error(&self, message: String) -> Error {
+        Error::CustomOp {
+            op: self.op_name.to_string(),
+            message,
+        }
+    }
+}
+
+unsafe extern "C" fn create_kernel(
+    op: *const OrtCustomOp,
+    api: *const OrtApi,
+    info: *const OrtKernelInfo,
+) -> *mut c_void {
+    let wrapper = OpWrapper::from_ort(op);
+    let mut attributes = HashMap::new();
+    if let Some(api_ref) = api.as_ref() {
+        let info = KernelInfo {
+            api: api_ref,
+            ptr: info,
+        };
+        for (name, kind) in &wrapper.attributes {
+            // Attributes which are not set by the node are left out
+            if let Ok(value) = info.attribute(name, *kind) {
+                attributes.insert(name.to_string_lossy().to_string(), value);
+            }
+        }
+    }
+    let kernel = Kernel {
+        api,
+        op: wrapper.op.clone(),
+        attributes,
+    };
+    Box::into_raw(Box::new(kernel)) as *mut c_void
+}
+
+unsafe extern "C" fn get_name(op: *const OrtCustomOp) -> *const c_char {
+    OpWrapper::from_ort(op).name.as_ptr()
+}
+
+unsafe extern "C" fn get_execution_provider_type(op: *const OrtCustomOp) -> *const c_char {
+    match &OpWrapper::from_ort(op).provider {
+        Some(provider) => provider.as_ptr(),
+        None => std::ptr::null(),
+    }
+}
+
+unsafe extern "C" fn get_input_type(
+    op: *const OrtCustomOp,
+    index: usize,
+) -> ONNXTensorElementDataType {
+    let types = &OpWrapper::from_ort(op).input_types;
+    types
+        .get(index)
+        .copied()
+        .unwrap_or(ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED)
+}
+
+unsafe extern "C" fn get_input_type_count(op: *const OrtCustomOp) -> usize {
+    OpWrapper::from_ort(op).input_types.len()
+}
+
+unsafe extern "C" fn get_output_type(
+    op: *const OrtCustomOp,
+    index: usize,
+) -> ONNXTensorElementDataType {
+    let types = &OpWrapper::from_ort(op).output_types;
+    types
+        .get(index)
+        .copied()
+        .unwrap_or(ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED)
+}
+
+unsafe extern "C" fn get_output_type_count(op: *const OrtCustomOp) -> usize {
+    OpWrapper::from_ort(op).output_types.len()
+}
+
+unsafe extern "C" fn get_input_characteristic(
+    _op: *const OrtCustomOp,
+    _index: usize,
+) -> OrtCustomOpInputOutputCharacteristic {
+    OrtCustomOpInputOutputCharacteristic_INPUT_OUTPUT_REQUIRED
+}
+
+unsafe extern "C" fn get_output_characteristic(
+    _op: *const OrtCustomOp,
+    _index: usize,
+) -> OrtCustomOpInputOutputCharacteristic {
+    OrtCustomOpInputOutputCharacteristic_INPUT_OUTPUT_REQUIRED
+}
+
+unsafe extern "C" fn kernel_compute(op_kernel: *mut c_void, context: *mut OrtKernelContext) {
+    let kernel = match (op_kernel as *const Kernel).as_ref() {
+        Some(kernel) => kernel,
+        None => return,
+    };
+    let api = match kernel.api.as_ref() {
+        Some(api) => api,
+        None => return,
+    };
+    let op_name = kernel.op.name();
+    let context = KernelContext {
+        api,
+        ptr: context,
+        op_name,
+        attributes: &kernel.attributes,
+        outputs: RefCell::new(vec![]),
+    };
+    // Panics must not unwind into onnxruntime
+    match catch_unwind(AssertUnwindSafe(|| kernel.op.compute(&context))) {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => error!("Failed to compute {}: {}", op_name, e),
+        Err(_) => error!("Custom op {} panicked", op_name),
+    }
+}
+
+unsafe extern "C" fn kernel_destroy(op_kernel: *mut c_void) {
+    if !op_kernel.is_null() {
+        drop(Box::from_raw(op_kernel as *mut Kernel));
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod test {
+    use ndarray::{ArrayD, IxDyn};
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use crate::mock::{test_support::FLOAT, MockBackend, MockModel};
+    use crate::session::run as run_session;
+
+    struct Affine;
+
+    impl CustomOp for Affine {
+        fn name(&self) -> &str {
+            "Affine"
+        }
+
+        fn input_types(&self) -> Vec<ONNXTensorElementDataType> {
+            vec![FLOAT]
+        }
+
+        fn output_types(&self) -> Vec<ONNXTensorElementDataType> {
+            vec![FLOAT]
+        }
+
+        fn attributes(&self) -> Vec<(&'static str, AttributeKind)> {
+            vec![
+                ("scale", AttributeKind::Float),
+                ("bias", AttributeKind::Floats),
+                ("mode", AttributeKind::String),
+                ("repeat", AttributeKind::Int),
+            ]
+        }
+
+        fn compute(&self, context: &KernelContext) -> Result<()> {
+            assert_eq!(1, context.input_count()?);
+            assert_eq!(1, context.output_count()?);
+            assert!(context.input::<i64>(0).is_err());
+            assert_eq!(None, context.attribute("repeat"));
+            if context.attribute("mode") != Some(&AttributeValue::String("affine".to_string())) {
+                return Err(Error::CustomOp {
+                    op: self.name().to_string(),
+                    message: "unsupported mode".to_string(),
+                });
+            }
+            let scale = match context.attribute("scale") {
+                Some(AttributeValue::Float(scale)) => *scale,
+                _ => 1.,
+            };
+            let bias = match context.attribute("bias") {
+                Some(AttributeValue::Floats(bias)) => bias.clone(),
+                _ => vec![0.],
+            };
+            let x = context.input::<f32>(0)?;
+            let mut y = context.output::<f32>(0, x.shape())?;
+            assert!(context.output::<f32>(0, x.shape()).is_err());
+            for (i, (y, x)) in y.iter_mut().zip(x.iter()).enumerate() {
+                *y = x * scale + bias[i % bias.len()];
+            }
+            Ok(())
+        }
+    }
+
+    fn affine_model(mode: &str) -> MockModel {
+        MockModel::new()
+            .input("x", FLOAT, &[None])
+            .output("y", FLOAT, &[None])
+            .custom_op("Affine")
+            .attribute("scale", AttributeValue::Float(2.))
+            .attribute("bias", AttributeValue::Floats(vec![1., -1.]))
+            .attribute("mode", AttributeValue::String(mode.to_string()))
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_mock_custom_op() {
+        let backend = MockBackend::new();
+        backend.add_model("affine", affine_model("affine"));
+        backend.add_model("unknown_mode", affine_model("other"));
+        backend.add_model("missing", MockModel::new().custom_op("Missing"));
+        let runtime = backend.runtime().unwrap();
+
+        let builder = runtime
+            .session_builder()
+            .unwrap()
+            .with_custom_op_domain(CustomOpDomain::new("test").add_op(Affine))
+            .unwrap();
+        let other = builder.try_clone().unwrap();
+        let missing = builder.try_clone().unwrap();
+        let mut session = builder.build_with_model_in_memory("affine").unwrap();
+        assert!(backend.calls().contains(&"AddCustomOpDomain"));
+
+        // The domain is kept alive by the session after the builder is released
+        let x = ArrayD::<f32>::from_shape_vec(IxDyn(&[3]), vec![1., 2., 3.]).unwrap();
+        let inputs = vec![runtime.create_tensor_with_ndarray(x).unwrap()];
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[3])))
+            .unwrap()];
+        run_session(&mut session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[3., 3., 7.],
+            outputs[0].data.as_f32_array().unwrap().as_slice().unwrap()
+        );
+
+        // Errors of the op are logged, and the output is not allocated
+        let mut other_session = other.build_with_model_in_memory("unknown_mode").unwrap();
+        assert!(run_session(&mut other_session, &inputs, &mut outputs).is_err());
+        assert!(logs_contain("Failed to compute Affine"));
+
+        let err = missing.build_with_model_in_memory("missing").unwrap_err();
+        assert_eq!(Some(ErrorCode::NotImplemented), err.code());
+
+        std::mem::drop((session, other_session, inputs, outputs));
+        assert!(runtime.live_objects().is_empty());
+    }
+
+    #[test]
+    fn test_mock_custom_op_api_version() {
+        let backend = MockBackend::new();
+        backend.add_model("affine", affine_model("affine"));
+        // An older onnxruntime rejects ops of the version of the bindings
+        backend.set_api_version(ORT_API_VERSION - 1);
+        let runtime = backend.runtime().unwrap();
+        assert_eq!(ORT_API_VERSION - 1, runtime.info().unwrap().api_version);
+        runtime
+            .session_builder()
+            .unwrap()
+            .with_custom_op_domain(CustomOpDomain::new("test").add_op(Affine))
+            .unwrap()
+            .build_with_model_in_memory("affine")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_mock_custom_ops_library() {
+        let backend = MockBackend::new();
+        let runtime = backend.runtime().unwrap();
+
+        let builder = runtime.session_builder().unwrap();
+        let err = builder
+            .register_custom_ops_library("missing_custom_ops.so")
+            .err()
+            .unwrap();
+        assert!(matches!(&err, Error::CustomOpsLibrary { message, .. }
+                if message.starts_with("the library cannot be loaded")));
+
+        // A system library which doesn't export `RegisterCustomOps`
+        #[cfg(target_os = "linux")]
+        {
+            let builder = runtime.session_builder().unwrap();
+            let err = builder
+                .register_custom_ops_library("libm.so.6")
+                .err()
+                .unwrap();
+            assert!(matches!(&err, Error::CustomOpsLibrary { message, .. }
+                    if message == "the library doesn't export RegisterCustomOps"));
+        }
+
+        runtime
+            .session_builder()
+            .unwrap()
+            .enable_ort_custom_ops()
+            .unwrap();
+        assert!(backend.calls().contains(&"EnableOrtCustomOps"));
+    }
+}
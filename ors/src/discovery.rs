@@ -0,0 +1,176 @@
+use std::env;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use ors_sys::onnxruntime;
+use tracing::debug;
+
+/// Environment variable which overrides where onnxruntime is loaded from
+///
+/// It can be a path to the shared library itself, or a directory containing it.
+/// Multiple entries are separated like `PATH` (`:` on unix, `;` on windows).
+pub const ORS_DYLIB_PATH: &str = "ORS_DYLIB_PATH";
+
+/// File names of onnxruntime shared library on current platform, the most specific one comes first
+#[cfg(target_os = "windows")]
+const LIB_NAMES: &[&str] = &["onnxruntime.dll"];
+#[cfg(target_os = "macos")]
+const LIB_NAMES: &[&str] = &["libonnxruntime.1.12.0.dylib", "libonnxruntime.dylib"];
+#[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+const LIB_NAMES: &[&str] = &["libonnxruntime.so.1.12.0", "libonnxruntime.so"];
+
+/// Directories which are not always searched by the system loader, but where onnxruntime is usually installed
+#[cfg(target_os = "windows")]
+const FALLBACK_DIRS: &[&str] = &[];
+#[cfg(target_os = "macos")]
+const FALLBACK_DIRS: &[&str] = &["/usr/local/lib", "/opt/homebrew/lib"];
+#[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+const FALLBACK_DIRS: &[&str] = &["/usr/local/lib", "/usr/lib"];
+
+/// A location which is tried when searching onnxruntime
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Candidate {
+    /// A full path to the shared library
+    Path(PathBuf),
+    /// A bare file name which is resolved by the system loader, e.g. using `LD_LIBRARY_PATH`
+    SystemLoader(String),
+}
+
+impl Candidate {
+    fn load(&self) -> std::result::Result<onnxruntime, String> {
+        let res = match self {
+            Candidate::Path(path) => {
+                if !path.is_file() {
+                    return Err("file not found".to_string());
+                }
+                unsafe { onnxruntime::new(path) }
+            }
+            Candidate::SystemLoader(name) => unsafe { onnxruntime::new(name) },
+        };
+        res.map_err(|e| e.to_string())
+    }
+
+    /// Path of the candidate, system loader candidates are returned as bare file name
+    pub fn path(&self) -> PathBuf {
+        match self {
+            Candidate::Path(path) => path.clone(),
+            Candidate::SystemLoader(name) => PathBuf::from(name),
+        }
+    }
+}
+
+impl std::fmt::Display for Candidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Candidate::Path(path) => write!(f, "{}", path.display()),
+            Candidate::SystemLoader(name) => write!(f, "{} (system loader)", name),
+        }
+    }
+}
+
+/// List all locations where onnxruntime is searched, in order:
+///
+/// 1. entries of `ORS_DYLIB_PATH`
+/// 2. the directory where `ors-sys` build script extracted or found onnxruntime
+/// 3. the directory of current executable
+/// 4. the standard system loader paths
+pub fn candidates() -> Vec<Candidate> {
+    let mut candidates = vec![];
+    if let Some(paths) = env::var_os(ORS_DYLIB_PATH) {
+        for path in env::split_paths(&paths) {
+            if path.as_os_str().is_empty() {
+                continue;
+            }
+            if path.is_dir() {
+                push_dir(&mut candidates, &path);
+            } else {
+                candidates.push(Candidate::Path(path));
+            }
+        }
+    }
+
+    push_dir(&mut candidates, Path::new(ors_sys::ORT_BUILD_LIB_DIR));
+
+    if let Some(exe_dir) = env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+    {
+        push_dir(&mut candidates, &exe_dir);
+        // Test binaries and examples live in `target/<profile>/deps` or `target/<profile>/examples`
+        if let Some(parent) = exe_dir.parent() {
+            push_dir(&mut candidates, parent);
+        }
+    }
+
+    for name in LIB_NAMES {
+        candidates.push(Candidate::SystemLoader(name.to_string()));
+    }
+    for dir in FALLBACK_DIRS {
+        push_dir(&mut candidates, Path::new(dir));
+    }
+
+    // Remove duplicated locations, e.g. when `ORS_DYLIB_PATH` points to the build directory
+    let mut deduped: Vec<Candidate> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !deduped.contains(&candidate) {
+            deduped.push(candidate);
+        }
+    }
+    deduped
+}
+
+fn push_dir(candidates: &mut Vec<Candidate>, dir: &Path) {
+    for name in LIB_NAMES {
+        candidates.push(Candidate::Path(dir.join(name)));
+    }
+}
+
+/// Try all candidates and return the first loaded library, together with the location it's loaded from
+///
+/// If none of candidates works, the error lists every location which has been tried
+pub(crate) fn load_library() -> Result<(onnxruntime, PathBuf)> {
+    let mut tried = String::new();
+    for candidate in candidates() {
+        match candidate.load() {
+            Ok(lib) => {
+                debug!("Loaded onnxruntime from {}", candidate);
+                return Ok((lib, candidate.path()));
+            }
+            Err(e) => {
+                debug!("Failed to load onnxruntime from {}: {}", candidate, e);
+                let _ = writeln!(tried, "  {}: {}", candidate, e);
+            }
+        }
+    }
+    Err(Error::LibraryLoad(format!(
+        "Failed to find onnxruntime shared library, set {} to its location. Tried:\n{}",
+        ORS_DYLIB_PATH, tried
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_candidates_order() {
+        let candidates = candidates();
+        let build_dir = Path::new(ors_sys::ORT_BUILD_LIB_DIR).join(LIB_NAMES[0]);
+        let build_dir_pos = candidates
+            .iter()
+            .position(|c| *c == Candidate::Path(build_dir.clone()))
+            .unwrap();
+        let system_pos = candidates
+            .iter()
+            .position(|c| matches!(c, Candidate::SystemLoader(_)))
+            .unwrap();
+        assert!(build_dir_pos < system_pos);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let candidate = Candidate::Path(PathBuf::from("/path/not/exist/libonnxruntime.so"));
+        assert_eq!(candidate.load().err().unwrap(), "file not found");
+    }
+}
@@ -1,73 +1,460 @@
 use std::{
     ffi::CString,
-    ops::DerefMut,
     ptr::null_mut,
-    sync::{atomic::AtomicPtr, Arc, Mutex},
+    sync::{Arc, Mutex, MutexGuard},
 };
 
-use crate::{api::get_api, call_ort, log::custom_logger, status::check_status};
-use lazy_static::lazy_static;
+use crate::error::{Error, Result};
+use crate::{
+    call_ort,
+    log::{custom_logger, LogSink, Logger, LoggingLevel, TracingLogSink},
+    status::check_status,
+    thread::{create_thread, join_thread, ThreadCreator},
+};
 use ors_sys::*;
-use tracing::debug;
+use tracing::{debug, warn};
+
+/// Options of the onnxruntime inference environment
+///
+/// onnxruntime has one environment per loaded library. It's created with these options by the first
+/// `Runtime` of the library, and shared by all runtimes of the library until the last of them is dropped.
+#[derive(Debug, Clone)]
+pub struct EnvironmentBuilder {
+    name: String,
+    log_level: LoggingLevel,
+    log_sink: Arc<dyn LogSink>,
+    global_thread_pools: Option<ThreadingOptions>,
+    telemetry_events: Option<bool>,
+}
+
+impl Default for EnvironmentBuilder {
+    fn default() -> Self {
+        Self {
+            name: "onnxruntime".to_string(),
+            log_level: LoggingLevel::Warning,
+            log_sink: Arc::new(TracingLogSink),
+            global_thread_pools: None,
+            telemetry_events: None,
+        }
+    }
+}
+
+impl EnvironmentBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the log id of the environment
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the minimal logging level of onnxruntime
+    ///
+    /// onnxruntime drops messages below this level, [`Runtime::set_log_level`](crate::runtime::Runtime::set_log_level)
+    /// can raise the level later but can't lower it below this one.
+    pub fn log_level(mut self, log_level: LoggingLevel) -> Self {
+        self.log_level = log_level;
+        self
+    }
+
+    /// Send messages of onnxruntime to the given sink, instead of the tracing crate
+    pub fn log_sink<S: LogSink + 'static>(mut self, log_sink: S) -> Self {
+        self.log_sink = Arc::new(log_sink);
+        self
+    }
+
+    /// Create the logger of the environment, which must be kept alive until the environment is released
+    pub(crate) fn logger(&self) -> Arc<Logger> {
+        Arc::new(Logger::new(self.log_sink.clone(), self.log_level))
+    }
+
+    /// Create thread pools shared by all sessions of the environment
+    ///
+    /// Sessions use the shared pools only if they are built with
+    /// [`SessionBuilder::disable_per_session_threads`](crate::session::SessionBuilder::disable_per_session_threads),
+    /// otherwise each session still creates its own pools. Use
+    /// [`initialize_runtime_with`](crate::api::initialize_runtime_with) to give them to the default runtime.
+    pub fn global_thread_pools(mut self, options: ThreadingOptions) -> Self {
+        self.global_thread_pools = Some(options);
+        self
+    }
+
+    /// Enable or disable telemetry events of onnxruntime, which are only sent on Windows
+    ///
+    /// The setting applies to the whole process. If it's not set, the default of onnxruntime is kept.
+    pub fn telemetry_events(mut self, enabled: bool) -> Self {
+        self.telemetry_events = Some(enabled);
+        self
+    }
+
+    pub(crate) fn create_env(&self, api: &OrtApi, logger: &Arc<Logger>) -> Result<*mut OrtEnv> {
+        debug!("Creating onnxruntime environment");
+        let mut env_ptr: *mut OrtEnv = std::ptr::null_mut();
+        let logging_function: OrtLoggingFunction = Some(custom_logger);
+        let logger_param = Arc::as_ptr(logger) as *mut std::ffi::c_void;
+        let name = CString::new(self.name.clone())?;
+
+        match &self.global_thread_pools {
+            Some(options) => {
+                debug!("Creating global thread pools: {:?}", options);
+                let tp_options = options.create(api)?;
+                let status = call_ort!(
+                    api => CreateEnvWithCustomLoggerAndGlobalThreadPools,
+                    logging_function,
+                    logger_param,
+                    self.log_level.into(),
+                    name.as_ptr(),
+                    tp_options.ptr,
+                    &mut env_ptr
+                )?;
+                check_status(api, status, "CreateEnvWithCustomLoggerAndGlobalThreadPools")?;
+            }
+            None => {
+                let status = call_ort!(
+                    api => CreateEnvWithCustomLogger,
+                    logging_function,
+                    logger_param,
+                    self.log_level.into(),
+                    name.as_ptr(),
+                    &mut env_ptr
+                )?;
+                check_status(api, status, "CreateEnvWithCustomLogger")?;
+            }
+        }
+
+        if let Err(e) = self.set_telemetry_events(api, env_ptr) {
+            if let Err(e) = call_ort!(api => ReleaseEnv, env_ptr) {
+                warn!("Failed to release environment: {}", e);
+            }
+            return Err(e);
+        }
+        Ok(env_ptr)
+    }
+
+    /// The creator of global threads, which must be kept alive until the environment is released
+    pub(crate) fn thread_creator(&self) -> Option<ThreadCreator> {
+        self.global_thread_pools
+            .as_ref()
+            .and_then(|options| options.thread_creator.clone())
+    }
+
+    fn set_telemetry_events(&self, api: &OrtApi, env: *mut OrtEnv) -> Result<()> {
+        match self.telemetry_events {
+            Some(true) => {
+                let status = call_ort!(api => EnableTelemetryEvents, env)?;
+                check_status(api, status, "EnableTelemetryEvents")
+            }
+            Some(false) => {
+                let status = call_ort!(api => DisableTelemetryEvents, env)?;
+                check_status(api, status, "DisableTelemetryEvents")
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Environments of the loaded libraries
+///
+/// Later `CreateEnv*` calls of a library return its existing environment and ignore their logger and
+/// thread pool options, so the logger and the thread creator of the environment are kept here until
+/// the last runtime of the library releases it.
+static ENVIRONMENTS: Mutex<Vec<SharedEnv>> = Mutex::new(Vec::new());
+
+struct SharedEnv {
+    library: usize,
+    ptr: *mut OrtEnv,
+    api: OrtApi,
+    // Messages may be logged and threads of the global thread pools may be created
+    // until the environment is released
+    logger: Arc<Logger>,
+    thread_creator: Option<ThreadCreator>,
+    runtimes: usize,
+}
+
+// Only used while holding the lock of `ENVIRONMENTS`
+unsafe impl Send for SharedEnv {}
+
+fn lock_environments() -> MutexGuard<'static, Vec<SharedEnv>> {
+    // Entries are updated without panicking, a poisoned lock leaves nothing inconsistent
+    ENVIRONMENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A runtime's handle of the environment of its library, which is released with the last handle
+pub(crate) struct Environment {
+    library: usize,
+    ptr: *mut OrtEnv,
+    logger: Arc<Logger>,
+}
+
+impl Environment {
+    /// Get the environment of a library, identified by the address of its `OrtApiBase`
+    ///
+    /// If no runtime of the library is alive, the environment is created by `create` with the given
+    /// options, or the default ones. Otherwise the existing environment is shared, and it's an error
+    /// to give options, since they can't be applied.
+    pub(crate) fn shared<F>(
+        library: usize,
+        options: Option<&EnvironmentBuilder>,
+        api: &OrtApi,
+        create: F,
+    ) -> Result<Self>
+    where
+        F: FnOnce(&EnvironmentBuilder, &Arc<Logger>) -> Result<*mut OrtEnv>,
+    {
+        let mut envs = lock_environments();
+        if let Some(env) = envs.iter_mut().find(|env| env.library == library) {
+            if options.is_some() {
+                return Err(Error::EnvironmentExists);
+            }
+            debug!("Sharing the onnxruntime environment of the library");
+            env.runtimes += 1;
+            return Ok(Self {
+                library,
+                ptr: env.ptr,
+                logger: env.logger.clone(),
+            });
+        }
+
+        let default_options;
+        let options = match options {
+            Some(options) => options,
+            None => {
+                default_options = EnvironmentBuilder::default();
+                &default_options
+            }
+        };
+        let logger = options.logger();
+        let ptr = create(options, &logger)?;
+        envs.push(SharedEnv {
+            library,
+            ptr,
+            api: *api,
+            logger: logger.clone(),
+            thread_creator: options.thread_creator(),
+            runtimes: 1,
+        });
+        Ok(Self {
+            library,
+            ptr,
+            logger,
+        })
+    }
+
+    pub(crate) fn ptr(&self) -> *mut OrtEnv {
+        self.ptr
+    }
+
+    pub(crate) fn logger(&self) -> &Logger {
+        &self.logger
+    }
 
-lazy_static! {
-    static ref ENV: Arc<Mutex<AtomicPtr<OrtEnv>>> =
-        Arc::new(Mutex::new(AtomicPtr::new(create_env())));
+    /// Number of runtimes sharing the environment of the library
+    #[cfg(test)]
+    pub(crate) fn runtimes(library: usize) -> usize {
+        lock_environments()
+            .iter()
+            .find(|env| env.library == library)
+            .map_or(0, |env| env.runtimes)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn library(&self) -> usize {
+        self.library
+    }
 }
 
-// This function can be only called once
-fn create_env() -> *mut OrtEnv {
-    debug!("Creating onnxruntime environment");
-    let mut env_ptr: *mut OrtEnv = std::ptr::null_mut();
-    let logging_function: OrtLoggingFunction = Some(custom_logger);
-    let logger_param: *mut std::ffi::c_void = null_mut();
-    let name = CString::new("onnxruntime").unwrap();
-
-    let status = call_ort!(
-        CreateEnvWithCustomLogger,
-        logging_function,
-        logger_param,
-        OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING,
-        name.as_ptr(),
-        &mut env_ptr
-    );
-
-    // panic when failed to create env
-    check_status(status).expect("Failed to create inference environment");
-
-    env_ptr
+impl Drop for Environment {
+    fn drop(&mut self) {
+        let mut envs = lock_environments();
+        let index = match envs.iter().position(|env| env.library == self.library) {
+            Some(index) => index,
+            None => return,
+        };
+        envs[index].runtimes -= 1;
+        if envs[index].runtimes == 0 {
+            let env = envs.remove(index);
+            debug!("Releasing onnxruntime environment");
+            // Released while holding the lock, so a new runtime of the library can't get this environment
+            if let Err(e) = call_ort!(env.api => ReleaseEnv, env.ptr) {
+                warn!("Failed to release environment: {}", e);
+            }
+        }
+    }
 }
 
-pub(crate) fn get_env_ptr() -> *mut OrtEnv {
-    let mut env_guard = ENV.try_lock().unwrap();
-    *env_guard.deref_mut().get_mut()
+/// Options of the global thread pools of an environment
+///
+/// Unset options keep the defaults of onnxruntime
+#[derive(Debug, Clone, Default)]
+pub struct ThreadingOptions {
+    intra_op_num_threads: Option<i32>,
+    inter_op_num_threads: Option<i32>,
+    spin_control: Option<bool>,
+    denormal_as_zero: bool,
+    thread_creator: Option<ThreadCreator>,
+}
+
+impl ThreadingOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of threads of the global intra-op thread pool, 0 lets onnxruntime choose
+    pub fn intra_op_num_threads(mut self, num_threads: i32) -> Self {
+        self.intra_op_num_threads = Some(num_threads);
+        self
+    }
+
+    /// Set the number of threads of the global inter-op thread pool, 0 lets onnxruntime choose
+    pub fn inter_op_num_threads(mut self, num_threads: i32) -> Self {
+        self.inter_op_num_threads = Some(num_threads);
+        self
+    }
+
+    /// Allow threads of the global pools to spin while waiting for work
+    ///
+    /// Spinning reduces latency but keeps CPUs busy, disable it when many models share the CPUs
+    pub fn spin_control(mut self, allow_spinning: bool) -> Self {
+        self.spin_control = Some(allow_spinning);
+        self
+    }
+
+    /// Treat denormal floats as zero in the global thread pools, which may speed up some models
+    ///
+    /// Sessions should also set the `session.set_denormal_as_zero` config entry to get the same results
+    pub fn denormal_as_zero(mut self) -> Self {
+        self.denormal_as_zero = true;
+        self
+    }
+
+    /// Spawn threads of the global pools with the given creator instead of onnxruntime
+    pub fn custom_thread_creator(mut self, thread_creator: ThreadCreator) -> Self {
+        self.thread_creator = Some(thread_creator);
+        self
+    }
+
+    fn create<'a>(&self, api: &'a OrtApi) -> Result<OrtThreadingOptions<'a>> {
+        let mut ptr: *mut ors_sys::OrtThreadingOptions = null_mut();
+        let status = call_ort!(api => CreateThreadingOptions, &mut ptr)?;
+        check_status(api, status, "CreateThreadingOptions")?;
+        // Released when returning, even if setting an option fails
+        let options = OrtThreadingOptions { api, ptr };
+
+        if let Some(num_threads) = self.intra_op_num_threads {
+            let status = call_ort!(api => SetGlobalIntraOpNumThreads, ptr, num_threads)?;
+            check_status(api, status, "SetGlobalIntraOpNumThreads")?;
+        }
+        if let Some(num_threads) = self.inter_op_num_threads {
+            let status = call_ort!(api => SetGlobalInterOpNumThreads, ptr, num_threads)?;
+            check_status(api, status, "SetGlobalInterOpNumThreads")?;
+        }
+        if let Some(allow_spinning) = self.spin_control {
+            let status = call_ort!(api => SetGlobalSpinControl, ptr, allow_spinning as i32)?;
+            check_status(api, status, "SetGlobalSpinControl")?;
+        }
+        if self.denormal_as_zero {
+            let status = call_ort!(api => SetGlobalDenormalAsZero, ptr)?;
+            check_status(api, status, "SetGlobalDenormalAsZero")?;
+        }
+        if let Some(thread_creator) = &self.thread_creator {
+            let status = call_ort!(api => SetGlobalCustomCreateThreadFn, ptr, Some(create_thread))?;
+            check_status(api, status, "SetGlobalCustomCreateThreadFn")?;
+            let status = call_ort!(
+                api => SetGlobalCustomThreadCreationOptions,
+                ptr,
+                thread_creator.options_ptr()
+            )?;
+            check_status(api, status, "SetGlobalCustomThreadCreationOptions")?;
+            let status = call_ort!(api => SetGlobalCustomJoinThreadFn, ptr, Some(join_thread))?;
+            check_status(api, status, "SetGlobalCustomJoinThreadFn")?;
+        }
+        Ok(options)
+    }
+}
+
+/// `OrtThreadingOptions` which is released when dropped, it's only needed while creating the environment
+struct OrtThreadingOptions<'a> {
+    api: &'a OrtApi,
+    ptr: *mut ors_sys::OrtThreadingOptions,
+}
+
+impl Drop for OrtThreadingOptions<'_> {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            if let Err(e) = call_ort!(self.api => ReleaseThreadingOptions, self.ptr) {
+                warn!("Failed to release threading options: {}", e);
+            }
+        }
+        self.ptr = null_mut();
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use std::path::Path;
-
     use tracing_test::traced_test;
 
-    use crate::api::initialize_runtime;
-
+    #[cfg(feature = "dynamic-loading")]
     use super::*;
+    use crate::api::default_runtime;
+    #[cfg(feature = "dynamic-loading")]
+    use crate::runtime::Runtime;
 
     #[test]
     #[traced_test]
     fn test_env() {
-        setup_runtime();
-        let p = get_env_ptr();
-        assert_ne!(p, null_mut());
-    }
-
-    fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).unwrap();
+        let runtime = default_runtime().unwrap();
+        let p = runtime.env_ptr();
+        assert!(!p.is_null());
+    }
+
+    /// Runtimes of the default library share one environment with the default runtime, which is alive
+    /// during all tests, so the test loads a private copy of the library to create its own environment
+    #[cfg(feature = "dynamic-loading")]
+    #[test]
+    #[traced_test]
+    fn test_env_with_global_thread_pools() {
+        let library = Runtime::auto().unwrap().info().unwrap().library_path;
+        let dir =
+            std::env::temp_dir().join(format!("ors-global-thread-pools-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let copy = dir.join(library.file_name().unwrap());
+        std::fs::copy(&library, &copy).unwrap();
+
+        let runtime = Runtime::builder()
+            .library_path(&copy)
+            .environment(
+                EnvironmentBuilder::new()
+                    .name("global_thread_pools")
+                    .global_thread_pools(
+                        ThreadingOptions::new()
+                            .intra_op_num_threads(2)
+                            .inter_op_num_threads(1)
+                            .spin_control(false)
+                            .denormal_as_zero()
+                            .custom_thread_creator(ThreadCreator::new(|worker| {
+                                std::thread::Builder::new()
+                                    .name("ors-global-worker".to_string())
+                                    .spawn(move || worker.run())
+                            })),
+                    )
+                    .telemetry_events(false),
+            )
+            .build()
+            .unwrap();
+        assert!(!runtime.env_ptr().is_null());
+
+        // Sessions can join the global thread pools
+        runtime
+            .session_builder()
+            .unwrap()
+            .disable_per_session_threads()
+            .unwrap();
+
+        std::mem::drop(runtime);
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
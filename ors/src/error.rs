@@ -0,0 +1,430 @@
+use std::ffi::NulError;
+use std::path::PathBuf;
+
+use ors_sys::*;
+
+use crate::runtime::LiveObjects;
+use crate::types::element_type_name;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by ors
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// An onnxruntime API returned a failing status
+    #[error("onnxruntime error in {function}{}: {code}: {message}", ort_context(.session, .input))]
+    Ort {
+        /// Name of the API function, for example `CreateSession`
+        function: &'static str,
+        code: ErrorCode,
+        message: String,
+        /// The session which returned the error, its log id or the path of its model
+        session: Option<String>,
+        /// The input whose information was being read
+        input: Option<String>,
+    },
+    /// onnxruntime shared library cannot be found or loaded
+    #[error("Failed to load onnxruntime: {0}")]
+    LibraryLoad(String),
+    /// The loaded onnxruntime doesn't support any API version known by ors
+    #[error("onnxruntime {version} doesn't support any API version from {min} to {max}")]
+    UnsupportedApiVersion { version: String, min: u32, max: u32 },
+    /// The default runtime is not available
+    #[error("The library has not been initialized, you should initialize it first using initialize_runtime(): {0}")]
+    NotInitialized(Box<Error>),
+    /// The function is not available in the negotiated API version of onnxruntime
+    #[error("onnxruntime function {function} is not available in the loaded library")]
+    FunctionUnavailable { function: &'static str },
+    /// The runtime cannot be unloaded because it's still used
+    #[error("Cannot unload onnxruntime, objects are still alive: {live_objects}, other handles: {handles}")]
+    RuntimeInUse {
+        live_objects: LiveObjects,
+        /// Number of other `Runtime` handles
+        handles: usize,
+    },
+    /// Environment options are given for a library whose environment is already created by another runtime
+    #[error("The environment of onnxruntime already exists, its options can't be changed until all runtimes of the library are dropped")]
+    EnvironmentExists,
+    /// A runtime with options is installed as the default runtime, but the default runtime is already loaded
+    #[error("The default runtime has been initialized, unload it with drop_lib() before initializing it with other options")]
+    DefaultRuntimeExists,
+    /// Objects created by different runtimes are used together
+    #[error("All tensors must be created in the same runtime as the session")]
+    RuntimeMismatch,
+    /// The model file doesn't exist
+    #[error("Model doesn't exist at {}", .0.display())]
+    ModelNotFound(PathBuf),
+    /// A model cannot be parsed to find its external data
+    #[error("Invalid model: {0}")]
+    InvalidModel(String),
+    /// External data of a model is missing or doesn't match the initializers of the model
+    #[error("Invalid external data {location}: {message}")]
+    ExternalData { location: String, message: String },
+    /// A model or external data file cannot be mapped into memory
+    #[error("Failed to map {}: {source}", .path.display())]
+    ModelMmap {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// An input required by the session is not given
+    #[error("Missing input {input}")]
+    MissingInput { input: String },
+    /// More inputs are given than the inputs of the model
+    #[error("Too many inputs: the model has {expected} inputs, got {actual}")]
+    InputCountMismatch { expected: usize, actual: usize },
+    /// An input given by name is neither an input nor an overridable initializer of the model
+    #[error("Unknown input {input}")]
+    UnknownInput { input: String },
+    /// An input is given more than once
+    #[error("Duplicate input {input}")]
+    DuplicateInput { input: String },
+    /// An output requested by name is not an output of the model
+    #[error("Unknown output {output}")]
+    UnknownOutput { output: String },
+    /// An output is requested more than once
+    #[error("Duplicate output {output}")]
+    DuplicateOutput { output: String },
+    /// A tensor is read as another element type
+    #[error(
+        "Tensor has element type {}, but it's read as {}",
+        element_type_name(*.actual),
+        element_type_name(*.expected)
+    )]
+    ElementTypeMismatch {
+        expected: ONNXTensorElementDataType,
+        actual: ONNXTensorElementDataType,
+    },
+    /// Tensors of the element type cannot be created by ors
+    #[error("Unsupported tensor element type {}", element_type_name(*.0))]
+    UnsupportedDataType(ONNXTensorElementDataType),
+    /// An initializer to override is not an overridable initializer of the model
+    #[error("Unknown initializer {name}, it's not an overridable initializer of the model")]
+    UnknownInitializer { name: String },
+    /// The shape of an input doesn't match the model, `None` in `expected` is a free dimension
+    #[error("Shape mismatch of input {input}: expected {expected:?}, got {actual:?}")]
+    ShapeMismatch {
+        input: String,
+        expected: Vec<Option<i64>>,
+        actual: Vec<i64>,
+    },
+    /// The element type of an input doesn't match the model
+    #[error(
+        "Data type mismatch of input {input}: expected {}, got {}",
+        element_type_name(*.expected),
+        element_type_name(*.actual)
+    )]
+    DataTypeMismatch {
+        input: String,
+        expected: ONNXTensorElementDataType,
+        actual: ONNXTensorElementDataType,
+    },
+    /// The value of a configuration entry is not valid for its key
+    #[error("Invalid value {value} of config entry {key}, expected {expected}")]
+    InvalidConfigValue {
+        key: &'static str,
+        value: String,
+        expected: &'static str,
+    },
+    /// A session config file cannot be read or parsed
+    #[error("Invalid session config: {0}")]
+    InvalidConfigFile(String),
+    /// The directory or a file of a model cache cannot be accessed
+    #[error("Model cache error at {}: {source}", .path.display())]
+    ModelCache {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A profile written by onnxruntime cannot be read or parsed
+    #[error("Invalid profile at {}: {message}", .path.display())]
+    InvalidProfile { path: PathBuf, message: String },
+    /// A custom op cannot read its inputs or write its outputs
+    #[error("Custom op {op} failed: {message}")]
+    CustomOp { op: String, message: String },
+    /// A custom ops library cannot be loaded or its ops cannot be registered
+    #[error("Failed to register custom ops library {}: {message}", .path.display())]
+    CustomOpsLibrary { path: PathBuf, message: String },
+    /// An execution provider is not compiled into the loaded onnxruntime
+    #[error(
+        "Execution provider {provider} is not available, available providers are {available:?}"
+    )]
+    ProviderUnavailable {
+        provider: &'static str,
+        available: Vec<String>,
+    },
+    /// An execution provider is not known by ors, see [`ExecutionProvider::from_name`](crate::providers::ExecutionProvider::from_name)
+    #[error("Unknown execution provider {name}")]
+    UnknownProvider { name: String },
+    /// An option of an execution provider is not supported
+    #[error("Invalid option {key}={value} of execution provider {provider}")]
+    InvalidProviderOption {
+        provider: &'static str,
+        key: String,
+        value: String,
+    },
+    /// A string passed to onnxruntime contains a nul byte
+    #[error("Invalid string: {0}")]
+    InvalidString(#[from] NulError),
+}
+
+impl Error {
+    /// Name the session of an onnxruntime error, unless the error already names one
+    pub(crate) fn in_session(mut self, name: Option<&str>) -> Self {
+        if let Error::Ort { session, .. } = &mut self {
+            if session.is_none() {
+                *session = name.map(str::to_string);
+            }
+        }
+        self
+    }
+
+    /// Name the input of an onnxruntime error, unless the error already names one
+    pub(crate) fn for_input(mut self, name: &str) -> Self {
+        if let Error::Ort { input, .. } = &mut self {
+            if input.is_none() {
+                *input = Some(name.to_string());
+            }
+        }
+        self
+    }
+
+    /// The onnxruntime error code, if the error is returned by onnxruntime
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            Error::Ort { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Whether the error is caused by the request itself, like a wrong input or a missing model,
+    /// so retrying the same request doesn't help
+    pub fn is_bad_request(&self) -> bool {
+        match self {
+            Error::Ort { code, .. } => code.is_bad_request(),
+            Error::RuntimeMismatch
+            | Error::ModelNotFound(_)
+            | Error::InvalidModel(_)
+            | Error::ExternalData { .. }
+            | Error::MissingInput { .. }
+            | Error::InputCountMismatch { .. }
+            | Error::UnknownInput { .. }
+            | Error::DuplicateInput { .. }
+            | Error::UnknownOutput { .. }
+            | Error::DuplicateOutput { .. }
+            | Error::UnknownInitializer { .. }
+            | Error::ShapeMismatch { .. }
+            | Error::DataTypeMismatch { .. }
+            | Error::InvalidConfigValue { .. }
+            | Error::InvalidConfigFile(_)
+            | Error::UnknownProvider { .. }
+            | Error::InvalidProviderOption { .. }
+            | Error::InvalidString(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Error codes of onnxruntime, see `OrtErrorCode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    Fail,
+    InvalidArgument,
+    NoSuchFile,
+    NoModel,
+    EngineError,
+    RuntimeException,
+    InvalidProtobuf,
+    ModelLoaded,
+    NotImplemented,
+    InvalidGraph,
+    EpFail,
+    /// A code which is unknown to ors
+    Unknown(OrtErrorCode),
+}
+
+impl ErrorCode {
+    /// Whether the error is caused by the request, like invalid arguments or models
+    pub fn is_bad_request(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::InvalidArgument
+                | ErrorCode::NoSuchFile
+                | ErrorCode::NoModel
+                | ErrorCode::InvalidProtobuf
+                | ErrorCode::ModelLoaded
+                | ErrorCode::InvalidGraph
+        )
+    }
+}
+
+#[allow(non_upper_case_globals)]
+impl From<OrtErrorCode> for ErrorCode {
+    fn from(code: OrtErrorCode) -> Self {
+        match code {
+            OrtErrorCode_ORT_FAIL => ErrorCode::Fail,
+            OrtErrorCode_ORT_INVALID_ARGUMENT => ErrorCode::InvalidArgument,
+            OrtErrorCode_ORT_NO_SUCHFILE => ErrorCode::NoSuchFile,
+            OrtErrorCode_ORT_NO_MODEL => ErrorCode::NoModel,
+            OrtErrorCode_ORT_ENGINE_ERROR => ErrorCode::EngineError,
+            OrtErrorCode_ORT_RUNTIME_EXCEPTION => ErrorCode::RuntimeException,
+            OrtErrorCode_ORT_INVALID_PROTOBUF => ErrorCode::InvalidProtobuf,
+            OrtErrorCode_ORT_MODEL_LOADED => ErrorCode::ModelLoaded,
+            OrtErrorCode_ORT_NOT_IMPLEMENTED => ErrorCode::NotImplemented,
+            OrtErrorCode_ORT_INVALID_GRAPH => ErrorCode::InvalidGraph,
+            OrtErrorCode_ORT_EP_FAIL => ErrorCode::EpFail,
+            code => ErrorCode::Unknown(code),
+        }
+    }
+}
+
+impl From<ErrorCode> for OrtErrorCode {
+    fn from(code: ErrorCode) -> Self {
+        match code {
+            ErrorCode::Fail => OrtErrorCode_ORT_FAIL,
+            ErrorCode::InvalidArgument => OrtErrorCode_ORT_INVALID_ARGUMENT,
+            ErrorCode::NoSuchFile => OrtErrorCode_ORT_NO_SUCHFILE,
+            ErrorCode::NoModel => OrtErrorCode_ORT_NO_MODEL,
+            ErrorCode::EngineError => OrtErrorCode_ORT_ENGINE_ERROR,
+            ErrorCode::RuntimeException => OrtErrorCode_ORT_RUNTIME_EXCEPTION,
+            ErrorCode::InvalidProtobuf => OrtErrorCode_ORT_INVALID_PROTOBUF,
+            ErrorCode::ModelLoaded => OrtErrorCode_ORT_MODEL_LOADED,
+            ErrorCode::NotImplemented => OrtErrorCode_ORT_NOT_IMPLEMENTED,
+            ErrorCode::InvalidGraph => OrtErrorCode_ORT_INVALID_GRAPH,
+            ErrorCode::EpFail => OrtErrorCode_ORT_EP_FAIL,
+            ErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ErrorCode::Fail => "ORT_FAIL",
+            ErrorCode::InvalidArgument => "ORT_INVALID_ARGUMENT",
+            ErrorCode::NoSuchFile => "ORT_NO_SUCHFILE",
+            ErrorCode::NoModel => "ORT_NO_MODEL",
+            ErrorCode::EngineError => "ORT_ENGINE_ERROR",
+            ErrorCode::RuntimeException => "ORT_RUNTIME_EXCEPTION",
+            ErrorCode::InvalidProtobuf => "ORT_INVALID_PROTOBUF",
+            ErrorCode::ModelLoaded => "ORT_MODEL_LOADED",
+            ErrorCode::NotImplemented => "ORT_NOT_IMPLEMENTED",
+            ErrorCode::InvalidGraph => "ORT_INVALID_GRAPH",
+            ErrorCode::EpFail => "ORT_EP_FAIL",
+            ErrorCode::Unknown(code) => return write!(f, "unknown error code {}", code),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Session and input of an onnxruntime error, like ` (session gpt2, input input_ids)`
+fn ort_context(session: &Option<String>, input: &Option<String>) -> String {
+    match (session, input) {
+        (Some(session), Some(input)) => format!(" (session {}, input {})", session, input),
+        (Some(session), None) => format!(" (session {})", session),
+        (None, Some(input)) => format!(" (input {})", input),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(
+            ErrorCode::InvalidArgument,
+            ErrorCode::from(OrtErrorCode_ORT_INVALID_ARGUMENT)
+        );
+        assert_eq!(ErrorCode::EpFail, ErrorCode::from(OrtErrorCode_ORT_EP_FAIL));
+        assert_eq!(ErrorCode::Unknown(100), ErrorCode::from(100));
+        assert_eq!(
+            OrtErrorCode_ORT_NO_MODEL,
+            OrtErrorCode::from(ErrorCode::NoModel)
+        );
+        assert_eq!("ORT_NO_SUCHFILE", ErrorCode::NoSuchFile.to_string());
+    }
+
+    #[test]
+    fn test_bad_request() {
+        let err = Error::Ort {
+            function: "Run",
+            code: ErrorCode::InvalidArgument,
+            message: "invalid input".to_string(),
+            session: None,
+            input: None,
+        };
+        assert!(err.is_bad_request());
+        assert_eq!(Some(ErrorCode::InvalidArgument), err.code());
+        assert_eq!(
+            "onnxruntime error in Run: ORT_INVALID_ARGUMENT: invalid input",
+            err.to_string()
+        );
+
+        let err = Error::Ort {
+            function: "Run",
+            code: ErrorCode::RuntimeException,
+            message: "out of memory".to_string(),
+            session: None,
+            input: None,
+        };
+        assert!(!err.is_bad_request());
+        assert!(Error::MissingInput {
+            input: "input_ids".to_string()
+        }
+        .is_bad_request());
+    }
+
+    #[test]
+    fn test_error_context() {
+        let err = Error::Ort {
+            function: "SessionGetInputTypeInfo",
+            code: ErrorCode::Fail,
+            message: "broken model".to_string(),
+            session: None,
+            input: None,
+        };
+        let err = err.for_input("input_ids").in_session(Some("gpt2"));
+        assert_eq!(
+            "onnxruntime error in SessionGetInputTypeInfo (session gpt2, input input_ids): ORT_FAIL: broken model",
+            err.to_string()
+        );
+        // The innermost context is kept
+        let err = err.in_session(Some("other"));
+        assert!(matches!(err, Error::Ort { session: Some(ref s), .. } if s == "gpt2"));
+        assert!(!Error::MissingInput {
+            input: "x".to_string()
+        }
+        .in_session(Some("gpt2"))
+        .to_string()
+        .contains("gpt2"));
+    }
+
+    #[test]
+    fn test_element_type_names() {
+        let err = Error::DataTypeMismatch {
+            input: "input_ids".to_string(),
+            expected: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64,
+            actual: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+        };
+        assert_eq!(
+            "Data type mismatch of input input_ids: expected int64, got float",
+            err.to_string()
+        );
+        let err = Error::ElementTypeMismatch {
+            expected: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+            actual: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL,
+        };
+        assert_eq!(
+            "Tensor has element type bool, but it's read as float",
+            err.to_string()
+        );
+        assert_eq!(
+            "Unsupported tensor element type string",
+            Error::UnsupportedDataType(
+                ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING
+            )
+            .to_string()
+        );
+    }
+}
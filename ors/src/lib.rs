@@ -1,40 +1,53 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
+// `call_ort!` wraps its parameters in the unsafe block of the ffi call
+#![allow(clippy::macro_metavars_in_unsafe)]
 pub mod api;
+pub mod cache;
 pub mod config;
+pub mod custom_op;
+#[cfg(feature = "dynamic-loading")]
+pub mod discovery;
 pub mod env;
+pub mod error;
 pub mod log;
 pub mod memory_info;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod model;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+pub mod providers;
+pub mod runtime;
 pub mod session;
 mod status;
 pub mod tensor;
+pub mod thread;
 pub mod types;
+pub mod version;
+
+pub use error::{Error, Result};
 
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, ptr::null};
+    use std::ptr::null;
 
-    use crate::api::{get_api, initialize_runtime};
+    use crate::api::{default_runtime, initialize_runtime_auto};
 
     #[test]
     fn it_works() {
         setup_runtime();
         println!("onnxruntime api verseion: {}", ors_sys::ORT_API_VERSION);
-        assert_eq!(11, ors_sys::ORT_API_VERSION);
+        assert_eq!(12, ors_sys::ORT_API_VERSION);
         let error_code = 1;
         let msg_ptr: *const i8 = std::ptr::null_mut();
-        let create_status_fn = get_api().CreateStatus.unwrap();
+        let runtime = default_runtime().unwrap();
+        let create_status_fn = runtime.api().CreateStatus.unwrap();
         let status_ptr = unsafe { create_status_fn(error_code, msg_ptr) };
         assert_ne!(null(), status_ptr);
         println!("{:?}", status_ptr);
     }
     fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).unwrap();
+        initialize_runtime_auto().unwrap();
     }
 }
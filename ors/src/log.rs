@@ -1,11 +1,15 @@
 use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 
 use ors_sys::*;
-use tracing::{debug, error, info, span, trace, warn, Level};
+use tracing::{error, info, span, trace, warn, Level, Span};
 
 // Logging level of the ONNX Runtime C API
 // Borrowed from: https://github.com/nbigaouette/onnxruntime-rs/blob/master/onnxruntime/src/lib.rs
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(not(windows), repr(u32))]
 #[cfg_attr(windows, repr(i32))]
 pub enum LoggingLevel {
@@ -33,6 +37,20 @@ impl From<LoggingLevel> for OrtLoggingLevel {
     }
 }
 
+#[allow(non_upper_case_globals)]
+impl From<OrtLoggingLevel> for LoggingLevel {
+    fn from(val: OrtLoggingLevel) -> Self {
+        match val {
+            OrtLoggingLevel_ORT_LOGGING_LEVEL_VERBOSE => LoggingLevel::Verbose,
+            OrtLoggingLevel_ORT_LOGGING_LEVEL_INFO => LoggingLevel::Info,
+            OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING => LoggingLevel::Warning,
+            OrtLoggingLevel_ORT_LOGGING_LEVEL_ERROR => LoggingLevel::Error,
+            // Unknown levels are more severe than the known ones
+            _ => LoggingLevel::Fatal,
+        }
+    }
+}
+
 /// Runtime's logging sends the code location where the log happened, will be parsed to this struct.
 #[derive(Debug)]
 struct CodeLocation<'a> {
@@ -62,55 +80,377 @@ impl<'a> From<&'a str> for CodeLocation<'a> {
     }
 }
 
-/// Callback from C that will handle the logging, forwarding the runtime's logs to the tracing crate.
-pub(crate) extern "C" fn custom_logger(
-    _params: *mut std::ffi::c_void,
+/// A message logged by onnxruntime
+#[derive(Debug, Clone, Copy)]
+pub struct LogRecord<'a> {
+    pub severity: LoggingLevel,
+    /// Category of the message, for example `onnxruntime` or `VerifyEachNodeIsAssignedToAnEp`
+    pub category: &'a str,
+    /// Log id of the environment or the session which logs the message
+    pub log_id: &'a str,
+    /// Location in the source of onnxruntime, like `inference_session.cc:1234 Initialize`
+    pub code_location: &'a str,
+    pub message: &'a str,
+}
+
+/// Destination of messages logged by onnxruntime
+///
+/// A sink is called from threads of onnxruntime. A panic in it is caught and the message is dropped.
+/// Messages of a session or a run with a log id are passed inside its `session` or `run` span,
+/// which the sink can get by [`Span::current`].
+pub trait LogSink: Send + Sync {
+    fn log(&self, record: &LogRecord<'_>);
+}
+
+impl std::fmt::Debug for dyn LogSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LogSink")
+    }
+}
+
+/// The default sink, which forwards messages to the tracing crate
+///
+/// Each message is logged in an `onnxruntime` span with its category, code location and log id.
+/// Messages of a session or a run with a log id are logged inside its `session` or `run` span.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingLogSink;
+
+impl LogSink for TracingLogSink {
+    fn log(&self, record: &LogRecord<'_>) {
+        // Parse the code location
+        let code_location: CodeLocation = record.code_location.into();
+
+        let span = span!(
+            Level::TRACE,
+            "onnxruntime",
+            category = record.category,
+            file = code_location.file,
+            line_number = code_location.line_number,
+            function = code_location.function,
+            logid = record.log_id,
+        );
+        let _enter = span.enter();
+
+        match record.severity {
+            LoggingLevel::Verbose => trace!("{}", record.message),
+            LoggingLevel::Info => info!("{}", record.message),
+            LoggingLevel::Warning => warn!("{}", record.message),
+            LoggingLevel::Error | LoggingLevel::Fatal => error!("{}", record.message),
+        }
+    }
+}
+
+/// Spans of sessions and runs which have a log id, the latest one is used if log ids are duplicated
+static LOG_ID_SPANS: Mutex<Vec<(String, Span)>> = Mutex::new(Vec::new());
+
+/// A tracing span of a session or a run, which is the parent of messages logged with its log id until dropped
+#[derive(Debug)]
+pub(crate) struct LogIdSpan {
+    log_id: String,
+    span: Span,
+}
+
+impl LogIdSpan {
+    /// Create a `session` span
+    pub(crate) fn session(log_id: &str) -> Self {
+        Self::register(log_id, tracing::info_span!("session", log_id))
+    }
+
+    /// Create a `run` span, whose parent is the current span
+    pub(crate) fn run(run_tag: &str) -> Self {
+        Self::register(run_tag, tracing::info_span!("run", run_tag))
+    }
+
+    fn register(log_id: &str, span: Span) -> Self {
+        lock_log_id_spans().push((log_id.to_string(), span.clone()));
+        Self {
+            log_id: log_id.to_string(),
+            span,
+        }
+    }
+
+    pub(crate) fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn find(log_id: &str) -> Option<Span> {
+        if log_id.is_empty() {
+            return None;
+        }
+        lock_log_id_spans()
+            .iter()
+            .rev()
+            .find(|(id, _)| id == log_id)
+            .map(|(_, span)| span.clone())
+    }
+}
+
+impl Drop for LogIdSpan {
+    fn drop(&mut self) {
+        let mut spans = lock_log_id_spans();
+        if let Some(index) = spans
+            .iter()
+            .rposition(|(id, span)| *id == self.log_id && span.id() == self.span.id())
+        {
+            spans.remove(index);
+        }
+    }
+}
+
+fn lock_log_id_spans() -> std::sync::MutexGuard<'static, Vec<(String, Span)>> {
+    // Spans are still consistent if a thread panicked while holding the lock
+    LOG_ID_SPANS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The logger of an environment, passed to onnxruntime as the parameter of `custom_logger`
+pub(crate) struct Logger {
+    sink: Arc<dyn LogSink>,
+    /// Messages below this level are dropped before reaching the sink
+    level: AtomicU32,
+}
+
+impl Logger {
+    pub(crate) fn new(sink: Arc<dyn LogSink>, level: LoggingLevel) -> Self {
+        Self {
+            sink,
+            level: AtomicU32::new(level as u32),
+        }
+    }
+
+    pub(crate) fn level(&self) -> LoggingLevel {
+        LoggingLevel::from(self.level.load(Ordering::Relaxed) as OrtLoggingLevel)
+    }
+
+    pub(crate) fn set_level(&self, level: LoggingLevel) {
+        self.level.store(level as u32, Ordering::Relaxed);
+    }
+
+    fn log(&self, record: &LogRecord<'_>) {
+        if record.severity >= self.level() {
+            self.sink.log(record);
+        }
+    }
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new(Arc::new(TracingLogSink), LoggingLevel::Warning)
+    }
+}
+
+/// Read a string given by onnxruntime, null is an empty string
+unsafe fn str_from_ptr<'a>(ptr: *const c_char) -> std::borrow::Cow<'a, str> {
+    if ptr.is_null() {
+        std::borrow::Cow::Borrowed("")
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy()
+    }
+}
+
+/// Callback from C that will handle the logging, forwarding the runtime's logs to the `Logger` in `params`
+///
+/// Panics are caught, they must not unwind into onnxruntime.
+pub(crate) unsafe extern "C" fn custom_logger(
+    params: *mut std::ffi::c_void,
     severity: OrtLoggingLevel,
-    category: *const i8,
-    logid: *const i8,
-    code_location: *const i8,
-    message: *const i8,
+    category: *const c_char,
+    logid: *const c_char,
+    code_location: *const c_char,
+    message: *const c_char,
 ) {
-    let log_level = match severity {
-        ors_sys::OrtLoggingLevel_ORT_LOGGING_LEVEL_VERBOSE => Level::TRACE,
-        ors_sys::OrtLoggingLevel_ORT_LOGGING_LEVEL_INFO => Level::DEBUG,
-        ors_sys::OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING => Level::INFO,
-        ors_sys::OrtLoggingLevel_ORT_LOGGING_LEVEL_ERROR => Level::WARN,
-        ors_sys::OrtLoggingLevel_ORT_LOGGING_LEVEL_FATAL => Level::ERROR,
-        _ => Level::INFO,
-    };
-
-    assert_ne!(category, std::ptr::null());
-    let category = unsafe { CStr::from_ptr(category) };
-    assert_ne!(code_location, std::ptr::null());
-    let code_location = unsafe { CStr::from_ptr(code_location) }
-        .to_str()
-        .unwrap_or("unknown");
-    assert_ne!(message, std::ptr::null());
-    let message = unsafe { CStr::from_ptr(message) };
-
-    assert_ne!(logid, std::ptr::null());
-    let logid = unsafe { CStr::from_ptr(logid) };
-
-    // Parse the code location
-    let code_location: CodeLocation = code_location.into();
-
-    let span = span!(
-        Level::TRACE,
-        "onnxruntime",
-        category = category.to_str().unwrap_or("<unknown>"),
-        file = code_location.file,
-        line_number = code_location.line_number,
-        function = code_location.function,
-        logid = logid.to_str().unwrap_or("<unknown>"),
-    );
-    let _enter = span.enter();
-
-    match log_level {
-        Level::TRACE => trace!("{:?}", message),
-        Level::DEBUG => debug!("{:?}", message),
-        Level::INFO => info!("{:?}", message),
-        Level::WARN => warn!("{:?}", message),
-        Level::ERROR => error!("{:?}", message),
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let category = str_from_ptr(category);
+        let log_id = str_from_ptr(logid);
+        let code_location = str_from_ptr(code_location);
+        let message = str_from_ptr(message);
+        let record = LogRecord {
+            severity: severity.into(),
+            category: &category,
+            log_id: &log_id,
+            code_location: &code_location,
+            message: &message,
+        };
+        // Messages are logged from threads of onnxruntime, find the span of the session or the run
+        let parent = LogIdSpan::find(&log_id);
+        let _parent = parent.as_ref().map(|span| span.enter());
+        match (params as *const Logger).as_ref() {
+            Some(logger) => logger.log(&record),
+            None => TracingLogSink.log(&record),
+        }
+    }));
+    if result.is_err() {
+        // Logging from here may panic again, there is nothing more to do
+        let _ = catch_unwind(|| error!("The log sink of onnxruntime panicked"));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CString;
+    use std::sync::Mutex;
+
+    use tracing_test::traced_test;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        records: Mutex<Vec<(LoggingLevel, String, String)>>,
+    }
+
+    impl LogSink for RecordingSink {
+        fn log(&self, record: &LogRecord<'_>) {
+            self.records.lock().unwrap().push((
+                record.severity,
+                record.log_id.to_string(),
+                record.message.to_string(),
+            ));
+        }
+    }
+
+    /// Records the names of the spans which messages are passed in
+    #[derive(Default)]
+    struct SpanSink {
+        spans: Mutex<Vec<Option<&'static str>>>,
+    }
+
+    impl LogSink for SpanSink {
+        fn log(&self, _record: &LogRecord<'_>) {
+            let span = Span::current();
+            self.spans
+                .lock()
+                .unwrap()
+                .push(span.metadata().map(|m| m.name()));
+        }
+    }
+
+    struct PanickingSink;
+
+    impl LogSink for PanickingSink {
+        fn log(&self, _record: &LogRecord<'_>) {
+            panic!("sink failure");
+        }
+    }
+
+    fn log(logger: *const Logger, severity: OrtLoggingLevel, message: &str) {
+        let category = CString::new("onnxruntime").unwrap();
+        let logid = CString::new("session").unwrap();
+        let code_location = CString::new("inference_session.cc:1234 Initialize").unwrap();
+        let message = CString::new(message).unwrap();
+        unsafe {
+            custom_logger(
+                logger as *mut std::ffi::c_void,
+                severity,
+                category.as_ptr(),
+                logid.as_ptr(),
+                code_location.as_ptr(),
+                message.as_ptr(),
+            )
+        };
+    }
+
+    #[test]
+    fn test_log_sink() {
+        let sink = Arc::new(RecordingSink::default());
+        let logger = Logger::new(sink.clone(), LoggingLevel::Warning);
+        log(&logger, OrtLoggingLevel_ORT_LOGGING_LEVEL_INFO, "dropped");
+        log(&logger, OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING, "kept");
+
+        logger.set_level(LoggingLevel::Info);
+        assert_eq!(LoggingLevel::Info, logger.level());
+        log(&logger, OrtLoggingLevel_ORT_LOGGING_LEVEL_INFO, "info");
+
+        let records = sink.records.lock().unwrap();
+        assert_eq!(
+            vec![
+                (
+                    LoggingLevel::Warning,
+                    "session".to_string(),
+                    "kept".to_string()
+                ),
+                (
+                    LoggingLevel::Info,
+                    "session".to_string(),
+                    "info".to_string()
+                ),
+            ],
+            *records
+        );
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_tracing_sink() {
+        log(
+            std::ptr::null(),
+            OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING,
+            "Removing initializer",
+        );
+        assert!(logs_contain("Removing initializer"));
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_log_id_span() {
+        {
+            let span = LogIdSpan::session("session");
+            assert!(LogIdSpan::find("session").is_some());
+            log(
+                std::ptr::null(),
+                OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING,
+                "Session message",
+            );
+            std::mem::drop(span);
+        }
+        assert!(LogIdSpan::find("session").is_none());
+        assert!(logs_contain("session{log_id=\"session\"}"));
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_log_id_span_in_custom_sink() {
+        let sink = Arc::new(SpanSink::default());
+        let logger = Logger::new(sink.clone(), LoggingLevel::Warning);
+        log(
+            &logger,
+            OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING,
+            "outside",
+        );
+        let span = LogIdSpan::session("session");
+        log(&logger, OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING, "inside");
+        std::mem::drop(span);
+        // The test itself runs in a span of `traced_test`
+        assert_eq!(
+            vec![Some("test_log_id_span_in_custom_sink"), Some("session")],
+            *sink.spans.lock().unwrap()
+        );
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_panicking_sink() {
+        let logger = Logger::new(Arc::new(PanickingSink), LoggingLevel::Verbose);
+        log(&logger, OrtLoggingLevel_ORT_LOGGING_LEVEL_ERROR, "lost");
+        assert!(logs_contain("The log sink of onnxruntime panicked"));
+    }
+
+    #[test]
+    fn test_null_strings() {
+        let sink = Arc::new(RecordingSink::default());
+        let logger = Logger::new(sink.clone(), LoggingLevel::Verbose);
+        let logger_ptr = &logger as *const Logger as *mut std::ffi::c_void;
+        unsafe {
+            custom_logger(
+                logger_ptr,
+                OrtLoggingLevel_ORT_LOGGING_LEVEL_ERROR,
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+            )
+        };
+        assert_eq!(1, sink.records.lock().unwrap().len());
     }
 }
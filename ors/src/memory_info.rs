@@ -1,25 +1,42 @@
-use crate::{api::get_api, call_ort, status::check_status};
-use anyhow::Result;
+use crate::error::Result;
+use crate::{
+    api::default_runtime,
+    call_ort,
+    runtime::{ObjectKind, Runtime, TrackedRuntime},
+    status::check_status,
+};
 use ors_sys::*;
 use tracing::{debug, warn};
 
 #[derive(Debug)]
 pub struct MemoryInfo {
+    pub(crate) runtime: TrackedRuntime,
     pub(crate) ptr: *mut OrtMemoryInfo,
 }
 
 impl MemoryInfo {
+    /// Create a CPU memory info in the default runtime
     pub fn new(allocator_type: OrtAllocatorType, memory_type: OrtMemType) -> Result<Self> {
+        Self::new_with_runtime(&default_runtime()?, allocator_type, memory_type)
+    }
+
+    /// Create a CPU memory info in the given runtime
+    pub fn new_with_runtime(
+        runtime: &Runtime,
+        allocator_type: OrtAllocatorType,
+        memory_type: OrtMemType,
+    ) -> Result<Self> {
         debug!("Creating new memory info.");
         let mut memory_info_ptr: *mut OrtMemoryInfo = std::ptr::null_mut();
         let status = call_ort!(
-            CreateCpuMemoryInfo,
+            runtime => CreateCpuMemoryInfo,
             allocator_type,
             memory_type,
             &mut memory_info_ptr
-        );
-        check_status(status)?;
+        )?;
+        check_status(runtime, status, "CreateCpuMemoryInfo")?;
         Ok(Self {
+            runtime: runtime.track(ObjectKind::MemoryInfo),
             ptr: memory_info_ptr,
         })
     }
@@ -31,19 +48,42 @@ impl Drop for MemoryInfo {
             warn!("MemoryInfo pointer is null, not dropping");
         } else {
             debug!("Dropping the memory info");
-            call_ort!(ReleaseMemoryInfo, self.ptr);
+            if let Err(e) = call_ort!(self.runtime => ReleaseMemoryInfo, self.ptr) {
+                warn!("Failed to release memory info: {}", e);
+            }
         }
         self.ptr = std::ptr::null_mut();
     }
 }
 
+/// The default CPU allocator of onnxruntime
+///
+/// The allocator is owned by onnxruntime and never released, but it must not be used after the library is unloaded
+#[derive(Debug)]
+pub(crate) struct Allocator {
+    pub(crate) runtime: TrackedRuntime,
+    pub(crate) ptr: *mut OrtAllocator,
+}
+
+impl Allocator {
+    pub(crate) fn default_allocator(runtime: &Runtime) -> Result<Self> {
+        let mut allocator_ptr = std::ptr::null_mut();
+        let status = call_ort!(runtime => GetAllocatorWithDefaultOptions, &mut allocator_ptr)?;
+        check_status(runtime, status, "GetAllocatorWithDefaultOptions")?;
+        Ok(Self {
+            runtime: runtime.track(ObjectKind::Allocator),
+            ptr: allocator_ptr,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, ptr::null_mut};
+    use std::ptr::null_mut;
 
     use tracing_test::traced_test;
 
-    use crate::api::initialize_runtime;
+    use crate::api::initialize_runtime_auto;
 
     use super::*;
 
@@ -60,16 +100,24 @@ mod tests {
     #[traced_test]
     fn test_drop_empty_memory_info() {
         setup_runtime();
-        let memory_info = MemoryInfo { ptr: null_mut() };
+        let memory_info = MemoryInfo {
+            runtime: default_runtime().unwrap().track(ObjectKind::MemoryInfo),
+            ptr: null_mut(),
+        };
         std::mem::drop(memory_info);
     }
+
+    #[test]
+    #[traced_test]
+    fn test_default_allocator() {
+        setup_runtime();
+        let runtime = default_runtime().unwrap();
+        let allocator = Allocator::default_allocator(&runtime).unwrap();
+        assert!(!allocator.ptr.is_null());
+        assert!(runtime.live_objects().allocators >= 1);
+    }
+
     fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).unwrap();
+        initialize_runtime_auto().unwrap();
     }
 }
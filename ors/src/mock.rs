@@ -0,0 +1,2306 @@
+//! An in-process fake onnxruntime, which is used to test code using ors without the native library
+//!
+//! [`MockBackend`] implements the `OrtApi` table in Rust. Models are scripted with [`MockModel`],
+//! which declares inputs and outputs and computes the results of `Run`. Every API called through
+//! `call_ort!` is recorded, and errors can be injected into session creation, the session getters and `Run`.
+//!
+//! ## Example
+//! ```
+//! use ndarray::{ArrayD, IxDyn};
+//! use ors::mock::{MockBackend, MockModel, MockTensor};
+//! use ors::session::run;
+//! use ors_sys::*;
+//!
+//! let backend = MockBackend::new();
+//! backend.add_model(
+//!     "add_one",
+//!     MockModel::new()
+//!         .input("x", ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT, &[None])
+//!         .output("y", ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT, &[None])
+//!         .on_run(|inputs| {
+//!             let x = inputs[0].to_array::<f32>().unwrap();
+//!             Ok(vec![MockTensor::from_array(&(x + 1.))])
+//!         }),
+//! );
+//! let runtime = backend.runtime().unwrap();
+//! let mut session = runtime
+//!     .session_builder()
+//!     .unwrap()
+//!     .build_with_model_in_memory("add_one")
+//!     .unwrap();
+//!
+//! let x = ArrayD::<f32>::from_shape_vec(IxDyn(&[2]), vec![1., 2.]).unwrap();
+//! let y = ArrayD::<f32>::zeros(IxDyn(&[2]));
+//! let inputs = vec![runtime.create_tensor_with_ndarray(x).unwrap()];
+//! let mut outputs = vec![runtime.create_tensor_with_ndarray(y).unwrap()];
+//! run(&mut session, &inputs, &mut outputs).unwrap();
+//! assert_eq!(&[2., 3.], outputs[0].data.as_f32_array().unwrap().as_slice().unwrap());
+//! assert!(backend.calls().contains(&"Run"));
+//! ```
+use std::alloc::{alloc, dealloc, Layout};
+use std::collections::{HashMap, VecDeque};
+use std::ffi::{c_void, CStr, CString};
+use std::fmt::Debug;
+use std::os::raw::{c_char, c_int};
+use std::path::PathBuf;
+use std::ptr::{null, null_mut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use ndarray::{ArrayD, IxDyn};
+use ors_sys::*;
+
+use crate::api::set_default_runtime;
+use crate::custom_op::{
+    library_into_raw, AttributeValue, RegisterCustomOpsFn, REGISTER_CUSTOM_OPS,
+};
+use crate::env::Environment;
+use crate::error::{ErrorCode, Result};
+use crate::log::{custom_logger, Logger};
+use crate::runtime::Runtime;
+use crate::types::TypeToTensorElementDataType;
+
+/// Version string reported by mock runtimes
+pub const MOCK_VERSION: &str = "mock";
+
+/// A fake onnxruntime whose models are scripted in Rust
+///
+/// Cloning a `MockBackend` is cheap, all clones and the runtimes created from them share
+/// the registered models, the injected errors and the recorded calls.
+#[derive(Clone, Default)]
+pub struct MockBackend {
+    state: Arc<MockState>,
+}
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a model
+    ///
+    /// The model is loaded by sessions built from a file at the path `name` or a file containing `name`,
+    /// or from the bytes of `name` in memory. Note that ors checks the model file exists before loading it.
+    /// A session whose options set an optimized model path writes `name` to that path,
+    /// and to the file of external initializers if its name is set by a config entry.
+    pub fn add_model(&self, name: &str, model: MockModel) {
+        lock(&self.state.models).insert(name.to_string(), model);
+    }
+
+    /// Make the next call of `function` return an error status
+    ///
+    /// Errors can be injected into `CreateSession`, `CreateSessionFromArray`, `Run`
+    /// and the `SessionGet*` functions. Several errors of one function are returned in order.
+    pub fn fail_next(&self, function: &str, code: ErrorCode, message: &str) {
+        lock(&self.state.failures)
+            .entry(function.to_string())
+            .or_default()
+            .push_back(MockError::new(code, message));
+    }
+
+    /// Names of the API functions called through runtimes of this backend, in order
+    pub fn calls(&self) -> Vec<&'static str> {
+        lock(&self.state.calls).clone()
+    }
+
+    /// Forget the recorded calls
+    pub fn clear_calls(&self) {
+        lock(&self.state.calls).clear();
+    }
+
+    /// Negotiate an older API version with runtimes created afterwards, like an older onnxruntime
+    ///
+    /// Sessions reject custom ops whose version is newer than the negotiated one.
+    pub fn set_api_version(&self, version: u32) {
+        *lock(&self.state.api_version) = Some(version);
+    }
+
+    /// Create a runtime which calls this backend
+    ///
+    /// Runtimes of a backend share one environment, like runtimes of one library.
+    pub fn runtime(&self) -> Result<Runtime> {
+        let api = mock_api();
+        let env = Environment::shared(
+            Arc::as_ptr(&self.state) as usize,
+            None,
+            &api,
+            |_, logger| {
+                let env = Box::into_raw(Box::new(MockEnv {
+                    state: self.state.clone(),
+                    logger: logger.clone(),
+                }));
+                Ok(env as *mut OrtEnv)
+            },
+        )?;
+        Ok(Runtime::mock(api, env, self.state.clone()))
+    }
+
+    /// Create a runtime which calls this backend and make it the default runtime
+    ///
+    /// The replaced default runtime is unloaded after all objects created from it are dropped.
+    pub fn install(&self) -> Result<Runtime> {
+        let runtime = self.runtime()?;
+        set_default_runtime(runtime.clone());
+        Ok(runtime)
+    }
+}
+
+impl Debug for MockBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockBackend")
+            .field("models", &lock(&self.state.models).keys())
+            .finish()
+    }
+}
+
+/// Computes outputs of a mock model from its inputs
+pub type MockRunFn =
+    dyn Fn(&[MockTensor]) -> std::result::Result<Vec<MockTensor>, MockError> + Send + Sync;
+
+/// A scripted model
+///
+/// Inputs passed to the run function are in the order of declared inputs, followed by the values of
+/// declared initializers, and the returned outputs must be in the order of declared outputs.
+/// Without a run function, outputs are left untouched.
+#[derive(Clone, Default)]
+pub struct MockModel {
+    inputs: Vec<MockTensorInfo>,
+    outputs: Vec<MockTensorInfo>,
+    initializers: Vec<(MockTensorInfo, MockTensor)>,
+    on_run: Option<Arc<MockRunFn>>,
+    custom_op: Option<MockCustomOpNode>,
+}
+
+/// The node of a custom op which computes the outputs of a mock model
+#[derive(Debug, Clone)]
+struct MockCustomOpNode {
+    op_type: String,
+    attributes: HashMap<String, AttributeValue>,
+}
+
+impl MockModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare an input, `None` in `dims` is a free dimension
+    pub fn input(
+        mut self,
+        name: &str,
+        element_type: ONNXTensorElementDataType,
+        dims: &[Option<i64>],
+    ) -> Self {
+        self.inputs
+            .push(MockTensorInfo::new(name, element_type, dims));
+        self
+    }
+
+    /// Declare an output, `None` in `dims` is a free dimension
+    pub fn output(
+        mut self,
+        name: &str,
+        element_type: ONNXTensorElementDataType,
+        dims: &[Option<i64>],
+    ) -> Self {
+        self.outputs
+            .push(MockTensorInfo::new(name, element_type, dims));
+        self
+    }
+
+    /// Declare an overridable initializer with its default value
+    pub fn initializer(mut self, name: &str, value: MockTensor) -> Self {
+        let dims: Vec<Option<i64>> = value.shape.iter().map(|d| Some(*d)).collect();
+        let info = MockTensorInfo::new(name, value.element_type, &dims);
+        self.initializers.push((info, value));
+        self
+    }
+
+    /// Name the dimensions of a declared input or output, like `batch`
+    ///
+    /// Names are returned by `GetSymbolicDimensions`, unnamed dimensions have empty names.
+    pub fn symbolic_dims(mut self, tensor_name: &str, names: &[&str]) -> Self {
+        let names: Vec<CString> = names
+            .iter()
+            .map(|n| CString::new(*n).expect("Dimension names must not contain nul bytes"))
+            .collect();
+        for info in self.inputs.iter_mut().chain(self.outputs.iter_mut()) {
+            if info.name == tensor_name {
+                info.symbolic_dims = names.clone();
+            }
+        }
+        self
+    }
+
+    /// Compute outputs with a closure, an error is returned from `Run` as a failing status
+    pub fn on_run<F>(mut self, on_run: F) -> Self
+    where
+        F: Fn(&[MockTensor]) -> std::result::Result<Vec<MockTensor>, MockError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.on_run = Some(Arc::new(on_run));
+        self
+    }
+
+    /// Return the same outputs from every run
+    pub fn outputs(self, outputs: Vec<MockTensor>) -> Self {
+        self.on_run(move |_| Ok(outputs.clone()))
+    }
+
+    /// Compute outputs with a single node of a custom op, which must be registered in the session options
+    ///
+    /// The kernel is created with the session, and the op gets the inputs and outputs of the model.
+    pub fn custom_op(mut self, op_type: &str) -> Self {
+        self.custom_op = Some(MockCustomOpNode {
+            op_type: op_type.to_string(),
+            attributes: HashMap::new(),
+        });
+        self
+    }
+
+    /// Set an attribute of the custom op node
+    pub fn attribute(mut self, name: &str, value: AttributeValue) -> Self {
+        if let Some(node) = &mut self.custom_op {
+            node.attributes.insert(name.to_string(), value);
+        }
+        self
+    }
+}
+
+impl Debug for MockModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockModel")
+            .field("inputs", &self.inputs)
+            .field("outputs", &self.outputs)
+            .field("initializers", &self.initializers)
+            .field("custom_op", &self.custom_op)
+            .finish()
+    }
+}
+
+/// A tensor passed to or returned from a mock model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockTensor {
+    pub element_type: ONNXTensorElementDataType,
+    pub shape: Vec<i64>,
+    /// Elements in native byte order
+    pub data: Vec<u8>,
+}
+
+impl MockTensor {
+    pub fn from_array<T: TypeToTensorElementDataType + Copy>(array: &ArrayD<T>) -> Self {
+        let elements: Vec<T> = array.iter().copied().collect();
+        let data = unsafe {
+            std::slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * std::mem::size_of::<T>(),
+            )
+        }
+        .to_vec();
+        Self {
+            element_type: T::tensor_element_data_type(),
+            shape: array.shape().iter().map(|d| *d as i64).collect(),
+            data,
+        }
+    }
+
+    /// Convert to an array, returns `None` if the element type is not `T`
+    pub fn to_array<T: TypeToTensorElementDataType + Copy>(&self) -> Option<ArrayD<T>> {
+        if self.element_type != T::tensor_element_data_type() {
+            return None;
+        }
+        let elements: Vec<T> = self
+            .data
+            .chunks_exact(std::mem::size_of::<T>())
+            .map(|chunk| unsafe { std::ptr::read_unaligned(chunk.as_ptr() as *const T) })
+            .collect();
+        let shape: Vec<usize> = self.shape.iter().map(|d| *d as usize).collect();
+        ArrayD::from_shape_vec(IxDyn(&shape), elements).ok()
+    }
+}
+
+/// An error status returned by the mock backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl MockError {
+    pub fn new(code: ErrorCode, message: &str) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// State shared by a backend and its runtimes
+#[derive(Default)]
+pub(crate) struct MockState {
+    models: Mutex<HashMap<String, MockModel>>,
+    failures: Mutex<HashMap<String, VecDeque<MockError>>>,
+    calls: Mutex<Vec<&'static str>>,
+    api_version: Mutex<Option<u32>>,
+}
+
+impl MockState {
+    /// The API version negotiated by runtimes, `ORT_API_VERSION` unless it's set
+    pub(crate) fn api_version(&self) -> u32 {
+        lock(&self.api_version).unwrap_or(ORT_API_VERSION)
+    }
+
+    pub(crate) fn record_call(&self, function: &'static str) {
+        lock(&self.calls).push(function);
+    }
+
+    fn take_failure(&self, function: &str) -> OrtStatusPtr {
+        match lock(&self.failures)
+            .get_mut(function)
+            .and_then(|f| f.pop_front())
+        {
+            Some(err) => new_status(err.code.into(), &err.message),
+            None => null_mut(),
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panic in a test doesn't leave the state inconsistent
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[derive(Debug, Clone)]
+struct MockTensorInfo {
+    name: String,
+    element_type: ONNXTensorElementDataType,
+    dims: Vec<i64>,
+    /// Names of the dimensions, returned by `GetSymbolicDimensions`
+    symbolic_dims: Vec<CString>,
+}
+
+impl MockTensorInfo {
+    fn new(name: &str, element_type: ONNXTensorElementDataType, dims: &[Option<i64>]) -> Self {
+        Self {
+            name: name.to_string(),
+            element_type,
+            dims: dims.iter().map(|d| d.unwrap_or(-1)).collect(),
+            symbolic_dims: vec![],
+        }
+    }
+}
+
+struct MockEnv {
+    state: Arc<MockState>,
+    logger: Arc<Logger>,
+}
+
+impl MockEnv {
+    /// Log a message with the log id through the logger of the environment, like onnxruntime does
+    fn log(&self, log_id: &str, message: &str) {
+        let category = CString::new("onnxruntime").unwrap_or_default();
+        let log_id = CString::new(log_id).unwrap_or_default();
+        let code_location = CString::new("mock.rs:0 MockEnv::log").unwrap_or_default();
+        let message = CString::new(message).unwrap_or_default();
+        unsafe {
+            custom_logger(
+                Arc::as_ptr(&self.logger) as *mut c_void,
+                OrtLoggingLevel_ORT_LOGGING_LEVEL_WARNING,
+                category.as_ptr(),
+                log_id.as_ptr(),
+                code_location.as_ptr(),
+                message.as_ptr(),
+            )
+        };
+    }
+}
+
+struct MockStatus {
+    code: OrtErrorCode,
+    message: CString,
+}
+
+struct MockSession {
+    state: Arc<MockState>,
+    model: MockModel,
+    profile_prefix: Option<String>,
+    runs: AtomicUsize,
+    kernel: Option<MockKernel>,
+    /// Values of the initializers, added by the session options or the defaults of the model
+    initializers: Vec<MockTensor>,
+}
+
+#[derive(Clone, Default)]
+struct MockSessionOptions {
+    optimized_model_path: Option<PathBuf>,
+    log_id: Option<String>,
+    config_entries: HashMap<String, String>,
+    profile_prefix: Option<String>,
+    // Domains are owned by the caller, which keeps them alive until sessions are released
+    custom_op_domains: Vec<*const MockCustomOpDomain>,
+    // Values are owned by the caller, which keeps them alive until sessions are released
+    initializers: Vec<(String, *const OrtValue)>,
+}
+
+/// Mock sessions don't prepack weights
+struct MockPrepackedWeights;
+
+struct MockCustomOpDomain {
+    name: String,
+    ops: Vec<*const OrtCustomOp>,
+}
+
+/// The kernel created by a custom op for the node of a mock model
+struct MockKernel {
+    op: *const OrtCustomOp,
+    kernel: *mut c_void,
+}
+
+struct MockKernelInfo {
+    attributes: HashMap<String, AttributeValue>,
+}
+
+struct MockKernelContext {
+    inputs: Vec<MockValue>,
+    outputs: Vec<(MockTensorInfo, Option<MockValue>)>,
+}
+
+/// Start time of profiles written by mock sessions
+pub(crate) const MOCK_PROFILING_START_TIME_NS: u64 = 1_000_000_000;
+
+struct MockTypeInfo {
+    tensor_info: MockTensorInfo,
+}
+
+struct MockValue {
+    tensor_info: MockTensorInfo,
+    data: *mut u8,
+    len: usize,
+    // Keeps the data of values created by `Run`, values created by users borrow their data
+    owned: Vec<u8>,
+}
+
+struct MockMemoryInfo {
+    allocator_type: OrtAllocatorType,
+    mem_type: OrtMemType,
+}
+
+static CPU_MEMORY_INFO: MockMemoryInfo = MockMemoryInfo {
+    allocator_type: OrtAllocatorType_OrtDeviceAllocator,
+    mem_type: OrtMemType_OrtMemTypeDefault,
+};
+
+static ALLOCATOR: OrtAllocator = OrtAllocator {
+    version: ORT_API_VERSION,
+    Alloc: Some(allocator_alloc),
+    Free: Some(allocator_free),
+    Info: Some(allocator_info),
+};
+
+/// Providers of mock runtimes, which behave like a build of onnxruntime with XNNPACK
+const PROVIDERS: [&str; 2] = ["XnnpackExecutionProvider", "CPUExecutionProvider"];
+
+/// Config entry naming the file which initializers of the optimized model are saved to
+const EXTERNAL_INITIALIZERS_FILE_NAME: &str =
+    "session.optimized_model_external_initializers_file_name";
+
+/// Build the API table of the mock backend, functions which are not implemented are `None`
+fn mock_api() -> OrtApi {
+    let mut api: OrtApi = unsafe { std::mem::zeroed() };
+    api.CreateStatus = Some(create_status);
+    api.GetErrorCode = Some(get_error_code);
+    api.GetErrorMessage = Some(get_error_message);
+    api.ReleaseStatus = Some(release_status);
+    api.ReleaseEnv = Some(release_env);
+    api.CreateSessionOptions = Some(create_session_options);
+    api.ReleaseSessionOptions = Some(release_session_options);
+    api.SetIntraOpNumThreads = Some(set_int_option);
+    api.SetInterOpNumThreads = Some(set_int_option);
+    api.SetSessionGraphOptimizationLevel = Some(set_session_option);
+    api.SetSessionExecutionMode = Some(set_session_option);
+    api.EnableCpuMemArena = Some(enable_session_option);
+    api.EnableMemPattern = Some(enable_session_option);
+    api.DisableCpuMemArena = Some(enable_session_option);
+    api.DisableMemPattern = Some(enable_session_option);
+    api.CloneSessionOptions = Some(clone_session_options);
+    api.EnableProfiling = Some(enable_profiling);
+    api.SessionEndProfiling = Some(session_end_profiling);
+    api.SessionGetProfilingStartTimeNs = Some(session_get_profiling_start_time_ns);
+    api.AddFreeDimensionOverride = Some(add_free_dimension_override);
+    api.AddFreeDimensionOverrideByName = Some(add_free_dimension_override);
+    api.SetOptimizedModelFilePath = Some(set_optimized_model_file_path);
+    api.SessionOptionsAppendExecutionProvider = Some(append_execution_provider);
+    api.DisablePerSessionThreads = Some(enable_session_option);
+    api.SetSessionLogId = Some(set_session_log_id);
+    api.SetSessionLogSeverityLevel = Some(set_int_option);
+    api.SetSessionLogVerbosityLevel = Some(set_int_option);
+    api.AddSessionConfigEntry = Some(add_session_config_entry);
+    api.AddRunConfigEntry = Some(add_run_config_entry);
+    api.CreateRunOptions = Some(create_run_options);
+    api.ReleaseRunOptions = Some(release_run_options);
+    api.RunOptionsSetRunTag = Some(set_run_tag);
+    api.RunOptionsSetRunLogSeverityLevel = Some(set_run_log_level);
+    api.RunOptionsSetRunLogVerbosityLevel = Some(set_run_log_level);
+    api.SessionOptionsSetCustomCreateThreadFn = Some(set_create_thread_fn);
+    api.SessionOptionsSetCustomThreadCreationOptions = Some(set_thread_creation_options);
+    api.SessionOptionsSetCustomJoinThreadFn = Some(set_join_thread_fn);
+    api.CreateSession = Some(create_session);
+    api.CreateSessionFromArray = Some(create_session_from_array);
+    api.CreatePrepackedWeightsContainer = Some(create_prepacked_weights_container);
+    api.ReleasePrepackedWeightsContainer = Some(release_prepacked_weights_container);
+    api.CreateSessionWithPrepackedWeightsContainer = Some(create_session_with_prepacked_weights);
+    api.CreateSessionFromArrayWithPrepackedWeightsContainer =
+        Some(create_session_from_array_with_prepacked_weights);
+    api.ReleaseSession = Some(release_session);
+    api.SessionGetInputCount = Some(session_get_input_count);
+    api.SessionGetOutputCount = Some(session_get_output_count);
+    api.SessionGetInputName = Some(session_get_input_name);
+    api.SessionGetOutputName = Some(session_get_output_name);
+    api.SessionGetInputTypeInfo = Some(session_get_input_type_info);
+    api.SessionGetOverridableInitializerCount = Some(session_get_overridable_initializer_count);
+    api.SessionGetOverridableInitializerName = Some(session_get_overridable_initializer_name);
+    api.SessionGetOverridableInitializerTypeInfo =
+        Some(session_get_overridable_initializer_type_info);
+    api.AddInitializer = Some(add_initializer);
+    api.AddExternalInitializers = Some(add_external_initializers);
+    api.SessionGetOutputTypeInfo = Some(session_get_output_type_info);
+    api.CastTypeInfoToTensorInfo = Some(cast_type_info_to_tensor_info);
+    api.ReleaseTypeInfo = Some(release_type_info);
+    api.GetDimensionsCount = Some(get_dimensions_count);
+    api.GetDimensions = Some(get_dimensions);
+    api.GetSymbolicDimensions = Some(get_symbolic_dimensions);
+    api.GetTensorElementType = Some(get_tensor_element_type);
+    api.GetTensorShapeElementCount = Some(get_tensor_shape_element_count);
+    api.GetTensorTypeAndShape = Some(get_tensor_type_and_shape);
+    api.ReleaseTensorTypeAndShapeInfo = Some(release_tensor_type_and_shape_info);
+    api.GetAllocatorWithDefaultOptions = Some(get_allocator_with_default_options);
+    api.AllocatorAlloc = Some(allocator_alloc_status);
+    api.AllocatorFree = Some(allocator_free_status);
+    api.AllocatorGetInfo = Some(allocator_get_info);
+    api.CreateCpuMemoryInfo = Some(create_cpu_memory_info);
+    api.ReleaseMemoryInfo = Some(release_memory_info);
+    api.CreateTensorWithDataAsOrtValue = Some(create_tensor_with_data);
+    api.IsTensor = Some(is_tensor);
+    api.GetTensorMutableData = Some(get_tensor_mutable_data);
+    api.ReleaseValue = Some(release_value);
+    api.Run = Some(run);
+    api.GetAvailableProviders = Some(get_available_providers);
+    api.CreateCustomOpDomain = Some(create_custom_op_domain);
+    api.CustomOpDomain_Add = Some(custom_op_domain_add);
+    api.AddCustomOpDomain = Some(add_custom_op_domain);
+    api.ReleaseCustomOpDomain = Some(release_custom_op_domain);
+    api.RegisterCustomOpsLibrary = Some(register_custom_ops_library);
+    api.EnableOrtCustomOps = Some(enable_session_option);
+    api.KernelInfoGetAttribute_int64 = Some(kernel_info_get_attribute_int64);
+    api.KernelInfoGetAttribute_float = Some(kernel_info_get_attribute_float);
+    api.KernelInfoGetAttribute_string = Some(kernel_info_get_attribute_string);
+    api.KernelInfoGetAttributeArray_int64 = Some(kernel_info_get_attribute_array_int64);
+    api.KernelInfoGetAttributeArray_float = Some(kernel_info_get_attribute_array_float);
+    api.KernelContext_GetInputCount = Some(kernel_context_get_input_count);
+    api.KernelContext_GetOutputCount = Some(kernel_context_get_output_count);
+    api.KernelContext_GetInput = Some(kernel_context_get_input);
+    api.KernelContext_GetOutput = Some(kernel_context_get_output);
+    api.ReleaseAvailableProviders = Some(release_available_providers);
+    api
+}
+
+fn new_status(code: OrtErrorCode, message: &str) -> OrtStatusPtr {
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    Box::into_raw(Box::new(MockStatus { code, message })) as OrtStatusPtr
+}
+
+fn invalid_argument(message: &str) -> OrtStatusPtr {
+    new_status(OrtErrorCode_ORT_INVALID_ARGUMENT, message)
+}
+
+unsafe extern "C" fn create_status(code: OrtErrorCode, msg: *const c_char) -> *mut OrtStatus {
+    let message = if msg.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(msg).to_string_lossy().to_string()
+    };
+    new_status(code, &message)
+}
+
+unsafe extern "C" fn get_error_code(status: *const OrtStatus) -> OrtErrorCode {
+    match (status as *const MockStatus).as_ref() {
+        Some(status) => status.code,
+        None => OrtErrorCode_ORT_OK,
+    }
+}
+
+unsafe extern "C" fn get_error_message(status: *const OrtStatus) -> *const c_char {
+    match (status as *const MockStatus).as_ref() {
+        Some(status) => status.message.as_ptr(),
+        None => null(),
+    }
+}
+
+unsafe extern "C" fn release_status(status: *mut OrtStatus) {
+    if !status.is_null() {
+        drop(Box::from_raw(status as *mut MockStatus));
+    }
+}
+
+unsafe extern "C" fn release_env(env: *mut OrtEnv) {
+    if !env.is_null() {
+        drop(Box::from_raw(env as *mut MockEnv));
+    }
+}
+
+unsafe extern "C" fn create_session_options(out: *mut *mut OrtSessionOptions) -> OrtStatusPtr {
+    // Options are accepted but ignored, except the path of the optimized model
+    *out = Box::into_raw(Box::<MockSessionOptions>::default()) as *mut OrtSessionOptions;
+    null_mut()
+}
+
+unsafe extern "C" fn release_session_options(options: *mut OrtSessionOptions) {
+    if !options.is_null() {
+        drop(Box::from_raw(options as *mut MockSessionOptions));
+    }
+}
+
+unsafe extern "C" fn clone_session_options(
+    options: *const OrtSessionOptions,
+    out: *mut *mut OrtSessionOptions,
+) -> OrtStatusPtr {
+    match (options as *const MockSessionOptions).as_ref() {
+        Some(options) => {
+            *out = Box::into_raw(Box::new(options.clone())) as *mut OrtSessionOptions;
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+unsafe extern "C" fn enable_profiling(
+    options: *mut OrtSessionOptions,
+    prefix: *const c_char,
+) -> OrtStatusPtr {
+    match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => {
+            options.profile_prefix = Some(ort_string(prefix));
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+unsafe extern "C" fn add_free_dimension_override(
+    _options: *mut OrtSessionOptions,
+    _dim: *const c_char,
+    _value: i64,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn add_initializer(
+    options: *mut OrtSessionOptions,
+    name: *const c_char,
+    val: *const OrtValue,
+) -> OrtStatusPtr {
+    match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => {
+            options.initializers.push((ort_string(name), val));
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+unsafe extern "C" fn add_external_initializers(
+    options: *mut OrtSessionOptions,
+    initializer_names: *const *const c_char,
+    initializers: *const *const OrtValue,
+    initializers_num: usize,
+) -> OrtStatusPtr {
+    let options = match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => options,
+        None => return invalid_argument("Session options are null"),
+    };
+    for (i, name) in names(initializer_names, initializers_num)
+        .into_iter()
+        .enumerate()
+    {
+        options.initializers.push((name, *initializers.add(i)));
+    }
+    null_mut()
+}
+
+unsafe extern "C" fn set_optimized_model_file_path(
+    options: *mut OrtSessionOptions,
+    path: *const c_char,
+) -> OrtStatusPtr {
+    match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => {
+            options.optimized_model_path = Some(PathBuf::from(ort_string(path)));
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+/// Read a null terminated `ORTCHAR_T` string
+unsafe fn ort_string(s: *const c_char) -> String {
+    #[cfg(not(target_family = "windows"))]
+    let s = CStr::from_ptr(s).to_string_lossy().to_string();
+    // Paths are wide strings on Windows
+    #[cfg(target_family = "windows")]
+    let s = {
+        let s = s as *const u16;
+        let len = (0..).take_while(|i| *s.add(*i) != 0).count();
+        String::from_utf16_lossy(std::slice::from_raw_parts(s, len))
+    };
+    s
+}
+
+unsafe extern "C" fn append_execution_provider(
+    _options: *mut OrtSessionOptions,
+    provider_name: *const c_char,
+    _keys: *const *const c_char,
+    _values: *const *const c_char,
+    _num_keys: usize,
+) -> OrtStatusPtr {
+    match CStr::from_ptr(provider_name).to_bytes() {
+        b"XNNPACK" => null_mut(),
+        b"SNPE" => new_status(
+            OrtErrorCode_ORT_FAIL,
+            "SNPE execution provider is not supported in this build.",
+        ),
+        _ => invalid_argument(
+            "Unknown provider name. Currently supported values are 'SNPE' and 'XNNPACK'",
+        ),
+    }
+}
+
+unsafe extern "C" fn set_int_option(_options: *mut OrtSessionOptions, _num: c_int) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn set_session_option(
+    _options: *mut OrtSessionOptions,
+    _value: u32,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn enable_session_option(_options: *mut OrtSessionOptions) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn set_session_log_id(
+    options: *mut OrtSessionOptions,
+    log_id: *const c_char,
+) -> OrtStatusPtr {
+    match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => {
+            options.log_id = Some(CStr::from_ptr(log_id).to_string_lossy().to_string());
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+unsafe extern "C" fn add_session_config_entry(
+    options: *mut OrtSessionOptions,
+    key: *const c_char,
+    value: *const c_char,
+) -> OrtStatusPtr {
+    match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => {
+            let key = CStr::from_ptr(key).to_string_lossy().to_string();
+            let value = CStr::from_ptr(value).to_string_lossy().to_string();
+            options.config_entries.insert(key, value);
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+unsafe extern "C" fn add_run_config_entry(
+    _options: *mut OrtRunOptions,
+    _key: *const c_char,
+    _value: *const c_char,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn create_run_options(out: *mut *mut OrtRunOptions) -> OrtStatusPtr {
+    *out = Box::into_raw(Box::new(0u8)) as *mut OrtRunOptions;
+    null_mut()
+}
+
+unsafe extern "C" fn release_run_options(options: *mut OrtRunOptions) {
+    if !options.is_null() {
+        drop(Box::from_raw(options as *mut u8));
+    }
+}
+
+unsafe extern "C" fn set_run_tag(
+    _options: *mut OrtRunOptions,
+    _tag: *const c_char,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn set_run_log_level(
+    _options: *mut OrtRunOptions,
+    _level: c_int,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+// The mock backend doesn't create threads
+unsafe extern "C" fn set_create_thread_fn(
+    _options: *mut OrtSessionOptions,
+    _create_thread_fn: OrtCustomCreateThreadFn,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn set_thread_creation_options(
+    _options: *mut OrtSessionOptions,
+    _creation_options: *mut c_void,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe extern "C" fn set_join_thread_fn(
+    _options: *mut OrtSessionOptions,
+    _join_thread_fn: OrtCustomJoinThreadFn,
+) -> OrtStatusPtr {
+    null_mut()
+}
+
+unsafe fn new_session(
+    env: *const OrtEnv,
+    name: &str,
+    options: *const OrtSessionOptions,
+    function: &str,
+    out: *mut *mut OrtSession,
+) -> OrtStatusPtr {
+    let env = &*(env as *const MockEnv);
+    let status = env.state.take_failure(function);
+    if !status.is_null() {
+        return status;
+    }
+    match lock(&env.state.models).get(name) {
+        Some(model) => {
+            let options = (options as *const MockSessionOptions).as_ref();
+            if let Some(log_id) = options.and_then(|o| o.log_id.as_deref()) {
+                env.log(log_id, "Creating the mock session");
+            }
+            let optimized_model_path = options.and_then(|o| o.optimized_model_path.as_ref());
+            if let Some(path) = optimized_model_path {
+                // External initializers are saved next to the optimized model
+                let external_initializers = options
+                    .and_then(|o| o.config_entries.get(EXTERNAL_INITIALIZERS_FILE_NAME))
+                    .map(|file_name| path.with_file_name(file_name));
+                let saved = std::fs::write(path, name).and_then(|()| match external_initializers {
+                    Some(path) => std::fs::write(path, name),
+                    None => Ok(()),
+                });
+                if let Err(e) = saved {
+                    return new_status(
+                        OrtErrorCode_ORT_FAIL,
+                        &format!("Failed to save the optimized model: {}", e),
+                    );
+                }
+            }
+            let mut initializers: Vec<MockTensor> =
+                model.initializers.iter().map(|(_, v)| v.clone()).collect();
+            for (name, value) in options.map(|o| o.initializers.as_slice()).unwrap_or(&[]) {
+                let index = match model.initializers.iter().position(|(i, _)| i.name == *name) {
+                    Some(index) => index,
+                    None => {
+                        return invalid_argument(&format!(
+                            "Initializer {} is not in the graph",
+                            name
+                        ))
+                    }
+                };
+                match read_value(*value) {
+                    Some(tensor) => initializers[index] = tensor,
+                    None => return invalid_argument(&format!("Initializer {} is null", name)),
+                }
+            }
+            let kernel = match &model.custom_op {
+                Some(node) => match MockKernel::new(options, node, env.state.api_version()) {
+                    Ok(kernel) => Some(kernel),
+                    Err(status) => return status,
+                },
+                None => None,
+            };
+            let session = MockSession {
+                state: env.state.clone(),
+                model: model.clone(),
+                profile_prefix: options.and_then(|o| o.profile_prefix.clone()),
+                runs: AtomicUsize::new(0),
+                kernel,
+                initializers,
+            };
+            *out = Box::into_raw(Box::new(session)) as *mut OrtSession;
+            null_mut()
+        }
+        None => new_status(
+            OrtErrorCode_ORT_NO_SUCHFILE,
+            &format!("Mock model {} is not registered", name),
+        ),
+    }
+}
+
+unsafe fn model_name_of_file(env: *const OrtEnv, model_path: *const c_char) -> String {
+    let path = ort_string(model_path);
+    let mock_env = &*(env as *const MockEnv);
+    let registered = lock(&mock_env.state.models).contains_key(&path);
+    // Files of models which are not registered by path contain the name of the model
+    match registered {
+        true => path,
+        false => std::fs::read_to_string(&path).unwrap_or(path),
+    }
+}
+
+unsafe fn model_name_of_bytes(model_data: *const c_void, model_data_length: usize) -> String {
+    let bytes = std::slice::from_raw_parts(model_data as *const u8, model_data_length);
+    String::from_utf8_lossy(bytes).to_string()
+}
+
+unsafe extern "C" fn create_session(
+    env: *const OrtEnv,
+    model_path: *const c_char,
+    options: *const OrtSessionOptions,
+    out: *mut *mut OrtSession,
+) -> OrtStatusPtr {
+    let name = model_name_of_file(env, model_path);
+    new_session(env, &name, options, "CreateSession", out)
+}
+
+unsafe extern "C" fn create_session_from_array(
+    env: *const OrtEnv,
+    model_data: *const c_void,
+    model_data_length: usize,
+    options: *const OrtSessionOptions,
+    out: *mut *mut OrtSession,
+) -> OrtStatusPtr {
+    let name = model_name_of_bytes(model_data, model_data_length);
+    new_session(env, &name, options, "CreateSessionFromArray", out)
+}
+
+unsafe extern "C" fn create_prepacked_weights_container(
+    out: *mut *mut OrtPrepackedWeightsContainer,
+) -> OrtStatusPtr {
+    *out = Box::into_raw(Box::new(MockPrepackedWeights)) as *mut OrtPrepackedWeightsContainer;
+    null_mut()
+}
+
+unsafe extern "C" fn release_prepacked_weights_container(
+    container: *mut OrtPrepackedWeightsContainer,
+) {
+    if !container.is_null() {
+        drop(Box::from_raw(container as *mut MockPrepackedWeights));
+    }
+}
+
+unsafe extern "C" fn create_session_with_prepacked_weights(
+    env: *const OrtEnv,
+    model_path: *const c_char,
+    options: *const OrtSessionOptions,
+    container: *mut OrtPrepackedWeightsContainer,
+    out: *mut *mut OrtSession,
+) -> OrtStatusPtr {
+    if container.is_null() {
+        return invalid_argument("Prepacked weights container is null");
+    }
+    let name = model_name_of_file(env, model_path);
+    let function = "CreateSessionWithPrepackedWeightsContainer";
+    new_session(env, &name, options, function, out)
+}
+
+unsafe extern "C" fn create_session_from_array_with_prepacked_weights(
+    env: *const OrtEnv,
+    model_data: *const c_void,
+    model_data_length: usize,
+    options: *const OrtSessionOptions,
+    container: *mut OrtPrepackedWeightsContainer,
+    out: *mut *mut OrtSession,
+) -> OrtStatusPtr {
+    if container.is_null() {
+        return invalid_argument("Prepacked weights container is null");
+    }
+    let name = model_name_of_bytes(model_data, model_data_length);
+    let function = "CreateSessionFromArrayWithPrepackedWeightsContainer";
+    new_session(env, &name, options, function, out)
+}
+
+unsafe extern "C" fn release_session(session: *mut OrtSession) {
+    if !session.is_null() {
+        drop(Box::from_raw(session as *mut MockSession));
+    }
+}
+
+/// Get the session and check injected errors of the function
+unsafe fn session_of<'a>(
+    session: *const OrtSession,
+    function: &str,
+) -> std::result::Result<&'a MockSession, OrtStatusPtr> {
+    match (session as *const MockSession).as_ref() {
+        None => Err(invalid_argument("Session is null")),
+        Some(session) => {
+            let status = session.state.take_failure(function);
+            if status.is_null() {
+                Ok(session)
+            } else {
+                Err(status)
+            }
+        }
+    }
+}
+
+unsafe fn tensor_info_at(
+    infos: &[MockTensorInfo],
+    index: usize,
+) -> std::result::Result<&MockTensorInfo, OrtStatusPtr> {
+    infos
+        .get(index)
+        .ok_or_else(|| invalid_argument(&format!("Index {} is out of range", index)))
+}
+
+unsafe extern "C" fn session_get_input_count(
+    session: *const OrtSession,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetInputCount") {
+        Ok(session) => {
+            *out = session.model.inputs.len();
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn session_get_output_count(
+    session: *const OrtSession,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetOutputCount") {
+        Ok(session) => {
+            *out = session.model.outputs.len();
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe fn allocate_name(
+    allocator: *mut OrtAllocator,
+    name: &str,
+    out: *mut *mut c_char,
+) -> OrtStatusPtr {
+    let name = CString::new(name).unwrap_or_default();
+    let bytes = name.as_bytes_with_nul();
+    let ptr = match (*allocator).Alloc {
+        Some(alloc) => alloc(allocator, bytes.len()) as *mut u8,
+        None => null_mut(),
+    };
+    if ptr.is_null() {
+        return new_status(OrtErrorCode_ORT_FAIL, "Failed to allocate the name");
+    }
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+    *out = ptr as *mut c_char;
+    null_mut()
+}
+
+unsafe extern "C" fn session_get_input_name(
+    session: *const OrtSession,
+    index: usize,
+    allocator: *mut OrtAllocator,
+    value: *mut *mut c_char,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetInputName")
+        .and_then(|session| tensor_info_at(&session.model.inputs, index))
+    {
+        Ok(info) => allocate_name(allocator, &info.name, value),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn session_get_output_name(
+    session: *const OrtSession,
+    index: usize,
+    allocator: *mut OrtAllocator,
+    value: *mut *mut c_char,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetOutputName")
+        .and_then(|session| tensor_info_at(&session.model.outputs, index))
+    {
+        Ok(info) => allocate_name(allocator, &info.name, value),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn session_get_overridable_initializer_count(
+    session: *const OrtSession,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetOverridableInitializerCount") {
+        Ok(session) => {
+            *out = session.model.initializers.len();
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn session_get_overridable_initializer_name(
+    session: *const OrtSession,
+    index: usize,
+    allocator: *mut OrtAllocator,
+    value: *mut *mut c_char,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetOverridableInitializerName").and_then(|session| {
+        session
+            .model
+            .initializers
+            .get(index)
+            .ok_or_else(|| invalid_argument(&format!("Index {} is out of range", index)))
+    }) {
+        Ok((info, _)) => allocate_name(allocator, &info.name, value),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn session_get_overridable_initializer_type_info(
+    session: *const OrtSession,
+    index: usize,
+    type_info: *mut *mut OrtTypeInfo,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetOverridableInitializerTypeInfo").and_then(|session| {
+        session
+            .model
+            .initializers
+            .get(index)
+            .ok_or_else(|| invalid_argument(&format!("Index {} is out of range", index)))
+    }) {
+        Ok((info, _)) => new_type_info(info, type_info),
+        Err(status) => status,
+    }
+}
+
+unsafe fn new_type_info(info: &MockTensorInfo, out: *mut *mut OrtTypeInfo) -> OrtStatusPtr {
+    let type_info = MockTypeInfo {
+        tensor_info: info.clone(),
+    };
+    *out = Box::into_raw(Box::new(type_info)) as *mut OrtTypeInfo;
+    null_mut()
+}
+
+unsafe extern "C" fn session_get_input_type_info(
+    session: *const OrtSession,
+    index: usize,
+    type_info: *mut *mut OrtTypeInfo,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetInputTypeInfo")
+        .and_then(|session| tensor_info_at(&session.model.inputs, index))
+    {
+        Ok(info) => new_type_info(info, type_info),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn session_get_output_type_info(
+    session: *const OrtSession,
+    index: usize,
+    type_info: *mut *mut OrtTypeInfo,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetOutputTypeInfo")
+        .and_then(|session| tensor_info_at(&session.model.outputs, index))
+    {
+        Ok(info) => new_type_info(info, type_info),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn cast_type_info_to_tensor_info(
+    type_info: *const OrtTypeInfo,
+    out: *mut *const OrtTensorTypeAndShapeInfo,
+) -> OrtStatusPtr {
+    match (type_info as *const MockTypeInfo).as_ref() {
+        Some(type_info) => {
+            *out =
+                &type_info.tensor_info as *const MockTensorInfo as *const OrtTensorTypeAndShapeInfo;
+            null_mut()
+        }
+        None => invalid_argument("Type info is null"),
+    }
+}
+
+unsafe extern "C" fn release_type_info(type_info: *mut OrtTypeInfo) {
+    if !type_info.is_null() {
+        drop(Box::from_raw(type_info as *mut MockTypeInfo));
+    }
+}
+
+unsafe fn tensor_info_of<'a>(
+    info: *const OrtTensorTypeAndShapeInfo,
+) -> std::result::Result<&'a MockTensorInfo, OrtStatusPtr> {
+    (info as *const MockTensorInfo)
+        .as_ref()
+        .ok_or_else(|| invalid_argument("Tensor info is null"))
+}
+
+unsafe extern "C" fn get_dimensions_count(
+    info: *const OrtTensorTypeAndShapeInfo,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match tensor_info_of(info) {
+        Ok(info) => {
+            *out = info.dims.len();
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn get_dimensions(
+    info: *const OrtTensorTypeAndShapeInfo,
+    dim_values: *mut i64,
+    dim_values_length: usize,
+) -> OrtStatusPtr {
+    match tensor_info_of(info) {
+        Ok(info) => {
+            let len = dim_values_length.min(info.dims.len());
+            std::ptr::copy_nonoverlapping(info.dims.as_ptr(), dim_values, len);
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn get_symbolic_dimensions(
+    info: *const OrtTensorTypeAndShapeInfo,
+    dim_params: *mut *const c_char,
+    dim_params_length: usize,
+) -> OrtStatusPtr {
+    static EMPTY: &[u8] = b"\0";
+    match tensor_info_of(info) {
+        Ok(info) => {
+            for i in 0..dim_params_length.min(info.dims.len()) {
+                *dim_params.add(i) = info
+                    .symbolic_dims
+                    .get(i)
+                    .map_or(EMPTY.as_ptr() as *const c_char, |n| n.as_ptr());
+            }
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn get_tensor_element_type(
+    info: *const OrtTensorTypeAndShapeInfo,
+    out: *mut ONNXTensorElementDataType,
+) -> OrtStatusPtr {
+    match tensor_info_of(info) {
+        Ok(info) => {
+            *out = info.element_type;
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn get_tensor_shape_element_count(
+    info: *const OrtTensorTypeAndShapeInfo,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match tensor_info_of(info) {
+        Ok(info) => {
+            *out = info.dims.iter().map(|d| (*d).max(0) as usize).product();
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn get_tensor_type_and_shape(
+    value: *const OrtValue,
+    out: *mut *mut OrtTensorTypeAndShapeInfo,
+) -> OrtStatusPtr {
+    match (value as *const MockValue).as_ref() {
+        Some(value) => {
+            *out = Box::into_raw(Box::new(value.tensor_info.clone()))
+                as *mut OrtTensorTypeAndShapeInfo;
+            null_mut()
+        }
+        None => invalid_argument("Value is null"),
+    }
+}
+
+unsafe extern "C" fn release_tensor_type_and_shape_info(info: *mut OrtTensorTypeAndShapeInfo) {
+    if !info.is_null() {
+        drop(Box::from_raw(info as *mut MockTensorInfo));
+    }
+}
+
+// The allocator keeps the size of each block in a header before it
+const ALLOCATION_HEADER: usize = 16;
+
+fn allocation_layout(size: usize) -> Layout {
+    Layout::from_size_align(size + ALLOCATION_HEADER, ALLOCATION_HEADER).unwrap()
+}
+
+unsafe extern "C" fn allocator_alloc(_allocator: *mut OrtAllocator, size: usize) -> *mut c_void {
+    let block = alloc(allocation_layout(size));
+    if block.is_null() {
+        return null_mut();
+    }
+    *(block as *mut usize) = size;
+    block.add(ALLOCATION_HEADER) as *mut c_void
+}
+
+unsafe extern "C" fn allocator_free(_allocator: *mut OrtAllocator, p: *mut c_void) {
+    if !p.is_null() {
+        let block = (p as *mut u8).sub(ALLOCATION_HEADER);
+        let size = *(block as *const usize);
+        dealloc(block, allocation_layout(size));
+    }
+}
+
+unsafe extern "C" fn allocator_info(_allocator: *const OrtAllocator) -> *const OrtMemoryInfo {
+    &CPU_MEMORY_INFO as *const MockMemoryInfo as *const OrtMemoryInfo
+}
+
+unsafe extern "C" fn get_allocator_with_default_options(
+    out: *mut *mut OrtAllocator,
+) -> OrtStatusPtr {
+    // The allocator is never modified through the pointer
+    *out = &ALLOCATOR as *const OrtAllocator as *mut OrtAllocator;
+    null_mut()
+}
+
+unsafe extern "C" fn allocator_alloc_status(
+    allocator: *mut OrtAllocator,
+    size: usize,
+    out: *mut *mut c_void,
+) -> OrtStatusPtr {
+    *out = allocator_alloc(allocator, size);
+    null_mut()
+}
+
+unsafe extern "C" fn allocator_free_status(
+    allocator: *mut OrtAllocator,
+    p: *mut c_void,
+) -> OrtStatusPtr {
+    allocator_free(allocator, p);
+    null_mut()
+}
+
+unsafe extern "C" fn allocator_get_info(
+    allocator: *const OrtAllocator,
+    out: *mut *const OrtMemoryInfo,
+) -> OrtStatusPtr {
+    *out = allocator_info(allocator);
+    null_mut()
+}
+
+unsafe extern "C" fn create_cpu_memory_info(
+    allocator_type: OrtAllocatorType,
+    mem_type: OrtMemType,
+    out: *mut *mut OrtMemoryInfo,
+) -> OrtStatusPtr {
+    let info = MockMemoryInfo {
+        allocator_type,
+        mem_type,
+    };
+    *out = Box::into_raw(Box::new(info)) as *mut OrtMemoryInfo;
+    null_mut()
+}
+
+unsafe extern "C" fn release_memory_info(info: *mut OrtMemoryInfo) {
+    // The memory info of the allocator is static
+    if !info.is_null() && !std::ptr::eq(info as *const MockMemoryInfo, &CPU_MEMORY_INFO) {
+        drop(Box::from_raw(info as *mut MockMemoryInfo));
+    }
+}
+
+fn element_size(element_type: ONNXTensorElementDataType) -> Option<usize> {
+    #[allow(non_upper_case_globals)]
+    let size = match element_type {
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL => 1,
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => 2,
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32 => 4,
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64
+        | ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64 => 8,
+        _ => return None,
+    };
+    Some(size)
+}
+
+fn byte_len(tensor_info: &MockTensorInfo) -> Option<usize> {
+    let elements: usize = tensor_info.dims.iter().map(|d| *d as usize).product();
+    Some(elements * element_size(tensor_info.element_type)?)
+}
+
+unsafe extern "C" fn create_tensor_with_data(
+    _info: *const OrtMemoryInfo,
+    p_data: *mut c_void,
+    p_data_len: usize,
+    shape: *const i64,
+    shape_len: usize,
+    type_: ONNXTensorElementDataType,
+    out: *mut *mut OrtValue,
+) -> OrtStatusPtr {
+    let dims = if shape_len == 0 {
+        vec![]
+    } else {
+        std::slice::from_raw_parts(shape, shape_len).to_vec()
+    };
+    if dims.iter().any(|d| *d < 0) {
+        return invalid_argument(&format!("Invalid shape {:?}", dims));
+    }
+    let tensor_info = MockTensorInfo {
+        name: String::new(),
+        element_type: type_,
+        dims,
+        symbolic_dims: vec![],
+    };
+    match byte_len(&tensor_info) {
+        Some(len) if len == p_data_len => {}
+        Some(len) => {
+            return invalid_argument(&format!(
+                "Buffer of {} bytes doesn't match the shape {:?}, which needs {} bytes",
+                p_data_len, tensor_info.dims, len
+            ))
+        }
+        None => return new_status(OrtErrorCode_ORT_NOT_IMPLEMENTED, "Unsupported element type"),
+    }
+    let value = MockValue {
+        tensor_info,
+        data: p_data as *mut u8,
+        len: p_data_len,
+        owned: vec![],
+    };
+    *out = Box::into_raw(Box::new(value)) as *mut OrtValue;
+    null_mut()
+}
+
+unsafe extern "C" fn is_tensor(value: *const OrtValue, out: *mut c_int) -> OrtStatusPtr {
+    *out = (!value.is_null()) as c_int;
+    null_mut()
+}
+
+unsafe extern "C" fn get_tensor_mutable_data(
+    value: *mut OrtValue,
+    out: *mut *mut c_void,
+) -> OrtStatusPtr {
+    match (value as *const MockValue).as_ref() {
+        Some(value) => {
+            *out = value.data as *mut c_void;
+            null_mut()
+        }
+        None => invalid_argument("Value is null"),
+    }
+}
+
+unsafe extern "C" fn release_value(value: *mut OrtValue) {
+    if !value.is_null() {
+        drop(Box::from_raw(value as *mut MockValue));
+    }
+}
+
+unsafe fn read_value(value: *const OrtValue) -> Option<MockTensor> {
+    let value = (value as *const MockValue).as_ref()?;
+    let data = if value.len == 0 {
+        vec![]
+    } else {
+        std::slice::from_raw_parts(value.data, value.len).to_vec()
+    };
+    Some(MockTensor {
+        element_type: value.tensor_info.element_type,
+        shape: value.tensor_info.dims.clone(),
+        data,
+    })
+}
+
+/// Write an output into a value given by the caller, or a new value if it's null
+unsafe fn write_value(name: &str, tensor: MockTensor, out: *mut *mut OrtValue) -> OrtStatusPtr {
+    match (*out as *mut MockValue).as_mut() {
+        Some(value) => {
+            if value.tensor_info.element_type != tensor.element_type
+                || value.tensor_info.dims != tensor.shape
+                || value.len != tensor.data.len()
+            {
+                return invalid_argument(&format!(
+                    "Output {} has type {} and shape {:?}, but the mock model returns type {} and shape {:?}",
+                    name, value.tensor_info.element_type, value.tensor_info.dims, tensor.element_type, tensor.shape
+                ));
+            }
+            if value.len > 0 {
+                std::ptr::copy_nonoverlapping(tensor.data.as_ptr(), value.data, value.len);
+            }
+        }
+        None => {
+            let value = owned_value(name, tensor);
+            *out = Box::into_raw(Box::new(value)) as *mut OrtValue;
+        }
+    }
+    null_mut()
+}
+
+fn owned_value(name: &str, tensor: MockTensor) -> MockValue {
+    let mut owned = tensor.data;
+    MockValue {
+        tensor_info: MockTensorInfo {
+            name: name.to_string(),
+            element_type: tensor.element_type,
+            dims: tensor.shape,
+            symbolic_dims: vec![],
+        },
+        data: owned.as_mut_ptr(),
+        len: owned.len(),
+        owned,
+    }
+}
+
+unsafe fn names(names: *const *const c_char, len: usize) -> Vec<String> {
+    if len == 0 {
+        return vec![];
+    }
+    std::slice::from_raw_parts(names, len)
+        .iter()
+        .map(|name| CStr::from_ptr(*name).to_string_lossy().to_string())
+        .collect()
+}
+
+unsafe extern "C" fn run(
+    session: *mut OrtSession,
+    _run_options: *const OrtRunOptions,
+    input_names: *const *const c_char,
+    inputs: *const *const OrtValue,
+    input_len: usize,
+    output_names: *const *const c_char,
+    output_names_len: usize,
+    outputs: *mut *mut OrtValue,
+) -> OrtStatusPtr {
+    let session = match session_of(session, "Run") {
+        Ok(session) => session,
+        Err(status) => return status,
+    };
+    let model = &session.model;
+    session.runs.fetch_add(1, Ordering::SeqCst);
+
+    // Pass inputs in the order of the model, followed by initializers
+    let input_names = names(input_names, input_len);
+    if let Some(unknown) = input_names.iter().find(|name| {
+        !model.inputs.iter().any(|info| info.name == **name)
+            && !model
+                .initializers
+                .iter()
+                .any(|(info, _)| info.name == **name)
+    }) {
+        return invalid_argument(&format!("Invalid Feed Input Name:{}", unknown));
+    }
+    let mut run_inputs = Vec::with_capacity(model.inputs.len() + model.initializers.len());
+    for info in &model.inputs {
+        let index = match input_names.iter().position(|name| *name == info.name) {
+            Some(index) => index,
+            None => return invalid_argument(&format!("Missing Input: {}", info.name)),
+        };
+        match read_value(*inputs.add(index)) {
+            Some(tensor) => run_inputs.push(tensor),
+            None => return invalid_argument(&format!("Input {} is null", info.name)),
+        }
+    }
+    for ((info, _), value) in model.initializers.iter().zip(&session.initializers) {
+        match input_names.iter().position(|name| *name == info.name) {
+            Some(index) => match read_value(*inputs.add(index)) {
+                Some(tensor) => run_inputs.push(tensor),
+                None => return invalid_argument(&format!("Initializer {} is null", info.name)),
+            },
+            None => run_inputs.push(value.clone()),
+        }
+    }
+
+    let result = match (&session.kernel, &model.on_run) {
+        (Some(kernel), _) => kernel.compute(run_inputs, &model.outputs),
+        (None, Some(on_run)) => on_run(&run_inputs),
+        (None, None) => return null_mut(),
+    };
+    let mut run_outputs = match result {
+        Ok(outputs) => outputs,
+        Err(err) => return new_status(err.code.into(), &err.message),
+    };
+    if run_outputs.len() != model.outputs.len() {
+        return new_status(
+            OrtErrorCode_ORT_FAIL,
+            &format!(
+                "The mock model returns {} outputs, but {} are declared",
+                run_outputs.len(),
+                model.outputs.len()
+            ),
+        );
+    }
+
+    for (i, name) in names(output_names, output_names_len).iter().enumerate() {
+        let index = match model.outputs.iter().position(|info| info.name == *name) {
+            Some(index) => index,
+            None => return invalid_argument(&format!("Invalid Output Name: {}", name)),
+        };
+        let tensor = std::mem::replace(
+            &mut run_outputs[index],
+            MockTensor {
+                element_type: 0,
+                shape: vec![],
+                data: vec![],
+            },
+        );
+        let status = write_value(name, tensor, outputs.add(i));
+        if !status.is_null() {
+            return status;
+        }
+    }
+    null_mut()
+}
+
+unsafe extern "C" fn session_get_profiling_start_time_ns(
+    session: *const OrtSession,
+    out: *mut u64,
+) -> OrtStatusPtr {
+    match session_of(session, "SessionGetProfilingStartTimeNs") {
+        Ok(_) => {
+            *out = MOCK_PROFILING_START_TIME_NS;
+            null_mut()
+        }
+        Err(status) => status,
+    }
+}
+
+/// Write a Chrome trace with a `Session` event and a 100µs `Node` event named `mock` per run
+unsafe extern "C" fn session_end_profiling(
+    session: *mut OrtSession,
+    allocator: *mut OrtAllocator,
+    out: *mut *mut c_char,
+) -> OrtStatusPtr {
+    let session = match session_of(session, "SessionEndProfiling") {
+        Ok(session) => session,
+        Err(status) => return status,
+    };
+    let prefix = match &session.profile_prefix {
+        Some(prefix) => prefix,
+        None => return allocate_name(allocator, "", out),
+    };
+    let events: Vec<String> = (0..session.runs.load(Ordering::SeqCst))
+        .flat_map(|i| {
+            let ts = i * 200;
+            vec![
+                format!(
+                    r#"{{"cat":"Session","pid":1,"tid":1,"dur":150,"ts":{},"ph":"X","name":"model_run","args":{{}}}}"#,
+                    ts
+                ),
+                format!(
+                    r#"{{"cat":"Node","pid":1,"tid":1,"dur":100,"ts":{},"ph":"X","name":"mock_kernel_time","args":{{"op_name":"Mock","provider":"CPUExecutionProvider"}}}}"#,
+                    ts + 10
+                ),
+            ]
+        })
+        .collect();
+    let path = format!("{}_mock.json", prefix);
+    if let Err(e) = std::fs::write(&path, format!("[{}]", events.join(",\n"))) {
+        return new_status(
+            OrtErrorCode_ORT_FAIL,
+            &format!("Failed to write the profile: {}", e),
+        );
+    }
+    allocate_name(allocator, &path, out)
+}
+
+unsafe extern "C" fn get_available_providers(
+    out_ptr: *mut *mut *mut c_char,
+    provider_length: *mut c_int,
+) -> OrtStatusPtr {
+    let providers: Box<[*mut c_char]> = PROVIDERS
+        .iter()
+        .map(|p| CString::new(*p).unwrap().into_raw())
+        .collect();
+    *provider_length = providers.len() as c_int;
+    *out_ptr = Box::into_raw(providers) as *mut *mut c_char;
+    null_mut()
+}
+
+unsafe extern "C" fn release_available_providers(
+    ptr: *mut *mut c_char,
+    providers_length: c_int,
+) -> OrtStatusPtr {
+    if !ptr.is_null() {
+        let providers = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+            ptr,
+            providers_length as usize,
+        ));
+        for provider in providers.iter() {
+            drop(CString::from_raw(*provider));
+        }
+    }
+    null_mut()
+}
+
+unsafe extern "C" fn create_custom_op_domain(
+    domain: *const c_char,
+    out: *mut *mut OrtCustomOpDomain,
+) -> OrtStatusPtr {
+    let domain = MockCustomOpDomain {
+        name: CStr::from_ptr(domain).to_string_lossy().to_string(),
+        ops: vec![],
+    };
+    *out = Box::into_raw(Box::new(domain)) as *mut OrtCustomOpDomain;
+    null_mut()
+}
+
+unsafe extern "C" fn custom_op_domain_add(
+    domain: *mut OrtCustomOpDomain,
+    op: *const OrtCustomOp,
+) -> OrtStatusPtr {
+    match (domain as *mut MockCustomOpDomain).as_mut() {
+        Some(domain) => {
+            domain.ops.push(op);
+            null_mut()
+        }
+        None => invalid_argument("Custom op domain is null"),
+    }
+}
+
+unsafe extern "C" fn add_custom_op_domain(
+    options: *mut OrtSessionOptions,
+    domain: *mut OrtCustomOpDomain,
+) -> OrtStatusPtr {
+    match (options as *mut MockSessionOptions).as_mut() {
+        Some(options) => {
+            options
+                .custom_op_domains
+                .push(domain as *const MockCustomOpDomain);
+            null_mut()
+        }
+        None => invalid_argument("Session options are null"),
+    }
+}
+
+unsafe extern "C" fn release_custom_op_domain(domain: *mut OrtCustomOpDomain) {
+    if !domain.is_null() {
+        drop(Box::from_raw(domain as *mut MockCustomOpDomain));
+    }
+}
+
+/// The API passed to kernels of custom ops
+fn kernel_api() -> &'static OrtApi {
+    static API: OnceLock<OrtApi> = OnceLock::new();
+    API.get_or_init(mock_api)
+}
+
+unsafe extern "C" fn get_kernel_api(version: u32) -> *const OrtApi {
+    match version {
+        ORT_API_VERSION => kernel_api(),
+        _ => null(),
+    }
+}
+
+unsafe extern "C" fn get_version_string() -> *const c_char {
+    static VERSION: OnceLock<CString> = OnceLock::new();
+    VERSION
+        .get_or_init(|| CString::new(MOCK_VERSION).unwrap())
+        .as_ptr()
+}
+
+/// The API base passed to `RegisterCustomOps` of custom ops libraries
+static API_BASE: OrtApiBase = OrtApiBase {
+    GetApi: Some(get_kernel_api),
+    GetVersionString: Some(get_version_string),
+};
+
+/// Load the library and call its `RegisterCustomOps` like onnxruntime,
+/// the library is left loaded if the entry point is missing or fails
+unsafe extern "C" fn register_custom_ops_library(
+    options: *mut OrtSessionOptions,
+    library_path: *const c_char,
+    library_handle: *mut *mut c_void,
+) -> OrtStatusPtr {
+    let path = CStr::from_ptr(library_path).to_string_lossy().to_string();
+    let library = match libloading::Library::new(&path) {
+        Ok(library) => library,
+        Err(e) => return new_status(OrtErrorCode_ORT_FAIL, &e.to_string()),
+    };
+    let register = match library.get::<RegisterCustomOpsFn>(REGISTER_CUSTOM_OPS) {
+        Ok(register) => *register,
+        Err(e) => {
+            *library_handle = library_into_raw(library);
+            return new_status(OrtErrorCode_ORT_FAIL, &e.to_string());
+        }
+    };
+    *library_handle = library_into_raw(library);
+    register(options, &API_BASE)
+}
+
+impl MockKernel {
+    /// Find the op of the node in the registered domains and create its kernel
+    unsafe fn new(
+        options: Option<&MockSessionOptions>,
+        node: &MockCustomOpNode,
+        api_version: u32,
+    ) -> std::result::Result<Self, OrtStatusPtr> {
+        let domains = options
+            .map(|o| o.custom_op_domains.as_slice())
+            .unwrap_or(&[]);
+        let op = domains
+            .iter()
+            .flat_map(|domain| (**domain).ops.iter().copied())
+            .find(|op| match (**op).GetName {
+                Some(get_name) => CStr::from_ptr(get_name(*op)).to_string_lossy() == node.op_type,
+                None => false,
+            });
+        let (op, create_kernel) = match op.map(|op| (op, (*op).CreateKernel)) {
+            Some((op, Some(create_kernel))) => (op, create_kernel),
+            _ => {
+                return Err(new_status(
+                    OrtErrorCode_ORT_NOT_IMPLEMENTED,
+                    &format!("Could not find an implementation for {}", node.op_type),
+                ))
+            }
+        };
+        // onnxruntime doesn't know the fields of ops newer than itself
+        if (*op).version > api_version {
+            return Err(new_status(
+                OrtErrorCode_ORT_NOT_IMPLEMENTED,
+                &format!(
+                    "Unsupported version '{}' of custom op {}, the API version is {}",
+                    (*op).version,
+                    node.op_type,
+                    api_version
+                ),
+            ));
+        }
+        let info = MockKernelInfo {
+            attributes: node.attributes.clone(),
+        };
+        let kernel = create_kernel(
+            op,
+            kernel_api(),
+            &info as *const MockKernelInfo as *const OrtKernelInfo,
+        );
+        Ok(MockKernel { op, kernel })
+    }
+
+    /// Compute the outputs of the node, each output must be allocated by the op
+    unsafe fn compute(
+        &self,
+        inputs: Vec<MockTensor>,
+        outputs: &[MockTensorInfo],
+    ) -> std::result::Result<Vec<MockTensor>, MockError> {
+        let compute = match (*self.op).KernelCompute {
+            Some(compute) => compute,
+            None => return Err(MockError::new(ErrorCode::Fail, "KernelCompute is null")),
+        };
+        let mut context = MockKernelContext {
+            inputs: inputs.into_iter().map(|t| owned_value("", t)).collect(),
+            outputs: outputs.iter().map(|info| (info.clone(), None)).collect(),
+        };
+        compute(
+            self.kernel,
+            &mut context as *mut MockKernelContext as *mut OrtKernelContext,
+        );
+        context
+            .outputs
+            .iter()
+            .map(|(info, value)| {
+                value
+                    .as_ref()
+                    .and_then(|v| read_value(v as *const MockValue as *const OrtValue))
+                    .ok_or_else(|| {
+                        MockError::new(
+                            ErrorCode::Fail,
+                            &format!("The custom op didn't allocate output {}", info.name),
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+impl Drop for MockKernel {
+    fn drop(&mut self) {
+        unsafe {
+            if let Some(destroy) = (*self.op).KernelDestroy {
+                destroy(self.kernel);
+            }
+        }
+    }
+}
+
+unsafe fn attribute_of<'a>(
+    info: *const OrtKernelInfo,
+    name: *const c_char,
+) -> std::result::Result<&'a AttributeValue, OrtStatusPtr> {
+    let name = CStr::from_ptr(name).to_string_lossy();
+    match (info as *const MockKernelInfo).as_ref() {
+        Some(info) => info.attributes.get(name.as_ref()).ok_or_else(|| {
+            new_status(
+                OrtErrorCode_ORT_FAIL,
+                &format!("No attribute with name:{} is defined.", name),
+            )
+        }),
+        None => Err(invalid_argument("Kernel info is null")),
+    }
+}
+
+/// Copy an attribute into a buffer of `size` elements, the size is set to the needed length
+unsafe fn copy_attribute<T: Copy>(values: &[T], out: *mut T, size: *mut usize) -> OrtStatusPtr {
+    if out.is_null() {
+        *size = values.len();
+        return null_mut();
+    }
+    if *size < values.len() {
+        *size = values.len();
+        return invalid_argument("Result buffer is not large enough");
+    }
+    std::ptr::copy_nonoverlapping(values.as_ptr(), out, values.len());
+    *size = values.len();
+    null_mut()
+}
+
+unsafe extern "C" fn kernel_info_get_attribute_int64(
+    info: *const OrtKernelInfo,
+    name: *const c_char,
+    out: *mut i64,
+) -> OrtStatusPtr {
+    match attribute_of(info, name) {
+        Ok(AttributeValue::Int(value)) => {
+            *out = *value;
+            null_mut()
+        }
+        Ok(_) => invalid_argument("Attribute is not an int"),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn kernel_info_get_attribute_float(
+    info: *const OrtKernelInfo,
+    name: *const c_char,
+    out: *mut f32,
+) -> OrtStatusPtr {
+    match attribute_of(info, name) {
+        Ok(AttributeValue::Float(value)) => {
+            *out = *value;
+            null_mut()
+        }
+        Ok(_) => invalid_argument("Attribute is not a float"),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn kernel_info_get_attribute_string(
+    info: *const OrtKernelInfo,
+    name: *const c_char,
+    out: *mut c_char,
+    size: *mut usize,
+) -> OrtStatusPtr {
+    match attribute_of(info, name) {
+        Ok(AttributeValue::String(value)) => {
+            // The size includes the nul byte
+            let value = CString::new(value.as_str()).unwrap_or_default();
+            copy_attribute(value.as_bytes_with_nul(), out as *mut u8, size)
+        }
+        Ok(_) => invalid_argument("Attribute is not a string"),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn kernel_info_get_attribute_array_int64(
+    info: *const OrtKernelInfo,
+    name: *const c_char,
+    out: *mut i64,
+    size: *mut usize,
+) -> OrtStatusPtr {
+    match attribute_of(info, name) {
+        Ok(AttributeValue::Ints(values)) => copy_attribute(values, out, size),
+        Ok(_) => invalid_argument("Attribute is not an array of ints"),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn kernel_info_get_attribute_array_float(
+    info: *const OrtKernelInfo,
+    name: *const c_char,
+    out: *mut f32,
+    size: *mut usize,
+) -> OrtStatusPtr {
+    match attribute_of(info, name) {
+        Ok(AttributeValue::Floats(values)) => copy_attribute(values, out, size),
+        Ok(_) => invalid_argument("Attribute is not an array of floats"),
+        Err(status) => status,
+    }
+}
+
+unsafe extern "C" fn kernel_context_get_input_count(
+    context: *const OrtKernelContext,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match (context as *const MockKernelContext).as_ref() {
+        Some(context) => {
+            *out = context.inputs.len();
+            null_mut()
+        }
+        None => invalid_argument("Kernel context is null"),
+    }
+}
+
+unsafe extern "C" fn kernel_context_get_output_count(
+    context: *const OrtKernelContext,
+    out: *mut usize,
+) -> OrtStatusPtr {
+    match (context as *const MockKernelContext).as_ref() {
+        Some(context) => {
+            *out = context.outputs.len();
+            null_mut()
+        }
+        None => invalid_argument("Kernel context is null"),
+    }
+}
+
+unsafe extern "C" fn kernel_context_get_input(
+    context: *const OrtKernelContext,
+    index: usize,
+    out: *mut *const OrtValue,
+) -> OrtStatusPtr {
+    let context = match (context as *const MockKernelContext).as_ref() {
+        Some(context) => context,
+        None => return invalid_argument("Kernel context is null"),
+    };
+    match context.inputs.get(index) {
+        Some(value) => {
+            *out = value as *const MockValue as *const OrtValue;
+            null_mut()
+        }
+        None => invalid_argument(&format!("Invalid input index {}", index)),
+    }
+}
+
+/// Allocate an output with zeroed data, the element type is the type of the model's output
+unsafe extern "C" fn kernel_context_get_output(
+    context: *mut OrtKernelContext,
+    index: usize,
+    dim_values: *const i64,
+    dim_count: usize,
+    out: *mut *mut OrtValue,
+) -> OrtStatusPtr {
+    let context = match (context as *mut MockKernelContext).as_mut() {
+        Some(context) => context,
+        None => return invalid_argument("Kernel context is null"),
+    };
+    let (info, value) = match context.outputs.get_mut(index) {
+        Some(output) => output,
+        None => return invalid_argument(&format!("Invalid output index {}", index)),
+    };
+    let dims = if dim_count == 0 {
+        vec![]
+    } else {
+        std::slice::from_raw_parts(dim_values, dim_count).to_vec()
+    };
+    let tensor_info = MockTensorInfo {
+        dims,
+        ..info.clone()
+    };
+    let len = match byte_len(&tensor_info) {
+        Some(len) => len,
+        None => return new_status(OrtErrorCode_ORT_NOT_IMPLEMENTED, "Unsupported element type"),
+    };
+    let tensor = MockTensor {
+        element_type: tensor_info.element_type,
+        shape: tensor_info.dims,
+        data: vec![0; len],
+    };
+    let output = value.insert(owned_value(&info.name, tensor));
+    *out = output as *mut MockValue as *mut OrtValue;
+    null_mut()
+}
+
+#[cfg(test)]
+pub(crate) mod test_support;
+
+#[cfg(test)]
+mod test {
+    use tracing_test::traced_test;
+
+    use super::test_support::{sum_model, FLOAT};
+    use super::*;
+    use crate::error::Error;
+    use crate::session::run as run_session;
+
+    #[test]
+    #[traced_test]
+    fn test_mock_session() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+        assert_eq!(MOCK_VERSION, runtime.info().unwrap().version);
+
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .unwrap();
+        assert_eq!(1, runtime.live_objects().sessions);
+
+        let a = ArrayD::<i64>::from_shape_vec(IxDyn(&[1, 2]), vec![1, 2]).unwrap();
+        let b = ArrayD::<i64>::from_shape_vec(IxDyn(&[1, 2]), vec![10, 20]).unwrap();
+        let inputs = vec![
+            runtime.create_tensor_with_ndarray(a).unwrap(),
+            runtime.create_tensor_with_ndarray(b).unwrap(),
+        ];
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+            .unwrap()];
+        run_session(&mut session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[11, 22],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+
+        let calls = backend.calls();
+        assert!(calls.contains(&"CreateSessionFromArray"));
+        assert!(calls.contains(&"SessionGetInputTypeInfo"));
+        assert_eq!(Some(&"Run"), calls.last());
+
+        std::mem::drop((session, inputs, outputs));
+        assert!(runtime.live_objects().is_empty());
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_mock_injected_errors() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("missing")
+            .err()
+            .unwrap();
+        assert_eq!(Some(ErrorCode::NoSuchFile), err.code());
+
+        backend.fail_next(
+            "CreateSessionFromArray",
+            ErrorCode::InvalidProtobuf,
+            "bad model",
+        );
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            Error::Ort {
+                function: "CreateSessionFromArray",
+                code: ErrorCode::InvalidProtobuf,
+                session: None,
+                ..
+            }
+        ));
+
+        // Errors name the session by its log id and the input whose information is read
+        backend.fail_next("SessionGetInputTypeInfo", ErrorCode::Fail, "broken input");
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .log_id("adder")
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .err()
+            .unwrap();
+        assert_eq!(
+            "onnxruntime error in SessionGetInputTypeInfo (session adder, input a): ORT_FAIL: broken input",
+            err.to_string()
+        );
+
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .log_id("adder")
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .unwrap();
+        let inputs: Vec<_> = (0..2)
+            .map(|_| {
+                runtime
+                    .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+                    .unwrap()
+            })
+            .collect();
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+            .unwrap()];
+        backend.fail_next("Run", ErrorCode::RuntimeException, "out of memory");
+        let err = run_session(&mut session, &inputs, &mut outputs)
+            .err()
+            .unwrap();
+        assert_eq!(Some(ErrorCode::RuntimeException), err.code());
+        assert!(err.to_string().contains("out of memory"));
+        assert!(
+            matches!(err, Error::Ort { session: Some(ref s), input: None, .. } if s == "adder")
+        );
+        // Only the next call fails
+        run_session(&mut session, &inputs, &mut outputs).unwrap();
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_mock_canned_outputs() {
+        let backend = MockBackend::new();
+        let output = ArrayD::<f32>::from_shape_vec(IxDyn(&[2]), vec![0.5, 1.5]).unwrap();
+        backend.add_model(
+            "canned",
+            MockModel::new()
+                .output("y", FLOAT, &[Some(2)])
+                .outputs(vec![MockTensor::from_array(&output)]),
+        );
+        let runtime = backend.runtime().unwrap();
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("canned")
+            .unwrap();
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[2])))
+            .unwrap()];
+        run_session(&mut session, &[], &mut outputs).unwrap();
+        assert_eq!(&output, outputs[0].data.as_f32_array().unwrap());
+
+        // The output buffer doesn't match the model
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[3])))
+            .unwrap()];
+        let err = run_session(&mut session, &[], &mut outputs).err().unwrap();
+        assert_eq!(Some(ErrorCode::InvalidArgument), err.code());
+    }
+
+    #[test]
+    fn test_mock_tensor() {
+        let array = ArrayD::<i64>::from_shape_vec(IxDyn(&[2, 1]), vec![3, -4]).unwrap();
+        let tensor = MockTensor::from_array(&array);
+        assert_eq!(vec![2, 1], tensor.shape);
+        assert_eq!(16, tensor.data.len());
+        assert_eq!(Some(array), tensor.to_array::<i64>());
+        assert_eq!(None, tensor.to_array::<f32>());
+    }
+}
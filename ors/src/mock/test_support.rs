@@ -0,0 +1,22 @@
+//! Models shared by the tests of modules using the mock backend
+use ors_sys::*;
+
+use super::{MockModel, MockTensor};
+
+pub(crate) const INT64: ONNXTensorElementDataType =
+    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64;
+pub(crate) const FLOAT: ONNXTensorElementDataType =
+    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT;
+
+/// A model which adds two `[batch, 2]` tensors of i64
+pub(crate) fn sum_model() -> MockModel {
+    MockModel::new()
+        .input("a", INT64, &[None, Some(2)])
+        .input("b", INT64, &[None, Some(2)])
+        .output("sum", INT64, &[None, Some(2)])
+        .on_run(|inputs| {
+            let a = inputs[0].to_array::<i64>().unwrap();
+            let b = inputs[1].to_array::<i64>().unwrap();
+            Ok(vec![MockTensor::from_array(&(a + b))])
+        })
+}
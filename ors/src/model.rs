@@ -0,0 +1,553 @@
+//! Bytes of models and their external data
+//!
+//! Models over 2 GB store their weights in external data files next to the model. [`ModelData`] holds
+//! the bytes of a model or an external data file, either in memory or mapped from a file, and is kept
+//! alive by the sessions which use it, since onnxruntime reads external initializers without copying.
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::ffi::{c_void, CString};
+use std::fmt::Debug;
+use std::fs::File;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::ptr::null_mut;
+use std::sync::Arc;
+
+use ors_sys::*;
+use tracing::warn;
+
+use crate::call_ort;
+use crate::error::{Error, Result};
+use crate::runtime::{ObjectKind, Runtime, TrackedRuntime};
+use crate::session::get_default_memory_info;
+use crate::status::check_status;
+
+pub(crate) use self::proto::{
+    dim_denotations, external_tensors, DimDenotations, ExternalTensorProto,
+};
+
+#[cfg(all(test, feature = "mock"))]
+pub(crate) use self::proto::encode;
+
+mod proto;
+
+/// Bytes of a model or an external data file, which are shared without copying
+#[derive(Clone)]
+pub struct ModelData {
+    bytes: Arc<dyn AsRef<[u8]> + Send + Sync>,
+    mapped: bool,
+}
+
+impl ModelData {
+    /// Use bytes in memory, like a buffer downloaded from an object store
+    pub fn new<B>(bytes: B) -> Self
+    where
+        B: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        Self {
+            bytes: Arc::new(bytes),
+            mapped: false,
+        }
+    }
+
+    /// Map a file into memory, read-only
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while the data is alive, which is until every
+    /// session using it is released.
+    pub unsafe fn map_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mmap = File::open(path)
+            .and_then(|file| Mmap::new(&file))
+            .map_err(|source| Error::ModelMmap {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        Ok(Self {
+            bytes: Arc::new(mmap),
+            mapped: true,
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        (*self.bytes).as_ref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the data is mapped from a file
+    pub fn is_mapped(&self) -> bool {
+        self.mapped
+    }
+}
+
+impl AsRef<[u8]> for ModelData {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Vec<u8>> for ModelData {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<&'static [u8]> for ModelData {
+    fn from(bytes: &'static [u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl Debug for ModelData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModelData")
+            .field("len", &self.len())
+            .field("mapped", &self.mapped)
+            .finish()
+    }
+}
+
+/// A read-only memory map of a whole file
+struct Mmap {
+    ptr: *mut c_void,
+    len: usize,
+    #[cfg(windows)]
+    mapping: *mut c_void,
+}
+
+// The mapped memory is never written
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+#[cfg(unix)]
+impl Mmap {
+    unsafe fn new(file: &File) -> std::io::Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = file.metadata()?.len() as usize;
+        // Empty files cannot be mapped
+        if len == 0 {
+            return Ok(Self {
+                ptr: null_mut(),
+                len,
+            });
+        }
+        let ptr = libc::mmap(
+            null_mut(),
+            len,
+            libc::PROT_READ,
+            libc::MAP_PRIVATE,
+            file.as_raw_fd(),
+            0,
+        );
+        if ptr == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(Self { ptr, len })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len > 0 && unsafe { libc::munmap(self.ptr, self.len) } != 0 {
+            warn!(
+                "Failed to unmap model data: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+}
+
+#[cfg(windows)]
+mod win32 {
+    use std::ffi::c_void;
+
+    pub const PAGE_READONLY: u32 = 0x02;
+    pub const FILE_MAP_READ: u32 = 0x04;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn CreateFileMappingW(
+            file: *mut c_void,
+            attributes: *mut c_void,
+            protect: u32,
+            maximum_size_high: u32,
+            maximum_size_low: u32,
+            name: *const u16,
+        ) -> *mut c_void;
+        pub fn MapViewOfFile(
+            mapping: *mut c_void,
+            desired_access: u32,
+            file_offset_high: u32,
+            file_offset_low: u32,
+            number_of_bytes_to_map: usize,
+        ) -> *mut c_void;
+        pub fn UnmapViewOfFile(base_address: *const c_void) -> i32;
+        pub fn CloseHandle(handle: *mut c_void) -> i32;
+    }
+}
+
+#[cfg(windows)]
+impl Mmap {
+    unsafe fn new(file: &File) -> std::io::Result<Self> {
+        use std::os::windows::io::AsRawHandle;
+
+        let len = file.metadata()?.len() as usize;
+        // Empty files cannot be mapped
+        if len == 0 {
+            return Ok(Self {
+                ptr: null_mut(),
+                len,
+                mapping: null_mut(),
+            });
+        }
+        let mapping = win32::CreateFileMappingW(
+            file.as_raw_handle() as *mut c_void,
+            null_mut(),
+            win32::PAGE_READONLY,
+            0,
+            0,
+            std::ptr::null(),
+        );
+        if mapping.is_null() {
+            return Err(std::io::Error::last_os_error());
+        }
+        let ptr = win32::MapViewOfFile(mapping, win32::FILE_MAP_READ, 0, 0, len);
+        if ptr.is_null() {
+            let err = std::io::Error::last_os_error();
+            win32::CloseHandle(mapping);
+            return Err(err);
+        }
+        Ok(Self { ptr, len, mapping })
+    }
+}
+
+#[cfg(windows)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe {
+                if win32::UnmapViewOfFile(self.ptr) == 0 {
+                    warn!(
+                        "Failed to unmap model data: {}",
+                        std::io::Error::last_os_error()
+                    );
+                }
+                win32::CloseHandle(self.mapping);
+            }
+        }
+    }
+}
+
+/// Size in bytes of an element of `TensorProto.DataType`, strings cannot be stored in external data
+fn element_size(data_type: u32) -> Option<usize> {
+    match data_type {
+        2 | 3 | 9 => Some(1),
+        4 | 5 | 10 | 16 => Some(2),
+        1 | 6 | 12 => Some(4),
+        7 | 11 | 13 | 14 => Some(8),
+        15 => Some(16),
+        _ => None,
+    }
+}
+
+/// A tensor whose data is borrowed from [`ModelData`]
+#[derive(Debug)]
+struct ExternalValue {
+    runtime: TrackedRuntime,
+    ptr: *mut OrtValue,
+}
+
+impl Drop for ExternalValue {
+    fn drop(&mut self) {
+        if let Err(e) = call_ort!(self.runtime => ReleaseValue, self.ptr) {
+            warn!("Failed to release external initializer: {}", e);
+        }
+    }
+}
+
+/// Initializers added by `AddExternalInitializers`, which are used by sessions until they are released
+#[derive(Debug)]
+pub(crate) struct ExternalInitializers {
+    // Values are released before the data they point to
+    values: Vec<ExternalValue>,
+    data: Vec<ModelData>,
+}
+
+impl ExternalInitializers {
+    /// Add the initializers of the model stored in external data to the session options
+    ///
+    /// `external_data` maps the locations in the model to their data.
+    pub(crate) fn add(
+        runtime: &Runtime,
+        options: *mut OrtSessionOptions,
+        model: &[u8],
+        external_data: &HashMap<String, ModelData>,
+    ) -> Result<Self> {
+        let mem_info = get_default_memory_info(runtime)?;
+        let mut initializers = ExternalInitializers {
+            values: vec![],
+            data: vec![],
+        };
+        let mut names = vec![];
+        for tensor in external_tensors(model)? {
+            let data = external_data
+                .get(&tensor.location)
+                .or_else(|| external_data.get(tensor.location.trim_start_matches("./")))
+                .ok_or_else(|| Error::ExternalData {
+                    location: tensor.location.clone(),
+                    message: format!("data of initializer {} is not given", tensor.name),
+                })?;
+            let bytes = external_bytes(&tensor, data.as_bytes())?;
+            let mut value_ptr: *mut OrtValue = null_mut();
+            let status = call_ort!(
+                runtime => CreateTensorWithDataAsOrtValue,
+                mem_info,
+                bytes.as_ptr() as *mut c_void,
+                bytes.len(),
+                tensor.dims.as_ptr(),
+                tensor.dims.len(),
+                tensor.data_type as ONNXTensorElementDataType,
+                &mut value_ptr
+            )?;
+            check_status(runtime, status, "CreateTensorWithDataAsOrtValue")?;
+            initializers.values.push(ExternalValue {
+                runtime: runtime.track(ObjectKind::Tensor),
+                ptr: value_ptr,
+            });
+            initializers.data.push(data.clone());
+            names.push(CString::new(tensor.name)?);
+        }
+        if names.is_empty() {
+            return Ok(initializers);
+        }
+
+        let names_ptr: Vec<*const c_char> = names.iter().map(|n| n.as_ptr()).collect();
+        let values_ptr: Vec<*const OrtValue> = initializers
+            .values
+            .iter()
+            .map(|v| v.ptr as *const OrtValue)
+            .collect();
+        let status = call_ort!(
+            runtime => AddExternalInitializers,
+            options,
+            names_ptr.as_ptr(),
+            values_ptr.as_ptr(),
+            names_ptr.len()
+        )?;
+        check_status(runtime, status, "AddExternalInitializers")?;
+        Ok(initializers)
+    }
+}
+
+/// Find the bytes of a tensor in its external data
+fn external_bytes<'a>(tensor: &ExternalTensorProto, data: &'a [u8]) -> Result<&'a [u8]> {
+    let error = |message: String| Error::ExternalData {
+        location: tensor.location.clone(),
+        message,
+    };
+    let element_size = element_size(tensor.data_type).ok_or_else(|| {
+        error(format!(
+            "initializer {} has unsupported data type {}",
+            tensor.name, tensor.data_type
+        ))
+    })?;
+    let len = tensor
+        .dims
+        .iter()
+        .try_fold(element_size, |len, dim| len.checked_mul(*dim as usize))
+        .ok_or_else(|| error(format!("initializer {} is too large", tensor.name)))?;
+    if let Some(length) = tensor.length.filter(|length| *length != len as u64) {
+        return Err(error(format!(
+            "initializer {} has {} bytes, but its shape {:?} needs {}",
+            tensor.name, length, tensor.dims, len
+        )));
+    }
+    let start = tensor.offset as usize;
+    data.get(start..start.saturating_add(len)).ok_or_else(|| {
+        error(format!(
+            "initializer {} at {}..{} is out of the {} bytes of the data",
+            tensor.name,
+            start,
+            start.saturating_add(len),
+            data.len()
+        ))
+    })
+}
+
+/// Map the external data files of a model, which are relative to the directory of the model
+pub(crate) unsafe fn map_external_data(
+    model_path: &Path,
+    model: &[u8],
+) -> Result<HashMap<String, ModelData>> {
+    let dir = model_path.parent().unwrap_or_else(|| Path::new(""));
+    let mut external_data = HashMap::new();
+    for tensor in external_tensors(model)? {
+        if let Entry::Vacant(entry) = external_data.entry(tensor.location) {
+            let data = ModelData::map_file(dir.join(entry.key()))?;
+            entry.insert(data);
+        }
+    }
+    Ok(external_data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "mock")]
+    use crate::mock::{test_support::INT64, MockBackend, MockModel, MockTensor};
+    #[cfg(feature = "mock")]
+    use crate::session::run as run_session;
+    #[cfg(feature = "mock")]
+    use ndarray::{ArrayD, IxDyn};
+    #[cfg(feature = "mock")]
+    use tracing_test::traced_test;
+
+    #[test]
+    fn test_external_bytes() {
+        let data: Vec<u8> = (0..32).collect();
+        let mut tensor = ExternalTensorProto {
+            name: "bias".to_string(),
+            data_type: 7,
+            dims: vec![2],
+            location: "weights.bin".to_string(),
+            offset: 8,
+            length: Some(16),
+        };
+        assert_eq!(&data[8..24], external_bytes(&tensor, &data).unwrap());
+
+        tensor.length = Some(8);
+        assert!(matches!(
+            external_bytes(&tensor, &data),
+            Err(Error::ExternalData { .. })
+        ));
+        tensor.length = None;
+        tensor.offset = 24;
+        assert!(matches!(
+            external_bytes(&tensor, &data),
+            Err(Error::ExternalData { .. })
+        ));
+        tensor.data_type = 8;
+        assert!(external_bytes(&tensor, &data).is_err());
+    }
+
+    #[test]
+    fn test_map_file() {
+        let path = std::env::temp_dir().join(format!("ors-model-data-{}", std::process::id()));
+        std::fs::write(&path, b"model").unwrap();
+        let data = unsafe { ModelData::map_file(&path) }.unwrap();
+        assert!(data.is_mapped());
+        assert_eq!(b"model", data.as_bytes());
+        std::mem::drop(data);
+        std::fs::remove_file(&path).unwrap();
+
+        let err = unsafe { ModelData::map_file(&path) }.unwrap_err();
+        assert!(matches!(err, Error::ModelMmap { .. }));
+        assert_eq!(b"model", ModelData::from(b"model".to_vec()).as_bytes());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    #[traced_test]
+    fn test_mock_external_data() {
+        let model_bytes = proto::encode::model(&[ExternalTensorProto {
+            name: "bias".to_string(),
+            // INT64
+            data_type: 7,
+            dims: vec![2],
+            location: "weights.bin".to_string(),
+            offset: 8,
+            length: Some(16),
+        }]);
+        let default_bias = ArrayD::<i64>::zeros(IxDyn(&[2]));
+        let backend = MockBackend::new();
+        // Mock sessions created from bytes look up models by the bytes as a string
+        backend.add_model(
+            &String::from_utf8_lossy(&model_bytes),
+            MockModel::new()
+                .input("x", INT64, &[Some(2)])
+                .output("y", INT64, &[Some(2)])
+                .initializer("bias", MockTensor::from_array(&default_bias))
+                .on_run(|inputs| {
+                    let x = inputs[0].to_array::<i64>().unwrap();
+                    let bias = inputs[1].to_array::<i64>().unwrap();
+                    Ok(vec![MockTensor::from_array(&(x + bias))])
+                }),
+        );
+        let runtime = backend.runtime().unwrap();
+        let mut weights = vec![0u8; 8];
+        weights.extend(2i64.to_ne_bytes());
+        weights.extend(3i64.to_ne_bytes());
+        weights.extend([0u8; 8]);
+        let x = ArrayD::<i64>::from_shape_vec(IxDyn(&[2]), vec![10, 20]).unwrap();
+        let inputs = vec![runtime.create_tensor_with_ndarray(x).unwrap()];
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[2])))
+            .unwrap()];
+
+        // External data in memory
+        let mut external_data = HashMap::new();
+        external_data.insert("weights.bin".to_string(), ModelData::new(weights.clone()));
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_and_external_data(&model_bytes, &external_data)
+            .unwrap();
+        std::mem::drop(external_data);
+        run_session(&mut session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[12, 23],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+        assert!(backend.calls().contains(&"AddExternalInitializers"));
+        std::mem::drop(session);
+
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_and_external_data(&model_bytes, &HashMap::new())
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::ExternalData { .. }));
+        assert!(err.is_bad_request());
+
+        // The model and its external data mapped from files
+        let dir = std::env::temp_dir().join(format!("ors-external-data-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("model.onnx"), &model_bytes).unwrap();
+        std::fs::write(dir.join("weights.bin"), &weights).unwrap();
+        let mut session = unsafe {
+            runtime
+                .session_builder()
+                .unwrap()
+                .build_with_model_from_mmap(dir.join("model.onnx"))
+        }
+        .unwrap();
+        run_session(&mut session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[12, 23],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+
+        std::mem::drop((session, inputs, outputs));
+        assert!(runtime.live_objects().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,423 @@
+//! A reader of the protobuf wire format, which reads only the fields of ONNX models needed to find
+//! initializers stored in external data and denotations of dimensions
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+
+// Field numbers in onnx.proto
+const MODEL_GRAPH: u32 = 7;
+const GRAPH_INITIALIZER: u32 = 5;
+const GRAPH_INPUT: u32 = 11;
+const GRAPH_OUTPUT: u32 = 12;
+const VALUE_INFO_NAME: u32 = 1;
+const VALUE_INFO_TYPE: u32 = 2;
+const TYPE_TENSOR_TYPE: u32 = 1;
+const TENSOR_TYPE_SHAPE: u32 = 2;
+const SHAPE_DIM: u32 = 1;
+const DIMENSION_DENOTATION: u32 = 3;
+const TENSOR_DIMS: u32 = 1;
+const TENSOR_DATA_TYPE: u32 = 2;
+const TENSOR_NAME: u32 = 8;
+const TENSOR_EXTERNAL_DATA: u32 = 13;
+const TENSOR_DATA_LOCATION: u32 = 14;
+const ENTRY_KEY: u32 = 1;
+const ENTRY_VALUE: u32 = 2;
+const DATA_LOCATION_EXTERNAL: u64 = 1;
+
+/// An initializer of the main graph whose data is stored in an external file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExternalTensorProto {
+    pub name: String,
+    /// `TensorProto.DataType`, whose values are the same as `ONNXTensorElementDataType`
+    pub data_type: u32,
+    pub dims: Vec<i64>,
+    /// Path of the file relative to the model
+    pub location: String,
+    pub offset: u64,
+    pub length: Option<u64>,
+}
+
+enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let (byte, rest) = self
+                .buf
+                .split_first()
+                .ok_or_else(|| invalid("truncated varint"))?;
+            self.buf = rest;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(invalid("varint is too long"))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.buf.len() {
+            return Err(invalid("truncated field"));
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    /// Read the next field number and its value, fixed-size values are skipped
+    fn field(&mut self) -> Result<Option<(u32, Value<'a>)>> {
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 0x7 {
+            0 => Value::Varint(self.varint()?),
+            1 => {
+                self.take(8)?;
+                Value::Fixed
+            }
+            2 => {
+                let len = self.varint()? as usize;
+                Value::Bytes(self.take(len)?)
+            }
+            5 => {
+                self.take(4)?;
+                Value::Fixed
+            }
+            wire_type => return Err(invalid(&format!("unsupported wire type {}", wire_type))),
+        };
+        Ok(Some(((key >> 3) as u32, value)))
+    }
+}
+
+fn invalid(message: &str) -> Error {
+    Error::InvalidModel(message.to_string())
+}
+
+fn string(bytes: &[u8]) -> Result<String> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| invalid("string is not valid UTF-8"))
+}
+
+/// Find the initializers of the main graph stored in external data
+///
+/// Initializers of subgraphs, like the branches of `If`, are not read.
+pub(crate) fn external_tensors(model: &[u8]) -> Result<Vec<ExternalTensorProto>> {
+    let mut tensors = vec![];
+    let mut model = Reader::new(model);
+    while let Some((number, value)) = model.field()? {
+        if let (MODEL_GRAPH, Value::Bytes(graph)) = (number, value) {
+            let mut graph = Reader::new(graph);
+            while let Some((number, value)) = graph.field()? {
+                if let (GRAPH_INITIALIZER, Value::Bytes(tensor)) = (number, value) {
+                    if let Some(tensor) = external_tensor(tensor)? {
+                        tensors.push(tensor);
+                    }
+                }
+            }
+        }
+    }
+    Ok(tensors)
+}
+
+fn external_tensor(tensor: &[u8]) -> Result<Option<ExternalTensorProto>> {
+    let mut name = String::new();
+    let mut data_type = 0;
+    let mut dims = vec![];
+    let mut external_data = vec![];
+    let mut data_location = 0;
+    let mut reader = Reader::new(tensor);
+    while let Some((number, value)) = reader.field()? {
+        match (number, value) {
+            (TENSOR_DIMS, Value::Varint(dim)) => dims.push(dim as i64),
+            // Packed dims
+            (TENSOR_DIMS, Value::Bytes(packed)) => {
+                let mut packed = Reader::new(packed);
+                while !packed.buf.is_empty() {
+                    dims.push(packed.varint()? as i64);
+                }
+            }
+            (TENSOR_DATA_TYPE, Value::Varint(value)) => data_type = value as u32,
+            (TENSOR_NAME, Value::Bytes(value)) => name = string(value)?,
+            (TENSOR_EXTERNAL_DATA, Value::Bytes(entry)) => external_data.push(entry_of(entry)?),
+            (TENSOR_DATA_LOCATION, Value::Varint(value)) => data_location = value,
+            _ => {}
+        }
+    }
+    if data_location != DATA_LOCATION_EXTERNAL {
+        return Ok(None);
+    }
+
+    let mut location = None;
+    let mut offset = 0;
+    let mut length = None;
+    for (key, value) in external_data {
+        let number = || {
+            value
+                .parse::<u64>()
+                .map_err(|_| invalid(&format!("invalid {} {} of {}", key, value, name)))
+        };
+        match key.as_str() {
+            "location" => location = Some(value.clone()),
+            "offset" => offset = number()?,
+            "length" => length = Some(number()?),
+            _ => {}
+        }
+    }
+    let location =
+        location.ok_or_else(|| invalid(&format!("external data of {} has no location", name)))?;
+    Ok(Some(ExternalTensorProto {
+        name,
+        data_type,
+        dims,
+        location,
+        offset,
+        length,
+    }))
+}
+
+fn entry_of(entry: &[u8]) -> Result<(String, String)> {
+    let mut key = String::new();
+    let mut value = String::new();
+    let mut reader = Reader::new(entry);
+    while let Some((number, field)) = reader.field()? {
+        match (number, field) {
+            (ENTRY_KEY, Value::Bytes(bytes)) => key = string(bytes)?,
+            (ENTRY_VALUE, Value::Bytes(bytes)) => value = string(bytes)?,
+            _ => {}
+        }
+    }
+    Ok((key, value))
+}
+
+/// Denotations of dimensions of a graph input or output, `None` for dimensions without one
+pub(crate) type DimDenotations = Vec<Option<String>>;
+
+/// Find the denotations of dimensions of the main graph's inputs and outputs by their names
+///
+/// onnxruntime doesn't report them, they are only kept in the model.
+pub(crate) fn dim_denotations(model: &[u8]) -> Result<HashMap<String, DimDenotations>> {
+    let mut denotations = HashMap::new();
+    let mut model = Reader::new(model);
+    while let Some((number, value)) = model.field()? {
+        if let (MODEL_GRAPH, Value::Bytes(graph)) = (number, value) {
+            let mut graph = Reader::new(graph);
+            while let Some((number, value)) = graph.field()? {
+                match (number, value) {
+                    (GRAPH_INPUT, Value::Bytes(value_info))
+                    | (GRAPH_OUTPUT, Value::Bytes(value_info)) => {
+                        let (name, dims) = value_info_denotations(value_info)?;
+                        denotations.insert(name, dims);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(denotations)
+}
+
+fn value_info_denotations(value_info: &[u8]) -> Result<(String, DimDenotations)> {
+    let mut name = String::new();
+    let mut dims = vec![];
+    let mut reader = Reader::new(value_info);
+    while let Some((number, value)) = reader.field()? {
+        match (number, value) {
+            (VALUE_INFO_NAME, Value::Bytes(value)) => name = string(value)?,
+            (VALUE_INFO_TYPE, Value::Bytes(type_proto)) => {
+                // TypeProto.tensor_type.shape.dim, other types have no dimensions
+                for tensor_type in fields(type_proto, TYPE_TENSOR_TYPE)? {
+                    for shape in fields(tensor_type, TENSOR_TYPE_SHAPE)? {
+                        for dim in fields(shape, SHAPE_DIM)? {
+                            let denotation = match fields(dim, DIMENSION_DENOTATION)?.pop() {
+                                Some(denotation) => Some(string(denotation)?),
+                                None => None,
+                            };
+                            dims.push(denotation);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok((name, dims))
+}
+
+/// The values of a length-delimited field of a message
+fn fields(message: &[u8], field_number: u32) -> Result<Vec<&[u8]>> {
+    let mut values = vec![];
+    let mut reader = Reader::new(message);
+    while let Some((number, value)) = reader.field()? {
+        match value {
+            Value::Bytes(bytes) if number == field_number => values.push(bytes),
+            _ => {}
+        }
+    }
+    Ok(values)
+}
+
+/// Encoding of models with external initializers, for tests
+#[cfg(test)]
+pub(crate) mod encode {
+    use super::*;
+
+    fn varint(out: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn varint_field(out: &mut Vec<u8>, number: u32, value: u64) {
+        varint(out, u64::from(number) << 3);
+        varint(out, value);
+    }
+
+    fn bytes_field(out: &mut Vec<u8>, number: u32, bytes: &[u8]) {
+        varint(out, u64::from(number) << 3 | 2);
+        varint(out, bytes.len() as u64);
+        out.extend_from_slice(bytes);
+    }
+
+    fn entry(key: &str, value: &str) -> Vec<u8> {
+        let mut out = vec![];
+        bytes_field(&mut out, ENTRY_KEY, key.as_bytes());
+        bytes_field(&mut out, ENTRY_VALUE, value.as_bytes());
+        out
+    }
+
+    /// Encode a model whose graph has only the external initializers
+    pub(crate) fn model(tensors: &[ExternalTensorProto]) -> Vec<u8> {
+        let mut graph = vec![];
+        for tensor in tensors {
+            let mut out = vec![];
+            for dim in &tensor.dims {
+                varint_field(&mut out, TENSOR_DIMS, *dim as u64);
+            }
+            varint_field(&mut out, TENSOR_DATA_TYPE, u64::from(tensor.data_type));
+            bytes_field(&mut out, TENSOR_NAME, tensor.name.as_bytes());
+            bytes_field(
+                &mut out,
+                TENSOR_EXTERNAL_DATA,
+                &entry("location", &tensor.location),
+            );
+            bytes_field(
+                &mut out,
+                TENSOR_EXTERNAL_DATA,
+                &entry("offset", &tensor.offset.to_string()),
+            );
+            if let Some(length) = tensor.length {
+                bytes_field(
+                    &mut out,
+                    TENSOR_EXTERNAL_DATA,
+                    &entry("length", &length.to_string()),
+                );
+            }
+            varint_field(&mut out, TENSOR_DATA_LOCATION, DATA_LOCATION_EXTERNAL);
+            bytes_field(&mut graph, GRAPH_INITIALIZER, &out);
+        }
+        model_of_graph(&graph)
+    }
+
+    /// Encode a model whose graph has only the inputs and outputs, with denotations of their dimensions
+    pub(crate) fn model_with_denotations(
+        inputs: &[(&str, DimDenotations)],
+        outputs: &[(&str, DimDenotations)],
+    ) -> Vec<u8> {
+        let mut graph = vec![];
+        for (number, values) in &[(GRAPH_INPUT, inputs), (GRAPH_OUTPUT, outputs)] {
+            for (name, dims) in values.iter() {
+                let mut shape = vec![];
+                for denotation in dims {
+                    let mut dim = vec![];
+                    if let Some(denotation) = denotation {
+                        bytes_field(&mut dim, DIMENSION_DENOTATION, denotation.as_bytes());
+                    }
+                    bytes_field(&mut shape, SHAPE_DIM, &dim);
+                }
+                let mut tensor_type = vec![];
+                bytes_field(&mut tensor_type, TENSOR_TYPE_SHAPE, &shape);
+                let mut type_proto = vec![];
+                bytes_field(&mut type_proto, TYPE_TENSOR_TYPE, &tensor_type);
+                let mut value_info = vec![];
+                bytes_field(&mut value_info, VALUE_INFO_NAME, name.as_bytes());
+                bytes_field(&mut value_info, VALUE_INFO_TYPE, &type_proto);
+                bytes_field(&mut graph, *number, &value_info);
+            }
+        }
+        model_of_graph(&graph)
+    }
+
+    fn model_of_graph(graph: &[u8]) -> Vec<u8> {
+        let mut model = vec![];
+        // ir_version
+        varint_field(&mut model, 1, 8);
+        bytes_field(&mut model, MODEL_GRAPH, graph);
+        model
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_external_tensors() {
+        let tensors = vec![
+            ExternalTensorProto {
+                name: "weight".to_string(),
+                data_type: 1,
+                dims: vec![300, 2],
+                location: "weights.bin".to_string(),
+                offset: 0,
+                length: Some(2400),
+            },
+            ExternalTensorProto {
+                name: "bias".to_string(),
+                data_type: 7,
+                dims: vec![2],
+                location: "weights.bin".to_string(),
+                offset: 2432,
+                length: None,
+            },
+        ];
+        let model = encode::model(&tensors);
+        assert_eq!(tensors, external_tensors(&model).unwrap());
+
+        assert!(external_tensors(&[]).unwrap().is_empty());
+        let err = external_tensors(&model[..model.len() - 1]).unwrap_err();
+        assert!(matches!(err, Error::InvalidModel(_)));
+    }
+
+    #[test]
+    fn test_dim_denotations() {
+        let batch = || Some("DATA_BATCH".to_string());
+        let model = encode::model_with_denotations(
+            &[("ids", vec![batch(), None])],
+            &[("embeddings", vec![batch(), None, None])],
+        );
+        let denotations = dim_denotations(&model).unwrap();
+        assert_eq!(2, denotations.len());
+        assert_eq!(vec![batch(), None], denotations["ids"]);
+        assert_eq!(vec![batch(), None, None], denotations["embeddings"]);
+
+        // Models with external initializers only have no inputs
+        let model = encode::model(&[]);
+        assert!(dim_denotations(&model).unwrap().is_empty());
+        assert!(dim_denotations(b"\xff").is_err());
+    }
+}
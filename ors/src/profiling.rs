@@ -0,0 +1,316 @@
+//! Parsing profiles written by onnxruntime
+//!
+//! Sessions built with [`SessionBuilder::enable_profiling`](crate::session::SessionBuilder::enable_profiling)
+//! record the time spent by every operator. [`Session::end_profiling`](crate::session::Session::end_profiling)
+//! stops profiling and parses the Chrome trace written by onnxruntime into a [`Profile`], which can be
+//! aggregated into a [`ProfileReport`] per node and per op type.
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+/// Suffix of the names of events measuring the kernel of a node
+const KERNEL_TIME_SUFFIX: &str = "_kernel_time";
+
+/// An event of a Chrome trace written by onnxruntime
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEvent {
+    /// `Session` for loading the model and whole runs, `Node` for operators
+    pub category: String,
+    /// Name of the event, node events are named like `<node>_kernel_time`
+    pub name: String,
+    pub process_id: i64,
+    pub thread_id: i64,
+    /// Start time in nanoseconds since the Unix epoch, aligned to `SessionGetProfilingStartTimeNs`
+    pub start_time_ns: u64,
+    pub duration: Duration,
+    /// Op type of node events, like `MatMul`
+    pub op_name: Option<String>,
+    /// Execution provider of node events, like `CPUExecutionProvider`
+    pub provider: Option<String>,
+    /// All arguments of the event, including the input and output shapes of node events
+    pub args: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    cat: String,
+    name: String,
+    #[serde(default)]
+    pid: i64,
+    #[serde(default)]
+    tid: i64,
+    ts: u64,
+    #[serde(default)]
+    dur: u64,
+    #[serde(default)]
+    args: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ProfileEvent {
+    /// Name of the node if it's the kernel event of a node
+    pub fn node_name(&self) -> Option<&str> {
+        if self.category != "Node" {
+            return None;
+        }
+        self.name.strip_suffix(KERNEL_TIME_SUFFIX)
+    }
+}
+
+/// Events of a profile written by onnxruntime
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    /// Path of the Chrome trace, which can be opened by `chrome://tracing`
+    pub path: PathBuf,
+    /// Start time of profiling in nanoseconds since the Unix epoch
+    pub start_time_ns: u64,
+    pub events: Vec<ProfileEvent>,
+}
+
+impl Profile {
+    /// Read the Chrome trace, whose timestamps are relative to `start_time_ns`
+    pub fn from_file<P: AsRef<Path>>(path: P, start_time_ns: u64) -> Result<Profile> {
+        let path = path.as_ref();
+        let invalid = |message: String| Error::InvalidProfile {
+            path: path.to_path_buf(),
+            message,
+        };
+        let trace = std::fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let events = Self::parse_events(&trace, start_time_ns).map_err(invalid)?;
+        Ok(Profile {
+            path: path.to_path_buf(),
+            start_time_ns,
+            events,
+        })
+    }
+
+    fn parse_events(
+        trace: &str,
+        start_time_ns: u64,
+    ) -> std::result::Result<Vec<ProfileEvent>, String> {
+        let raw_events: Vec<RawEvent> = serde_json::from_str(trace).map_err(|e| e.to_string())?;
+        Ok(raw_events
+            .into_iter()
+            .map(|raw| {
+                let string_arg = |key: &str| {
+                    raw.args
+                        .get(key)
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                };
+                ProfileEvent {
+                    op_name: string_arg("op_name"),
+                    provider: string_arg("provider"),
+                    category: raw.cat,
+                    name: raw.name,
+                    process_id: raw.pid,
+                    thread_id: raw.tid,
+                    // Timestamps and durations of Chrome traces are in microseconds
+                    start_time_ns: start_time_ns + raw.ts * 1000,
+                    duration: Duration::from_micros(raw.dur),
+                    args: raw.args,
+                }
+            })
+            .collect())
+    }
+
+    /// Aggregate the kernel events per node and per op type
+    pub fn report(&self) -> ProfileReport {
+        let mut nodes: BTreeMap<(String, Option<String>), Vec<Duration>> = BTreeMap::new();
+        let mut op_types: BTreeMap<(String, Option<String>), Vec<Duration>> = BTreeMap::new();
+        for event in &self.events {
+            let node_name = match event.node_name() {
+                Some(node_name) => node_name,
+                None => continue,
+            };
+            nodes
+                .entry((node_name.to_string(), event.provider.clone()))
+                .or_default()
+                .push(event.duration);
+            if let Some(op_name) = &event.op_name {
+                op_types
+                    .entry((op_name.clone(), event.provider.clone()))
+                    .or_default()
+                    .push(event.duration);
+            }
+        }
+        ProfileReport {
+            nodes: OpStats::from_groups(nodes),
+            op_types: OpStats::from_groups(op_types),
+        }
+    }
+}
+
+/// Time spent by nodes and op types, sorted by the total duration in descending order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileReport {
+    pub nodes: Vec<OpStats>,
+    pub op_types: Vec<OpStats>,
+}
+
+/// Statistics of the kernel events of a node or an op type on an execution provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpStats {
+    /// Name of the node or the op type
+    pub name: String,
+    pub provider: Option<String>,
+    pub count: usize,
+    pub total: Duration,
+    pub mean: Duration,
+    /// The 99th percentile by the nearest-rank method
+    pub p99: Duration,
+}
+
+impl OpStats {
+    fn from_groups(groups: BTreeMap<(String, Option<String>), Vec<Duration>>) -> Vec<OpStats> {
+        let mut stats: Vec<OpStats> = groups
+            .into_iter()
+            .map(|((name, provider), mut durations)| {
+                durations.sort();
+                let count = durations.len();
+                let total: Duration = durations.iter().sum();
+                // Rank ceil(0.99 * count), which is at least 1
+                let p99_rank = (count * 99 + 99) / 100;
+                OpStats {
+                    name,
+                    provider,
+                    count,
+                    total,
+                    mean: total / count as u32,
+                    p99: durations[p99_rank - 1],
+                }
+            })
+            .collect();
+        stats.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(&b.name)));
+        stats
+    }
+}
+
+impl ProfileReport {
+    /// Statistics of the node
+    pub fn node(&self, name: &str) -> Option<&OpStats> {
+        self.nodes.iter().find(|stats| stats.name == name)
+    }
+
+    /// Total time spent by each op type across execution providers
+    pub fn op_type_totals(&self) -> HashMap<&str, Duration> {
+        let mut totals = HashMap::new();
+        for stats in &self.op_types {
+            *totals.entry(stats.name.as_str()).or_default() += stats.total;
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "mock")]
+    use crate::mock::{test_support::sum_model, MockBackend, MOCK_PROFILING_START_TIME_NS};
+    #[cfg(feature = "mock")]
+    use crate::session::run as run_session;
+    #[cfg(feature = "mock")]
+    use ndarray::{ArrayD, IxDyn};
+
+    const TRACE: &str = r#"[
+        {"cat": "Session", "pid": 1, "tid": 1, "dur": 300, "ts": 5, "ph": "X", "name": "model_loading_uri", "args": {}},
+        {"cat": "Node", "pid": 1, "tid": 1, "dur": 0, "ts": 10, "ph": "X", "name": "matmul_1_fence_before", "args": {"op_name": "MatMul"}},
+        {"cat": "Node", "pid": 1, "tid": 1, "dur": 100, "ts": 10, "ph": "X", "name": "matmul_1_kernel_time",
+         "args": {"op_name": "MatMul", "provider": "CPUExecutionProvider", "output_size": "64"}},
+        {"cat": "Node", "pid": 1, "tid": 1, "dur": 20, "ts": 110, "ph": "X", "name": "add_1_kernel_time",
+         "args": {"op_name": "Add", "provider": "CPUExecutionProvider"}},
+        {"cat": "Node", "pid": 1, "tid": 1, "dur": 200, "ts": 130, "ph": "X", "name": "matmul_1_kernel_time",
+         "args": {"op_name": "MatMul", "provider": "CPUExecutionProvider"}},
+        {"cat": "Node", "pid": 1, "tid": 1, "dur": 30, "ts": 330, "ph": "X", "name": "matmul_2_kernel_time",
+         "args": {"op_name": "MatMul", "provider": "CPUExecutionProvider"}}
+    ]"#;
+
+    #[test]
+    fn test_parse_profile() {
+        let events = Profile::parse_events(TRACE, 1_000_000).unwrap();
+        assert_eq!(6, events.len());
+        assert_eq!(1_010_000, events[2].start_time_ns);
+        assert_eq!(Duration::from_micros(100), events[2].duration);
+        assert_eq!(Some("matmul_1"), events[2].node_name());
+        assert_eq!(None, events[1].node_name());
+        assert_eq!(Some("MatMul"), events[2].op_name.as_deref());
+        assert_eq!("64", events[2].args["output_size"]);
+        assert!(Profile::parse_events("{}", 0).is_err());
+    }
+
+    #[test]
+    fn test_profile_report() {
+        let profile = Profile {
+            path: PathBuf::new(),
+            start_time_ns: 0,
+            events: Profile::parse_events(TRACE, 0).unwrap(),
+        };
+        let report = profile.report();
+        assert_eq!(
+            vec!["matmul_1", "matmul_2", "add_1"],
+            report
+                .nodes
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        let matmul_1 = report.node("matmul_1").unwrap();
+        assert_eq!(2, matmul_1.count);
+        assert_eq!(Duration::from_micros(300), matmul_1.total);
+        assert_eq!(Duration::from_micros(150), matmul_1.mean);
+        assert_eq!(Duration::from_micros(200), matmul_1.p99);
+        assert_eq!(Some("CPUExecutionProvider"), matmul_1.provider.as_deref());
+
+        assert_eq!("MatMul", report.op_types[0].name);
+        assert_eq!(3, report.op_types[0].count);
+        assert_eq!(
+            Duration::from_micros(330),
+            report.op_type_totals()["MatMul"]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_mock_profiling() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+        let prefix = std::env::temp_dir().join(format!("ors-profile-{}", std::process::id()));
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .enable_profiling(&prefix)
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .unwrap();
+        let inputs: Vec<_> = (0..2)
+            .map(|_| {
+                runtime
+                    .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+                    .unwrap()
+            })
+            .collect();
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+            .unwrap()];
+        for _ in 0..3 {
+            run_session(&mut session, &inputs, &mut outputs).unwrap();
+        }
+        let profile = session.end_profiling().unwrap();
+        assert_eq!(MOCK_PROFILING_START_TIME_NS, profile.start_time_ns);
+        assert_eq!(6, profile.events.len());
+        assert_eq!(
+            MOCK_PROFILING_START_TIME_NS + 210_000,
+            profile.events[3].start_time_ns
+        );
+        let report = profile.report();
+        let mock = report.node("mock").unwrap();
+        assert_eq!(3, mock.count);
+        assert_eq!(std::time::Duration::from_micros(300), mock.total);
+        assert_eq!("Mock", report.op_types[0].name);
+        std::fs::remove_file(&profile.path).unwrap();
+    }
+}
@@ -0,0 +1,352 @@
+//! Execution providers of onnxruntime
+//!
+//! Providers are compiled into onnxruntime, so the same binary may run with builds which support
+//! different providers. [`available`] lists the providers of the loaded build, and
+//! [`SessionBuilder::execution_providers`](crate::session::SessionBuilder::execution_providers)
+//! appends [`ExecutionProvider`]s in priority order, optionally falling back to the CPU provider.
+//!
+//! ## Example
+//! ```no_run
+//! use ors::providers::ExecutionProvider;
+//! use ors::session::SessionBuilder;
+//!
+//! let session = SessionBuilder::new()
+//!     .unwrap()
+//!     .execution_providers(&[
+//!         ExecutionProvider::xnnpack().option("intra_op_num_threads", "4"),
+//!         ExecutionProvider::cpu().option("use_arena", "0"),
+//!     ])
+//!     .unwrap()
+//!     .cpu_fallback_enabled(true)
+//!     .unwrap()
+//!     .build_with_model_from_file("model.onnx")
+//!     .unwrap();
+//! ```
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::ptr::null_mut;
+
+use ors_sys::*;
+
+use crate::api::default_runtime;
+use crate::error::{Error, Result};
+use crate::runtime::Runtime;
+use crate::status::check_status;
+
+/// Names of the execution providers compiled into the onnxruntime of the default runtime,
+/// like `CPUExecutionProvider`
+pub fn available() -> Result<Vec<String>> {
+    available_with_runtime(&default_runtime()?)
+}
+
+/// Names of the execution providers compiled into the onnxruntime of the runtime
+pub fn available_with_runtime(runtime: &Runtime) -> Result<Vec<String>> {
+    get_available_providers(runtime.api())
+}
+
+/// Options of an execution provider
+pub type ProviderOptions = BTreeMap<String, String>;
+
+/// An execution provider which can be appended to session options
+///
+/// The CPU provider always runs the nodes which other providers don't support,
+/// so [`ExecutionProvider::Cpu`] only sets its options, wherever it's in the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExecutionProvider {
+    /// The default CPU provider, its only option is `use_arena`, `1` or `0`
+    Cpu(ProviderOptions),
+    /// XNNPACK, options are like `intra_op_num_threads`
+    Xnnpack(ProviderOptions),
+    /// Qualcomm SNPE, options are like `runtime` and `buffer_type`
+    Snpe(ProviderOptions),
+}
+
+impl ExecutionProvider {
+    pub fn cpu() -> Self {
+        ExecutionProvider::Cpu(ProviderOptions::new())
+    }
+
+    pub fn xnnpack() -> Self {
+        ExecutionProvider::Xnnpack(ProviderOptions::new())
+    }
+
+    pub fn snpe() -> Self {
+        ExecutionProvider::Snpe(ProviderOptions::new())
+    }
+
+    /// Set a provider specific option
+    pub fn option(mut self, key: &str, value: &str) -> Self {
+        self.options_mut()
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn options(&self) -> &ProviderOptions {
+        match self {
+            ExecutionProvider::Cpu(options)
+            | ExecutionProvider::Xnnpack(options)
+            | ExecutionProvider::Snpe(options) => options,
+        }
+    }
+
+    fn options_mut(&mut self) -> &mut ProviderOptions {
+        match self {
+            ExecutionProvider::Cpu(options)
+            | ExecutionProvider::Xnnpack(options)
+            | ExecutionProvider::Snpe(options) => options,
+        }
+    }
+
+    /// Name used by `SessionOptionsAppendExecutionProvider`, like `XNNPACK`
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExecutionProvider::Cpu(_) => "CPU",
+            ExecutionProvider::Xnnpack(_) => "XNNPACK",
+            ExecutionProvider::Snpe(_) => "SNPE",
+        }
+    }
+
+    /// Name reported by [`available`], like `XnnpackExecutionProvider`
+    pub fn provider_type(&self) -> &'static str {
+        match self {
+            ExecutionProvider::Cpu(_) => "CPUExecutionProvider",
+            ExecutionProvider::Xnnpack(_) => "XnnpackExecutionProvider",
+            ExecutionProvider::Snpe(_) => "SNPEExecutionProvider",
+        }
+    }
+
+    /// Find a provider by the name used by `SessionOptionsAppendExecutionProvider` or its provider type
+    pub fn from_name(name: &str, options: ProviderOptions) -> Option<Self> {
+        vec![
+            ExecutionProvider::cpu(),
+            ExecutionProvider::xnnpack(),
+            ExecutionProvider::snpe(),
+        ]
+        .into_iter()
+        .find(|provider| {
+            provider.name().eq_ignore_ascii_case(name) || provider.provider_type() == name
+        })
+        .map(|mut provider| {
+            *provider.options_mut() = options;
+            provider
+        })
+    }
+
+    /// Whether the provider is compiled into the onnxruntime of the runtime
+    pub fn is_available(&self, runtime: &Runtime) -> Result<bool> {
+        Ok(available_with_runtime(runtime)?
+            .iter()
+            .any(|p| p == self.provider_type()))
+    }
+
+    /// Check that the provider is available, returning [`Error::ProviderUnavailable`] if it's not
+    pub(crate) fn check_available(&self, runtime: &Runtime) -> Result<()> {
+        let available = available_with_runtime(runtime)?;
+        if available.iter().any(|p| p == self.provider_type()) {
+            Ok(())
+        } else {
+            Err(Error::ProviderUnavailable {
+                provider: self.provider_type(),
+                available,
+            })
+        }
+    }
+
+    /// Value of the `use_arena` option of the CPU provider
+    pub(crate) fn cpu_arena_option(options: &ProviderOptions) -> Result<Option<bool>> {
+        let mut use_arena = None;
+        for (key, value) in options {
+            use_arena = match (key.as_str(), value.as_str()) {
+                ("use_arena", "1" | "true") => Some(true),
+                ("use_arena", "0" | "false") => Some(false),
+                _ => {
+                    return Err(Error::InvalidProviderOption {
+                        provider: "CPUExecutionProvider",
+                        key: key.clone(),
+                        value: value.clone(),
+                    })
+                }
+            };
+        }
+        Ok(use_arena)
+    }
+}
+
+pub(crate) fn get_available_providers(api: &OrtApi) -> Result<Vec<String>> {
+    let (get_providers, release_providers) =
+        match (api.GetAvailableProviders, api.ReleaseAvailableProviders) {
+            (Some(get_providers), Some(release_providers)) => (get_providers, release_providers),
+            // `GetAvailableProviders` is introduced in API version 4
+            _ => return Ok(vec![]),
+        };
+    let mut providers_ptr: *mut *mut std::os::raw::c_char = null_mut();
+    let mut providers_len = 0;
+    let status = unsafe { get_providers(&mut providers_ptr, &mut providers_len) };
+    check_status(api, status, "GetAvailableProviders")?;
+    let providers = (0..providers_len as usize)
+        .map(|i| unsafe {
+            CStr::from_ptr(*providers_ptr.add(i))
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    let status = unsafe { release_providers(providers_ptr, providers_len) };
+    check_status(api, status, "ReleaseAvailableProviders")?;
+    Ok(providers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "mock")]
+    use crate::error::ErrorCode;
+    #[cfg(feature = "mock")]
+    use crate::mock::{test_support::sum_model, MockBackend};
+    #[cfg(feature = "mock")]
+    use tracing_test::traced_test;
+
+    #[test]
+    fn test_execution_provider() {
+        let provider = ExecutionProvider::from_name("xnnpack", ProviderOptions::new())
+            .unwrap()
+            .option("intra_op_num_threads", "2");
+        assert_eq!("XNNPACK", provider.name());
+        assert_eq!(
+            Some("2"),
+            provider
+                .options()
+                .get("intra_op_num_threads")
+                .map(|v| v.as_str())
+        );
+        assert_eq!(
+            Some(ExecutionProvider::cpu()),
+            ExecutionProvider::from_name("CPUExecutionProvider", ProviderOptions::new())
+        );
+        assert_eq!(
+            None,
+            ExecutionProvider::from_name("CUDA", ProviderOptions::new())
+        );
+
+        let cpu = ExecutionProvider::cpu().option("use_arena", "0");
+        assert_eq!(
+            Some(false),
+            ExecutionProvider::cpu_arena_option(cpu.options()).unwrap()
+        );
+        let cpu = cpu.option("arena_extend_strategy", "1");
+        assert!(matches!(
+            ExecutionProvider::cpu_arena_option(cpu.options()),
+            Err(Error::InvalidProviderOption { key, .. }) if key == "arena_extend_strategy"
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    #[traced_test]
+    fn test_mock_execution_providers() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+        assert_eq!(
+            vec!["XnnpackExecutionProvider", "CPUExecutionProvider"],
+            available_with_runtime(&runtime).unwrap()
+        );
+        assert!(ExecutionProvider::xnnpack().is_available(&runtime).unwrap());
+        assert!(!ExecutionProvider::snpe().is_available(&runtime).unwrap());
+
+        let providers = [
+            ExecutionProvider::snpe(),
+            ExecutionProvider::xnnpack().option("intra_op_num_threads", "2"),
+            ExecutionProvider::cpu().option("use_arena", "0"),
+        ];
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .execution_providers(&providers)
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            Error::ProviderUnavailable {
+                provider: "SNPEExecutionProvider",
+                ..
+            }
+        ));
+
+        // The missing provider is skipped
+        let builder = runtime
+            .session_builder()
+            .unwrap()
+            .execution_providers(&providers)
+            .unwrap()
+            .cpu_fallback_enabled(true)
+            .unwrap();
+        backend.clear_calls();
+        builder.build_with_model_in_memory("sum").unwrap();
+        assert!(logs_contain("Skipping execution provider SNPE"));
+        let calls = backend.calls();
+        assert_eq!(
+            1,
+            calls
+                .iter()
+                .filter(|f| **f == "SessionOptionsAppendExecutionProvider")
+                .count()
+        );
+        assert!(calls.contains(&"DisableCpuMemArena"));
+
+        // The session is created again without XNNPACK if it fails to initialize
+        let builder = runtime
+            .session_builder()
+            .unwrap()
+            .execution_providers(&providers[1..])
+            .unwrap()
+            .cpu_fallback_enabled(true)
+            .unwrap();
+        backend.fail_next(
+            "CreateSessionFromArray",
+            ErrorCode::EpFail,
+            "XNNPACK failed to initialize",
+        );
+        backend.clear_calls();
+        builder.build_with_model_in_memory("sum").unwrap();
+        assert!(logs_contain("falling back to CPU"));
+        let calls = backend.calls();
+        assert_eq!(
+            2,
+            calls
+                .iter()
+                .filter(|f| **f == "CreateSessionFromArray")
+                .count()
+        );
+        assert_eq!(
+            1,
+            calls
+                .iter()
+                .filter(|f| **f == "SessionOptionsAppendExecutionProvider")
+                .count()
+        );
+
+        // Errors caused by the model are not retried
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .execution_providers(&providers[1..])
+            .unwrap()
+            .cpu_fallback_enabled(true)
+            .unwrap()
+            .build_with_model_in_memory("missing")
+            .err()
+            .unwrap();
+        assert_eq!(Some(ErrorCode::NoSuchFile), err.code());
+
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .execution_providers(&[ExecutionProvider::cpu().option("use_arena", "yes")])
+            .err()
+            .unwrap();
+        assert!(err.is_bad_request());
+    }
+}
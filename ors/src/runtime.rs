@@ -0,0 +1,604 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+use arc_swap::ArcSwapOption;
+use ndarray::ArrayD;
+use ors_sys::*;
+use tracing::debug;
+#[cfg(not(feature = "dynamic-loading"))]
+use tracing::warn;
+
+#[cfg(feature = "dynamic-loading")]
+use crate::discovery::load_library;
+use crate::env::{Environment, EnvironmentBuilder};
+use crate::log::LoggingLevel;
+use crate::memory_info::MemoryInfo;
+#[cfg(feature = "mock")]
+use crate::mock::{MockState, MOCK_VERSION};
+use crate::providers::get_available_providers;
+use crate::session::SessionBuilder;
+use crate::tensor::{create_tensor_with_ndarray_and_runtime, Tensor, TypedArray};
+use crate::types::TypeToTensorElementDataType;
+use crate::version::{negotiate_api, RuntimeInfo};
+
+/// A loaded onnxruntime, which owns the shared library, the API table and the inference environment
+///
+/// Sessions, tensors, memory infos and allocators created from a runtime keep a handle of it,
+/// so the library is unloaded only after all of them are dropped.
+/// The number of them can be checked using [`Runtime::live_objects`].
+/// Cloning a `Runtime` is cheap, all clones share the same library.
+///
+/// onnxruntime has one inference environment per loaded library. Runtimes loading the same library
+/// share it, including its name, log level, log sink and global thread pools, which are set by the
+/// first runtime of the library. Building a runtime with [`RuntimeBuilder::environment`] fails with
+/// [`Error::EnvironmentExists`] while another runtime of the library is alive.
+///
+/// Several runtimes can coexist in one process, for example a stock onnxruntime and a custom build:
+/// ```no_run
+/// use ors::runtime::Runtime;
+/// use std::path::Path;
+///
+/// let stock = Runtime::new(Path::new("/path/to/libonnxruntime.so")).unwrap();
+/// let custom = Runtime::new(Path::new("/path/to/custom/libonnxruntime.so")).unwrap();
+/// let session = custom
+///     .session_builder()
+///     .unwrap()
+///     .build_with_model_from_file("/path/to/model.onnx")
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Runtime {
+    inner: Arc<RuntimeInner>,
+}
+
+struct RuntimeInner {
+    api: OrtApi,
+    version: String,
+    api_version: u32,
+    library_path: PathBuf,
+    live_objects: LiveObjectCounters,
+    // The library must be dropped after the environment is released, mock runtimes don't have one
+    env: Environment,
+    lib: Option<Library>,
+    #[cfg(feature = "mock")]
+    mock: Option<Arc<MockState>>,
+}
+
+/// The loaded shared library of onnxruntime
+#[cfg(feature = "dynamic-loading")]
+type Library = onnxruntime;
+
+/// onnxruntime linked at compile time, which is never unloaded
+#[cfg(not(feature = "dynamic-loading"))]
+struct Library;
+
+#[cfg(not(feature = "dynamic-loading"))]
+impl Library {
+    #[allow(non_snake_case)]
+    unsafe fn OrtGetApiBase(&self) -> *const OrtApiBase {
+        ors_sys::OrtGetApiBase()
+    }
+}
+
+// The API table only contains function pointers, and `OrtEnv` can be shared between threads
+unsafe impl Send for RuntimeInner {}
+unsafe impl Sync for RuntimeInner {}
+
+impl Drop for RuntimeInner {
+    fn drop(&mut self) {
+        // The environment is released when the field is dropped, if this is the last runtime of the library
+        if self.lib.is_some() {
+            debug!("Unloading onnxruntime from {}", self.library_path.display());
+        }
+    }
+}
+
+impl Runtime {
+    /// Load onnxruntime from the given shared library, with the default environment
+    ///
+    /// If another runtime of the library is alive, its environment is shared instead.
+    pub fn new(path: &Path) -> Result<Runtime> {
+        RuntimeBuilder::new().library_path(path).build()
+    }
+
+    /// Find onnxruntime automatically and load it, with the default environment
+    ///
+    /// If another runtime of the library is alive, its environment is shared instead.
+    /// See [`crate::discovery::candidates`] for locations which are searched.
+    pub fn auto() -> Result<Runtime> {
+        RuntimeBuilder::new().build()
+    }
+
+    /// Create a builder to customize the library path and the environment
+    pub fn builder() -> RuntimeBuilder {
+        RuntimeBuilder::new()
+    }
+
+    /// The API table of this runtime
+    ///
+    /// The functions point into the loaded library, the borrow keeps this handle and the library alive.
+    pub fn api(&self) -> &OrtApi {
+        &self.inner.api
+    }
+
+    /// The API version negotiated with the library
+    pub(crate) fn api_version(&self) -> u32 {
+        self.inner.api_version
+    }
+
+    /// Create a runtime of the mock backend with the environment of the backend
+    #[cfg(feature = "mock")]
+    pub(crate) fn mock(api: OrtApi, env: Environment, state: Arc<MockState>) -> Runtime {
+        Runtime {
+            inner: Arc::new(RuntimeInner {
+                api,
+                version: MOCK_VERSION.to_string(),
+                api_version: state.api_version(),
+                library_path: PathBuf::new(),
+                live_objects: LiveObjectCounters::default(),
+                env,
+                lib: None,
+                mock: Some(state),
+            }),
+        }
+    }
+
+    pub(crate) fn env_ptr(&self) -> *mut OrtEnv {
+        self.inner.env.ptr()
+    }
+
+    /// Record a call of the API function if this is a mock runtime
+    pub(crate) fn record_call(&self, function: &'static str) {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.inner.mock {
+            mock.record_call(function);
+        }
+    }
+
+    /// The minimal level of messages sent to the log sink
+    pub fn log_level(&self) -> LoggingLevel {
+        self.inner.env.logger().level()
+    }
+
+    /// Change the minimal level of messages sent to the log sink
+    ///
+    /// onnxruntime itself drops messages below the level of [`EnvironmentBuilder::log_level`],
+    /// so a lower level than that has no effect.
+    pub fn set_log_level(&self, level: LoggingLevel) {
+        self.inner.env.logger().set_level(level);
+    }
+
+    /// Number of objects created from this runtime which are still alive
+    pub fn live_objects(&self) -> LiveObjects {
+        self.inner.live_objects.load()
+    }
+
+    /// Check whether the runtime can be unloaded now, which means this is the only handle of it
+    /// and no object created from it is alive
+    pub(crate) fn check_unloadable(&self) -> Result<()> {
+        let live_objects = self.live_objects();
+        let handles = Arc::strong_count(&self.inner) - 1;
+        if !live_objects.is_empty() || handles > 0 {
+            return Err(Error::RuntimeInUse {
+                live_objects,
+                handles,
+            });
+        }
+        Ok(())
+    }
+
+    /// Keep a handle of the runtime in an object of the given kind, the object is counted until the handle is dropped
+    pub(crate) fn track(&self, kind: ObjectKind) -> TrackedRuntime {
+        self.inner
+            .live_objects
+            .counter(kind)
+            .fetch_add(1, Ordering::SeqCst);
+        TrackedRuntime {
+            runtime: self.clone(),
+            kind,
+        }
+    }
+
+    /// Version string reported by onnxruntime
+    pub fn version(&self) -> &str {
+        &self.inner.version
+    }
+
+    /// Whether two handles refer to the same loaded runtime
+    pub fn same_runtime(&self, other: &Runtime) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Get information of this runtime, including its version, the negotiated API version,
+    /// available execution providers and the path of the shared library
+    pub fn info(&self) -> Result<RuntimeInfo> {
+        Ok(RuntimeInfo {
+            version: self.inner.version.clone(),
+            api_version: self.inner.api_version,
+            available_providers: get_available_providers(self.api())?,
+            library_path: self.inner.library_path.clone(),
+        })
+    }
+
+    /// Create a `SessionBuilder` whose sessions are created in this runtime
+    pub fn session_builder(&self) -> Result<SessionBuilder> {
+        SessionBuilder::with_runtime(self)
+    }
+
+    /// Create a `MemoryInfo` in this runtime
+    pub fn memory_info(
+        &self,
+        allocator_type: OrtAllocatorType,
+        memory_type: OrtMemType,
+    ) -> Result<MemoryInfo> {
+        MemoryInfo::new_with_runtime(self, allocator_type, memory_type)
+    }
+
+    /// Create a tensor in this runtime, the ndarray is owned by the created tensor
+    pub fn create_tensor_with_ndarray<T>(&self, array: ArrayD<T>) -> Result<Tensor>
+    where
+        T: TypeToTensorElementDataType,
+        TypedArray: From<ArrayD<T>>,
+    {
+        create_tensor_with_ndarray_and_runtime(self, array)
+    }
+}
+
+impl Debug for Runtime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Runtime")
+            .field("version", &self.inner.version)
+            .field("api_version", &self.inner.api_version)
+            .field("library_path", &self.inner.library_path)
+            .finish()
+    }
+}
+
+/// Builder of `Runtime`
+///
+/// If the library path is not set, onnxruntime is searched automatically
+#[derive(Debug, Default)]
+pub struct RuntimeBuilder {
+    library_path: Option<PathBuf>,
+    environment: Option<EnvironmentBuilder>,
+}
+
+impl RuntimeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load onnxruntime from the given shared library
+    pub fn library_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.library_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set options of the inference environment
+    ///
+    /// The environment is shared by all runtimes of a library, so [`build`](RuntimeBuilder::build) returns
+    /// [`Error::EnvironmentExists`] if another runtime of the library is still alive.
+    pub fn environment(mut self, environment: EnvironmentBuilder) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    pub fn build(self) -> Result<Runtime> {
+        let (lib, library_path) = self.load_library()?;
+
+        // Negotiate the API version first, an unsupported library is dropped here
+        let api_base = unsafe { lib.OrtGetApiBase() };
+        let negotiated = negotiate_api(api_base)?;
+        debug!(
+            "Loaded onnxruntime {} from {} using API version {}",
+            negotiated.version,
+            library_path.display(),
+            negotiated.api_version
+        );
+
+        let env = Environment::shared(
+            api_base as usize,
+            self.environment.as_ref(),
+            &negotiated.api,
+            |options, logger| options.create_env(&negotiated.api, logger),
+        )?;
+        Ok(Runtime {
+            inner: Arc::new(RuntimeInner {
+                api: negotiated.api,
+                version: negotiated.version,
+                api_version: negotiated.api_version,
+                library_path,
+                live_objects: LiveObjectCounters::default(),
+                env,
+                lib: Some(lib),
+                #[cfg(feature = "mock")]
+                mock: None,
+            }),
+        })
+    }
+
+    #[cfg(feature = "dynamic-loading")]
+    fn load_library(&self) -> Result<(Library, PathBuf)> {
+        match &self.library_path {
+            Some(path) => match unsafe { onnxruntime::new(path) } {
+                Ok(lib) => Ok((lib, path.clone())),
+                Err(err) => Err(Error::LibraryLoad(format!(
+                    "Failed to load onnxruntime shared library {}: {}",
+                    path.display(),
+                    err
+                ))),
+            },
+            None => load_library(),
+        }
+    }
+
+    /// onnxruntime is linked at compile time, the library path is the directory it's linked from
+    #[cfg(not(feature = "dynamic-loading"))]
+    fn load_library(&self) -> Result<(Library, PathBuf)> {
+        if let Some(path) = &self.library_path {
+            warn!(
+                "onnxruntime is linked at compile time, ignoring library path {}",
+                path.display()
+            );
+        }
+        Ok((Library, PathBuf::from(ors_sys::ORT_BUILD_LIB_DIR)))
+    }
+}
+
+/// A slot holding a runtime, which can be read from many threads without locking
+pub(crate) struct RuntimeCell {
+    inner: ArcSwapOption<RuntimeInner>,
+}
+
+impl RuntimeCell {
+    pub(crate) const fn new() -> Self {
+        Self {
+            inner: ArcSwapOption::const_empty(),
+        }
+    }
+
+    pub(crate) fn get(&self) -> Option<Runtime> {
+        self.inner.load_full().map(|inner| Runtime { inner })
+    }
+
+    /// Replace the runtime in the slot, returns the old one
+    pub(crate) fn set(&self, runtime: Option<Runtime>) -> Option<Runtime> {
+        self.inner
+            .swap(runtime.map(|runtime| runtime.inner))
+            .map(|inner| Runtime { inner })
+    }
+}
+
+/// Kinds of objects which are created from a runtime and must be released before it's unloaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObjectKind {
+    Session,
+    Tensor,
+    MemoryInfo,
+    Allocator,
+}
+
+/// Number of alive objects created from a runtime
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LiveObjects {
+    pub sessions: usize,
+    pub tensors: usize,
+    pub memory_infos: usize,
+    pub allocators: usize,
+}
+
+impl LiveObjects {
+    pub fn is_empty(&self) -> bool {
+        self.sessions == 0 && self.tensors == 0 && self.memory_infos == 0 && self.allocators == 0
+    }
+}
+
+impl std::fmt::Display for LiveObjects {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} session(s), {} tensor(s), {} memory info(s), {} allocator(s)",
+            self.sessions, self.tensors, self.memory_infos, self.allocators
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct LiveObjectCounters {
+    sessions: AtomicUsize,
+    tensors: AtomicUsize,
+    memory_infos: AtomicUsize,
+    allocators: AtomicUsize,
+}
+
+impl LiveObjectCounters {
+    fn counter(&self, kind: ObjectKind) -> &AtomicUsize {
+        match kind {
+            ObjectKind::Session => &self.sessions,
+            ObjectKind::Tensor => &self.tensors,
+            ObjectKind::MemoryInfo => &self.memory_infos,
+            ObjectKind::Allocator => &self.allocators,
+        }
+    }
+
+    fn load(&self) -> LiveObjects {
+        LiveObjects {
+            sessions: self.sessions.load(Ordering::SeqCst),
+            tensors: self.tensors.load(Ordering::SeqCst),
+            memory_infos: self.memory_infos.load(Ordering::SeqCst),
+            allocators: self.allocators.load(Ordering::SeqCst),
+        }
+    }
+}
+
+/// Handle of a runtime kept by an object created from it
+///
+/// The object is counted in [`Runtime::live_objects`] until the handle is dropped,
+/// so the handle must be dropped after the object is released
+#[derive(Debug)]
+pub(crate) struct TrackedRuntime {
+    runtime: Runtime,
+    kind: ObjectKind,
+}
+
+impl Deref for TrackedRuntime {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        &self.runtime
+    }
+}
+
+impl Drop for TrackedRuntime {
+    fn drop(&mut self) {
+        self.runtime
+            .inner
+            .live_objects
+            .counter(self.kind)
+            .fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ndarray::IxDyn;
+    use tracing_test::traced_test;
+
+    use super::*;
+
+    /// A new runtime, which is a mock runtime if the `mock` feature is enabled
+    fn new_runtime() -> Runtime {
+        #[cfg(feature = "mock")]
+        return crate::mock::MockBackend::new().runtime().unwrap();
+        #[cfg(not(feature = "mock"))]
+        return Runtime::auto().unwrap();
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_runtime_info() {
+        let runtime = new_runtime();
+        let info = runtime.info().unwrap();
+        assert!(info.api_version <= ORT_API_VERSION);
+        assert!(info
+            .available_providers
+            .contains(&"CPUExecutionProvider".to_string()));
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_two_runtimes() {
+        let runtime1 = Runtime::auto().unwrap();
+        let runtime2 = Runtime::auto().unwrap();
+        assert!(!runtime1.same_runtime(&runtime2));
+        assert!(runtime1.same_runtime(&runtime1.clone()));
+        // Runtimes of one library share its environment
+        assert_eq!(runtime1.env_ptr(), runtime2.env_ptr());
+        let err = Runtime::builder()
+            .environment(
+                EnvironmentBuilder::new()
+                    .name("second")
+                    .log_level(LoggingLevel::Verbose),
+            )
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::EnvironmentExists));
+
+        let array = ArrayD::<f32>::from_shape_vec(IxDyn(&[1, 2]), vec![0.; 2]).unwrap();
+        let tensor = runtime2.create_tensor_with_ndarray(array).unwrap();
+        // The tensor keeps the second runtime alive
+        let runtime2_clone = runtime2.clone();
+        std::mem::drop(runtime2);
+        assert!(!tensor.ptr.is_null());
+        assert_eq!(1, runtime2_clone.live_objects().tensors);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_shared_environment() {
+        let backend = crate::mock::MockBackend::new();
+        let runtime1 = backend.runtime().unwrap();
+        let runtime2 = backend.runtime().unwrap();
+        let library = runtime1.inner.env.library();
+        assert_eq!(runtime1.env_ptr(), runtime2.env_ptr());
+        assert_eq!(2, Environment::runtimes(library));
+        assert_ne!(
+            runtime1.env_ptr(),
+            crate::mock::MockBackend::new().runtime().unwrap().env_ptr()
+        );
+
+        // The logger is shared too
+        runtime2.set_log_level(LoggingLevel::Error);
+        assert_eq!(LoggingLevel::Error, runtime1.log_level());
+
+        // Options can't be applied to the existing environment
+        let err = Environment::shared(
+            library,
+            Some(&EnvironmentBuilder::new().name("second")),
+            runtime1.api(),
+            |_, _| unreachable!(),
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(err, Error::EnvironmentExists));
+
+        // The environment and its logger are kept until the last runtime is dropped
+        std::mem::drop(runtime1);
+        assert_eq!(1, Environment::runtimes(library));
+        assert_eq!(LoggingLevel::Error, runtime2.log_level());
+        std::mem::drop(runtime2);
+        assert_eq!(0, Environment::runtimes(library));
+        assert_eq!(
+            LoggingLevel::Warning,
+            backend.runtime().unwrap().log_level()
+        );
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_log_level() {
+        let runtime = new_runtime();
+        assert_eq!(LoggingLevel::Warning, runtime.log_level());
+        runtime.set_log_level(LoggingLevel::Error);
+        assert_eq!(LoggingLevel::Error, runtime.clone().log_level());
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_live_objects() {
+        let runtime = new_runtime();
+        runtime.check_unloadable().unwrap();
+
+        let memory_info = runtime
+            .memory_info(OrtAllocatorType_OrtArenaAllocator, OrtMemType_OrtMemTypeCPU)
+            .unwrap();
+        let array = ArrayD::<f32>::from_shape_vec(IxDyn(&[1, 2]), vec![0.; 2]).unwrap();
+        let tensor = runtime.create_tensor_with_ndarray(array).unwrap();
+        assert_eq!(
+            LiveObjects {
+                sessions: 0,
+                tensors: 1,
+                memory_infos: 1,
+                allocators: 0,
+            },
+            runtime.live_objects()
+        );
+        let err = runtime.check_unloadable().err().unwrap();
+        assert!(err.to_string().contains("1 tensor(s)"));
+
+        std::mem::drop(tensor);
+        std::mem::drop(memory_info);
+        assert!(runtime.live_objects().is_empty());
+        runtime.check_unloadable().unwrap();
+
+        let runtime_clone = runtime.clone();
+        assert!(runtime.check_unloadable().is_err());
+        std::mem::drop(runtime_clone);
+        runtime.check_unloadable().unwrap();
+    }
+}
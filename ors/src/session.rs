@@ -1,85 +1,724 @@
-use self::io::{get_session_outputs, SessionInputInfo, SessionOutputInfo};
-use crate::api::get_api;
+use self::io::{free_name, get_session_initializers, get_session_outputs, DimOverrides};
+use crate::api::default_runtime;
 use crate::call_ort;
-use crate::config::{SessionExecutionMode, SessionGraphOptimizationLevel};
-use crate::env::get_env_ptr;
+use crate::config::{
+    ProviderConfig, SessionConfig, SessionConfigKey, SessionExecutionMode,
+    SessionGraphOptimizationLevel,
+};
+use crate::custom_op::{CustomOpDomain, CustomOps};
+use crate::error::{Error, Result};
+use crate::log::{LogIdSpan, LoggingLevel};
+use crate::memory_info::Allocator;
+use crate::model::{dim_denotations, map_external_data, ExternalInitializers, ModelData};
+#[cfg(feature = "profiling")]
+use crate::profiling::Profile;
+use crate::providers::ExecutionProvider;
+use crate::runtime::{ObjectKind, Runtime, TrackedRuntime};
 use crate::session::io::get_session_inputs;
 use crate::status::check_status;
-use crate::tensor::Tensor;
-use anyhow::{anyhow, Result};
+use crate::tensor::{create_zeroed_tensor, OrtOwnedTensor, Tensor};
+use crate::thread::{create_thread, join_thread, ThreadCreator};
 use ors_sys::*;
-use std::ffi::{c_void, CString};
-use std::path::Path;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr, CString};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
 use std::ptr::{null, null_mut};
+use std::rc::Rc;
 #[cfg(not(target_family = "windows"))]
 use std::{ffi::OsString, os::unix::prelude::OsStrExt};
 #[cfg(target_family = "windows")]
 use std::{ffi::OsString, os::windows::prelude::OsStrExt};
+use tracing::warn;
 
 pub(crate) mod io;
+mod prepacked_weights;
+mod run_options;
+
+pub use self::io::{SessionInitializerInfo, SessionInputInfo, SessionOutputInfo};
+pub use self::prepacked_weights::PrepackedWeights;
+pub use self::run_options::RunOptions;
 
 #[derive(Debug)]
 pub struct Session {
+    runtime: TrackedRuntime,
     session_ptr: *mut OrtSession,
-    allocator: *mut OrtAllocator,
+    // Names the session in errors of onnxruntime
+    name: Option<String>,
+    allocator: Allocator,
     mem_info: *mut OrtMemoryInfo,
     input_info: Vec<SessionInputInfo>,
     output_info: Vec<SessionOutputInfo>,
+    initializer_info: Vec<SessionInitializerInfo>,
+    // Threads of the session are created until it's released
+    thread_creator: Option<ThreadCreator>,
+    log_span: Option<LogIdSpan>,
+    // Custom ops, added initializers, model data and prepacked weights are used by the session until it's released
+    custom_ops: CustomOps,
+    initializers: Vec<Rc<Tensor>>,
+    external_initializers: Option<Rc<ExternalInitializers>>,
+    model_data: Option<ModelData>,
+    prepacked_weights: Option<PrepackedWeights>,
 }
 
-pub fn run(session: &mut Session, inputs: &[Tensor], outputs: &mut Vec<Tensor>) -> Result<()> {
-    let input_names_ptr: Vec<*const i8> = session
-        .input_info
+impl Session {
+    /// Names, element types and dimensions of the inputs of the model
+    pub fn input_info(&self) -> &[SessionInputInfo] {
+        &self.input_info
+    }
+
+    /// Names, element types and dimensions of the outputs of the model
+    pub fn output_info(&self) -> &[SessionOutputInfo] {
+        &self.output_info
+    }
+
+    /// Names, element types and dimensions of the initializers which can be overridden by
+    /// [`run_with_initializers`]
+    pub fn overridable_initializers(&self) -> &[SessionInitializerInfo] {
+        &self.initializer_info
+    }
+
+    /// Run the session with inputs given by name in any order, and get outputs by name
+    ///
+    /// Inputs can also override initializers of [`overridable_initializers`](Session::overridable_initializers).
+    /// All outputs are fetched if `output_names` is `None`. Outputs whose dimensions are all known, or
+    /// overridden by the builder, are allocated before the run. Outputs with free dimensions are
+    /// allocated by onnxruntime and copied, use [`run_named_allocated`](Session::run_named_allocated)
+    /// to read them without copying.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # use ndarray::{ArrayD, IxDyn};
+    /// # use ors::session::SessionBuilder;
+    /// # use ors::tensor::{create_tensor_with_ndarray, Tensor};
+    /// let mut session = SessionBuilder::new()
+    ///     .unwrap()
+    ///     .build_with_model_from_file("gpt2.onnx")
+    ///     .unwrap();
+    /// let input_ids = create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 8]))).unwrap();
+    /// let position_ids =
+    ///     create_tensor_with_ndarray(ArrayD::<i64>::from_shape_fn(IxDyn(&[1, 8]), |d| d[1] as i64))
+    ///         .unwrap();
+    /// let attention_mask = create_tensor_with_ndarray(ArrayD::<f32>::ones(IxDyn(&[1, 8]))).unwrap();
+    /// // The first step has no past keys and values
+    /// let past_names: Vec<String> = (0..12).map(|i| format!("past_{}", i)).collect();
+    /// let past: Vec<Tensor> = (0..12)
+    ///     .map(|_| create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[2, 1, 12, 0, 64]))))
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// let mut inputs = vec![
+    ///     ("input_ids", &input_ids),
+    ///     ("position_ids", &position_ids),
+    ///     ("attention_mask", &attention_mask),
+    /// ];
+    /// inputs.extend(past_names.iter().map(String::as_str).zip(&past));
+    ///
+    /// // The batch and sequence dimensions of logits are free, so onnxruntime allocates it
+    /// let outputs = session.run_named(&inputs, Some(&["logits"])).unwrap();
+    /// let logits = outputs["logits"].data.as_f32_array().unwrap();
+    /// ```
+    pub fn run_named(
+        &mut self,
+        inputs: &[(&str, &Tensor)],
+        output_names: Option<&[&str]>,
+    ) -> Result<HashMap<String, Tensor>> {
+        run_named_session(self, inputs, output_names, None)
+    }
+
+    /// [`run_named`](Session::run_named) with options, like the run tag and the log level of this run
+    pub fn run_named_with_options(
+        &mut self,
+        inputs: &[(&str, &Tensor)],
+        output_names: Option<&[&str]>,
+        run_options: &RunOptions,
+    ) -> Result<HashMap<String, Tensor>> {
+        run_named_session(self, inputs, output_names, Some(run_options))
+    }
+
+    /// [`run_named`](Session::run_named) with outputs allocated by onnxruntime, whose shapes can depend
+    /// on the inputs
+    pub fn run_named_allocated(
+        &mut self,
+        inputs: &[(&str, &Tensor)],
+        output_names: Option<&[&str]>,
+    ) -> Result<HashMap<String, OrtOwnedTensor>> {
+        let output_info = check_named_run(self, inputs, output_names, None)?;
+        let mut outputs_ptr: Vec<*mut OrtValue> = vec![null_mut(); output_info.len()];
+        let (input_names, inputs): (Vec<&str>, Vec<&Tensor>) = inputs.iter().copied().unzip();
+        run_values(
+            self,
+            &input_names,
+            &inputs,
+            output_info.iter().map(|info| info.name.as_str()),
+            &mut outputs_ptr,
+            None,
+        )?;
+        let outputs = OrtOwnedTensor::from_values(&self.runtime, outputs_ptr)?;
+        Ok(output_info
+            .iter()
+            .map(|info| info.name.clone())
+            .zip(outputs)
+            .collect())
+    }
+
+    /// Start time of profiling in nanoseconds since the Unix epoch
+    pub fn profiling_start_time_ns(&self) -> Result<u64> {
+        let mut start_time_ns = 0;
+        let status = call_ort!(
+            self.runtime => SessionGetProfilingStartTimeNs,
+            self.session_ptr,
+            &mut start_time_ns
+        )?;
+        check_status(&self.runtime, status, "SessionGetProfilingStartTimeNs")?;
+        Ok(start_time_ns)
+    }
+
+    /// Stop profiling, returning the path of the Chrome trace written by onnxruntime
+    pub fn end_profiling_file(&mut self) -> Result<PathBuf> {
+        let mut path_ptr: *mut c_char = null_mut();
+        let status = call_ort!(
+            self.runtime => SessionEndProfiling,
+            self.session_ptr,
+            self.allocator.ptr,
+            &mut path_ptr
+        )?;
+        check_status(&self.runtime, status, "SessionEndProfiling")?;
+        let path = unsafe { CStr::from_ptr(path_ptr) }
+            .to_string_lossy()
+            .to_string();
+        free_name(&self.runtime, self.allocator.ptr, path_ptr)?;
+        Ok(PathBuf::from(path))
+    }
+
+    /// Stop profiling and parse the profile written by onnxruntime
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use ors::session::SessionBuilder;
+    ///
+    /// let mut session = SessionBuilder::new()
+    ///     .unwrap()
+    ///     .enable_profiling("gpt2")
+    ///     .unwrap()
+    ///     .build_with_model_from_file("gpt2.onnx")
+    ///     .unwrap();
+    /// // Run the session
+    /// let report = session.end_profiling().unwrap().report();
+    /// for op_type in report.op_types.iter().take(5) {
+    ///     println!("{}: {:?} in {} calls", op_type.name, op_type.total, op_type.count);
+    /// }
+    /// ```
+    #[cfg(feature = "profiling")]
+    pub fn end_profiling(&mut self) -> Result<Profile> {
+        let start_time_ns = self.profiling_start_time_ns()?;
+        let path = self.end_profiling_file()?;
+        Profile::from_file(path, start_time_ns)
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        if !self.session_ptr.is_null() {
+            if let Err(e) = call_ort!(self.runtime => ReleaseSession, self.session_ptr) {
+                warn!("Failed to release session: {}", e);
+            }
+        }
+        self.session_ptr = null_mut();
+    }
+}
+
+pub fn run(session: &mut Session, inputs: &[Tensor], outputs: &mut [Tensor]) -> Result<()> {
+    run_session(session, inputs, &[], outputs, None)
+}
+
+/// Run the session with options, like the run tag and the log level of this run
+pub fn run_with_options(
+    session: &mut Session,
+    inputs: &[Tensor],
+    outputs: &mut [Tensor],
+    run_options: &RunOptions,
+) -> Result<()> {
+    run_session(session, inputs, &[], outputs, Some(run_options))
+}
+
+/// Run the session with initializers overridden by name for this run
+///
+/// The names must be in [`Session::overridable_initializers`].
+///
+/// ## Example
+/// ```no_run
+/// # use ndarray::{ArrayD, IxDyn};
+/// # use ors::session::{run_with_initializers, SessionBuilder};
+/// # use ors::tensor::create_tensor_with_ndarray;
+/// let mut session = SessionBuilder::new()
+///     .unwrap()
+///     .build_with_model_from_file("model.onnx")
+///     .unwrap();
+/// let x = create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[1, 4]))).unwrap();
+/// let weight = create_tensor_with_ndarray(ArrayD::<f32>::ones(IxDyn(&[4, 4]))).unwrap();
+/// let mut outputs = vec![create_tensor_with_ndarray(ArrayD::<f32>::zeros(IxDyn(&[1, 4]))).unwrap()];
+/// run_with_initializers(&mut session, &[x], &[("weight", &weight)], &mut outputs, None).unwrap();
+/// ```
+pub fn run_with_initializers(
+    session: &mut Session,
+    inputs: &[Tensor],
+    initializers: &[(&str, &Tensor)],
+    outputs: &mut [Tensor],
+    run_options: Option<&RunOptions>,
+) -> Result<()> {
+    run_session(session, inputs, initializers, outputs, run_options)
+}
+
+fn run_session(
+    session: &mut Session,
+    inputs: &[Tensor],
+    initializers: &[(&str, &Tensor)],
+    outputs: &mut [Tensor],
+    run_options: Option<&RunOptions>,
+) -> Result<()> {
+    // Values created by another onnxruntime cannot be used in this session
+    if inputs
         .iter()
-        .map(|n| CString::new(n.name.clone()).unwrap())
-        .map(|n| n.into_raw() as *const i8)
-        .collect();
+        .chain(initializers.iter().map(|(_, t)| *t))
+        .chain(outputs.iter())
+        .any(|t| !t.runtime.same_runtime(&session.runtime))
+        || run_options.is_some_and(|o| !o.runtime.same_runtime(&session.runtime))
+    {
+        return Err(Error::RuntimeMismatch);
+    }
+    check_inputs(&session.input_info, inputs)?;
+    check_initializers(&session.initializer_info, initializers)?;
 
-    let output_names_cstring: Vec<CString> = session
+    // Overridden initializers are passed as inputs after the inputs of the model
+    let mut input_names: Vec<&str> = session.input_info.iter().map(|n| n.name.as_str()).collect();
+    input_names.extend(initializers.iter().map(|(name, _)| *name));
+    let mut input_values: Vec<&Tensor> = inputs.iter().collect();
+    input_values.extend(initializers.iter().map(|(_, t)| *t));
+    // onnxruntime writes an output per name, so only the outputs given are fetched
+    let output_names = session
         .output_info
         .iter()
-        .map(|n| CString::new(n.name.clone()).unwrap())
+        .map(|n| n.name.as_str())
+        .take(outputs.len());
+    let mut outputs_ptr: Vec<*mut OrtValue> = outputs.iter().map(|o| o.ptr).collect();
+    run_values(
+        session,
+        &input_names,
+        &input_values,
+        output_names,
+        &mut outputs_ptr,
+        run_options,
+    )
+}
+
+/// Run the session with values addressed by name, `input_names` and `inputs` are in the same order
+fn run_values<'a>(
+    session: &Session,
+    input_names: &[&str],
+    inputs: &[&Tensor],
+    output_names: impl Iterator<Item = &'a str>,
+    outputs_ptr: &mut [*mut OrtValue],
+    run_options: Option<&RunOptions>,
+) -> Result<()> {
+    // Messages logged by onnxruntime during the run are put in the spans of the session and the run
+    let _session_span = session.log_span.as_ref().map(|s| s.span().enter());
+    let run_span = run_options
+        .and_then(|o| o.tag.as_deref())
+        .map(LogIdSpan::run);
+    let _run_span = run_span.as_ref().map(|s| s.span().enter());
+
+    debug_assert_eq!(input_names.len(), inputs.len());
+    let input_names_cstring: Vec<CString> = input_names
+        .iter()
+        .map(|name| CString::new(*name))
+        .collect::<std::result::Result<_, _>>()?;
+    let input_names_ptr: Vec<*const c_char> =
+        input_names_cstring.iter().map(|n| n.as_ptr()).collect();
+    let inputs_ptr: Vec<*const OrtValue> =
+        inputs.iter().map(|i| (i.ptr) as *const OrtValue).collect();
+
+    let output_names_cstring: Vec<CString> = output_names
+        .map(CString::new)
+        .collect::<std::result::Result<_, _>>()?;
+    let output_names_ptr: Vec<*const c_char> =
+        output_names_cstring.iter().map(|n| n.as_ptr()).collect();
+
+    let status = call_ort!(
+        session.runtime => Run,
+        session.session_ptr,
+        run_options.map_or(null(), |o| o.ptr as *const OrtRunOptions),
+        input_names_ptr.as_ptr(),
+        inputs_ptr.as_ptr(),
+        inputs_ptr.len(),
+        output_names_ptr.as_ptr(),
+        output_names_ptr.len(),
+        outputs_ptr.as_mut_ptr()
+    )?;
+    check_status(&session.runtime, status, "Run").map_err(|e| e.in_session(session.name.as_deref()))
+}
+
+/// Run the session with outputs allocated by onnxruntime, whose shapes can depend on the inputs,
+/// like the outputs of `NonZero`
+///
+/// Inputs are in the order of [`Session::input_info`], and all outputs are returned in the order of
+/// [`Session::output_info`].
+pub fn run_allocated(session: &mut Session, inputs: &[Tensor]) -> Result<Vec<OrtOwnedTensor>> {
+    if inputs
+        .iter()
+        .any(|t| !t.runtime.same_runtime(&session.runtime))
+    {
+        return Err(Error::RuntimeMismatch);
+    }
+    check_inputs(&session.input_info, inputs)?;
+    // Null values are allocated by onnxruntime
+    let mut outputs_ptr: Vec<*mut OrtValue> = vec![null_mut(); session.output_info.len()];
+    let input_names: Vec<&str> = session
+        .input_info
+        .iter()
+        .map(|info| info.name.as_str())
         .collect();
+    let inputs: Vec<&Tensor> = inputs.iter().collect();
+    run_values(
+        session,
+        &input_names,
+        &inputs,
+        session.output_info.iter().map(|info| info.name.as_str()),
+        &mut outputs_ptr,
+        None,
+    )?;
+    OrtOwnedTensor::from_values(&session.runtime, outputs_ptr)
+}
+
+fn run_named_session(
+    session: &mut Session,
+    inputs: &[(&str, &Tensor)],
+    output_names: Option<&[&str]>,
+    run_options: Option<&RunOptions>,
+) -> Result<HashMap<String, Tensor>> {
+    let output_info = check_named_run(session, inputs, output_names, run_options)?;
 
-    let output_names_ptr: Vec<*const i8> = output_names_cstring
+    // Outputs with free dimensions are allocated by onnxruntime and copied after the run
+    let mut outputs: Vec<Option<Tensor>> = Vec::with_capacity(output_info.len());
+    for info in &output_info {
+        let shape = info
+            .output_dim
+            .iter()
+            .map(|d| d.map(|d| d as usize))
+            .collect::<Option<Vec<usize>>>();
+        outputs.push(match shape {
+            Some(shape) => Some(create_zeroed_tensor(
+                &session.runtime,
+                info.output_type,
+                &shape,
+            )?),
+            None => None,
+        });
+    }
+    let mut outputs_ptr: Vec<*mut OrtValue> = outputs
+        .iter()
+        .map(|o| o.as_ref().map_or(null_mut(), |o| o.ptr))
+        .collect();
+    let (input_names, inputs): (Vec<&str>, Vec<&Tensor>) = inputs.iter().copied().unzip();
+    run_values(
+        session,
+        &input_names,
+        &inputs,
+        output_info.iter().map(|info| info.name.as_str()),
+        &mut outputs_ptr,
+        run_options,
+    )?;
+    let allocated = outputs
         .iter()
-        .map(|n| n.as_ptr() as *const i8)
+        .zip(&outputs_ptr)
+        .filter(|(output, _)| output.is_none())
+        .map(|(_, ptr)| *ptr)
         .collect();
+    let mut allocated = OrtOwnedTensor::from_values(&session.runtime, allocated)?.into_iter();
+    output_info
+        .iter()
+        .zip(outputs)
+        .map(|(info, output)| {
+            let output = match output {
+                Some(output) => output,
+                None => allocated.next().unwrap().to_tensor()?,
+            };
+            Ok((info.name.clone(), output))
+        })
+        .collect()
+}
 
-    let inputs_ptr: Vec<*const OrtValue> =
-        inputs.iter().map(|i| (i.ptr) as *const OrtValue).collect();
-    let mut outputs_ptr: Vec<*mut OrtValue> = outputs.iter().map(|o| o.ptr).collect();
+/// Check a run by name before running the session, and find the outputs to fetch
+fn check_named_run<'a>(
+    session: &'a Session,
+    inputs: &[(&str, &Tensor)],
+    output_names: Option<&[&str]>,
+    run_options: Option<&RunOptions>,
+) -> Result<Vec<&'a SessionOutputInfo>> {
+    if inputs
+        .iter()
+        .any(|(_, t)| !t.runtime.same_runtime(&session.runtime))
+        || run_options.is_some_and(|o| !o.runtime.same_runtime(&session.runtime))
+    {
+        return Err(Error::RuntimeMismatch);
+    }
+    check_named_inputs(session, inputs)?;
+    named_outputs(&session.output_info, output_names)
+}
 
-    let status = unsafe {
-        get_api().Run.unwrap()(
-            session.session_ptr,
-            null(),
-            input_names_ptr.as_ptr(),
-            inputs_ptr.as_ptr(),
-            inputs.len(),
-            output_names_ptr.as_ptr(),
-            output_names_ptr.len(),
-            outputs_ptr.as_mut_ptr(),
-        )
+/// Check inputs given by name against the inputs and overridable initializers of the model
+fn check_named_inputs(session: &Session, inputs: &[(&str, &Tensor)]) -> Result<()> {
+    for (i, (name, input)) in inputs.iter().enumerate() {
+        if inputs[..i].iter().any(|(other, _)| other == name) {
+            return Err(Error::DuplicateInput {
+                input: name.to_string(),
+            });
+        }
+        if let Some(info) = session.input_info.iter().find(|info| info.name == *name) {
+            check_tensor(name, info.input_type, &info.input_dim, input)?;
+        } else if let Some(info) = session
+            .initializer_info
+            .iter()
+            .find(|info| info.name == *name)
+        {
+            check_tensor(name, info.initializer_type, &info.initializer_dim, input)?;
+        } else {
+            return Err(Error::UnknownInput {
+                input: name.to_string(),
+            });
+        }
+    }
+    if let Some(missing) = session
+        .input_info
+        .iter()
+        .find(|info| !inputs.iter().any(|(name, _)| *name == info.name))
+    {
+        return Err(Error::MissingInput {
+            input: missing.name.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Find the outputs to fetch, all outputs of the model if no names are given
+fn named_outputs<'a>(
+    output_info: &'a [SessionOutputInfo],
+    output_names: Option<&[&str]>,
+) -> Result<Vec<&'a SessionOutputInfo>> {
+    let output_names = match output_names {
+        Some(output_names) => output_names,
+        None => return Ok(output_info.iter().collect()),
     };
-    check_status(status)?;
+    let mut outputs = Vec::with_capacity(output_names.len());
+    for (i, name) in output_names.iter().enumerate() {
+        if output_names[..i].contains(name) {
+            return Err(Error::DuplicateOutput {
+                output: name.to_string(),
+            });
+        }
+        let info = output_info
+            .iter()
+            .find(|info| info.name == *name)
+            .ok_or_else(|| Error::UnknownOutput {
+                output: name.to_string(),
+            })?;
+        outputs.push(info);
+    }
+    Ok(outputs)
+}
+
+/// Check inputs against the model, so that wrong requests are reported before running the session
+fn check_inputs(input_info: &[SessionInputInfo], inputs: &[Tensor]) -> Result<()> {
+    if let Some(missing) = input_info.get(inputs.len()) {
+        return Err(Error::MissingInput {
+            input: missing.name.clone(),
+        });
+    }
+    if inputs.len() > input_info.len() {
+        return Err(Error::InputCountMismatch {
+            expected: input_info.len(),
+            actual: inputs.len(),
+        });
+    }
+    for (info, input) in input_info.iter().zip(inputs) {
+        check_tensor(&info.name, info.input_type, &info.input_dim, input)?;
+    }
+    Ok(())
+}
+
+/// Check overridden initializers against the model
+fn check_initializers(
+    initializer_info: &[SessionInitializerInfo],
+    initializers: &[(&str, &Tensor)],
+) -> Result<()> {
+    for (name, initializer) in initializers {
+        let info = initializer_info
+            .iter()
+            .find(|info| info.name == *name)
+            .ok_or_else(|| Error::UnknownInitializer {
+                name: name.to_string(),
+            })?;
+        check_tensor(
+            name,
+            info.initializer_type,
+            &info.initializer_dim,
+            initializer,
+        )?;
+    }
+    Ok(())
+}
+
+fn check_tensor(
+    name: &str,
+    expected_type: ONNXTensorElementDataType,
+    expected_dim: &[Option<i64>],
+    tensor: &Tensor,
+) -> Result<()> {
+    let data_type = tensor.data.element_type();
+    if data_type != expected_type {
+        return Err(Error::DataTypeMismatch {
+            input: name.to_string(),
+            expected: expected_type,
+            actual: data_type,
+        });
+    }
+    let shape: Vec<i64> = tensor.data.shape().iter().map(|d| *d as i64).collect();
+    let shape_matches = shape.len() == expected_dim.len()
+        && shape
+            .iter()
+            .zip(expected_dim)
+            .all(|(actual, expected)| expected.is_none() || *expected == Some(*actual));
+    if !shape_matches {
+        return Err(Error::ShapeMismatch {
+            input: name.to_string(),
+            expected: expected_dim.to_vec(),
+            actual: shape,
+        });
+    }
     Ok(())
 }
 
 pub struct SessionBuilder {
+    runtime: Runtime,
     session_options_ptr: *mut OrtSessionOptions,
+    thread_creator: Option<ThreadCreator>,
+    log_id: Option<String>,
+    dim_overrides: DimOverrides,
+    custom_ops: CustomOps,
+    initializers: Vec<Rc<Tensor>>,
+    external_initializers: Option<Rc<ExternalInitializers>>,
+    model_data: Option<ModelData>,
+    prepacked_weights: Option<PrepackedWeights>,
+    // Providers are appended when the session is created, so that it can be created again without them
+    providers: Vec<ExecutionProvider>,
+    cpu_fallback: bool,
+}
+
+/// The model of a session
+#[derive(Debug, Clone, Copy)]
+pub enum ModelSource<'a> {
+    /// Path of an ONNX or ORT model file
+    File(&'a Path),
+    /// Bytes of a model in memory
+    Bytes(&'a [u8]),
 }
 
 impl SessionBuilder {
+    /// Create a `SessionBuilder` whose sessions are created in the default runtime
     pub fn new() -> Result<Self> {
-        let allocator_type = OrtAllocatorType_OrtArenaAllocator;
-        let mem_type = OrtMemType_OrtMemTypeDefault;
+        Self::with_runtime(&default_runtime()?)
+    }
+
+    /// Create a `SessionBuilder` whose sessions are created in the given runtime
+    pub fn with_runtime(runtime: &Runtime) -> Result<Self> {
         let mut session_options_ptr: *mut OrtSessionOptions = null_mut();
-        let status = call_ort!(CreateSessionOptions, &mut session_options_ptr);
-        check_status(status)?;
+        let status = call_ort!(runtime => CreateSessionOptions, &mut session_options_ptr)?;
+        check_status(runtime, status, "CreateSessionOptions")?;
 
         Ok(SessionBuilder {
+            runtime: runtime.clone(),
             session_options_ptr,
+            thread_creator: None,
+            log_id: None,
+            dim_overrides: DimOverrides::default(),
+            custom_ops: CustomOps::default(),
+            initializers: vec![],
+            external_initializers: None,
+            model_data: None,
+            prepacked_weights: None,
+            providers: vec![],
+            cpu_fallback: false,
+        })
+    }
+
+    /// Create a `SessionBuilder` in the default runtime with the options of a [`SessionConfig`]
+    ///
+    /// The options are built for a single session,
+    /// use [`SessionTemplate`] to create many sessions without building them again.
+    pub fn from_config(config: &SessionConfig) -> Result<Self> {
+        Self::from_config_with_runtime(&default_runtime()?, config)
+    }
+
+    /// Create a `SessionBuilder` in the given runtime with the options of a [`SessionConfig`]
+    ///
+    /// See [`SessionBuilder::from_config`]
+    pub fn from_config_with_runtime(runtime: &Runtime, config: &SessionConfig) -> Result<Self> {
+        config.validate()?;
+        let mut builder = Self::with_runtime(runtime)?;
+        if let Some(execution_mode) = config.execution_mode {
+            builder = builder.execution_mode(execution_mode)?;
+        }
+        if let Some(opt_level) = config.graph_optimization_level {
+            builder = builder.graph_optimization_level(opt_level)?;
+        }
+        if let Some(num_threads) = config.intra_op_num_threads {
+            builder = builder.intra_number_threads(num_threads)?;
+        }
+        if let Some(num_threads) = config.inter_op_num_threads {
+            builder = builder.inter_number_threads(num_threads)?;
+        }
+        if let Some(enabled) = config.cpu_mem_arena {
+            builder = builder.cpu_mem_arena_enabled(enabled)?;
+        }
+        if let Some(enabled) = config.mem_pattern {
+            builder = builder.mem_pattern_enabled(enabled)?;
+        }
+        for (key, value) in &config.config_entries {
+            builder = builder.config_entry(key, value)?;
+        }
+        let providers = config
+            .providers
+            .iter()
+            .map(ProviderConfig::execution_provider)
+            .collect::<Result<Vec<_>>>()?;
+        builder = builder.execution_providers(&providers)?;
+        if let Some(enabled) = config.cpu_fallback {
+            builder = builder.cpu_fallback_enabled(enabled)?;
+        }
+        Ok(builder)
+    }
+
+    /// Copy the options into a new builder by `CloneSessionOptions`
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut session_options_ptr: *mut OrtSessionOptions = null_mut();
+        let status = call_ort!(
+            self.runtime => CloneSessionOptions,
+            self.session_options_ptr,
+            &mut session_options_ptr
+        )?;
+        check_status(&self.runtime, status, "CloneSessionOptions")?;
+        Ok(SessionBuilder {
+            runtime: self.runtime.clone(),
+            session_options_ptr,
+            thread_creator: self.thread_creator.clone(),
+            log_id: self.log_id.clone(),
+            dim_overrides: self.dim_overrides.clone(),
+            custom_ops: self.custom_ops.clone(),
+            initializers: self.initializers.clone(),
+            external_initializers: self.external_initializers.clone(),
+            model_data: self.model_data.clone(),
+            prepacked_weights: self.prepacked_weights.clone(),
+            providers: self.providers.clone(),
+            cpu_fallback: self.cpu_fallback,
         })
     }
 
@@ -88,50 +727,10 @@ impl SessionBuilder {
         P: AsRef<Path>,
     {
         let filepath = model_filepath.as_ref();
-        let mut session_ptr: *mut OrtSession = null_mut();
         if !filepath.exists() {
-            return Err(anyhow!(
-                "Model doesn't exist at {}",
-                filepath.to_string_lossy()
-            ));
+            return Err(Error::ModelNotFound(filepath.to_path_buf()));
         }
-
-        // Build an OsString than a vector of bytes to pass to C
-        let model_path = OsString::from(filepath);
-        #[cfg(target_family = "windows")]
-        let model_path: Vec<u16> = model_path
-            .encode_wide()
-            .chain(std::iter::once(0)) // Make sure we have a null terminated string
-            .collect();
-        #[cfg(not(target_family = "windows"))]
-        let model_path: Vec<std::os::raw::c_char> = model_path
-            .as_bytes()
-            .iter()
-            .chain(std::iter::once(&b'\0')) // Make sure we have a null terminated string
-            .map(|b| *b as std::os::raw::c_char)
-            .collect();
-
-        let status = call_ort!(
-            CreateSession,
-            get_env_ptr(),
-            model_path.as_ptr(),
-            self.session_options_ptr,
-            &mut session_ptr
-        );
-        check_status(status)?;
-
-        let allocator = get_default_allocator()?;
-        let mem_info = get_allocator_mem_info(allocator)?;
-
-        let input_info = get_session_inputs(session_ptr, allocator)?;
-        let output_info = get_session_outputs(session_ptr, allocator)?;
-        Ok(Session {
-            session_ptr,
-            allocator,
-            mem_info,
-            input_info,
-            output_info,
-        })
+        self.build(&ModelSource::File(filepath))
     }
 
     pub fn build_with_model_in_memory<B>(self, model_bytes: B) -> Result<Session>
@@ -142,44 +741,576 @@ impl SessionBuilder {
     }
 
     pub fn create_session_with_model_bytes(self, model_bytes: &[u8]) -> Result<Session> {
-        let mut session_ptr: *mut OrtSession = null_mut();
+        self.build(&ModelSource::Bytes(model_bytes))
+    }
 
-        let model = model_bytes.as_ptr() as *const c_void;
-        let model_length = model_bytes.len();
-        let status = call_ort!(
-            CreateSessionFromArray,
-            get_env_ptr(),
-            model,
-            model_length,
+    /// Create the session from the bytes of a model whose initializers are stored in external data files
+    ///
+    /// `external_data` maps the locations of the files in the model, like `weights.bin`, to their data.
+    /// onnxruntime uses the initializers without copying them, so the data is kept alive until the
+    /// session is released.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use ors::model::ModelData;
+    /// use ors::session::SessionBuilder;
+    ///
+    /// # let (graph, weights) = (vec![], vec![]);
+    /// let mut external_data = HashMap::new();
+    /// external_data.insert("weights.bin".to_string(), ModelData::new(weights));
+    /// let session = SessionBuilder::new()
+    ///     .unwrap()
+    ///     .build_with_model_and_external_data(graph, &external_data)
+    ///     .unwrap();
+    /// ```
+    pub fn build_with_model_and_external_data<B>(
+        mut self,
+        model_bytes: B,
+        external_data: &HashMap<String, ModelData>,
+    ) -> Result<Session>
+    where
+        B: AsRef<[u8]>,
+    {
+        let model_bytes = model_bytes.as_ref();
+        self.add_external_initializers(model_bytes, external_data)?;
+        self.build(&ModelSource::Bytes(model_bytes))
+    }
+
+    /// Create the session from a memory map of the model file, without reading the model into the heap
+    ///
+    /// External data files of the model are mapped from the directory of the model too.
+    ///
+    /// # Safety
+    /// The files must not be modified or truncated until the session is released.
+    pub unsafe fn build_with_model_from_mmap<P>(mut self, model_filepath: P) -> Result<Session>
+    where
+        P: AsRef<Path>,
+    {
+        let filepath = model_filepath.as_ref();
+        if !filepath.exists() {
+            return Err(Error::ModelNotFound(filepath.to_path_buf()));
+        }
+        let model = ModelData::map_file(filepath)?;
+        let external_data = map_external_data(filepath, model.as_bytes())?;
+        self.add_external_initializers(model.as_bytes(), &external_data)?;
+        self.model_data = Some(model.clone());
+        self.build(&ModelSource::Bytes(model.as_bytes()))
+    }
+
+    /// Create the session with a container of prepacked weights shared with other sessions of the model
+    ///
+    /// The container is kept alive until the session is released.
+    pub fn build_with_prepacked_weights(
+        mut self,
+        prepacked_weights: &PrepackedWeights,
+        model: ModelSource,
+    ) -> Result<Session> {
+        if !prepacked_weights.runtime().same_runtime(&self.runtime) {
+            return Err(Error::RuntimeMismatch);
+        }
+        if let ModelSource::File(filepath) = model {
+            if !filepath.exists() {
+                return Err(Error::ModelNotFound(filepath.to_path_buf()));
+            }
+        }
+        self.prepacked_weights = Some(prepacked_weights.clone());
+        self.build(&model)
+    }
+
+    fn add_external_initializers(
+        &mut self,
+        model_bytes: &[u8],
+        external_data: &HashMap<String, ModelData>,
+    ) -> Result<()> {
+        let initializers = ExternalInitializers::add(
+            &self.runtime,
             self.session_options_ptr,
-            &mut session_ptr
-        );
-        check_status(status)?;
+            model_bytes,
+            external_data,
+        )?;
+        self.external_initializers = Some(Rc::new(initializers));
+        Ok(())
+    }
+
+    /// Append the execution providers and create the session, falling back to the CPU provider if it's enabled
+    fn build(self, model: &ModelSource) -> Result<Session> {
+        let needs_fallback = self.cpu_fallback
+            && self
+                .providers
+                .iter()
+                .any(|p| !matches!(p, ExecutionProvider::Cpu(_)));
+        if !needs_fallback {
+            return self.append_providers()?.create_session(model);
+        }
+        let fallback = self.try_clone()?;
+        match self.append_providers()?.create_session(model) {
+            Err(e @ Error::Ort { .. }) if !e.is_bad_request() => {
+                warn!(
+                    "Failed to create the session with execution providers, falling back to CPU: {}",
+                    e
+                );
+                let mut fallback = fallback;
+                fallback
+                    .providers
+                    .retain(|p| matches!(p, ExecutionProvider::Cpu(_)));
+                fallback.append_providers()?.create_session(model)
+            }
+            result => result,
+        }
+    }
+
+    /// Append the execution providers in priority order, providers which fail are skipped if CPU fallback is enabled
+    fn append_providers(mut self) -> Result<SessionBuilder> {
+        for provider in std::mem::take(&mut self.providers) {
+            if let ExecutionProvider::Cpu(options) = &provider {
+                if let Some(use_arena) = ExecutionProvider::cpu_arena_option(options)? {
+                    self = self.cpu_mem_arena_enabled(use_arena)?;
+                }
+                continue;
+            }
+            let options: Vec<(&str, &str)> = provider
+                .options()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect();
+            let result = provider
+                .check_available(&self.runtime)
+                .and_then(|()| self.append_execution_provider(provider.name(), &options));
+            match result {
+                Ok(()) => {}
+                Err(e) if self.cpu_fallback => {
+                    warn!("Skipping execution provider {}: {}", provider.name(), e)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self)
+    }
 
-        let allocator = get_default_allocator()?;
-        let mem_info = get_allocator_mem_info(allocator)?;
+    /// Create the session, errors of onnxruntime name it by its log id or the path of its model
+    fn create_session(&self, model: &ModelSource) -> Result<Session> {
+        let name = match (&self.log_id, model) {
+            (Some(log_id), _) => Some(log_id.clone()),
+            (None, ModelSource::File(filepath)) => Some(filepath.display().to_string()),
+            (None, ModelSource::Bytes(_)) => None,
+        };
+        self.create_named_session(model, name.clone())
+            .map_err(|e| e.in_session(name.as_deref()))
+    }
+
+    fn create_named_session(&self, model: &ModelSource, name: Option<String>) -> Result<Session> {
+        // Registered first, so that messages logged while creating the session are in its span
+        let log_span = self.log_id.as_deref().map(LogIdSpan::session);
+        let mut session_ptr: *mut OrtSession = null_mut();
+        match (model, &self.prepacked_weights) {
+            (ModelSource::File(filepath), None) => {
+                let model_path = ort_path(filepath);
+                let status = call_ort!(
+                    self.runtime => CreateSession,
+                    self.runtime.env_ptr(),
+                    model_path.as_ptr(),
+                    self.session_options_ptr,
+                    &mut session_ptr
+                )?;
+                check_status(&self.runtime, status, "CreateSession")?;
+            }
+            (ModelSource::Bytes(model_bytes), None) => {
+                let model = model_bytes.as_ptr() as *const c_void;
+                let model_length = model_bytes.len();
+                let status = call_ort!(
+                    self.runtime => CreateSessionFromArray,
+                    self.runtime.env_ptr(),
+                    model,
+                    model_length,
+                    self.session_options_ptr,
+                    &mut session_ptr
+                )?;
+                check_status(&self.runtime, status, "CreateSessionFromArray")?;
+            }
+            (ModelSource::File(filepath), Some(prepacked_weights)) => {
+                let model_path = ort_path(filepath);
+                let status = call_ort!(
+                    self.runtime => CreateSessionWithPrepackedWeightsContainer,
+                    self.runtime.env_ptr(),
+                    model_path.as_ptr(),
+                    self.session_options_ptr,
+                    prepacked_weights.ptr(),
+                    &mut session_ptr
+                )?;
+                check_status(
+                    &self.runtime,
+                    status,
+                    "CreateSessionWithPrepackedWeightsContainer",
+                )?;
+            }
+            (ModelSource::Bytes(model_bytes), Some(prepacked_weights)) => {
+                let model = model_bytes.as_ptr() as *const c_void;
+                let model_length = model_bytes.len();
+                let status = call_ort!(
+                    self.runtime => CreateSessionFromArrayWithPrepackedWeightsContainer,
+                    self.runtime.env_ptr(),
+                    model,
+                    model_length,
+                    self.session_options_ptr,
+                    prepacked_weights.ptr(),
+                    &mut session_ptr
+                )?;
+                check_status(
+                    &self.runtime,
+                    status,
+                    "CreateSessionFromArrayWithPrepackedWeightsContainer",
+                )?;
+            }
+        }
 
-        let input_info = get_session_inputs(session_ptr, allocator)?;
-        let output_info = get_session_outputs(session_ptr, allocator)?;
+        let allocator = Allocator::default_allocator(&self.runtime)?;
+        let mem_info = get_allocator_mem_info(&self.runtime, allocator.ptr)?;
+        let dim_overrides = self.model_dim_overrides(model);
+
+        let input_info =
+            get_session_inputs(&self.runtime, session_ptr, allocator.ptr, &dim_overrides)?;
+        let output_info =
+            get_session_outputs(&self.runtime, session_ptr, allocator.ptr, &dim_overrides)?;
+        let initializer_info =
+            get_session_initializers(&self.runtime, session_ptr, allocator.ptr, &dim_overrides)?;
         Ok(Session {
+            runtime: self.runtime.track(ObjectKind::Session),
             session_ptr,
+            name,
             allocator,
             mem_info,
             input_info,
             output_info,
+            initializer_info,
+            thread_creator: self.thread_creator.clone(),
+            log_span,
+            custom_ops: self.custom_ops.clone(),
+            initializers: self.initializers.clone(),
+            external_initializers: self.external_initializers.clone(),
+            model_data: self.model_data.clone(),
+            prepacked_weights: self.prepacked_weights.clone(),
         })
     }
 
+    /// The overrides of free dimensions, with the denotations of dimensions read from the model if they are needed
+    ///
+    /// Models which can't be read as ONNX, like ORT format models, have no denotations.
+    fn model_dim_overrides(&self, model: &ModelSource) -> DimOverrides {
+        if !self.dim_overrides.needs_denotations() {
+            return self.dim_overrides.clone();
+        }
+        let denotations = match model {
+            ModelSource::File(filepath) => std::fs::read(filepath)
+                .map_err(|e| Error::InvalidModel(e.to_string()))
+                .and_then(|bytes| dim_denotations(&bytes)),
+            ModelSource::Bytes(model_bytes) => dim_denotations(model_bytes),
+        };
+        match denotations {
+            Ok(denotations) => self.dim_overrides.with_denotations(denotations),
+            Err(e) => {
+                warn!(
+                    "Failed to read denotations of dimensions from the model: {}",
+                    e
+                );
+                self.dim_overrides.clone()
+            }
+        }
+    }
+
     /// Configure the session to use a number of threads
     pub fn intra_number_threads(self, num_threads: i32) -> Result<SessionBuilder> {
-        let status = call_ort!(SetIntraOpNumThreads, self.session_options_ptr, num_threads);
-        check_status(status)?;
+        let status =
+            call_ort!(self.runtime => SetIntraOpNumThreads, self.session_options_ptr, num_threads)?;
+        check_status(&self.runtime, status, "SetIntraOpNumThreads")?;
         Ok(self)
     }
 
     pub fn inter_number_threads(self, num_threads: i32) -> Result<SessionBuilder> {
-        let status = call_ort!(SetInterOpNumThreads, self.session_options_ptr, num_threads);
-        check_status(status)?;
+        let status =
+            call_ort!(self.runtime => SetInterOpNumThreads, self.session_options_ptr, num_threads)?;
+        check_status(&self.runtime, status, "SetInterOpNumThreads")?;
+        Ok(self)
+    }
+
+    /// Set a session configuration entry, the value is checked before it's passed to onnxruntime
+    pub fn config(self, key: SessionConfigKey, value: &str) -> Result<SessionBuilder> {
+        key.validate(value)?;
+        self.config_entry(key.key(), value)
+    }
+
+    /// Set a session configuration entry without checking it, for keys which are not in [`SessionConfigKey`]
+    pub fn config_entry(self, key: &str, value: &str) -> Result<SessionBuilder> {
+        let c_key = CString::new(key)?;
+        let c_value = CString::new(value)?;
+        let status = call_ort!(
+            self.runtime => AddSessionConfigEntry,
+            self.session_options_ptr,
+            c_key.as_ptr(),
+            c_value.as_ptr()
+        )?;
+        check_status(&self.runtime, status, "AddSessionConfigEntry")?;
+        Ok(self)
+    }
+
+    /// Profile sessions, writing Chrome traces to files named like `<prefix>_<date>.json`
+    ///
+    /// Profiling is stopped by [`Session::end_profiling`] or when the session is released.
+    pub fn enable_profiling<P: AsRef<Path>>(self, prefix: P) -> Result<SessionBuilder> {
+        let c_prefix = ort_path(prefix.as_ref());
+        let status = call_ort!(
+            self.runtime => EnableProfiling,
+            self.session_options_ptr,
+            c_prefix.as_ptr()
+        )?;
+        check_status(&self.runtime, status, "EnableProfiling")?;
+        Ok(self)
+    }
+
+    /// Fix free dimensions with the denotation, like `DATA_BATCH`, to the value
+    ///
+    /// Overridden dimensions are reported as `Some(value)` in the inputs and outputs info of sessions.
+    /// onnxruntime doesn't report denotations, so they are read from the model when the session is created.
+    pub fn free_dimension_override(
+        mut self,
+        denotation: &str,
+        value: i64,
+    ) -> Result<SessionBuilder> {
+        let c_denotation = CString::new(denotation)?;
+        let status = call_ort!(
+            self.runtime => AddFreeDimensionOverride,
+            self.session_options_ptr,
+            c_denotation.as_ptr(),
+            value
+        )?;
+        check_status(&self.runtime, status, "AddFreeDimensionOverride")?;
+        self.dim_overrides.insert_denotation(denotation, value);
+        Ok(self)
+    }
+
+    /// Fix free dimensions with the symbolic name, like `batch_size`, to the value
+    ///
+    /// Overridden dimensions are reported as `Some(value)` in the inputs and outputs info of sessions.
+    pub fn free_dimension_override_by_name(
+        mut self,
+        name: &str,
+        value: i64,
+    ) -> Result<SessionBuilder> {
+        let c_name = CString::new(name)?;
+        let status = call_ort!(
+            self.runtime => AddFreeDimensionOverrideByName,
+            self.session_options_ptr,
+            c_name.as_ptr(),
+            value
+        )?;
+        check_status(&self.runtime, status, "AddFreeDimensionOverrideByName")?;
+        self.dim_overrides.insert_name(name, value);
+        Ok(self)
+    }
+
+    /// Register a domain of ops implemented in Rust, which can be used by nodes of the model
+    ///
+    /// The domain is kept alive until the builder and all sessions built from it are released.
+    pub fn with_custom_op_domain(mut self, domain: CustomOpDomain) -> Result<SessionBuilder> {
+        self.custom_ops
+            .add_domain(&self.runtime, self.session_options_ptr, &domain)?;
+        Ok(self)
+    }
+
+    /// Use the tensor as the initializer of the name, instead of the weights in the model
+    ///
+    /// onnxruntime uses the buffer of the tensor without copying it, so the tensor is kept alive until
+    /// the builder and all sessions built from it are released.
+    pub fn add_initializer(mut self, name: &str, tensor: Tensor) -> Result<SessionBuilder> {
+        if !tensor.runtime.same_runtime(&self.runtime) {
+            return Err(Error::RuntimeMismatch);
+        }
+        let c_name = CString::new(name)?;
+        let status = call_ort!(
+            self.runtime => AddInitializer,
+            self.session_options_ptr,
+            c_name.as_ptr(),
+            tensor.ptr as *const OrtValue
+        )?;
+        check_status(&self.runtime, status, "AddInitializer")?;
+        self.initializers.push(Rc::new(tensor));
+        Ok(self)
+    }
+
+    /// Load a shared library of custom ops, like onnxruntime-extensions, which exports `RegisterCustomOps`
+    ///
+    /// The library is unloaded after the builder and all sessions built from it are released.
+    pub fn register_custom_ops_library<P: AsRef<Path>>(
+        mut self,
+        path: P,
+    ) -> Result<SessionBuilder> {
+        self.custom_ops
+            .add_library(&self.runtime, self.session_options_ptr, path.as_ref())?;
+        Ok(self)
+    }
+
+    /// Enable the custom ops of onnxruntime-extensions built into onnxruntime
+    pub fn enable_ort_custom_ops(self) -> Result<SessionBuilder> {
+        let status = call_ort!(self.runtime => EnableOrtCustomOps, self.session_options_ptr)?;
+        check_status(&self.runtime, status, "EnableOrtCustomOps")?;
+        Ok(self)
+    }
+
+    /// Save the model optimized by onnxruntime to the path when a session is created
+    ///
+    /// The format is ORT if the path ends with `.ort`, otherwise ONNX, unless
+    /// [`SessionConfigKey::SaveModelFormat`] is set. The saved model can be loaded later with
+    /// optimizations disabled, see [`ModelCache`](crate::cache::ModelCache).
+    pub fn save_optimized_model<P: AsRef<Path>>(self, path: P) -> Result<SessionBuilder> {
+        let optimized_path = ort_path(path.as_ref());
+        let status = call_ort!(
+            self.runtime => SetOptimizedModelFilePath,
+            self.session_options_ptr,
+            optimized_path.as_ptr()
+        )?;
+        check_status(&self.runtime, status, "SetOptimizedModelFilePath")?;
+        Ok(self)
+    }
+
+    /// Append an execution provider by its name, like `XNNPACK`, with provider specific options
+    pub fn execution_provider(
+        self,
+        name: &str,
+        options: &[(&str, &str)],
+    ) -> Result<SessionBuilder> {
+        self.append_execution_provider(name, options)?;
+        Ok(self)
+    }
+
+    /// Use execution providers in priority order, onnxruntime assigns each node to the first provider supporting it
+    ///
+    /// Providers are appended when the session is built, which fails with [`Error::ProviderUnavailable`] if a
+    /// provider is not compiled into the loaded onnxruntime, unless
+    /// [`cpu_fallback_enabled`](SessionBuilder::cpu_fallback_enabled). The CPU provider always runs the nodes
+    /// which other providers don't support.
+    pub fn execution_providers(
+        mut self,
+        providers: &[ExecutionProvider],
+    ) -> Result<SessionBuilder> {
+        for provider in providers {
+            if let ExecutionProvider::Cpu(options) = provider {
+                ExecutionProvider::cpu_arena_option(options)?;
+            }
+        }
+        self.providers.extend_from_slice(providers);
+        Ok(self)
+    }
+
+    /// Fall back to the CPU provider when a provider of [`execution_providers`](SessionBuilder::execution_providers)
+    /// is not available or fails to initialize
+    ///
+    /// Providers which are missing or can't be appended are skipped with a warning. If creating the session
+    /// fails with an onnxruntime error which is not caused by the model, it's created again with only the CPU provider.
+    pub fn cpu_fallback_enabled(mut self, enabled: bool) -> Result<SessionBuilder> {
+        self.cpu_fallback = enabled;
+        Ok(self)
+    }
+
+    fn append_execution_provider(&self, name: &str, options: &[(&str, &str)]) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let c_keys = options
+            .iter()
+            .map(|(k, _)| CString::new(*k))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let c_values = options
+            .iter()
+            .map(|(_, v)| CString::new(*v))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let key_ptrs: Vec<*const c_char> = c_keys.iter().map(|k| k.as_ptr()).collect();
+        let value_ptrs: Vec<*const c_char> = c_values.iter().map(|v| v.as_ptr()).collect();
+        let status = call_ort!(
+            self.runtime => SessionOptionsAppendExecutionProvider,
+            self.session_options_ptr,
+            c_name.as_ptr(),
+            key_ptrs.as_ptr(),
+            value_ptrs.as_ptr(),
+            options.len()
+        )?;
+        check_status(
+            &self.runtime,
+            status,
+            "SessionOptionsAppendExecutionProvider",
+        )
+    }
+
+    /// Set the log id of the session, messages of the session are logged in a `session` span with this id
+    pub fn log_id(mut self, log_id: &str) -> Result<SessionBuilder> {
+        let c_log_id = CString::new(log_id)?;
+        let status = call_ort!(self.runtime => SetSessionLogId, self.session_options_ptr, c_log_id.as_ptr())?;
+        check_status(&self.runtime, status, "SetSessionLogId")?;
+        self.log_id = Some(log_id.to_string());
+        Ok(self)
+    }
+
+    /// Set the minimal level of messages logged by the session
+    ///
+    /// Messages below the level of the environment are still dropped.
+    pub fn log_severity_level(self, level: LoggingLevel) -> Result<SessionBuilder> {
+        let level = OrtLoggingLevel::from(level) as i32;
+        let status =
+            call_ort!(self.runtime => SetSessionLogSeverityLevel, self.session_options_ptr, level)?;
+        check_status(&self.runtime, status, "SetSessionLogSeverityLevel")?;
+        Ok(self)
+    }
+
+    /// Set the verbosity of `Verbose` messages logged by the session, only used by debug builds of onnxruntime
+    pub fn log_verbosity_level(self, verbosity_level: i32) -> Result<SessionBuilder> {
+        let status = call_ort!(
+            self.runtime => SetSessionLogVerbosityLevel,
+            self.session_options_ptr,
+            verbosity_level
+        )?;
+        check_status(&self.runtime, status, "SetSessionLogVerbosityLevel")?;
+        Ok(self)
+    }
+
+    /// Spawn threads of the session's thread pools with the given creator instead of onnxruntime
+    pub fn custom_thread_creator(
+        mut self,
+        thread_creator: ThreadCreator,
+    ) -> Result<SessionBuilder> {
+        let status = call_ort!(
+            self.runtime => SessionOptionsSetCustomCreateThreadFn,
+            self.session_options_ptr,
+            Some(create_thread)
+        )?;
+        check_status(
+            &self.runtime,
+            status,
+            "SessionOptionsSetCustomCreateThreadFn",
+        )?;
+        let status = call_ort!(
+            self.runtime => SessionOptionsSetCustomThreadCreationOptions,
+            self.session_options_ptr,
+            thread_creator.options_ptr()
+        )?;
+        check_status(
+            &self.runtime,
+            status,
+            "SessionOptionsSetCustomThreadCreationOptions",
+        )?;
+        let status = call_ort!(
+            self.runtime => SessionOptionsSetCustomJoinThreadFn,
+            self.session_options_ptr,
+            Some(join_thread)
+        )?;
+        check_status(&self.runtime, status, "SessionOptionsSetCustomJoinThreadFn")?;
+        self.thread_creator = Some(thread_creator);
+        Ok(self)
+    }
+
+    /// Use the global thread pools of the environment instead of creating thread pools for the session
+    ///
+    /// The runtime must be built with [`EnvironmentBuilder::global_thread_pools`](crate::env::EnvironmentBuilder::global_thread_pools),
+    /// otherwise creating the session fails. Thread numbers set on the session are ignored.
+    pub fn disable_per_session_threads(self) -> Result<SessionBuilder> {
+        let status = call_ort!(self.runtime => DisablePerSessionThreads, self.session_options_ptr)?;
+        check_status(&self.runtime, status, "DisablePerSessionThreads")?;
         Ok(self)
     }
 
@@ -190,11 +1321,11 @@ impl SessionBuilder {
     ) -> Result<SessionBuilder> {
         // Sets graph optimization level
         let status = call_ort!(
-            SetSessionGraphOptimizationLevel,
+            self.runtime => SetSessionGraphOptimizationLevel,
             self.session_options_ptr,
             opt_level.into()
-        );
-        check_status(status)?;
+        )?;
+        check_status(&self.runtime, status, "SetSessionGraphOptimizationLevel")?;
         Ok(self)
     }
 
@@ -203,55 +1334,158 @@ impl SessionBuilder {
     /// Controls whether you want to execute operators in your graph sequentially or in parallel. Usually when the model has many branches, setting this option to ExecutionMode.ORT_PARALLEL will give you better performance. See [docs/ONNX_Runtime_Perf_Tuning.md] for more details.
     pub fn execution_mode(self, execution_mode: SessionExecutionMode) -> Result<SessionBuilder> {
         let status = call_ort!(
-            SetSessionExecutionMode,
+            self.runtime => SetSessionExecutionMode,
             self.session_options_ptr,
             execution_mode.into()
-        );
-        check_status(status)?;
+        )?;
+        check_status(&self.runtime, status, "SetSessionExecutionMode")?;
         Ok(self)
     }
 
-    /// Enable the memory arena on CPU
+    /// Enable or disable the memory arena on CPU
     ///
     /// Arena may pre-allocate memory for future usage
     pub fn cpu_mem_arena_enabled(self, cpu_mem_arena_enabled: bool) -> Result<SessionBuilder> {
         if cpu_mem_arena_enabled {
-            let status = call_ort!(EnableCpuMemArena, self.session_options_ptr);
-            check_status(status)?;
+            let status = call_ort!(self.runtime => EnableCpuMemArena, self.session_options_ptr)?;
+            check_status(&self.runtime, status, "EnableCpuMemArena")?;
+        } else {
+            let status = call_ort!(self.runtime => DisableCpuMemArena, self.session_options_ptr)?;
+            check_status(&self.runtime, status, "DisableCpuMemArena")?;
         }
         Ok(self)
     }
 
-    /// Enable the memory pattern optimization
+    /// Enable or disable the memory pattern optimization
     ///
     /// The idea is if the input shapes are the same, we could trace the internal memory allocation and generate a memory pattern for future request. So next time we could just do one allocation with a big chunk for all the internal memory allocation
     ///
     /// Note: Memory pattern optimization is only available when Sequential Execution mode is enabled
     pub fn mem_pattern_enabled(self, mem_pattern_enabled: bool) -> Result<SessionBuilder> {
         if mem_pattern_enabled {
-            let status = call_ort!(EnableMemPattern, self.session_options_ptr);
-            check_status(status)?;
+            let status = call_ort!(self.runtime => EnableMemPattern, self.session_options_ptr)?;
+            check_status(&self.runtime, status, "EnableMemPattern")?;
+        } else {
+            let status = call_ort!(self.runtime => DisableMemPattern, self.session_options_ptr)?;
+            check_status(&self.runtime, status, "DisableMemPattern")?;
         }
         Ok(self)
     }
 }
 
-pub(crate) fn get_default_allocator() -> Result<*mut OrtAllocator> {
+impl Drop for SessionBuilder {
+    fn drop(&mut self) {
+        if !self.session_options_ptr.is_null() {
+            if let Err(e) =
+                call_ort!(self.runtime => ReleaseSessionOptions, self.session_options_ptr)
+            {
+                warn!("Failed to release session options: {}", e);
+            }
+        }
+        self.session_options_ptr = null_mut();
+    }
+}
+
+/// Session options built once from a [`SessionConfig`], to create many sessions with the same options
+///
+/// Each [`builder`](SessionTemplate::builder) gets a copy of the options made by `CloneSessionOptions`,
+/// so the config is not validated and applied again for every session.
+/// Options added to a builder don't change the template.
+///
+/// ## Example
+/// ```no_run
+/// use ors::config::SessionConfig;
+/// use ors::session::SessionTemplate;
+///
+/// let config = SessionConfig {
+///     intra_op_num_threads: Some(2),
+///     ..Default::default()
+/// };
+/// let template = SessionTemplate::from_config(&config).unwrap();
+/// let sessions: Vec<_> = ["a.onnx", "b.onnx"]
+///     .iter()
+///     .map(|model| template.builder().unwrap().build_with_model_from_file(model))
+///     .collect();
+/// ```
+pub struct SessionTemplate {
+    config: SessionConfig,
+    options: SessionBuilder,
+}
+
+impl SessionTemplate {
+    /// Build the options of a [`SessionConfig`] in the default runtime
+    pub fn from_config(config: &SessionConfig) -> Result<Self> {
+        Self::from_config_with_runtime(&default_runtime()?, config)
+    }
+
+    /// Build the options of a [`SessionConfig`] in the given runtime
+    pub fn from_config_with_runtime(runtime: &Runtime, config: &SessionConfig) -> Result<Self> {
+        Ok(SessionTemplate {
+            config: config.clone(),
+            options: SessionBuilder::from_config_with_runtime(runtime, config)?,
+        })
+    }
+
+    /// The config which the options are built from
+    pub fn config(&self) -> &SessionConfig {
+        &self.config
+    }
+
+    /// The runtime whose sessions are created
+    pub fn runtime(&self) -> &Runtime {
+        &self.options.runtime
+    }
+
+    /// Create a `SessionBuilder` with a copy of the template's options
+    pub fn builder(&self) -> Result<SessionBuilder> {
+        self.options.try_clone()
+    }
+
+    /// Take the options of the template, for its last session
+    pub fn into_builder(self) -> SessionBuilder {
+        self.options
+    }
+}
+
+/// Null terminated path in the encoding of `ORTCHAR_T`
+#[cfg(target_family = "windows")]
+fn ort_path(path: &Path) -> Vec<u16> {
+    OsString::from(path)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+/// Null terminated path in the encoding of `ORTCHAR_T`
+#[cfg(not(target_family = "windows"))]
+fn ort_path(path: &Path) -> Vec<c_char> {
+    OsString::from(path)
+        .as_bytes()
+        .iter()
+        .chain(std::iter::once(&b'\0'))
+        .map(|b| *b as c_char)
+        .collect()
+}
+
+pub(crate) fn get_default_allocator(runtime: &Runtime) -> Result<*mut OrtAllocator> {
     let mut allocator_ptr = null_mut();
-    let status = call_ort!(GetAllocatorWithDefaultOptions, &mut allocator_ptr);
-    check_status(status)?;
+    let status = call_ort!(runtime => GetAllocatorWithDefaultOptions, &mut allocator_ptr)?;
+    check_status(runtime, status, "GetAllocatorWithDefaultOptions")?;
     Ok(allocator_ptr)
 }
 
-pub(crate) fn get_default_memory_info() -> Result<*mut OrtMemoryInfo> {
-    let allocator = get_default_allocator()?;
-    get_allocator_mem_info(allocator)
+pub(crate) fn get_default_memory_info(runtime: &Runtime) -> Result<*mut OrtMemoryInfo> {
+    let allocator = get_default_allocator(runtime)?;
+    get_allocator_mem_info(runtime, allocator)
 }
 
-pub(crate) fn get_allocator_mem_info(allocator: *const OrtAllocator) -> Result<*mut OrtMemoryInfo> {
+pub(crate) fn get_allocator_mem_info(
+    runtime: &Runtime,
+    allocator: *const OrtAllocator,
+) -> Result<*mut OrtMemoryInfo> {
     let mut mem_info_ptr = null();
-    let status = unsafe { get_api().AllocatorGetInfo.unwrap()(allocator, &mut mem_info_ptr) };
-    check_status(status)?;
+    let status = call_ort!(runtime => AllocatorGetInfo, allocator, &mut mem_info_ptr)?;
+    check_status(runtime, status, "AllocatorGetInfo")?;
     Ok(mem_info_ptr as *mut OrtMemoryInfo)
 }
 
@@ -260,7 +1494,13 @@ mod test {
     use std::time::SystemTime;
 
     use super::*;
-    use crate::{api::initialize_runtime, tensor::create_tensor_with_ndarray};
+    #[cfg(feature = "mock")]
+    use crate::mock::test_support::{sum_model, INT64};
+    #[cfg(feature = "mock")]
+    use crate::mock::{MockBackend, MockModel, MockTensor};
+    #[cfg(feature = "mock")]
+    use crate::model::encode;
+    use crate::{api::initialize_runtime_auto, tensor::create_tensor_with_ndarray};
     use ndarray::{ArrayD, IxDyn};
     use tracing::debug;
     use tracing_test::traced_test;
@@ -377,6 +1617,83 @@ mod test {
         assert_ne!(session2.session_ptr, null_mut());
     }
 
+    #[cfg(feature = "mock")]
+    #[test]
+    #[traced_test]
+    fn test_mock_check_inputs() {
+        // Only tensors are created, the mock runtime is enough
+        let backend = MockBackend::new();
+        let runtime = backend.runtime().unwrap();
+        let input_info = vec![
+            SessionInputInfo {
+                name: "input_ids".to_string(),
+                input_type: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64,
+                input_dim: vec![None, Some(2)],
+            },
+            SessionInputInfo {
+                name: "attention_mask".to_string(),
+                input_type: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+                input_dim: vec![None, Some(2)],
+            },
+        ];
+        let input_ids = runtime
+            .create_tensor_with_ndarray(
+                ArrayD::<i64>::from_shape_vec(IxDyn(&[3, 2]), vec![0; 6]).unwrap(),
+            )
+            .unwrap();
+        let err = check_inputs(&input_info, &[input_ids]).err().unwrap();
+        assert!(matches!(err, Error::MissingInput { input } if input == "attention_mask"));
+
+        let input_ids = runtime
+            .create_tensor_with_ndarray(
+                ArrayD::<i64>::from_shape_vec(IxDyn(&[3, 2]), vec![0; 6]).unwrap(),
+            )
+            .unwrap();
+        let wrong_type = runtime
+            .create_tensor_with_ndarray(
+                ArrayD::<i64>::from_shape_vec(IxDyn(&[3, 2]), vec![0; 6]).unwrap(),
+            )
+            .unwrap();
+        let err = check_inputs(&input_info, &[input_ids, wrong_type])
+            .err()
+            .unwrap();
+        assert_eq!(
+            "Data type mismatch of input attention_mask: expected float, got int64",
+            err.to_string()
+        );
+        assert!(matches!(err, Error::DataTypeMismatch { input, .. } if input == "attention_mask"));
+
+        let wrong_shape = runtime
+            .create_tensor_with_ndarray(
+                ArrayD::<i64>::from_shape_vec(IxDyn(&[2, 3]), vec![0; 6]).unwrap(),
+            )
+            .unwrap();
+        let err = check_inputs(&input_info[..1], &[wrong_shape])
+            .err()
+            .unwrap();
+        assert!(
+            matches!(err, Error::ShapeMismatch { input, actual, .. } if input == "input_ids" && actual == vec![2, 3])
+        );
+
+        let inputs: Vec<_> = (0..2)
+            .map(|_| {
+                runtime
+                    .create_tensor_with_ndarray(
+                        ArrayD::<i64>::from_shape_vec(IxDyn(&[3, 2]), vec![0; 6]).unwrap(),
+                    )
+                    .unwrap()
+            })
+            .collect();
+        let err = check_inputs(&input_info[..1], &inputs).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::InputCountMismatch {
+                expected: 1,
+                actual: 2
+            }
+        ));
+    }
+
     fn get_path() -> &'static str {
         #[cfg(target_family = "windows")]
         let path = "D:\\Projects\\Rust\\ors\\gpt2.onnx";
@@ -386,12 +1703,482 @@ mod test {
     }
 
     fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).unwrap();
+        initialize_runtime_auto().unwrap();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_mock_session_config() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+        let mut config = SessionConfig {
+            intra_op_num_threads: Some(2),
+            cpu_mem_arena: Some(false),
+            mem_pattern: Some(false),
+            providers: vec![ProviderConfig {
+                name: "XNNPACK".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        config.config_entries.insert(
+            SessionConfigKey::IntraOpAllowSpinning.key().to_string(),
+            "0".to_string(),
+        );
+        let template = SessionTemplate::from_config_with_runtime(&runtime, &config).unwrap();
+        assert_eq!(Some(2), template.config().intra_op_num_threads);
+        for _ in 0..2 {
+            template
+                .builder()
+                .unwrap()
+                .build_with_model_in_memory("sum")
+                .unwrap();
+        }
+        let calls = backend.calls();
+        let count = |function: &str| calls.iter().filter(|f| **f == function).count();
+        for function in [
+            "SetIntraOpNumThreads",
+            "DisableCpuMemArena",
+            "DisableMemPattern",
+            "AddSessionConfigEntry",
+        ] {
+            assert_eq!(1, count(function));
+        }
+        assert_eq!(2, count("CloneSessionOptions"));
+        // Providers are appended to each session like providers of `execution_providers`
+        assert_eq!(2, count("SessionOptionsAppendExecutionProvider"));
+
+        // Missing providers are checked, and skipped with the CPU fallback
+        let mut providers_config = SessionConfig {
+            providers: vec![
+                ProviderConfig {
+                    name: "SNPE".to_string(),
+                    ..Default::default()
+                },
+                ProviderConfig {
+                    name: "CPU".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        providers_config.providers[1]
+            .options
+            .insert("use_arena".to_string(), "0".to_string());
+        let err = SessionBuilder::from_config_with_runtime(&runtime, &providers_config)
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::ProviderUnavailable { .. }));
+        providers_config.cpu_fallback = Some(true);
+        backend.clear_calls();
+        SessionBuilder::from_config_with_runtime(&runtime, &providers_config)
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .unwrap();
+        let calls = backend.calls();
+        assert!(calls.contains(&"DisableCpuMemArena"));
+        assert!(!calls.contains(&"SessionOptionsAppendExecutionProvider"));
+
+        providers_config.providers[0].name = "CUDA".to_string();
+        let err = SessionBuilder::from_config_with_runtime(&runtime, &providers_config)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::UnknownProvider { .. }));
+
+        config
+            .config_entries
+            .insert("session.dynamic_block_base".to_string(), "-1".to_string());
+        let err = SessionBuilder::from_config_with_runtime(&runtime, &config)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::InvalidConfigValue { .. }));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_mock_free_dimension_overrides() {
+        let batch = || Some("DATA_BATCH".to_string());
+        let model_bytes = encode::model_with_denotations(
+            &[("ids", vec![batch(), None])],
+            &[("embeddings", vec![batch(), None, None])],
+        );
+        let backend = MockBackend::new();
+        // Mock sessions created from bytes look up models by the bytes as a string
+        backend.add_model(
+            &String::from_utf8_lossy(&model_bytes),
+            MockModel::new()
+                .input(
+                    "ids",
+                    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64,
+                    &[None, None],
+                )
+                .output(
+                    "embeddings",
+                    ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT,
+                    &[None, None, Some(8)],
+                )
+                .symbolic_dims("ids", &["batch", "sequence"])
+                .symbolic_dims("embeddings", &["batch", "sequence", ""]),
+        );
+        let runtime = backend.runtime().unwrap();
+        let session = runtime
+            .session_builder()
+            .unwrap()
+            .free_dimension_override("DATA_BATCH", 1)
+            .unwrap()
+            .free_dimension_override_by_name("sequence", 128)
+            .unwrap()
+            .build_with_model_in_memory(&model_bytes)
+            .unwrap();
+        assert_eq!(vec![Some(1), Some(128)], session.input_info()[0].input_dim);
+        assert_eq!(
+            vec![Some(1), Some(128), Some(8)],
+            session.output_info()[0].output_dim
+        );
+        let calls = backend.calls();
+        assert!(calls.contains(&"AddFreeDimensionOverride"));
+        assert!(calls.contains(&"AddFreeDimensionOverrideByName"));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    #[traced_test]
+    fn test_mock_initializers() {
+        let backend = MockBackend::new();
+        let default_bias = ArrayD::<i64>::from_shape_vec(IxDyn(&[2]), vec![1, 1]).unwrap();
+        backend.add_model(
+            "bias",
+            MockModel::new()
+                .input("x", INT64, &[None, Some(2)])
+                .output("y", INT64, &[None, Some(2)])
+                .initializer("bias", MockTensor::from_array(&default_bias))
+                .on_run(|inputs| {
+                    let x = inputs[0].to_array::<i64>().unwrap();
+                    let bias = inputs[1].to_array::<i64>().unwrap();
+                    Ok(vec![MockTensor::from_array(&(x + bias))])
+                }),
+        );
+        let runtime = backend.runtime().unwrap();
+        let new_tensor = |values: Vec<i64>, shape: &[usize]| {
+            let array = ArrayD::<i64>::from_shape_vec(IxDyn(shape), values).unwrap();
+            runtime.create_tensor_with_ndarray(array).unwrap()
+        };
+        let inputs = vec![new_tensor(vec![10, 20], &[1, 2])];
+        let mut outputs = vec![new_tensor(vec![0, 0], &[1, 2])];
+
+        // The default value in the model
+        let mut default_session = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("bias")
+            .unwrap();
+        let initializers = default_session.overridable_initializers();
+        assert_eq!(1, initializers.len());
+        assert_eq!("bias", initializers[0].name);
+        assert_eq!(INT64, initializers[0].initializer_type);
+        assert_eq!(vec![Some(2)], initializers[0].initializer_dim);
+        run(&mut default_session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[11, 21],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+
+        // A value added to the session options, kept alive by the session
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .add_initializer("bias", new_tensor(vec![2, 3], &[2]))
+            .unwrap()
+            .build_with_model_in_memory("bias")
+            .unwrap();
+        run(&mut session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[12, 23],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+
+        // A value overridden for a single run
+        let bias = new_tensor(vec![5, 5], &[2]);
+        run_with_initializers(
+            &mut session,
+            &inputs,
+            &[("bias", &bias)],
+            &mut outputs,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            &[15, 25],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+        run(&mut session, &inputs, &mut outputs).unwrap();
+        assert_eq!(
+            &[12, 23],
+            outputs[0].data.as_i64_array().unwrap().as_slice().unwrap()
+        );
+
+        // Wrong overrides are rejected before running the session
+        let runs = backend.calls().iter().filter(|c| **c == "Run").count();
+        let err = run_with_initializers(
+            &mut session,
+            &inputs,
+            &[("weight", &bias)],
+            &mut outputs,
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(err, Error::UnknownInitializer { .. }));
+        assert!(err.is_bad_request());
+        // An extra input is not passed as an overridden initializer
+        let too_many = vec![
+            new_tensor(vec![10, 20], &[1, 2]),
+            new_tensor(vec![7, 7], &[2]),
+        ];
+        let err = run_with_initializers(
+            &mut session,
+            &too_many,
+            &[("bias", &bias)],
+            &mut outputs,
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(
+            err,
+            Error::InputCountMismatch {
+                expected: 1,
+                actual: 2
+            }
+        ));
+        let wrong_shape = new_tensor(vec![5, 5, 5], &[3]);
+        let err = run_with_initializers(
+            &mut session,
+            &inputs,
+            &[("bias", &wrong_shape)],
+            &mut outputs,
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(err, Error::ShapeMismatch { .. }));
+        let other_runtime = MockBackend::new().runtime().unwrap();
+        let other_bias = other_runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[2])))
+            .unwrap();
+        let err = run_with_initializers(
+            &mut session,
+            &inputs,
+            &[("bias", &other_bias)],
+            &mut outputs,
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(err, Error::RuntimeMismatch));
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .add_initializer("bias", other_bias)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::RuntimeMismatch));
+        assert_eq!(
+            runs,
+            backend.calls().iter().filter(|c| **c == "Run").count()
+        );
+
+        std::mem::drop((
+            default_session,
+            session,
+            bias,
+            wrong_shape,
+            too_many,
+            inputs,
+            outputs,
+        ));
+        assert!(runtime.live_objects().is_empty());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    #[traced_test]
+    fn test_mock_run_named() {
+        let backend = MockBackend::new();
+        backend.add_model(
+            "sum_and_diff",
+            MockModel::new()
+                .input("a", INT64, &[None, Some(2)])
+                .input("b", INT64, &[None, Some(2)])
+                .output("sum", INT64, &[None, Some(2)])
+                .output("diff", INT64, &[None, Some(2)])
+                .symbolic_dims("a", &["batch", ""])
+                .symbolic_dims("b", &["batch", ""])
+                .symbolic_dims("sum", &["batch", ""])
+                .symbolic_dims("diff", &["batch", ""])
+                .on_run(|inputs| {
+                    let a = inputs[0].to_array::<i64>().unwrap();
+                    let b = inputs[1].to_array::<i64>().unwrap();
+                    Ok(vec![
+                        MockTensor::from_array(&(&a + &b)),
+                        MockTensor::from_array(&(&a - &b)),
+                    ])
+                }),
+        );
+        let runtime = backend.runtime().unwrap();
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .free_dimension_override_by_name("batch", 1)
+            .unwrap()
+            .build_with_model_in_memory("sum_and_diff")
+            .unwrap();
+        let a = ArrayD::<i64>::from_shape_vec(IxDyn(&[1, 2]), vec![10, 20]).unwrap();
+        let b = ArrayD::<i64>::from_shape_vec(IxDyn(&[1, 2]), vec![1, 2]).unwrap();
+        let a = runtime.create_tensor_with_ndarray(a).unwrap();
+        let b = runtime.create_tensor_with_ndarray(b).unwrap();
+
+        // Inputs in any order, all outputs
+        let outputs = session.run_named(&[("b", &b), ("a", &a)], None).unwrap();
+        assert_eq!(2, outputs.len());
+        let sum = outputs["sum"].data.as_i64_array().unwrap();
+        assert_eq!(&[11, 22], sum.as_slice().unwrap());
+        let diff = outputs["diff"].data.as_i64_array().unwrap();
+        assert_eq!(&[9, 18], diff.as_slice().unwrap());
+
+        // A subset of outputs
+        let outputs = session
+            .run_named(&[("a", &a), ("b", &b)], Some(&["diff"]))
+            .unwrap();
+        assert_eq!(vec!["diff"], outputs.keys().collect::<Vec<_>>());
+        let diff = outputs["diff"].data.as_i64_array().unwrap();
+        assert_eq!(&[9, 18], diff.as_slice().unwrap());
+
+        // Wrong names are rejected before running the session
+        let runs = backend.calls().iter().filter(|c| **c == "Run").count();
+        let err = session
+            .run_named(&[("a", &a), ("c", &b)], None)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::UnknownInput { .. }));
+        let err = session.run_named(&[("a", &a)], None).err().unwrap();
+        assert!(matches!(err, Error::MissingInput { .. }));
+        let err = session
+            .run_named(&[("a", &a), ("b", &b), ("a", &a)], None)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::DuplicateInput { .. }));
+        let err = session
+            .run_named(&[("a", &a), ("b", &b)], Some(&["product"]))
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::UnknownOutput { .. }));
+        let err = session
+            .run_named(&[("a", &a), ("b", &b)], Some(&["sum", "sum"]))
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::DuplicateOutput { .. }));
+        assert!(err.is_bad_request());
+        assert_eq!(
+            runs,
+            backend.calls().iter().filter(|c| **c == "Run").count()
+        );
+
+        // Outputs with free dimensions are allocated by onnxruntime
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("sum_and_diff")
+            .unwrap();
+        let tensors = runtime.live_objects().tensors;
+        let outputs = session.run_named(&[("a", &a), ("b", &b)], None).unwrap();
+        // Values allocated by onnxruntime are released after they are copied
+        assert_eq!(tensors + 2, runtime.live_objects().tensors);
+        let sum = outputs["sum"].data.as_i64_array().unwrap();
+        assert_eq!(&[1, 2], sum.shape());
+        assert_eq!(&[11, 22], sum.as_slice().unwrap());
+        let diff = outputs["diff"].data.as_i64_array().unwrap();
+        assert_eq!(&[9, 18], diff.as_slice().unwrap());
+        std::mem::drop(outputs);
+        assert_eq!(tensors, runtime.live_objects().tensors);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    #[traced_test]
+    fn test_mock_allocated_outputs() {
+        let backend = MockBackend::new();
+        // Like NonZero, the shape of the output depends on the data of the input
+        backend.add_model(
+            "nonzero",
+            MockModel::new()
+                .input("x", INT64, &[None])
+                .output("indices", INT64, &[None])
+                .output("count", INT64, &[])
+                .on_run(|inputs| {
+                    let x = inputs[0].to_array::<i64>().unwrap();
+                    let indices: Vec<i64> = x
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, v)| **v != 0)
+                        .map(|(i, _)| i as i64)
+                        .collect();
+                    let count = ArrayD::from_elem(IxDyn(&[]), indices.len() as i64);
+                    let indices = ArrayD::from_shape_vec(IxDyn(&[indices.len()]), indices).unwrap();
+                    Ok(vec![
+                        MockTensor::from_array(&indices),
+                        MockTensor::from_array(&count),
+                    ])
+                }),
+        );
+        let runtime = backend.runtime().unwrap();
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_model_in_memory("nonzero")
+            .unwrap();
+        let x = ArrayD::<i64>::from_shape_vec(IxDyn(&[5]), vec![0, 3, 0, 1, 2]).unwrap();
+        let inputs = vec![runtime.create_tensor_with_ndarray(x).unwrap()];
+
+        let allocated = run_allocated(&mut session, &inputs).unwrap();
+        assert_eq!(2, allocated.len());
+        assert_eq!(INT64, allocated[0].element_type());
+        assert_eq!(&[3], allocated[0].shape());
+        assert_eq!(
+            &[1, 3, 4],
+            allocated[0].view::<i64>().unwrap().as_slice().unwrap()
+        );
+        assert_eq!(&[] as &[usize], allocated[1].shape());
+        assert_eq!(
+            3,
+            allocated[1].view::<i64>().unwrap().into_iter().sum::<i64>()
+        );
+        let err = allocated[0].view::<f32>().err().unwrap();
+        assert!(matches!(err, Error::ElementTypeMismatch { .. }));
+        assert_eq!(2, runtime.live_objects().tensors - inputs.len());
+
+        let outputs = session
+            .run_named_allocated(&[("x", &inputs[0])], Some(&["indices"]))
+            .unwrap();
+        assert_eq!(1, outputs.len());
+        assert_eq!(&[3], outputs["indices"].shape());
+        // run_named mixes outputs allocated before the run and outputs allocated by onnxruntime
+        let named = session.run_named(&[("x", &inputs[0])], None).unwrap();
+        assert_eq!(
+            &[1, 3, 4],
+            named["indices"]
+                .data
+                .as_i64_array()
+                .unwrap()
+                .as_slice()
+                .unwrap()
+        );
+        assert_eq!(3, named["count"].data.as_i64_array().unwrap().sum());
+
+        std::mem::drop((session, inputs, allocated, outputs, named));
+        assert!(runtime.live_objects().is_empty());
     }
 }
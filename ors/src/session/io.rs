@@ -1,12 +1,16 @@
-use std::ffi::CStr;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
 use std::fmt::Debug;
+use std::os::raw::c_char;
 use std::ptr::{null, null_mut};
 
-use anyhow::Result;
+use crate::error::Result;
 
 use ors_sys::*;
 
-use crate::api::get_api;
+use crate::api::AsOrtApi;
+use crate::model::DimDenotations;
+use crate::runtime::Runtime;
 use crate::{call_ort, status::check_status};
 
 #[derive(Clone)]
@@ -39,44 +43,159 @@ impl Debug for SessionOutputInfo {
     }
 }
 
+/// An initializer of the model which can be overridden by a run
+#[derive(Clone)]
+pub struct SessionInitializerInfo {
+    pub name: String,
+
+    pub initializer_type: ONNXTensorElementDataType,
+
+    pub initializer_dim: Vec<Option<i64>>,
+}
+
+impl Debug for SessionInitializerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "initializer name: {}, dim: {:?}",
+            self.name, self.initializer_dim
+        )
+    }
+}
+
+/// Overrides of free dimensions by their symbolic names and their denotations
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DimOverrides {
+    by_name: HashMap<String, i64>,
+    by_denotation: HashMap<String, i64>,
+    /// Denotations of the dimensions of graph inputs and outputs, which onnxruntime doesn't report
+    denotations: HashMap<String, DimDenotations>,
+}
+
+impl DimOverrides {
+    pub(crate) fn insert_name(&mut self, name: &str, value: i64) {
+        self.by_name.insert(name.to_string(), value);
+    }
+
+    pub(crate) fn insert_denotation(&mut self, denotation: &str, value: i64) {
+        self.by_denotation.insert(denotation.to_string(), value);
+    }
+
+    /// Whether the denotations of dimensions must be read from the model
+    pub(crate) fn needs_denotations(&self) -> bool {
+        !self.by_denotation.is_empty()
+    }
+
+    /// The overrides with the denotations of dimensions read from the model
+    pub(crate) fn with_denotations(&self, denotations: HashMap<String, DimDenotations>) -> Self {
+        DimOverrides {
+            denotations,
+            ..self.clone()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.by_name.is_empty() && self.by_denotation.is_empty()
+    }
+}
+
 pub(crate) fn get_session_inputs(
+    runtime: &Runtime,
     session: *const OrtSession,
     allocator: *mut OrtAllocator,
+    dim_overrides: &DimOverrides,
 ) -> Result<Vec<SessionInputInfo>> {
-    let input_cnt = get_input_count(session)?;
+    let input_cnt = get_input_count(runtime, session)?;
     let mut inputs: Vec<SessionInputInfo> = vec![];
     for i in 0..input_cnt {
-        inputs.push(get_session_input(session, i, allocator)?);
+        inputs.push(get_session_input(
+            runtime,
+            session,
+            i,
+            allocator,
+            dim_overrides,
+        )?);
     }
     Ok(inputs)
 }
 
 pub(crate) fn get_session_outputs(
+    runtime: &Runtime,
     session: *const OrtSession,
     allocator: *mut OrtAllocator,
+    dim_overrides: &DimOverrides,
 ) -> Result<Vec<SessionOutputInfo>> {
-    let output_cnt = get_output_count(session)?;
+    let output_cnt = get_output_count(runtime, session)?;
     let mut outputs: Vec<SessionOutputInfo> = vec![];
     for i in 0..output_cnt {
-        outputs.push(get_session_output(session, i, allocator)?);
+        outputs.push(get_session_output(
+            runtime,
+            session,
+            i,
+            allocator,
+            dim_overrides,
+        )?);
     }
     Ok(outputs)
 }
 
-fn get_session_input(
+pub(crate) fn get_session_initializers(
+    runtime: &Runtime,
     session: *const OrtSession,
-    index: usize,
     allocator: *mut OrtAllocator,
-) -> Result<SessionInputInfo> {
-    let input_name = get_input_name(session, index, allocator)?;
-    let input_typeinfo = get_input_typeinfo(session, index)?;
-    let dim_cnt = get_dimension_count(input_typeinfo)?;
-    let input_dim: Vec<Option<i64>> = get_dimensions(input_typeinfo, dim_cnt)?
+    dim_overrides: &DimOverrides,
+) -> Result<Vec<SessionInitializerInfo>> {
+    let initializer_cnt = get_overridable_initializer_count(runtime, session)?;
+    let mut initializers: Vec<SessionInitializerInfo> = vec![];
+    for i in 0..initializer_cnt {
+        let name = get_overridable_initializer_name(runtime, session, i, allocator)?;
+        let type_info = get_overridable_initializer_typeinfo(runtime, session, i)
+            .map_err(|e| e.for_input(&name))?;
+        let tensor_info = read_tensor_info(runtime, &name, type_info, dim_overrides);
+        release_typeinfo(runtime, type_info)?;
+        let (initializer_dim, initializer_type) = tensor_info.map_err(|e| e.for_input(&name))?;
+        initializers.push(SessionInitializerInfo {
+            name,
+            initializer_type,
+            initializer_dim,
+        });
+    }
+    Ok(initializers)
+}
+
+/// Read the dimensions and the element type of a tensor type info
+fn read_tensor_info(
+    runtime: &Runtime,
+    name: &str,
+    type_info: *const OrtTypeInfo,
+    dim_overrides: &DimOverrides,
+) -> Result<(Vec<Option<i64>>, ONNXTensorElementDataType)> {
+    // The tensor info is owned by the type info
+    let tensor_info = cast_to_tensor_info(runtime, type_info)?;
+    let dim_cnt = get_dimension_count(runtime, tensor_info)?;
+    let mut dims: Vec<Option<i64>> = get_dimensions(runtime, tensor_info, dim_cnt)?
         .into_iter()
         .map(|d| if d == -1 { None } else { Some(d) })
         .collect();
-    let input_type = get_tensor_element_type(input_typeinfo)?;
-    release_typeinfo(input_typeinfo);
+    override_free_dims(runtime, name, tensor_info, &mut dims, dim_overrides)?;
+    let element_type = get_tensor_element_type(runtime, tensor_info)?;
+    Ok((dims, element_type))
+}
+
+fn get_session_input(
+    runtime: &Runtime,
+    session: *const OrtSession,
+    index: usize,
+    allocator: *mut OrtAllocator,
+    dim_overrides: &DimOverrides,
+) -> Result<SessionInputInfo> {
+    let input_name = get_input_name(runtime, session, index, allocator)?;
+    let type_info =
+        get_input_typeinfo(runtime, session, index).map_err(|e| e.for_input(&input_name))?;
+    // The type info must be released even if reading it fails
+    let input_info = read_tensor_info(runtime, &input_name, type_info, dim_overrides);
+    release_typeinfo(runtime, type_info)?;
+    let (input_dim, input_type) = input_info.map_err(|e| e.for_input(&input_name))?;
     Ok(SessionInputInfo {
         name: input_name,
         input_type,
@@ -85,19 +204,18 @@ fn get_session_input(
 }
 
 fn get_session_output(
+    runtime: &Runtime,
     session: *const OrtSession,
     index: usize,
     allocator: *mut OrtAllocator,
+    dim_overrides: &DimOverrides,
 ) -> Result<SessionOutputInfo> {
-    let output_name = get_output_name(session, index, allocator)?;
-    let output_typeinfo = get_output_typeinfo(session, index)?;
-    let dim_cnt = get_dimension_count(output_typeinfo)?;
-    let output_dim: Vec<Option<i64>> = get_dimensions(output_typeinfo, dim_cnt)?
-        .into_iter()
-        .map(|d| if d == -1 { None } else { Some(d) })
-        .collect();
-    let output_type = get_tensor_element_type(output_typeinfo)?;
-    release_typeinfo(output_typeinfo);
+    let output_name = get_output_name(runtime, session, index, allocator)?;
+    let type_info = get_output_typeinfo(runtime, session, index)?;
+    // The type info must be released even if reading it fails
+    let output_info = read_tensor_info(runtime, &output_name, type_info, dim_overrides);
+    release_typeinfo(runtime, type_info)?;
+    let (output_dim, output_type) = output_info?;
     Ok(SessionOutputInfo {
         name: output_name,
         output_type,
@@ -105,23 +223,24 @@ fn get_session_output(
     })
 }
 
-fn get_input_count(session: *const OrtSession) -> Result<usize> {
+fn get_input_count(runtime: &Runtime, session: *const OrtSession) -> Result<usize> {
     let mut input_count: usize = 0;
     let input_count_ptr: *mut usize = &mut input_count;
-    let status = call_ort!(SessionGetInputCount, session, input_count_ptr);
-    check_status(status)?;
+    let status = call_ort!(runtime => SessionGetInputCount, session, input_count_ptr)?;
+    check_status(runtime, status, "SessionGetInputCount")?;
     Ok(input_count)
 }
 
-fn get_output_count(session: *const OrtSession) -> Result<usize> {
+fn get_output_count(runtime: &Runtime, session: *const OrtSession) -> Result<usize> {
     let mut output_count: usize = 0;
     let output_count_ptr: *mut usize = &mut output_count;
-    let status = call_ort!(SessionGetOutputCount, session, output_count_ptr);
-    check_status(status)?;
+    let status = call_ort!(runtime => SessionGetOutputCount, session, output_count_ptr)?;
+    check_status(runtime, status, "SessionGetOutputCount")?;
     Ok(output_count)
 }
 
 fn get_input_name(
+    runtime: &Runtime,
     session: *const OrtSession,
     index: usize,
     allocator: *mut OrtAllocator,
@@ -129,21 +248,24 @@ fn get_input_name(
     let mut input_name_ptr = null_mut();
     let input_name_ptr_ptr = &mut input_name_ptr;
     let status = call_ort!(
-        SessionGetInputName,
+        runtime => SessionGetInputName,
         session,
         index,
         allocator,
         input_name_ptr_ptr
-    );
-    check_status(status)?;
-    Ok(unsafe {
+    )?;
+    check_status(runtime, status, "SessionGetInputName")?;
+    let input_name = unsafe {
         (*(CStr::from_ptr(input_name_ptr)))
             .to_string_lossy()
             .to_string()
-    })
+    };
+    free_name(runtime, allocator, input_name_ptr)?;
+    Ok(input_name)
 }
 
 fn get_output_name(
+    runtime: &Runtime,
     session: *const OrtSession,
     index: usize,
     allocator: *mut OrtAllocator,
@@ -151,96 +273,201 @@ fn get_output_name(
     let mut output_name_ptr = null_mut();
     let output_name_ptr_ptr = &mut output_name_ptr;
     let status = call_ort!(
-        SessionGetOutputName,
+        runtime => SessionGetOutputName,
         session,
         index,
         allocator,
         output_name_ptr_ptr
-    );
-    check_status(status)?;
-    Ok(unsafe {
+    )?;
+    check_status(runtime, status, "SessionGetOutputName")?;
+    let output_name = unsafe {
         (*(CStr::from_ptr(output_name_ptr)))
             .to_string_lossy()
             .to_string()
-    })
+    };
+    free_name(runtime, allocator, output_name_ptr)?;
+    Ok(output_name)
 }
 
-fn get_input_typeinfo(
+fn get_overridable_initializer_count(
+    runtime: &Runtime,
+    session: *const OrtSession,
+) -> Result<usize> {
+    let mut initializer_count: usize = 0;
+    let status = call_ort!(
+        runtime => SessionGetOverridableInitializerCount,
+        session,
+        &mut initializer_count
+    )?;
+    check_status(runtime, status, "SessionGetOverridableInitializerCount")?;
+    Ok(initializer_count)
+}
+
+fn get_overridable_initializer_name(
+    runtime: &Runtime,
     session: *const OrtSession,
     index: usize,
-) -> Result<*const OrtTensorTypeAndShapeInfo> {
-    let mut type_info_ptr = null_mut();
-    let status = call_ort!(SessionGetInputTypeInfo, session, index, &mut type_info_ptr);
-    check_status(status)?;
+    allocator: *mut OrtAllocator,
+) -> Result<String> {
+    let mut name_ptr = null_mut();
+    let status = call_ort!(
+        runtime => SessionGetOverridableInitializerName,
+        session,
+        index,
+        allocator,
+        &mut name_ptr
+    )?;
+    check_status(runtime, status, "SessionGetOverridableInitializerName")?;
+    let name = unsafe { CStr::from_ptr(name_ptr) }
+        .to_string_lossy()
+        .to_string();
+    free_name(runtime, allocator, name_ptr)?;
+    Ok(name)
+}
 
-    let mut tensor_type_info_ptr = null();
+fn get_overridable_initializer_typeinfo(
+    runtime: &Runtime,
+    session: *const OrtSession,
+    index: usize,
+) -> Result<*mut OrtTypeInfo> {
+    let mut type_info_ptr = null_mut();
     let status = call_ort!(
-        CastTypeInfoToTensorInfo,
-        type_info_ptr,
-        &mut tensor_type_info_ptr
-    );
-    check_status(status)?;
+        runtime => SessionGetOverridableInitializerTypeInfo,
+        session,
+        index,
+        &mut type_info_ptr
+    )?;
+    check_status(runtime, status, "SessionGetOverridableInitializerTypeInfo")?;
+    Ok(type_info_ptr)
+}
 
-    Ok(tensor_type_info_ptr)
+fn get_input_typeinfo(
+    runtime: &Runtime,
+    session: *const OrtSession,
+    index: usize,
+) -> Result<*mut OrtTypeInfo> {
+    let mut type_info_ptr = null_mut();
+    let status = call_ort!(runtime => SessionGetInputTypeInfo, session, index, &mut type_info_ptr)?;
+    check_status(runtime, status, "SessionGetInputTypeInfo")?;
+    Ok(type_info_ptr)
 }
 
 fn get_output_typeinfo(
+    runtime: &Runtime,
     session: *const OrtSession,
     index: usize,
-) -> Result<*const OrtTensorTypeAndShapeInfo> {
+) -> Result<*mut OrtTypeInfo> {
     let mut type_info_ptr = null_mut();
-    let status = call_ort!(SessionGetOutputTypeInfo, session, index, &mut type_info_ptr);
-    check_status(status)?;
-
-    let mut tensor_type_info_ptr = null();
-    let status = call_ort!(
-        CastTypeInfoToTensorInfo,
-        type_info_ptr,
-        &mut tensor_type_info_ptr
-    );
-    check_status(status)?;
-
-    Ok(tensor_type_info_ptr)
+    let status =
+        call_ort!(runtime => SessionGetOutputTypeInfo, session, index, &mut type_info_ptr)?;
+    check_status(runtime, status, "SessionGetOutputTypeInfo")?;
+    Ok(type_info_ptr)
 }
 
-fn get_dimensions(
+pub(crate) fn get_dimensions(
+    runtime: &impl AsOrtApi,
     type_info: *const OrtTensorTypeAndShapeInfo,
     dimension_cnt: usize,
 ) -> Result<Vec<i64>> {
-    let mut dim_values: Vec<i64> = vec![0; dimension_cnt as usize];
+    let mut dim_values: Vec<i64> = vec![0; dimension_cnt];
 
     let dim_values_ptr = &mut dim_values;
 
     let status = call_ort!(
-        GetDimensions,
+        runtime => GetDimensions,
         type_info,
         dim_values_ptr.as_mut_ptr(),
         dimension_cnt
-    );
-    check_status(status)?;
+    )?;
+    check_status(runtime, status, "GetDimensions")?;
     Ok(dim_values)
 }
 
-fn get_dimension_count(type_info: *const OrtTensorTypeAndShapeInfo) -> Result<usize> {
+pub(crate) fn get_dimension_count(
+    runtime: &impl AsOrtApi,
+    type_info: *const OrtTensorTypeAndShapeInfo,
+) -> Result<usize> {
     let mut dimension_cnt = 0;
-    let status = call_ort!(GetDimensionsCount, type_info, &mut dimension_cnt);
-    check_status(status)?;
+    let status = call_ort!(runtime => GetDimensionsCount, type_info, &mut dimension_cnt)?;
+    check_status(runtime, status, "GetDimensionsCount")?;
 
     Ok(dimension_cnt)
 }
 
-fn get_tensor_element_type(
+pub(crate) fn get_tensor_element_type(
+    runtime: &impl AsOrtApi,
     type_info: *const OrtTensorTypeAndShapeInfo,
 ) -> Result<ONNXTensorElementDataType> {
     let mut data_type = ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
-    let status = call_ort!(GetTensorElementType, type_info, &mut data_type);
-    check_status(status)?;
+    let status = call_ort!(runtime => GetTensorElementType, type_info, &mut data_type)?;
+    check_status(runtime, status, "GetTensorElementType")?;
     Ok(data_type)
 }
 
-fn release_typeinfo(type_info: *const OrtTensorTypeAndShapeInfo) {
-    call_ort!(
-        ReleaseTensorTypeAndShapeInfo,
-        type_info as *mut OrtTensorTypeAndShapeInfo
-    );
+/// Fill free dimensions whose names or denotations are overridden
+///
+/// onnxruntime applies the overrides when it optimizes the graph, the graph inputs may still report free dimensions
+/// when optimizations are disabled.
+fn override_free_dims(
+    runtime: &Runtime,
+    tensor_name: &str,
+    tensor_info: *const OrtTensorTypeAndShapeInfo,
+    dims: &mut [Option<i64>],
+    dim_overrides: &DimOverrides,
+) -> Result<()> {
+    if dim_overrides.is_empty() || dims.iter().all(Option::is_some) {
+        return Ok(());
+    }
+    if !dim_overrides.by_name.is_empty() {
+        let mut names: Vec<*const c_char> = vec![null(); dims.len()];
+        let status = call_ort!(
+            runtime => GetSymbolicDimensions,
+            tensor_info,
+            names.as_mut_ptr(),
+            names.len()
+        )?;
+        check_status(runtime, status, "GetSymbolicDimensions")?;
+        for (dim, name) in dims.iter_mut().zip(names) {
+            if dim.is_none() && !name.is_null() {
+                let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+                if let Some(value) = dim_overrides.by_name.get(name.as_ref()) {
+                    *dim = Some(*value);
+                }
+            }
+        }
+    }
+    if let Some(denotations) = dim_overrides.denotations.get(tensor_name) {
+        for (dim, denotation) in dims.iter_mut().zip(denotations) {
+            if let (true, Some(denotation)) = (dim.is_none(), denotation) {
+                if let Some(value) = dim_overrides.by_denotation.get(denotation) {
+                    *dim = Some(*value);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cast_to_tensor_info(
+    runtime: &Runtime,
+    type_info: *const OrtTypeInfo,
+) -> Result<*const OrtTensorTypeAndShapeInfo> {
+    let mut tensor_info_ptr = null();
+    let status = call_ort!(runtime => CastTypeInfoToTensorInfo, type_info, &mut tensor_info_ptr)?;
+    check_status(runtime, status, "CastTypeInfoToTensorInfo")?;
+    Ok(tensor_info_ptr)
+}
+
+fn release_typeinfo(runtime: &Runtime, type_info: *mut OrtTypeInfo) -> Result<()> {
+    call_ort!(runtime => ReleaseTypeInfo, type_info)
+}
+
+/// Free a name which is allocated by the allocator passed to onnxruntime
+pub(crate) fn free_name(
+    runtime: &Runtime,
+    allocator: *mut OrtAllocator,
+    name: *mut c_char,
+) -> Result<()> {
+    let status = call_ort!(runtime => AllocatorFree, allocator, name as *mut c_void)?;
+    check_status(runtime, status, "AllocatorFree")
 }
@@ -0,0 +1,172 @@
+use std::ptr::null_mut;
+use std::sync::Arc;
+
+use ors_sys::*;
+use tracing::warn;
+
+use crate::api::default_runtime;
+use crate::call_ort;
+use crate::error::Result;
+use crate::runtime::Runtime;
+use crate::status::check_status;
+
+/// A container of weights prepacked by kernels, which is shared by the sessions created with it
+///
+/// Sessions of the same model created with one container prepack their weights once. The container
+/// is released after the last handle and the last session using it are dropped.
+///
+/// ## Example
+/// ```no_run
+/// use std::path::Path;
+/// use ors::session::{ModelSource, PrepackedWeights, SessionBuilder};
+///
+/// let prepacked_weights = PrepackedWeights::new().unwrap();
+/// let sessions: Vec<_> = (0..4)
+///     .map(|_| {
+///         SessionBuilder::new()
+///             .unwrap()
+///             .build_with_prepacked_weights(
+///                 &prepacked_weights,
+///                 ModelSource::File(Path::new("model.onnx")),
+///             )
+///             .unwrap()
+///     })
+///     .collect();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrepackedWeights {
+    inner: Arc<PrepackedWeightsContainer>,
+}
+
+#[derive(Debug)]
+struct PrepackedWeightsContainer {
+    runtime: Runtime,
+    ptr: *mut OrtPrepackedWeightsContainer,
+}
+
+// onnxruntime locks the container when sessions are created with it
+unsafe impl Send for PrepackedWeightsContainer {}
+unsafe impl Sync for PrepackedWeightsContainer {}
+
+impl PrepackedWeights {
+    /// Create a container in the default runtime
+    pub fn new() -> Result<Self> {
+        Self::with_runtime(&default_runtime()?)
+    }
+
+    /// Create a container in the given runtime
+    pub fn with_runtime(runtime: &Runtime) -> Result<Self> {
+        let mut ptr: *mut OrtPrepackedWeightsContainer = null_mut();
+        let status = call_ort!(runtime => CreatePrepackedWeightsContainer, &mut ptr)?;
+        check_status(runtime, status, "CreatePrepackedWeightsContainer")?;
+        Ok(Self {
+            inner: Arc::new(PrepackedWeightsContainer {
+                runtime: runtime.clone(),
+                ptr,
+            }),
+        })
+    }
+
+    pub(crate) fn runtime(&self) -> &Runtime {
+        &self.inner.runtime
+    }
+
+    pub(crate) fn ptr(&self) -> *mut OrtPrepackedWeightsContainer {
+        self.inner.ptr
+    }
+}
+
+impl Drop for PrepackedWeightsContainer {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            if let Err(e) = call_ort!(self.runtime => ReleasePrepackedWeightsContainer, self.ptr) {
+                warn!("Failed to release prepacked weights container: {}", e);
+            }
+        }
+        self.ptr = null_mut();
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod test {
+    use ndarray::{ArrayD, IxDyn};
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::mock::{test_support::sum_model, MockBackend};
+    use crate::session::{run as run_session, ModelSource};
+
+    #[test]
+    #[traced_test]
+    fn test_mock_prepacked_weights() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+        let path = std::env::temp_dir().join(format!("ors-prepacked-{}.onnx", std::process::id()));
+        std::fs::write(&path, "sum").unwrap();
+
+        let prepacked_weights = PrepackedWeights::with_runtime(&runtime).unwrap();
+        let mut sessions = vec![
+            runtime
+                .session_builder()
+                .unwrap()
+                .build_with_prepacked_weights(&prepacked_weights, ModelSource::Bytes(b"sum"))
+                .unwrap(),
+            runtime
+                .session_builder()
+                .unwrap()
+                .build_with_prepacked_weights(&prepacked_weights, ModelSource::File(&path))
+                .unwrap(),
+        ];
+        let calls = backend.calls();
+        assert!(calls.contains(&"CreateSessionFromArrayWithPrepackedWeightsContainer"));
+        assert!(calls.contains(&"CreateSessionWithPrepackedWeightsContainer"));
+
+        // The container is released after the last session using it
+        std::mem::drop(prepacked_weights);
+        let inputs: Vec<_> = (0..2)
+            .map(|_| {
+                runtime
+                    .create_tensor_with_ndarray(ArrayD::<i64>::ones(IxDyn(&[1, 2])))
+                    .unwrap()
+            })
+            .collect();
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+            .unwrap()];
+        for session in &mut sessions {
+            run_session(session, &inputs, &mut outputs).unwrap();
+        }
+        let released = |backend: &MockBackend| {
+            backend
+                .calls()
+                .iter()
+                .filter(|c| **c == "ReleasePrepackedWeightsContainer")
+                .count()
+        };
+        sessions.pop();
+        assert_eq!(0, released(&backend));
+        sessions.pop();
+        assert_eq!(1, released(&backend));
+
+        let other_weights =
+            PrepackedWeights::with_runtime(&MockBackend::new().runtime().unwrap()).unwrap();
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_prepacked_weights(&other_weights, ModelSource::Bytes(b"sum"))
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::RuntimeMismatch));
+        std::fs::remove_file(&path).unwrap();
+        let prepacked_weights = PrepackedWeights::with_runtime(&runtime).unwrap();
+        let err = runtime
+            .session_builder()
+            .unwrap()
+            .build_with_prepacked_weights(&prepacked_weights, ModelSource::File(&path))
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::ModelNotFound(_)));
+    }
+}
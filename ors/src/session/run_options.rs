@@ -0,0 +1,179 @@
+use std::ffi::CString;
+use std::ptr::null_mut;
+
+use ors_sys::*;
+use tracing::warn;
+
+use crate::api::default_runtime;
+use crate::call_ort;
+use crate::config::RunConfigKey;
+use crate::error::Result;
+use crate::log::LoggingLevel;
+use crate::runtime::Runtime;
+use crate::status::check_status;
+
+/// Options of a single run of a session
+///
+/// ## Example
+/// ```no_run
+/// use ors::log::LoggingLevel;
+/// use ors::session::RunOptions;
+///
+/// let run_options = RunOptions::new()
+///     .unwrap()
+///     .tag("request-42")
+///     .unwrap()
+///     .log_severity_level(LoggingLevel::Verbose)
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RunOptions {
+    pub(crate) runtime: Runtime,
+    pub(crate) ptr: *mut OrtRunOptions,
+    pub(crate) tag: Option<String>,
+}
+
+impl RunOptions {
+    /// Create run options in the default runtime
+    pub fn new() -> Result<Self> {
+        Self::with_runtime(&default_runtime()?)
+    }
+
+    /// Create run options in the given runtime
+    pub fn with_runtime(runtime: &Runtime) -> Result<Self> {
+        let mut ptr: *mut OrtRunOptions = null_mut();
+        let status = call_ort!(runtime => CreateRunOptions, &mut ptr)?;
+        check_status(runtime, status, "CreateRunOptions")?;
+        Ok(Self {
+            runtime: runtime.clone(),
+            ptr,
+            tag: None,
+        })
+    }
+
+    /// Set the tag of the run, which is the log id of messages logged by the run
+    pub fn tag(mut self, tag: &str) -> Result<Self> {
+        let c_tag = CString::new(tag)?;
+        let status = call_ort!(self.runtime => RunOptionsSetRunTag, self.ptr, c_tag.as_ptr())?;
+        check_status(&self.runtime, status, "RunOptionsSetRunTag")?;
+        self.tag = Some(tag.to_string());
+        Ok(self)
+    }
+
+    /// Set a run configuration entry, the value is checked before it's passed to onnxruntime
+    pub fn config(self, key: RunConfigKey, value: &str) -> Result<Self> {
+        key.validate(value)?;
+        self.config_entry(key.key(), value)
+    }
+
+    /// Set a run configuration entry without checking it, for keys which are not in [`RunConfigKey`]
+    pub fn config_entry(self, key: &str, value: &str) -> Result<Self> {
+        let c_key = CString::new(key)?;
+        let c_value = CString::new(value)?;
+        let status = call_ort!(
+            self.runtime => AddRunConfigEntry,
+            self.ptr,
+            c_key.as_ptr(),
+            c_value.as_ptr()
+        )?;
+        check_status(&self.runtime, status, "AddRunConfigEntry")?;
+        Ok(self)
+    }
+
+    /// Set the minimal level of messages logged by the run
+    ///
+    /// Messages below the level of the environment are still dropped.
+    pub fn log_severity_level(self, level: LoggingLevel) -> Result<Self> {
+        let level = OrtLoggingLevel::from(level) as i32;
+        let status = call_ort!(self.runtime => RunOptionsSetRunLogSeverityLevel, self.ptr, level)?;
+        check_status(&self.runtime, status, "RunOptionsSetRunLogSeverityLevel")?;
+        Ok(self)
+    }
+
+    /// Set the verbosity of `Verbose` messages logged by the run, only used by debug builds of onnxruntime
+    pub fn log_verbosity_level(self, verbosity_level: i32) -> Result<Self> {
+        let status = call_ort!(
+            self.runtime => RunOptionsSetRunLogVerbosityLevel,
+            self.ptr,
+            verbosity_level
+        )?;
+        check_status(&self.runtime, status, "RunOptionsSetRunLogVerbosityLevel")?;
+        Ok(self)
+    }
+}
+
+impl Drop for RunOptions {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            if let Err(e) = call_ort!(self.runtime => ReleaseRunOptions, self.ptr) {
+                warn!("Failed to release run options: {}", e);
+            }
+        }
+        self.ptr = null_mut();
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod test {
+    use ndarray::{ArrayD, IxDyn};
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::config::{RunConfigKey, SessionConfigKey};
+    use crate::error::Error;
+    use crate::mock::{test_support::sum_model, MockBackend};
+    use crate::session::run_with_options;
+
+    #[test]
+    #[traced_test]
+    fn test_mock_run_options() {
+        let backend = MockBackend::new();
+        backend.add_model("sum", sum_model());
+        let runtime = backend.runtime().unwrap();
+        let mut session = runtime
+            .session_builder()
+            .unwrap()
+            .log_id("sum_model")
+            .unwrap()
+            .log_severity_level(LoggingLevel::Info)
+            .unwrap()
+            .config(SessionConfigKey::IntraOpAllowSpinning, "0")
+            .unwrap()
+            .build_with_model_in_memory("sum")
+            .unwrap();
+        // Messages logged while creating the session are in its span
+        assert!(logs_contain("session{log_id=\"sum_model\"}"));
+        assert!(logs_contain("Creating the mock session"));
+        let inputs: Vec<_> = (0..2)
+            .map(|_| {
+                runtime
+                    .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+                    .unwrap()
+            })
+            .collect();
+        let mut outputs = vec![runtime
+            .create_tensor_with_ndarray(ArrayD::<i64>::zeros(IxDyn(&[1, 2])))
+            .unwrap()];
+        let run_options = RunOptions::with_runtime(&runtime)
+            .unwrap()
+            .tag("request-1")
+            .unwrap()
+            .log_severity_level(LoggingLevel::Verbose)
+            .unwrap()
+            .config(RunConfigKey::EnableMemoryArenaShrinkage, "cpu:0")
+            .unwrap();
+        run_with_options(&mut session, &inputs, &mut outputs, &run_options).unwrap();
+        let calls = backend.calls();
+        assert!(calls.contains(&"AddSessionConfigEntry"));
+        assert!(calls.contains(&"AddRunConfigEntry"));
+        assert!(calls.contains(&"RunOptionsSetRunTag"));
+
+        // Options of another runtime can't be used
+        let other_options =
+            RunOptions::with_runtime(&MockBackend::new().runtime().unwrap()).unwrap();
+        let err = run_with_options(&mut session, &inputs, &mut outputs, &other_options)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::RuntimeMismatch));
+    }
+}
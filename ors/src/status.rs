@@ -1,71 +1,109 @@
-use crate::{api::get_api, call_ort};
-use anyhow::{anyhow, Result};
+use crate::{
+    api::AsOrtApi,
+    call_ort,
+    error::{Error, Result},
+};
 use ors_sys::*;
 use std::ffi::{CStr, CString};
 
 /// Create an OrtStatus from a null terminated string
-fn create_status(error_code: OrtErrorCode, msg: String) -> *const OrtStatus {
-    let msg = CString::new(msg).unwrap();
-    call_ort!(CreateStatus, error_code, msg.as_ptr())
+fn create_status(
+    api: &impl AsOrtApi,
+    error_code: OrtErrorCode,
+    msg: String,
+) -> Result<*const OrtStatus> {
+    let msg = CString::new(msg)?;
+    call_ort!(api => CreateStatus, error_code, msg.as_ptr())
 }
 
 /// Get OrtErrorCode from OrtStatus
-fn get_error_code(status: *const OrtStatus) -> OrtErrorCode {
-    call_ort!(GetErrorCode, status)
+fn get_error_code(api: &impl AsOrtApi, status: *const OrtStatus) -> Result<OrtErrorCode> {
+    call_ort!(api => GetErrorCode, status)
 }
 
 /// Release an OrtStatus
-fn release_status(status: *mut OrtStatus) {
-    call_ort!(ReleaseStatus, status)
+fn release_status(api: &impl AsOrtApi, status: *mut OrtStatus) -> Result<()> {
+    call_ort!(api => ReleaseStatus, status)
 }
 
 /// Get error string from OrtStatus
-fn get_error_msg(status: *const OrtStatus) -> String {
-    let msg = unsafe { CStr::from_ptr(get_api().GetErrorMessage.unwrap()(status)) };
-    (*msg.to_string_lossy()).to_string()
+fn get_error_msg(api: &impl AsOrtApi, status: *const OrtStatus) -> Result<String> {
+    let msg_ptr = call_ort!(api => GetErrorMessage, status)?;
+    let msg = unsafe { CStr::from_ptr(msg_ptr) };
+    Ok((*msg.to_string_lossy()).to_string())
 }
 
 /// Check an OrtStatus, returns Ok(()) if the api runs good
-pub(crate) fn check_status(status: *mut OrtStatus) -> Result<()> {
+///
+/// The status must be checked with the runtime which created it,
+/// `function` is the name of the API which returned the status
+pub(crate) fn check_status(
+    api: &impl AsOrtApi,
+    status: *mut OrtStatus,
+    function: &'static str,
+) -> Result<()> {
     if status.is_null() {
         Ok(())
-    } else if OrtErrorCode_ORT_OK == get_error_code(status) {
-        release_status(status);
-        Ok(())
+    } else if OrtErrorCode_ORT_OK == get_error_code(api, status)? {
+        release_status(api, status)
     } else {
         // Extract onnxruntime error and then release the status
-        let err = anyhow!(
-            "onnxruntime error: {}:{}",
-            get_error_code(status),
-            get_error_msg(status)
-        );
-        release_status(status);
+        let err = Error::Ort {
+            function,
+            code: get_error_code(api, status)?.into(),
+            message: get_error_msg(api, status)?,
+            session: None,
+            input: None,
+        };
+        release_status(api, status)?;
         Err(err)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::path::Path;
-
-    use crate::api::initialize_runtime;
+    use crate::{api::default_runtime, error::ErrorCode};
 
     use super::*;
 
     #[test]
     fn test_ort_status() {
-        setup_runtime();
-        let status = create_status(OrtErrorCode_ORT_MODEL_LOADED, "OKOKOKO".to_string());
-        assert_eq!(8, get_error_code(status));
-        release_status(status as *mut OrtStatus);
+        let runtime = default_runtime().unwrap();
+        let status = create_status(
+            &runtime,
+            OrtErrorCode_ORT_MODEL_LOADED,
+            "OKOKOKO".to_string(),
+        )
+        .unwrap();
+        assert_eq!(8, get_error_code(&runtime, status).unwrap());
+        release_status(&runtime, status as *mut OrtStatus).unwrap();
     }
-    fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).unwrap();
+
+    #[test]
+    fn test_check_status() {
+        let runtime = default_runtime().unwrap();
+        let status = create_status(
+            &runtime,
+            OrtErrorCode_ORT_NO_SUCHFILE,
+            "model.onnx".to_string(),
+        )
+        .unwrap();
+        let err = check_status(&runtime, status as *mut OrtStatus, "CreateSession")
+            .err()
+            .unwrap();
+        match err {
+            Error::Ort {
+                function,
+                code,
+                message,
+                session: None,
+                input: None,
+            } => {
+                assert_eq!("CreateSession", function);
+                assert_eq!(ErrorCode::NoSuchFile, code);
+                assert_eq!("model.onnx", message);
+            }
+            _ => panic!("unexpected error: {}", err),
+        }
     }
 }
@@ -1,14 +1,23 @@
 use std::{ffi::c_void, ptr::null_mut};
 
-use anyhow::Result;
+use crate::error::{Error, Result};
 use enum_as_inner::EnumAsInner;
 use enum_dispatch::enum_dispatch;
-use ndarray::ArrayD;
+use ndarray::{ArrayD, ArrayViewD, IxDyn};
 use ors_sys::*;
+use tracing::warn;
 
 use crate::{
-    api::get_api, call_ort, memory_info::MemoryInfo, session::get_default_memory_info,
-    status::check_status, types::TypeToTensorElementDataType,
+    api::default_runtime,
+    call_ort,
+    memory_info::MemoryInfo,
+    runtime::{ObjectKind, Runtime, TrackedRuntime},
+    session::{
+        get_default_memory_info,
+        io::{get_dimension_count, get_dimensions, get_tensor_element_type},
+    },
+    status::check_status,
+    types::TypeToTensorElementDataType,
 };
 
 #[derive(Debug, EnumAsInner)]
@@ -27,6 +36,42 @@ pub enum TypedArray {
     BoolArray(ArrayD<bool>),
 }
 
+impl TypedArray {
+    /// ONNX element type of the array
+    pub fn element_type(&self) -> ONNXTensorElementDataType {
+        match self {
+            TypedArray::F32Array(_) => f32::tensor_element_data_type(),
+            TypedArray::F64Array(_) => f64::tensor_element_data_type(),
+            TypedArray::I8Array(_) => i8::tensor_element_data_type(),
+            TypedArray::I16Array(_) => i16::tensor_element_data_type(),
+            TypedArray::I32Array(_) => i32::tensor_element_data_type(),
+            TypedArray::I64Array(_) => i64::tensor_element_data_type(),
+            TypedArray::U8Array(_) => u8::tensor_element_data_type(),
+            TypedArray::U16Array(_) => u16::tensor_element_data_type(),
+            TypedArray::U32Array(_) => u32::tensor_element_data_type(),
+            TypedArray::U64Array(_) => u64::tensor_element_data_type(),
+            TypedArray::BoolArray(_) => bool::tensor_element_data_type(),
+        }
+    }
+
+    /// Shape of the array
+    pub fn shape(&self) -> &[usize] {
+        match self {
+            TypedArray::F32Array(a) => a.shape(),
+            TypedArray::F64Array(a) => a.shape(),
+            TypedArray::I8Array(a) => a.shape(),
+            TypedArray::I16Array(a) => a.shape(),
+            TypedArray::I32Array(a) => a.shape(),
+            TypedArray::I64Array(a) => a.shape(),
+            TypedArray::U8Array(a) => a.shape(),
+            TypedArray::U16Array(a) => a.shape(),
+            TypedArray::U32Array(a) => a.shape(),
+            TypedArray::U64Array(a) => a.shape(),
+            TypedArray::BoolArray(a) => a.shape(),
+        }
+    }
+}
+
 #[enum_dispatch(TypedArray)]
 pub trait TypeToOnnxTensor {}
 
@@ -35,6 +80,7 @@ impl<T: TypeToTensorElementDataType> TypeToOnnxTensor for ArrayD<T> {}
 // Tensor stores OrtValue ptr and owns tensor data
 #[derive(Debug)]
 pub struct Tensor {
+    pub(crate) runtime: TrackedRuntime,
     pub(crate) ptr: *mut OrtValue,
     pub data: TypedArray,
 }
@@ -53,6 +99,153 @@ macro_rules! convert_typed_array {
     };
 }
 
+impl Drop for Tensor {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            if let Err(e) = call_ort!(self.runtime => ReleaseValue, self.ptr) {
+                warn!("Failed to release tensor: {}", e);
+            }
+        }
+        self.ptr = null_mut();
+    }
+}
+
+/// A tensor allocated by onnxruntime, like an output whose shape is known only after the run
+///
+/// The element type and the shape are read from the value, and the value is released when it's dropped.
+#[derive(Debug)]
+pub struct OrtOwnedTensor {
+    runtime: TrackedRuntime,
+    ptr: *mut OrtValue,
+    element_type: ONNXTensorElementDataType,
+    shape: Vec<usize>,
+}
+
+impl OrtOwnedTensor {
+    /// Take the ownership of values allocated by onnxruntime
+    ///
+    /// All values are released if any of them cannot be read.
+    pub(crate) fn from_values(runtime: &Runtime, values: Vec<*mut OrtValue>) -> Result<Vec<Self>> {
+        let mut tensors: Vec<Self> = values
+            .into_iter()
+            .map(|ptr| OrtOwnedTensor {
+                runtime: runtime.track(ObjectKind::Tensor),
+                ptr,
+                element_type: ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED,
+                shape: vec![],
+            })
+            .collect();
+        for tensor in &mut tensors {
+            tensor.read_type_and_shape()?;
+        }
+        Ok(tensors)
+    }
+
+    fn read_type_and_shape(&mut self) -> Result<()> {
+        let mut info: *mut OrtTensorTypeAndShapeInfo = null_mut();
+        let status = call_ort!(self.runtime => GetTensorTypeAndShape, self.ptr, &mut info)?;
+        check_status(&self.runtime, status, "GetTensorTypeAndShape")?;
+        // The info must be released even if reading it fails
+        let type_and_shape =
+            get_tensor_element_type(&*self.runtime, info).and_then(|element_type| {
+                let dim_count = get_dimension_count(&*self.runtime, info)?;
+                Ok((
+                    element_type,
+                    get_dimensions(&*self.runtime, info, dim_count)?,
+                ))
+            });
+        call_ort!(self.runtime => ReleaseTensorTypeAndShapeInfo, info)?;
+        let (element_type, dims) = type_and_shape?;
+        self.element_type = element_type;
+        self.shape = dims.into_iter().map(|d| d.max(0) as usize).collect();
+        Ok(())
+    }
+
+    /// ONNX element type of the tensor
+    pub fn element_type(&self) -> ONNXTensorElementDataType {
+        self.element_type
+    }
+
+    /// Shape of the tensor
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// View the data of the tensor, `T` must match the element type of the tensor
+    pub fn view<T: TypeToTensorElementDataType>(&self) -> Result<ArrayViewD<'_, T>> {
+        let expected = T::tensor_element_data_type();
+        if self.element_type != expected
+            || expected == ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING
+        {
+            return Err(Error::ElementTypeMismatch {
+                expected,
+                actual: self.element_type,
+            });
+        }
+        let mut data: *mut c_void = null_mut();
+        let status = call_ort!(self.runtime => GetTensorMutableData, self.ptr, &mut data)?;
+        check_status(&self.runtime, status, "GetTensorMutableData")?;
+        // Views of empty tensors need an aligned non-null pointer
+        if data.is_null() {
+            data = std::ptr::NonNull::<T>::dangling().as_ptr() as *mut c_void;
+        }
+        Ok(unsafe { ArrayViewD::from_shape_ptr(IxDyn(&self.shape), data as *const T) })
+    }
+
+    /// Copy the data into a [`Tensor`] of the same runtime, which owns its data
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn to_tensor(&self) -> Result<Tensor> {
+        let runtime = &self.runtime;
+        match self.element_type {
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<f32>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<f64>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<i8>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<i16>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<i32>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<i64>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<u8>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<u16>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<u32>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64 => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<u64>()?.to_owned())
+            }
+            ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL => {
+                create_tensor_with_ndarray_and_runtime(runtime, self.view::<bool>()?.to_owned())
+            }
+            element_type => Err(Error::UnsupportedDataType(element_type)),
+        }
+    }
+}
+
+impl Drop for OrtOwnedTensor {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            if let Err(e) = call_ort!(self.runtime => ReleaseValue, self.ptr) {
+                warn!("Failed to release tensor: {}", e);
+            }
+        }
+        self.ptr = null_mut();
+    }
+}
+
 convert_typed_array!(as_f32_array, f32, TypedArray::F32Array(d) => d);
 convert_typed_array!(as_f64_array, f64, TypedArray::F64Array(d) => d);
 convert_typed_array!(as_i8_array, i8, TypedArray::I8Array(d) => d);
@@ -67,39 +260,88 @@ convert_typed_array!(as_bool_array, bool, TypedArray::BoolArray(d) => d);
 
 pub fn create_tensor_with_ndarray_and_mem_info<T>(
     memory_info: &MemoryInfo,
-    mut array: ArrayD<T>,
+    array: ArrayD<T>,
 ) -> Result<Tensor>
 where
     T: TypeToTensorElementDataType,
     TypedArray: From<ArrayD<T>>,
 {
-    let mut ort_value_ptr: *mut OrtValue = null_mut();
-    let array_ptr = array.as_mut_ptr() as *mut c_void;
-    let array_len = array.len() * std::mem::size_of::<T>();
-    let shape: Vec<i64> = array.shape().iter().map(|d: &usize| *d as i64).collect();
-    let shape_ptr: *const i64 = shape.as_ptr();
-    let shape_len = array.shape().len();
-    let onnx_data_type = T::tensor_element_data_type();
-    let status = call_ort!(
-        CreateTensorWithDataAsOrtValue,
-        memory_info.ptr,
-        array_ptr,
-        array_len,
-        shape_ptr,
-        shape_len,
-        onnx_data_type,
-        &mut ort_value_ptr
-    );
-    check_status(status)?;
-
-    Ok(Tensor {
-        ptr: ort_value_ptr,
-        data: TypedArray::from(array),
-    })
+    create_tensor(&memory_info.runtime, memory_info.ptr, array)
 }
 
 // The ndarray must live longer than tensor
-pub fn create_tensor_with_ndarray<T>(mut array: ndarray::ArrayD<T>) -> Result<Tensor>
+pub fn create_tensor_with_ndarray<T>(array: ndarray::ArrayD<T>) -> Result<Tensor>
+where
+    T: TypeToTensorElementDataType,
+    TypedArray: From<ArrayD<T>>,
+{
+    create_tensor_with_ndarray_and_runtime(&default_runtime()?, array)
+}
+
+/// Create a tensor in the given runtime, using memory info of the runtime's default allocator
+pub fn create_tensor_with_ndarray_and_runtime<T>(
+    runtime: &Runtime,
+    array: ArrayD<T>,
+) -> Result<Tensor>
+where
+    T: TypeToTensorElementDataType,
+    TypedArray: From<ArrayD<T>>,
+{
+    let mem_info = get_default_memory_info(runtime)?;
+    create_tensor(runtime, mem_info, array)
+}
+
+/// Create a tensor of zeros, whose element type is known only at runtime
+#[allow(non_upper_case_globals)]
+pub(crate) fn create_zeroed_tensor(
+    runtime: &Runtime,
+    element_type: ONNXTensorElementDataType,
+    shape: &[usize],
+) -> Result<Tensor> {
+    let shape = ndarray::IxDyn(shape);
+    match element_type {
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<f32>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<f64>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<i8>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<i16>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<i32>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<i64>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<u8>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<u16>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<u32>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64 => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<u64>::zeros(shape))
+        }
+        ONNXTensorElementDataType_ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL => {
+            create_tensor_with_ndarray_and_runtime(runtime, ArrayD::<bool>::from_elem(shape, false))
+        }
+        element_type => Err(Error::UnsupportedDataType(element_type)),
+    }
+}
+
+fn create_tensor<T>(
+    runtime: &Runtime,
+    mem_info: *const OrtMemoryInfo,
+    mut array: ArrayD<T>,
+) -> Result<Tensor>
 where
     T: TypeToTensorElementDataType,
     TypedArray: From<ArrayD<T>>,
@@ -111,9 +353,8 @@ where
     let shape_ptr: *const i64 = shape.as_ptr();
     let shape_len = array.shape().len();
     let onnx_data_type = T::tensor_element_data_type();
-    let mem_info = get_default_memory_info()?;
     let status = call_ort!(
-        CreateTensorWithDataAsOrtValue,
+        runtime => CreateTensorWithDataAsOrtValue,
         mem_info,
         array_ptr,
         array_len,
@@ -121,10 +362,11 @@ where
         shape_len,
         onnx_data_type,
         &mut ort_value_ptr
-    );
-    check_status(status)?;
+    )?;
+    check_status(runtime, status, "CreateTensorWithDataAsOrtValue")?;
 
     Ok(Tensor {
+        runtime: runtime.track(ObjectKind::Tensor),
         ptr: ort_value_ptr,
         data: TypedArray::from(array),
     })
@@ -132,13 +374,13 @@ where
 
 #[cfg(test)]
 mod test {
-    use std::{path::Path, time::SystemTime};
+    use std::time::SystemTime;
 
     use ndarray::{ArrayD, IxDyn};
     use tracing::info;
     use tracing_test::traced_test;
 
-    use crate::{api::initialize_runtime, session::SessionBuilder};
+    use crate::{api::initialize_runtime_auto, session::SessionBuilder};
 
     use super::*;
 
@@ -217,12 +459,6 @@ mod test {
     }
 
     fn setup_runtime() {
-        #[cfg(target_os = "windows")]
-        let path = "D:\\Projects\\Rust\\ors\\onnxruntime.dll";
-        #[cfg(target_os = "macos")]
-        let path = "/usr/local/lib/libonnxruntime.1.12.0.dylib";
-        #[cfg(target_os = "linux")]
-        let path = "/usr/local/lib/libonnxruntime.so";
-        initialize_runtime(Path::new(path)).unwrap();
+        initialize_runtime_auto().unwrap();
     }
 }
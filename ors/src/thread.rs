@@ -0,0 +1,165 @@
+//! Creating threads of onnxruntime thread pools in Rust
+//!
+//! By default onnxruntime spawns its intra-op and inter-op threads itself. With a [`ThreadCreator`],
+//! threads are spawned by a Rust closure, which can name them, set their affinity and priority,
+//! or install a tracing subscriber before running the work of onnxruntime.
+use std::ffi::c_void;
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::null;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+use ors_sys::*;
+use tracing::{error, warn};
+
+type SpawnFn = dyn Fn(OrtWorker) -> std::io::Result<JoinHandle<()>> + Send + Sync;
+
+/// A closure which spawns threads for onnxruntime
+///
+/// The closure receives the work of a thread and must run it in a new thread, returning the handle of the thread.
+/// onnxruntime joins the thread when its thread pool is destroyed.
+///
+/// ## Example
+/// ```no_run
+/// use ors::thread::ThreadCreator;
+///
+/// let creator = ThreadCreator::new(|worker| {
+///     std::thread::Builder::new()
+///         .name("onnxruntime-worker".to_string())
+///         .spawn(move || worker.run())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ThreadCreator {
+    spawn: Arc<Spawner>,
+}
+
+// A sized wrapper, so that a thin pointer to it can be passed to onnxruntime
+struct Spawner(Box<SpawnFn>);
+
+impl ThreadCreator {
+    pub fn new<F>(spawn: F) -> Self
+    where
+        F: Fn(OrtWorker) -> std::io::Result<JoinHandle<()>> + Send + Sync + 'static,
+    {
+        Self {
+            spawn: Arc::new(Spawner(Box::new(spawn))),
+        }
+    }
+
+    /// Parameter of `create_thread`, which is valid while this creator is alive
+    pub(crate) fn options_ptr(&self) -> *mut c_void {
+        Arc::as_ptr(&self.spawn) as *mut c_void
+    }
+}
+
+impl Debug for ThreadCreator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThreadCreator").finish_non_exhaustive()
+    }
+}
+
+/// The work of a thread in an onnxruntime thread pool
+pub struct OrtWorker {
+    worker_fn: unsafe extern "C" fn(*mut c_void),
+    param: *mut c_void,
+}
+
+// The parameter is owned by onnxruntime, which expects the work to be run in another thread
+unsafe impl Send for OrtWorker {}
+
+impl OrtWorker {
+    /// Run the work, it returns when the thread pool is shutting down
+    pub fn run(self) {
+        unsafe { (self.worker_fn)(self.param) }
+    }
+}
+
+impl Debug for OrtWorker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrtWorker").finish_non_exhaustive()
+    }
+}
+
+/// `OrtCustomCreateThreadFn` calling the closure of a `ThreadCreator`, returns null if the thread cannot be created
+pub(crate) unsafe extern "C" fn create_thread(
+    options: *mut c_void,
+    worker_fn: OrtThreadWorkerFn,
+    param: *mut c_void,
+) -> OrtCustomThreadHandle {
+    let (spawner, worker_fn) = match ((options as *const Spawner).as_ref(), worker_fn) {
+        (Some(spawner), Some(worker_fn)) => (spawner, worker_fn),
+        _ => {
+            error!("Invalid parameters of creating an onnxruntime thread");
+            return null();
+        }
+    };
+    let worker = OrtWorker { worker_fn, param };
+    // Panics must not unwind into onnxruntime
+    match catch_unwind(AssertUnwindSafe(|| (spawner.0)(worker))) {
+        Ok(Ok(handle)) => Box::into_raw(Box::new(handle)) as OrtCustomThreadHandle,
+        Ok(Err(e)) => {
+            error!("Failed to create an onnxruntime thread: {}", e);
+            null()
+        }
+        Err(_) => {
+            error!("Creating an onnxruntime thread panicked");
+            null()
+        }
+    }
+}
+
+/// `OrtCustomJoinThreadFn` joining a thread created by `create_thread`
+pub(crate) unsafe extern "C" fn join_thread(handle: OrtCustomThreadHandle) {
+    if handle.is_null() {
+        return;
+    }
+    let handle = Box::from_raw(handle as *mut JoinHandle<()>);
+    if handle.join().is_err() {
+        warn!("An onnxruntime thread panicked");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static WORK_DONE: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe extern "C" fn work(param: *mut c_void) {
+        WORK_DONE.fetch_add(param as usize, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_create_and_join_thread() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let created_clone = created.clone();
+        let creator = ThreadCreator::new(move |worker| {
+            created_clone.fetch_add(1, Ordering::SeqCst);
+            std::thread::Builder::new()
+                .name("ors-test-worker".to_string())
+                .spawn(move || worker.run())
+        });
+        let handle = unsafe { create_thread(creator.options_ptr(), Some(work), 3 as *mut c_void) };
+        assert!(!handle.is_null());
+        unsafe { join_thread(handle) };
+        assert_eq!(1, created.load(Ordering::SeqCst));
+        assert_eq!(3, WORK_DONE.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_create_thread_failure() {
+        let creator = ThreadCreator::new(|_| panic!("no thread"));
+        let handle =
+            unsafe { create_thread(creator.options_ptr(), Some(work), std::ptr::null_mut()) };
+        assert!(handle.is_null());
+
+        let creator = ThreadCreator::new(|_| Err(std::io::Error::other("no thread")));
+        let handle =
+            unsafe { create_thread(creator.options_ptr(), Some(work), std::ptr::null_mut()) };
+        assert!(handle.is_null());
+    }
+}
@@ -0,0 +1,236 @@
+use std::ffi::CStr;
+use std::mem::{size_of, MaybeUninit};
+use std::path::PathBuf;
+
+use crate::error::{Error, Result};
+use ors_sys::*;
+use tracing::{debug, warn};
+
+/// The oldest API version of onnxruntime which can be used by ors
+///
+/// Functions introduced after the negotiated version are not available in the API table.
+pub const ORT_MIN_API_VERSION: u32 = 1;
+
+/// Information of the loaded onnxruntime
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeInfo {
+    /// Version string reported by onnxruntime, for example `1.12.0`
+    pub version: String,
+    /// The negotiated API version, which is supported by both onnxruntime and ors
+    pub api_version: u32,
+    /// Execution providers which are compiled into the loaded onnxruntime
+    pub available_providers: Vec<String>,
+    /// Path of the loaded shared library
+    ///
+    /// Without the `dynamic-loading` feature, it's the directory which onnxruntime is linked from
+    pub library_path: PathBuf,
+}
+
+/// API table of onnxruntime negotiated with the loaded library
+pub(crate) struct NegotiatedApi {
+    pub(crate) version: String,
+    pub(crate) api_version: u32,
+    pub(crate) api: OrtApi,
+}
+
+/// Parse the version string of onnxruntime, like `1.12.0`, to (major, minor, patch)
+pub(crate) fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut splitter = version.trim().splitn(3, '.');
+    let major = splitter.next()?.parse().ok()?;
+    let minor = splitter.next()?.parse().ok()?;
+    // Patch version may have suffix, like `1.12.0-dev`
+    let patch = splitter
+        .next()
+        .map(|p| {
+            p.chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+        })
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+/// Offset in bytes of a function in `OrtApi`
+macro_rules! api_offset {
+    ($function:ident) => {{
+        let api = MaybeUninit::<OrtApi>::uninit();
+        let base = api.as_ptr();
+        // Only the address of the field is taken, the uninitialized table is never read
+        let field = unsafe { std::ptr::addr_of!((*base).$function) };
+        field as usize - base as usize
+    }};
+}
+
+/// Size in bytes of the API table of given version
+///
+/// onnxruntime only appends functions to the end of `OrtApi`, the table returned by `GetApi(version)`
+/// is valid up to the last function of that version
+pub(crate) fn api_table_size(api_version: u32) -> usize {
+    let last_function_offset = match api_version {
+        1 => api_offset!(ReleaseCustomOpDomain),
+        2 => api_offset!(ReleaseModelMetadata),
+        3 => api_offset!(AddFreeDimensionOverrideByName),
+        4 => api_offset!(ReleaseAvailableProviders),
+        5 => api_offset!(ClearBoundOutputs),
+        6 => api_offset!(ReleaseArenaCfg),
+        7 => api_offset!(GetCurrentGpuDeviceId),
+        8 => api_offset!(CreateSessionFromArrayWithPrepackedWeightsContainer),
+        9 => api_offset!(GetSparseTensorIndices),
+        10 => api_offset!(SynchronizeBoundOutputs),
+        11 => api_offset!(SessionOptionsAppendExecutionProvider_MIGraphX),
+        _ => return size_of::<OrtApi>(),
+    };
+    last_function_offset + size_of::<*const std::ffi::c_void>()
+}
+
+/// Read the version string of onnxruntime and pick the highest API version supported by both sides
+pub(crate) fn negotiate_api(api_base: *const OrtApiBase) -> Result<NegotiatedApi> {
+    if api_base.is_null() {
+        return Err(Error::LibraryLoad("OrtGetApiBase returns null".to_string()));
+    }
+    let api_base = unsafe { &*api_base };
+    let version = match api_base.GetVersionString {
+        Some(get_version_string) => {
+            let version_ptr = unsafe { get_version_string() };
+            if version_ptr.is_null() {
+                String::from("unknown")
+            } else {
+                unsafe { CStr::from_ptr(version_ptr) }
+                    .to_string_lossy()
+                    .to_string()
+            }
+        }
+        None => String::from("unknown"),
+    };
+    let get_api = api_base.GetApi.ok_or_else(|| {
+        Error::LibraryLoad(format!("GetApi is missing in onnxruntime {}", version))
+    })?;
+
+    // onnxruntime 1.x uses its minor version as API version, don't ask for a newer one than it has
+    let highest = match parse_version(&version) {
+        Some((1, minor, _)) => minor.min(ORT_API_VERSION),
+        _ => ORT_API_VERSION,
+    };
+    debug!(
+        "onnxruntime version: {}, trying API version {} to {}",
+        version, highest, ORT_MIN_API_VERSION
+    );
+
+    for api_version in (ORT_MIN_API_VERSION..=highest).rev() {
+        let api = unsafe { get_api(api_version) };
+        if api.is_null() {
+            continue;
+        }
+        if api_version < ORT_API_VERSION {
+            warn!(
+                "onnxruntime {} is older than the bindings, using API version {} instead of {}",
+                version, api_version, ORT_API_VERSION
+            );
+        }
+        // Only copy the part of the table which exists in the negotiated version, the rest are `None`
+        let api = unsafe {
+            let mut table = MaybeUninit::<OrtApi>::zeroed();
+            std::ptr::copy_nonoverlapping(
+                api as *const u8,
+                table.as_mut_ptr() as *mut u8,
+                api_table_size(api_version),
+            );
+            table.assume_init()
+        };
+        return Ok(NegotiatedApi {
+            version,
+            api_version,
+            api,
+        });
+    }
+
+    Err(Error::UnsupportedApiVersion {
+        version,
+        min: ORT_MIN_API_VERSION,
+        max: ORT_API_VERSION,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(Some((1, 12, 0)), parse_version("1.12.0"));
+        assert_eq!(Some((1, 8, 1)), parse_version("1.8.1"));
+        assert_eq!(Some((1, 13, 0)), parse_version("1.13.0-dev"));
+        assert_eq!(None, parse_version("unknown"));
+    }
+
+    #[test]
+    fn test_api_table_size() {
+        assert_eq!(size_of::<OrtApi>(), api_table_size(ORT_API_VERSION));
+        for version in ORT_MIN_API_VERSION..ORT_API_VERSION {
+            assert!(api_table_size(version) < api_table_size(version + 1));
+        }
+        // API version 1 has 102 functions
+        assert_eq!(
+            102 * size_of::<*const std::ffi::c_void>(),
+            api_table_size(1)
+        );
+    }
+
+    #[test]
+    fn test_negotiate_null_api_base() {
+        assert!(negotiate_api(std::ptr::null()).is_err());
+    }
+
+    static mut FAKE_API: MaybeUninit<OrtApi> = MaybeUninit::zeroed();
+
+    unsafe extern "C" fn fake_release_env(_env: *mut OrtEnv) {}
+
+    // A fake onnxruntime 1.10.0 which only supports API version 1 to 10
+    unsafe extern "C" fn get_api_v10(version: u32) -> *const OrtApi {
+        if version > 10 {
+            std::ptr::null()
+        } else {
+            let api = std::ptr::addr_of_mut!(FAKE_API);
+            (*api).as_mut_ptr().as_mut().unwrap().ReleaseEnv = Some(fake_release_env);
+            (*api).as_ptr()
+        }
+    }
+
+    unsafe extern "C" fn get_api_none(_version: u32) -> *const OrtApi {
+        std::ptr::null()
+    }
+
+    unsafe extern "C" fn version_1_10() -> *const std::os::raw::c_char {
+        b"1.10.0\0".as_ptr() as *const std::os::raw::c_char
+    }
+
+    unsafe extern "C" fn version_2_0() -> *const std::os::raw::c_char {
+        b"2.0.0\0".as_ptr() as *const std::os::raw::c_char
+    }
+
+    #[test]
+    fn test_negotiate_older_runtime() {
+        let api_base = OrtApiBase {
+            GetApi: Some(get_api_v10),
+            GetVersionString: Some(version_1_10),
+        };
+        let negotiated = negotiate_api(&api_base).unwrap();
+        assert_eq!("1.10.0", negotiated.version);
+        assert_eq!(10, negotiated.api_version);
+        assert!(negotiated.api.ReleaseEnv.is_some());
+        // Functions introduced after API version 10 are removed from the table
+        assert!(negotiated.api.AddExternalInitializers.is_none());
+    }
+
+    #[test]
+    fn test_negotiate_no_common_version() {
+        let api_base = OrtApiBase {
+            GetApi: Some(get_api_none),
+            GetVersionString: Some(version_2_0),
+        };
+        let err = negotiate_api(&api_base).err().unwrap();
+        assert!(err.to_string().contains("2.0.0"));
+        assert!(matches!(err, Error::UnsupportedApiVersion { .. }));
+    }
+}